@@ -0,0 +1,55 @@
+use std::hint::black_box;
+
+use benches::test_scenes::{empty, palettes, static_squares, transform_squares};
+use criterion::{BenchmarkId, Criterion, SamplingMode, criterion_group, criterion_main};
+use ranim::{SceneConstructor, core::store::CoreItemStore, prelude::*};
+
+fn store_benchmark(c: &mut Criterion) {
+    let mut group = c.benchmark_group("store");
+    group.sampling_mode(SamplingMode::Linear).sample_size(10);
+
+    // 空场景作为基准，以及 palettes 示例这样的静态场景
+    for (name, scene) in [
+        ("update_empty", empty as fn(&mut RanimScene)),
+        ("update_palettes", palettes),
+    ] {
+        group.bench_function(name, |b| {
+            let timeline = scene.build_scene();
+            let mut store = CoreItemStore::new();
+            store.update(timeline.eval_at_alpha(0.5));
+            b.iter(|| {
+                black_box(store.update(timeline.eval_at_alpha(0.5)));
+            });
+        });
+    }
+
+    // 静态场景中，相邻帧的 CoreItem 不变，update 不需要写入
+    for n in [10, 100].iter() {
+        group.bench_with_input(BenchmarkId::new("update_static_squares", n), n, |b, n| {
+            let timeline = (|r: &mut RanimScene| static_squares(r, *n)).build_scene();
+            let mut store = CoreItemStore::new();
+            store.update(timeline.eval_at_alpha(0.5));
+            b.iter(|| {
+                black_box(store.update(timeline.eval_at_alpha(0.5)));
+            });
+        });
+        group.bench_with_input(
+            BenchmarkId::new("update_transform_squares", n),
+            n,
+            |b, n| {
+                let timeline = (|r: &mut RanimScene| transform_squares(r, *n)).build_scene();
+                let mut store = CoreItemStore::new();
+                let mut alpha = 0.0;
+                b.iter(|| {
+                    alpha = (alpha + 0.01) % 1.0;
+                    black_box(store.update(timeline.eval_at_alpha(alpha)));
+                });
+            },
+        );
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, store_benchmark);
+criterion_main!(benches);
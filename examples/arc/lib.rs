@@ -53,7 +53,7 @@ pub fn arc(r: &mut RanimScene) {
     r.timelines_mut().sync();
 
     r.insert_time_mark(
-        r.timelines().max_total_secs(),
+        r.current_sec(),
         TimeMark::Capture("preview.png".to_string()),
     );
 }
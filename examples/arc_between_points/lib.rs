@@ -49,7 +49,7 @@ fn arc_between_points(r: &mut RanimScene) {
         .play(arcs.lagged(0.2, |arc| arc.fade_in()).with_duration(3.0));
 
     r.insert_time_mark(
-        r.timelines().max_total_secs(),
+        r.current_sec(),
         TimeMark::Capture("preview.png".to_string()),
     );
 }
@@ -46,7 +46,7 @@ fn basic(r: &mut RanimScene) {
     r.timelines_mut().sync();
 
     r.insert_time_mark(
-        r.timelines().max_total_secs(),
+        r.current_sec(),
         TimeMark::Capture("preview.png".to_string()),
     );
 }
@@ -58,7 +58,7 @@ fn bubble_sort(r: &mut RanimScene, num: usize) {
     };
     let shift_right = DVec3::X * width_unit;
     let swap_shift = [shift_right, -shift_right];
-    let anim_swap = |r: &mut RanimScene, r_rectab: [&mut (TimelineId, Rectangle); 2]| {
+    let anim_swap = |r: &mut RanimScene, r_rectab: [&mut (ItemId<Rectangle>, Rectangle); 2]| {
         let timelines = r.timeline_mut(r_rectab).unwrap();
         timelines
             .into_iter()
@@ -99,7 +99,7 @@ fn bubble_sort(r: &mut RanimScene, num: usize) {
     }
 
     r.insert_time_mark(
-        r.timelines().max_total_secs(),
+        r.current_sec(),
         TimeMark::Capture(format!("preview-{num}.png")),
     );
 }
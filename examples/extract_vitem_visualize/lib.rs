@@ -40,9 +40,9 @@ fn ranim_text(r: &mut RanimScene) {
 
     r.timelines_mut().forward(1.0);
     r.timeline_mut(r_cam).play(cam.morph(|cam| {
-        cam.scale = 0.3;
+        // Frame the start of the text rotated by 90 degrees
         cam.up = DVec3::NEG_X;
-        cam.pos.shift(DVec3::NEG_X * 6.0);
+        cam.zoom_to_rect(DVec3::NEG_X * 6.0, 4.2, 2.4, 16.0 / 9.0, RectFit::Fit);
     }));
     r.timelines_mut().forward(1.0);
     r.timeline_mut(r_cam).play(
@@ -24,7 +24,7 @@ fn perspective_blend(r: &mut RanimScene) {
 
     let square_with_color = |color: color::AlphaColor<color::Srgb>| {
         VItem::from(Square::new(side_length).with(|square| {
-            square.set_color(color).set_fill_opacity(0.5);
+            square.set_color(color).set_opacities(0.5, 1.0);
         }))
     };
 
@@ -110,7 +110,7 @@ fn perspective_blend(r: &mut RanimScene) {
         .with_duration(2.0),
     );
     r.insert_time_mark(
-        r.timelines().max_total_secs(),
+        r.current_sec(),
         TimeMark::Capture("preview.png".to_string()),
     );
 }
@@ -93,9 +93,8 @@ fn ranim_logo(r: &mut RanimScene) {
     );
     let r_ranim_text = r.insert_empty();
 
-    r_logo.iter_mut().for_each(|(r_logo, item)| {
-        r.timeline_mut(*r_logo)
-            .play(item.write().with_duration(3.0).with_rate_func(smooth));
+    r.play_each(r_logo.iter().map(|(r_logo, _)| r_logo), |mut item| {
+        item.write().with_duration(3.0).with_rate_func(smooth)
     });
     r.timelines_mut().sync();
 
@@ -143,15 +142,15 @@ fn ranim_logo(r: &mut RanimScene) {
     r.timelines_mut().sync();
 
     r.insert_time_mark(
-        r.timelines().max_total_secs(),
+        r.current_sec(),
         TimeMark::Capture("preview.png".to_string()),
     );
     r.timelines_mut().forward(1.0);
 
-    r_logo.iter_mut().for_each(|(r_logo_part, item)| {
-        r.timeline_mut(*r_logo_part)
-            .play(item.unwrite().with_duration(3.0).with_rate_func(smooth));
-    });
+    r.play_each(
+        r_logo.iter().map(|(r_logo_part, _)| r_logo_part),
+        |mut item| item.unwrite().with_duration(3.0).with_rate_func(smooth),
+    );
     r.timeline_mut(r_ranim_text)
         .play(ranim_text.lagged(0.0, |item| {
             item.unwrite().with_duration(3.0).with_rate_func(linear)
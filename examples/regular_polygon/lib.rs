@@ -91,7 +91,7 @@ pub fn regular_polygon(r: &mut RanimScene) {
     r.timelines_mut().sync();
 
     r.insert_time_mark(
-        r.timelines().max_total_secs(),
+        r.current_sec(),
         TimeMark::Capture("preview.png".to_string()),
     );
 
@@ -94,7 +94,7 @@ fn selective_sort(r: &mut RanimScene, num: usize) {
     }
 
     r.insert_time_mark(
-        r.timelines().max_total_secs() / 2.0,
+        r.current_sec() / 2.0,
         TimeMark::Capture(format!("preview-{num}.png")),
     );
 }
@@ -8,28 +8,30 @@ use ranim::{
     anims::{
         creation::{CreationAnim, WritingAnim},
         fading::FadingAnim,
-        morph::MorphAnim,
+        morph::{Morph, MorphAnim},
     },
     color::palettes::{
         css,
         manim::{self, BLUE_C, RED_C},
     },
     items::vitem::{
-        self, VItem,
+        self, FillPattern, VItem,
         geometry::{ArcBetweenPoints, Polygon, Rectangle, Square},
     },
     prelude::*,
+    utils::bezier::PathBuilder,
 };
+use ranim_core::animation::Eval;
 
 // const SVG: &str = include_str!("../../assets/Ghostscript_Tiger.svg");
 
 #[scene]
 #[output(save_frames = true, dir = "./output/output")]
 fn test(r: &mut RanimScene) {
-    let _r_cam = r.insert(CameraFrame::default().with(|x| {
+    let _r_cam = r.insert_camera_with(|x| {
         x.perspective_blend = 1.0;
         x.pos = DVec3::Z * 5.0;
-    }));
+    });
     let mut square = VItem::from(Square::new(4.0).with(|x| {
         x.set_color(manim::BLUE_C).set_fill_opacity(0.5);
     }));
@@ -55,3 +57,86 @@ fn test(r: &mut RanimScene) {
     // }));
     r.timelines_mut().forward(1.0);
 }
+
+/// Morphs a curve whose points are crowded at the start into a square,
+/// left is matched by index, right is matched by arc length.
+#[scene]
+#[output(dir = "./output/output")]
+fn arc_length_morph(r: &mut RanimScene) {
+    let _r_cam = r.insert(CameraFrame::default());
+
+    let mut builder = PathBuilder::new();
+    builder.move_to(dvec3(-2.0, 0.0, 0.0));
+    for i in 1..=40 {
+        let x = -2.0 + 4.0 * (i as f64 / 40.0).powi(3);
+        builder.line_to(dvec3(x, (x * PI).sin(), 0.0));
+    }
+    let dense = VItem::from_vpoints(builder.vpoints().to_vec()).with(|x| {
+        x.set_stroke_color(manim::BLUE_C);
+    });
+    let sparse = VItem::from(Square::new(3.0)).with(|x| {
+        x.set_stroke_color(manim::RED_C);
+    });
+
+    for (offset, arc_length_match) in [(-3.5, false), (3.5, true)] {
+        let (mut src, mut dst) = (dense.clone(), sparse.clone());
+        src.shift(DVec3::X * offset);
+        dst.shift(DVec3::X * offset);
+        let r_item = r.insert(src.clone());
+        r.timeline_mut(r_item).forward(0.5).play(
+            Morph::new(src, dst)
+                .with_arc_length_match(arc_length_match)
+                .into_animation_cell()
+                .with_duration(2.0),
+        );
+    }
+    r.timelines_mut().forward(0.5);
+}
+
+#[scene]
+#[output(save_frames = true, dir = "./output/output")]
+fn fill_patterns(r: &mut RanimScene) {
+    let _r_cam = r.insert(CameraFrame::default());
+
+    let patterns = [
+        FillPattern::horizontal(0.2, 0.05),
+        FillPattern::diagonal(0.2, 0.05),
+        FillPattern::cross_hatch(0.25, 0.04),
+        FillPattern::dots(0.2, 0.05),
+    ];
+    for (i, pattern) in patterns.into_iter().enumerate() {
+        let x = -5.2 + 2.6 * i as f64;
+        // The top row is opaque, the bottom row is half transparent
+        for (y, opacity) in [(1.3, 1.0), (-1.3, 0.5)] {
+            let item = VItem::from(Square::new(2.0)).with(|item| {
+                item.set_color(manim::BLUE_C)
+                    .set_fill_opacity(opacity)
+                    .set_fill_pattern(pattern)
+                    .shift(dvec3(x, y, 0.0));
+            });
+            r.insert(item);
+        }
+    }
+
+    // The hole of the even-odd rule stays empty
+    let mut builder = PathBuilder::new();
+    builder
+        .move_to(dvec3(-0.7, -0.7, 0.0))
+        .line_to(dvec3(0.7, -0.7, 0.0))
+        .line_to(dvec3(0.7, 0.7, 0.0))
+        .line_to(dvec3(-0.7, 0.7, 0.0))
+        .close_path()
+        .move_to(dvec3(-0.35, -0.35, 0.0))
+        .line_to(dvec3(0.35, -0.35, 0.0))
+        .line_to(dvec3(0.35, 0.35, 0.0))
+        .line_to(dvec3(-0.35, 0.35, 0.0))
+        .close_path();
+    let framed = VItem::from_vpoints(builder.vpoints().to_vec()).with(|item| {
+        item.set_color(manim::RED_C)
+            .set_fill_opacity(1.0)
+            .set_fill_pattern(FillPattern::diagonal(0.15, 0.04))
+            .shift(dvec3(0.0, 3.15, 0.0));
+    });
+    r.insert(framed);
+    r.timelines_mut().forward(1.0);
+}
@@ -110,7 +110,7 @@ fn tetrahedron_spheres(r: &mut RanimScene) {
     }
 
     r.insert_time_mark(
-        r.timelines().max_total_secs(),
+        r.current_sec(),
         TimeMark::Capture("preview.png".to_string()),
     );
 }
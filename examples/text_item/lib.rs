@@ -35,7 +35,7 @@ fn text_item(r: &mut RanimScene) {
     });
 
     r.insert_time_mark(
-        r.timelines().max_total_secs() / 2.0,
+        r.current_sec() / 2.0,
         TimeMark::Capture("preview.png".to_string()),
     );
 }
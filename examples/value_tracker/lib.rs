@@ -0,0 +1,55 @@
+use ranim::{
+    anims::func::ValueTracker,
+    color::palettes::manim,
+    glam::{dvec2, dvec3},
+    items::vitem::geometry::{Circle, Line, Rectangle},
+    prelude::*,
+    utils::rate_functions::smooth,
+};
+
+/// The parabola that the dot moves along
+fn f(x: f64) -> f64 {
+    x * x / 4.0 - 2.0
+}
+
+#[scene]
+#[output(dir = "./output/value_tracker")]
+fn value_tracker(r: &mut RanimScene) {
+    let _r_cam = r.insert(CameraFrame::default());
+
+    let mut x = ValueTracker::new(-3.0);
+    let r_x = r.insert(x);
+
+    // A dot on the parabola and the tangent line at it, both follow the tracked x
+    r.always_redraw(move |ctx| {
+        let x = ctx.state(r_x).map(|x| x.0).unwrap_or_default();
+        Circle::new(0.1).with(|dot| {
+            dot.set_color(manim::YELLOW_C)
+                .move_to(dvec3(x - 1.5, f(x), 0.0));
+        })
+    });
+    r.always_redraw(move |ctx| {
+        let x = ctx.state(r_x).map(|x| x.0).unwrap_or_default();
+        let dir = dvec3(1.0, x / 2.0, 0.0).normalize();
+        let p = dvec3(x - 1.5, f(x), 0.0);
+        Line::new(p - dir * 1.5, p + dir * 1.5).with(|line| {
+            line.set_stroke_color(manim::BLUE_C);
+        })
+    });
+    // A bar whose height is the slope of the tangent line
+    r.always_redraw(move |ctx| {
+        let x = ctx.state(r_x).map(|x| x.0).unwrap_or_default();
+        let height = x / 2.0;
+        Rectangle::from_min_size(dvec3(4.5, 0.0, 0.0), dvec2(1.0, height)).with(|bar| {
+            bar.stroke_width = 0.0;
+            bar.set_fill_color(manim::RED_C.with_alpha(0.8));
+        })
+    });
+
+    r.timeline_mut(r_x)
+        .play(x.animate_to(3.0).with_duration(3.0).with_rate_func(smooth))
+        .play(x.animate_to(0.0).with_duration(2.0).with_rate_func(smooth));
+    r.timelines_mut().forward(0.5);
+
+    r.insert_time_mark(2.0, TimeMark::Capture("preview.png".to_string()));
+}
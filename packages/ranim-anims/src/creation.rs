@@ -95,18 +95,69 @@ impl<T: CreationRequirement> Eval<T> for Create<T> {
     }
 }
 
+/// The end of the item that [`UnCreate`] and [`Unwrite`] erase from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum EraseFrom {
+    /// Erase from the end, the start remains
+    #[default]
+    End,
+    /// Erase from the start, the end remains
+    Start,
+}
+
+impl EraseFrom {
+    /// The remaining range of the item when `remaining` of it remains
+    fn remaining_range(&self, remaining: f64) -> std::ops::Range<f64> {
+        match self {
+            Self::End => 0.0..remaining,
+            Self::Start => 1.0 - remaining..1.0,
+        }
+    }
+}
+
 /// The uncreate anim
 ///
-/// This anim uses [`Partial::get_partial_closed`] to uncreate the item
+/// This anim uses [`Partial::get_partial_closed`] to uncreate the item.
+///
+/// By default the item is fully erased from the end, use [`UnCreate::with_tail`] to leave
+/// a part of it, and [`UnCreate::with_erase_from`] to choose the end to erase from:
+///
+/// ```rust,ignore
+/// // Retract the line to its end, leaving 30% of it
+/// r.timeline_mut(r_line).play(
+///     UnCreate::new(line.clone())
+///         .with_tail(0.3)
+///         .with_erase_from(EraseFrom::Start)
+///         .into_animation_cell(),
+/// );
+/// ```
 pub struct UnCreate<T: CreationRequirement> {
     /// The original object
     pub original: T,
+    /// The fraction of the item that remains at the end
+    pub tail: f64,
+    /// The end to erase from
+    pub erase_from: EraseFrom,
 }
 
 impl<T: CreationRequirement> UnCreate<T> {
     /// Constructor
     pub fn new(target: T) -> Self {
-        Self { original: target }
+        Self {
+            original: target,
+            tail: 0.0,
+            erase_from: EraseFrom::End,
+        }
+    }
+    /// Leave `tail` (clamped to `0.0..=1.0`) of the item instead of fully erasing it.
+    pub fn with_tail(mut self, tail: f64) -> Self {
+        self.tail = tail.clamp(0.0, 1.0);
+        self
+    }
+    /// Set the end to erase from, see [`EraseFrom`].
+    pub fn with_erase_from(mut self, erase_from: EraseFrom) -> Self {
+        self.erase_from = erase_from;
+        self
     }
 }
 
@@ -120,9 +171,16 @@ impl<T: CreationRequirement> Eval<T> for UnCreate<T> {
         if alpha == 0.0 {
             self.original.clone()
         } else if 0.0 < alpha && alpha < 1.0 {
-            self.original.get_partial_closed(0.0..1.0 - alpha)
+            let remaining = 1.0 - alpha * (1.0 - self.tail);
+            self.original
+                .get_partial_closed(self.erase_from.remaining_range(remaining))
         } else if alpha == 1.0 {
-            T::empty()
+            if self.tail == 0.0 {
+                T::empty()
+            } else {
+                self.original
+                    .get_partial_closed(self.erase_from.remaining_range(self.tail))
+            }
         } else {
             panic!("the alpha is out of range: {alpha}");
         }
@@ -172,9 +230,16 @@ impl<T: WritingRequirement> Eval<T> for Write<T> {
 /// Unwrite
 ///
 /// First lerp fill_opacity to 0.0, then update with partial from 0.0..1.0 to 0.0..0.0
+///
+/// Like [`UnCreate`], [`Unwrite::with_tail`] leaves a part of the outline,
+/// and [`Unwrite::with_erase_from`] chooses the end to erase from.
 pub struct Unwrite<T: WritingRequirement> {
     pub(crate) original: T,
     pub(crate) outline: T,
+    /// The fraction of the outline that remains at the end
+    pub tail: f64,
+    /// The end to erase from
+    pub erase_from: EraseFrom,
 }
 
 impl<T: WritingRequirement> Unwrite<T> {
@@ -188,8 +253,20 @@ impl<T: WritingRequirement> Unwrite<T> {
         Self {
             original: target,
             outline,
+            tail: 0.0,
+            erase_from: EraseFrom::End,
         }
     }
+    /// Leave `tail` (clamped to `0.0..=1.0`) of the outline instead of fully erasing it.
+    pub fn with_tail(mut self, tail: f64) -> Self {
+        self.tail = tail.clamp(0.0, 1.0);
+        self
+    }
+    /// Set the end to erase from, see [`EraseFrom`].
+    pub fn with_erase_from(mut self, erase_from: EraseFrom) -> Self {
+        self.erase_from = erase_from;
+        self
+    }
 }
 
 impl<T: WritingRequirement> Eval<T> for Unwrite<T> {
@@ -200,9 +277,16 @@ impl<T: WritingRequirement> Eval<T> for Unwrite<T> {
         } else if alpha == 1.0 {
             self.outline.clone()
         } else if (1.0..2.0).contains(&alpha) {
-            self.outline.get_partial(0.0..2.0 - alpha)
+            let remaining = 1.0 - (alpha - 1.0) * (1.0 - self.tail);
+            self.outline
+                .get_partial(self.erase_from.remaining_range(remaining))
         } else if alpha == 2.0 {
-            T::empty()
+            if self.tail == 0.0 {
+                T::empty()
+            } else {
+                self.outline
+                    .get_partial(self.erase_from.remaining_range(self.tail))
+            }
         } else if alpha == 0.0 {
             self.original.clone()
         } else {
@@ -210,3 +294,84 @@ impl<T: WritingRequirement> Eval<T> for Unwrite<T> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A part of the unit segment, which is enough to observe the partial ranges
+    #[derive(Debug, Clone, PartialEq)]
+    struct Segment(f64, f64);
+
+    impl Partial for Segment {
+        fn get_partial(&self, range: std::ops::Range<f64>) -> Self {
+            let len = self.1 - self.0;
+            Self(self.0 + len * range.start, self.0 + len * range.end)
+        }
+        fn get_partial_closed(&self, range: std::ops::Range<f64>) -> Self {
+            self.get_partial(range)
+        }
+    }
+
+    impl Empty for Segment {
+        fn empty() -> Self {
+            Self(0.0, 0.0)
+        }
+    }
+
+    impl Interpolatable for Segment {
+        fn lerp(&self, target: &Self, t: f64) -> Self {
+            Self(self.0.lerp(&target.0, t), self.1.lerp(&target.1, t))
+        }
+    }
+
+    fn assert_near(a: Segment, b: Segment) {
+        assert!(
+            (a.0 - b.0).abs() < 1e-9 && (a.1 - b.1).abs() < 1e-9,
+            "{a:?} != {b:?}"
+        );
+    }
+
+    #[test]
+    fn test_create_group() {
+        let group = vec![Segment(0.0, 1.0), Segment(1.0, 2.0), Segment(2.0, 3.0)];
+        let anim = Create::new(group.clone());
+
+        // Each child takes a third of the range, the third one is not drawn yet
+        let partial = anim.eval_alpha(0.5);
+        assert_eq!(partial.len(), 2);
+        assert_eq!(partial[0], group[0]);
+        assert_near(partial[1].clone(), Segment(1.0, 1.5));
+
+        assert!(anim.eval_alpha(0.0).is_empty());
+        assert_eq!(anim.eval_alpha(1.0), group);
+
+        // A range starting in the middle drops the children before it
+        let partial = group.get_partial(0.5..1.0);
+        assert_eq!(partial.len(), 2);
+        assert_near(partial[0].clone(), Segment(1.5, 2.0));
+        assert_eq!(partial[1], group[2]);
+    }
+
+    #[test]
+    fn test_uncreate_tail() {
+        let segment = Segment(0.0, 1.0);
+
+        // Fully erased
+        let anim = UnCreate::new(segment.clone()).with_tail(0.0);
+        assert_eq!(anim.eval_alpha(0.0), segment);
+        assert_near(anim.eval_alpha(0.5), Segment(0.0, 0.5));
+        assert_eq!(anim.eval_alpha(1.0), Segment::empty());
+
+        // 30% remains
+        let anim = UnCreate::new(segment.clone()).with_tail(0.3);
+        assert_near(anim.eval_alpha(0.5), Segment(0.0, 0.65));
+        assert_near(anim.eval_alpha(1.0), Segment(0.0, 0.3));
+
+        // Erased from the start, the end remains
+        let anim = UnCreate::new(segment)
+            .with_tail(0.3)
+            .with_erase_from(EraseFrom::Start);
+        assert_near(anim.eval_alpha(1.0), Segment(0.7, 1.0));
+    }
+}
@@ -33,6 +33,25 @@ impl<T: FadingRequirement + Sized + 'static> FadingAnim for T {
     }
 }
 
+/// The methods to create [`FadeTransform`] anims for `T` that satisfies [`FadingRequirement`]
+pub trait FadeTransformAnim: FadingRequirement + Sized + 'static {
+    /// Create a [`FadeTransform`] anim from `self` to `dst`.
+    ///
+    /// Unlike [`crate::morph::MorphAnim::morph_to`], nothing is aligned or morphed,
+    /// `self` fades out while `dst` fades in at the same time. It is useful when the two
+    /// items are too different to morph nicely. The anim evaluates to the union of them,
+    /// and the last state is `vec![dst]`.
+    fn fade_transform_to(&self, dst: Self) -> AnimationCell<Vec<Self>>;
+}
+
+impl<T: FadingRequirement + Sized + 'static> FadeTransformAnim for T {
+    fn fade_transform_to(&self, dst: Self) -> AnimationCell<Vec<Self>> {
+        FadeTransform::new(self.clone(), dst)
+            .into_animation_cell()
+            .with_rate_func(smooth)
+    }
+}
+
 // MARK: Impl
 
 /// Fade-in animation.
@@ -86,3 +105,102 @@ impl<T: FadingRequirement> Eval<T> for FadeOut<T> {
         self.src.lerp(&self.dst, alpha)
     }
 }
+
+/// Cross-fade animation, fades out `src` while fading in `dst`.
+///
+/// Evaluates to `vec![src]` at `0.0`, `vec![dst]` at `1.0`, and `vec![src, dst]` with
+/// their opacities interpolated in between, `dst` is on top of `src`.
+pub struct FadeTransform<T: FadingRequirement> {
+    src: T,
+    dst: T,
+    transparent_src: T,
+    transparent_dst: T,
+}
+
+impl<T: FadingRequirement> FadeTransform<T> {
+    /// Constructor
+    pub fn new(src: T, dst: T) -> Self {
+        let mut transparent_src = src.clone();
+        transparent_src.set_opacity(0.0);
+        let mut transparent_dst = dst.clone();
+        transparent_dst.set_opacity(0.0);
+        Self {
+            src,
+            dst,
+            transparent_src,
+            transparent_dst,
+        }
+    }
+}
+
+impl<T: FadingRequirement> Eval<Vec<T>> for FadeTransform<T> {
+    fn eval_alpha(&self, alpha: f64) -> Vec<T> {
+        if alpha <= 0.0 {
+            vec![self.src.clone()]
+        } else if alpha >= 1.0 {
+            vec![self.dst.clone()]
+        } else {
+            vec![
+                self.src.lerp(&self.transparent_src, alpha),
+                self.transparent_dst.lerp(&self.dst, alpha),
+            ]
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A point with an opacity, which is enough to observe the fading
+    #[derive(Debug, Clone, PartialEq)]
+    struct Dot {
+        x: f64,
+        opacity: f32,
+    }
+
+    impl Opacity for Dot {
+        fn set_opacity(&mut self, opacity: f32) -> &mut Self {
+            self.opacity = opacity;
+            self
+        }
+    }
+
+    impl Interpolatable for Dot {
+        fn lerp(&self, target: &Self, t: f64) -> Self {
+            Self {
+                x: self.x.lerp(&target.x, t),
+                opacity: self.opacity.lerp(&target.opacity, t),
+            }
+        }
+    }
+
+    #[test]
+    fn test_fade_transform() {
+        let src = Dot {
+            x: 0.0,
+            opacity: 1.0,
+        };
+        let dst = Dot {
+            x: 2.0,
+            opacity: 0.8,
+        };
+        let anim = FadeTransform::new(src.clone(), dst.clone());
+
+        assert_eq!(anim.eval_alpha(0.0), vec![src.clone()]);
+
+        // Both are there, the src fading out in place and the dst fading in in place
+        let mid = anim.eval_alpha(0.5);
+        assert_eq!(mid.len(), 2);
+        assert_eq!(mid[0].x, src.x);
+        assert!((mid[0].opacity - 0.5).abs() < 1e-6);
+        assert_eq!(mid[1].x, dst.x);
+        assert!((mid[1].opacity - 0.4).abs() < 1e-6);
+
+        // The src is fully transparent right before the end
+        let end = anim.eval_alpha(1.0 - 1e-9);
+        assert!(end[0].opacity.abs() < 1e-6);
+
+        assert_eq!(anim.eval_alpha(1.0), vec![dst]);
+    }
+}
@@ -0,0 +1,167 @@
+use std::f64::consts::PI;
+
+use ranim_core::{
+    animation::{AnimationCell, Eval},
+    glam::{DQuat, DVec3},
+    traits::{Aabb, AabbPoint, Locate, RotateTransform, ScaleTransform, ShiftTransformExt},
+    utils::rate_functions::smooth,
+};
+
+// MARK: Require Trait
+/// The requirement of [`FlippingAnimation`]
+pub trait FlippingRequirement:
+    RotateTransform + ScaleTransform + ShiftTransformExt + Aabb + Clone
+{
+}
+impl<T: RotateTransform + ScaleTransform + ShiftTransformExt + Aabb + Clone> FlippingRequirement
+    for T
+{
+}
+
+// MARK: Anim Trait
+/// The methods to create flipping animations for `T` that satisfies [`FlippingRequirement`]
+pub trait FlippingAnim: FlippingRequirement + Sized + 'static {
+    /// Flip the item across the given axis through its center in 2D, see [`FlipMode::Scale`].
+    ///
+    /// The axis lies in the xy plane, e.g. [`DVec3::Y`] flips the item horizontally.
+    fn flipping(&mut self, axis: DVec3) -> AnimationCell<Self> {
+        self.flipping_with(axis, FlipMode::Scale)
+    }
+    /// Flip the item around the given axis through its center in 3D, see [`FlipMode::Rotate`].
+    fn flipping_3d(&mut self, axis: DVec3) -> AnimationCell<Self> {
+        self.flipping_with(axis, FlipMode::Rotate)
+    }
+    /// Flip the item across the given axis through its center with the given [`FlipMode`].
+    fn flipping_with(&mut self, axis: DVec3, mode: FlipMode) -> AnimationCell<Self> {
+        FlippingAnimation::new(self.clone(), axis, AabbPoint::CENTER.locate(self), mode)
+            .into_animation_cell()
+            .with_rate_func(smooth)
+            .apply_to(self)
+    }
+}
+
+impl<T: FlippingRequirement + 'static> FlippingAnim for T {}
+
+// MARK: Impl
+/// The smallest scale of [`FlipMode::Scale`], so that the item is never flattened to a line.
+const MIN_FLIP_SCALE: f64 = 1e-3;
+
+/// How a [`FlippingAnimation`] goes from the item to its reflection
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FlipMode {
+    /// Scale the item perpendicular to the axis (in the xy plane) from `1.0` to `-1.0`,
+    /// like a card flip seen from the front.
+    ///
+    /// The item is scaled by at least `1e-3` at the midpoint, so it is never degenerate.
+    #[default]
+    Scale,
+    /// Rotate the item around the axis by half a turn, a true 3D flip which is
+    /// seen in perspective with a perspective camera.
+    ///
+    /// The result is the reflection across the axis only for flat items in the xy plane,
+    /// their back is facing the camera.
+    Rotate,
+}
+
+/// Flipping animation, the item ends as its reflection across the axis through `point`.
+pub struct FlippingAnimation<T: FlippingRequirement> {
+    src: T,
+    axis: DVec3,
+    point: DVec3,
+    mode: FlipMode,
+}
+
+impl<T: FlippingRequirement> FlippingAnimation<T> {
+    /// Constructor
+    pub fn new(src: T, axis: DVec3, point: DVec3, mode: FlipMode) -> Self {
+        Self {
+            src,
+            axis: axis.normalize_or(DVec3::Y),
+            point,
+            mode,
+        }
+    }
+}
+
+impl<T: FlippingRequirement> Eval<T> for FlippingAnimation<T> {
+    fn eval_alpha(&self, alpha: f64) -> T {
+        let mut result = self.src.clone();
+        match self.mode {
+            FlipMode::Scale => {
+                let mut scale = 1.0 - 2.0 * alpha;
+                if scale.abs() < MIN_FLIP_SCALE {
+                    scale = MIN_FLIP_SCALE.copysign(scale);
+                }
+                // Rotate the direction perpendicular to the axis onto x, scale x, and rotate back
+                let perp = self.axis.cross(DVec3::Z).normalize_or(DVec3::X);
+                let (rot_axis, rot_angle) =
+                    DQuat::from_rotation_arc(perp, DVec3::X).to_axis_angle();
+                result.with_origin(self.point, |x| {
+                    x.rotate_on_axis(rot_axis, rot_angle)
+                        .scale(DVec3::new(scale, 1.0, 1.0))
+                        .rotate_on_axis(rot_axis, -rot_angle);
+                });
+            }
+            FlipMode::Rotate => {
+                result.with_origin(self.point, |x| {
+                    x.rotate_on_axis(self.axis, PI * alpha);
+                });
+            }
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ranim_core::{
+        components::vpoint::VPointVec,
+        glam::{DVec3, dvec3},
+    };
+
+    use super::*;
+
+    fn assert_points_eq(a: &[DVec3], b: &[DVec3]) {
+        assert_eq!(a.len(), b.len());
+        for (a, b) in a.iter().zip(b) {
+            assert!(a.distance(*b) < 1e-9, "{a} != {b}");
+        }
+    }
+
+    #[test]
+    fn test_flipping() {
+        let triangle = VPointVec(vec![
+            dvec3(0.0, 0.0, 0.0),
+            dvec3(1.0, 0.0, 0.0),
+            dvec3(2.0, 0.0, 0.0),
+            dvec3(2.0, 1.0, 0.0),
+            dvec3(2.0, 2.0, 0.0),
+        ]);
+
+        for mode in [FlipMode::Scale, FlipMode::Rotate] {
+            for axis in [DVec3::X, DVec3::Y, dvec3(1.0, 1.0, 0.0)] {
+                let mut item = triangle.clone();
+                item.flipping_with(axis, mode);
+                // Flipped horizontally across the center x = 1.0
+                if axis == DVec3::Y {
+                    assert_points_eq(
+                        &item,
+                        &triangle
+                            .iter()
+                            .map(|p| dvec3(2.0 - p.x, p.y, p.z))
+                            .collect::<Vec<_>>(),
+                    );
+                }
+                item.flipping_with(axis, mode);
+                assert_points_eq(&item, &triangle);
+            }
+        }
+
+        // Not degenerate at the midpoint
+        let mid = FlippingAnimation::new(triangle.clone(), DVec3::Y, DVec3::ONE, FlipMode::Scale)
+            .eval_alpha(0.5);
+        let [min, max] = mid.aabb();
+        assert!(max.x - min.x > 0.0);
+        assert_eq!(max.y - min.y, 2.0);
+    }
+}
@@ -1,4 +1,9 @@
-use ranim_core::animation::{AnimationCell, Eval};
+use ranim_core::{
+    Extract,
+    animation::{AnimationCell, Eval},
+    core_item::CoreItem,
+    traits::Interpolatable,
+};
 
 // MARK: Require Trait
 /// The requirement for [`Func`]
@@ -45,3 +50,142 @@ impl<T: FuncRequirement> Eval<T> for Func<T> {
         (self.f)(&self.src, alpha)
     }
 }
+
+// MARK: ValueTracker
+/// A value animated on a timeline, like manim's `ValueTracker`.
+///
+/// It extracts no [`CoreItem`], so it is not rendered, but it can be inserted and animated
+/// like any item. Its value at any sec can be read with [`Timeline::state_at`] while
+/// constructing, to drive the other items that depend on it:
+///
+/// ```rust
+/// use ranim_anims::func::ValueTracker;
+/// use ranim_core::RanimScene;
+///
+/// let mut r = RanimScene::new();
+/// let mut tracker = ValueTracker::new(0.0);
+/// let r_tracker = r.insert(tracker.clone());
+/// r.timeline_mut(r_tracker)
+///     .play(tracker.animate_to(10.0).with_duration(2.0));
+///
+/// let value = |sec| r.timeline(r_tracker).state_at::<ValueTracker<f64>>(sec).unwrap().0;
+/// assert_eq!(value(1.0), 5.0);
+/// assert_eq!(value(3.0), 10.0);
+/// ```
+///
+/// [`Timeline::state_at`]: ranim_core::timeline::Timeline::state_at
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct ValueTracker<T>(pub T);
+
+impl<T: Interpolatable + Clone + 'static> ValueTracker<T> {
+    /// Constructor
+    pub fn new(value: T) -> Self {
+        Self(value)
+    }
+    /// Create an anim interpolating the value to `target`.
+    pub fn animate_to(&mut self, target: T) -> AnimationCell<Self> {
+        let target = Self(target);
+        Func::new(self.clone(), move |src, alpha| src.lerp(&target, alpha))
+            .into_animation_cell()
+            .apply_to(self)
+    }
+}
+
+impl<T: Interpolatable> Interpolatable for ValueTracker<T> {
+    fn lerp(&self, target: &Self, t: f64) -> Self {
+        Self(self.0.lerp(&target.0, t))
+    }
+}
+
+impl<T> Extract for ValueTracker<T> {
+    type Target = CoreItem;
+    fn extract_into(&self, _buf: &mut Vec<Self::Target>) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use ranim_core::{
+        RanimScene,
+        glam::{DVec3, dvec3},
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_value_tracker() {
+        let mut r = RanimScene::new();
+        let mut scalar = ValueTracker::new(1.0);
+        let mut vector = ValueTracker::new(DVec3::ZERO);
+        let r_scalar = r.insert(scalar);
+        let r_vector = r.insert(vector);
+        r.timeline_mut(r_scalar).play(scalar.animate_to(3.0));
+        r.timeline_mut(r_vector)
+            .play(vector.animate_to(dvec3(2.0, 4.0, 0.0)));
+        assert_eq!(scalar.0, 3.0);
+
+        // Read while constructing, the end state remains after the anim
+        let timeline = r.timeline(r_scalar);
+        assert_eq!(
+            timeline.state_at::<ValueTracker<f64>>(0.5),
+            Some(ValueTracker(2.0))
+        );
+        assert_eq!(
+            timeline.state_at::<ValueTracker<f64>>(5.0),
+            Some(ValueTracker(3.0))
+        );
+        assert_eq!(
+            r.timeline(r_vector).state_at::<ValueTracker<DVec3>>(0.5),
+            Some(ValueTracker(dvec3(1.0, 2.0, 0.0)))
+        );
+
+        // The tracker is not rendered
+        let scene = r.seal();
+        assert_eq!(scene.eval_at_sec(0.5).count(), 0);
+        let timeline = scene.timelines_iter().nth(r_scalar.id()).unwrap();
+        assert_eq!(
+            timeline.state_at::<ValueTracker<f64>>(0.5),
+            Some(ValueTracker(2.0))
+        );
+        assert_eq!(timeline.state_at::<ValueTracker<f64>>(5.0), None);
+    }
+
+    #[test]
+    fn test_always_redraw() {
+        use ranim_core::{
+            core_item::vitem::VItem,
+            glam::{Vec4, vec4},
+        };
+
+        // A bar whose height follows the tracker
+        let bar = |height: f32| VItem {
+            points: vec![
+                Vec4::ZERO,
+                vec4(0.0, height / 2.0, 0.0, 0.0),
+                vec4(0.0, height, 0.0, 0.0),
+            ],
+            ..Default::default()
+        };
+
+        let mut r = RanimScene::new();
+        let mut height = ValueTracker::new(1.0);
+        let r_height = r.insert(height);
+        let r_bar = r.always_redraw(move |ctx| {
+            bar(ctx.state(r_height).map(|x| x.0).unwrap_or_default() as f32)
+        });
+        r.timeline_mut(r_height)
+            .play(height.animate_to(3.0).with_duration(2.0));
+        r.timeline_mut(r_height).hide().forward(1.0);
+
+        let scene = r.seal();
+        let bar_at = |sec| {
+            scene
+                .eval_at_sec(sec)
+                .find_map(|((id, _), item)| (id == r_bar.id()).then_some(item))
+        };
+        for (sec, height) in [(0.0, 1.0), (0.5, 1.5), (1.0, 2.0), (2.0, 3.0)] {
+            assert_eq!(bar_at(sec), Some(CoreItem::VItem(bar(height))), "{sec}");
+        }
+        // The tracker is hidden
+        assert_eq!(bar_at(2.5), Some(CoreItem::VItem(bar(0.0))));
+    }
+}
@@ -1,4 +1,4 @@
-use ranim_core::animation::{AnimationCell, Eval};
+use ranim_core::animation::{AnimationCell, AnimationInfo, Eval};
 
 // MARK: LaggedAnim
 /// The methods to create animations for `Group<T>`
@@ -16,6 +16,24 @@ pub trait LaggedAnim<T>: Sized + 'static {
         lag_ratio: f64,
         anim_func: impl FnMut(&mut T) -> AnimationCell<T>,
     ) -> AnimationCell<Vec<T>>;
+    /// Create a [`Lagged`] anim, and also returns the [`LaggedTimes`] of its children.
+    ///
+    /// This is useful when something needs to be scheduled right after
+    /// a specific child finishes.
+    ///
+    /// ```rust,ignore
+    /// let start = r.timeline(t).cur_sec();
+    /// let (anim, times) = items.lagged_with_times(0.5, |x| x.fade_in());
+    /// let info = anim.info.clone().at(start);
+    /// r.timeline_mut(t).play(anim);
+    /// // The sec when the second item finishes fading in
+    /// let (_, end) = times.sec_range(1, &info).unwrap();
+    /// ```
+    fn lagged_with_times(
+        &mut self,
+        lag_ratio: f64,
+        anim_func: impl FnMut(&mut T) -> AnimationCell<T>,
+    ) -> (AnimationCell<Vec<T>>, LaggedTimes);
 }
 
 impl<T: Clone + 'static, I> LaggedAnim<T> for I
@@ -30,6 +48,15 @@ where
     ) -> AnimationCell<Vec<T>> {
         Lagged::new(lag_ratio, self.into_iter().map(anim_func).collect()).into_animation_cell()
     }
+    fn lagged_with_times(
+        &mut self,
+        lag_ratio: f64,
+        anim_func: impl FnMut(&mut T) -> AnimationCell<T>,
+    ) -> (AnimationCell<Vec<T>>, LaggedTimes) {
+        let lagged = Lagged::new(lag_ratio, self.into_iter().map(anim_func).collect());
+        let times = lagged.times();
+        (lagged.into_animation_cell(), times)
+    }
 }
 
 // pub fn lagged<T, I>(
@@ -56,27 +83,167 @@ impl<T> Lagged<T> {
     pub fn new(lag_ratio: f64, anims: Vec<AnimationCell<T>>) -> Self {
         Self { anims, lag_ratio }
     }
+    /// The time span of each child in the inner alpha (before rate func mapping) of [`Lagged`]
+    pub fn times(&self) -> LaggedTimes {
+        LaggedTimes {
+            ranges: (0..self.anims.len())
+                .map(|i| child_alpha_range(self.anims.len(), self.lag_ratio, i))
+                .collect(),
+        }
+    }
+}
+
+/// The start and end alpha of the `idx`-th child among `n` children.
+fn child_alpha_range(n: usize, lag_ratio: f64, idx: usize) -> (f64, f64) {
+    // -|--
+    //  -|--
+    //   -|--
+    // total_time - unit_time * (1.0 - lag_ratio)  = unit_time * lag_ratio * n
+    // total_time = unit_time * (1.0 + (n - 1) lag_ratio)
+    let unit_time = 1.0 / (1.0 + n.saturating_sub(1) as f64 * lag_ratio);
+    let start = unit_time * lag_ratio * idx as f64;
+    (start, start + unit_time)
+}
+
+// MARK: LaggedTimes
+/// The time span of each child of a [`Lagged`] anim.
+///
+/// The spans are stored in the inner alpha of the lagged anim, i.e. the alpha
+/// after the [`AnimationInfo::rate_func`] is applied, which is exactly what
+/// [`Lagged`] uses to evaluate its children. Use [`LaggedTimes::sec_range`] to
+/// map them to secs with the info of the played [`AnimationCell`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct LaggedTimes {
+    ranges: Vec<(f64, f64)>,
+}
+
+impl LaggedTimes {
+    /// The number of children
+    pub fn len(&self) -> usize {
+        self.ranges.len()
+    }
+    /// Whether there is no child
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+    /// The (start, end) inner alpha of the `idx`-th child
+    pub fn alpha_range(&self, idx: usize) -> Option<(f64, f64)> {
+        self.ranges.get(idx).copied()
+    }
+    /// The (start, end) sec of the `idx`-th child, under the given [`AnimationInfo`].
+    ///
+    /// The rate func is inverted numerically, so it is expected to be
+    /// monotonically non-decreasing, which holds for all the built-in ones.
+    pub fn sec_range(&self, idx: usize, info: &AnimationInfo) -> Option<(f64, f64)> {
+        self.alpha_range(idx).map(|(start, end)| {
+            (
+                info.start_sec + inverse_rate_func(info.rate_func, start) * info.duration_secs,
+                info.start_sec + inverse_rate_func(info.rate_func, end) * info.duration_secs,
+            )
+        })
+    }
+    /// The (start, end) secs of all the children, under the given [`AnimationInfo`].
+    pub fn sec_ranges(&self, info: &AnimationInfo) -> Vec<(f64, f64)> {
+        (0..self.len())
+            .filter_map(|idx| self.sec_range(idx, info))
+            .collect()
+    }
+}
+
+/// Find the smallest outer alpha whose mapped alpha reaches `alpha`.
+fn inverse_rate_func(rate_func: fn(f64) -> f64, alpha: f64) -> f64 {
+    if rate_func(0.0) >= alpha {
+        return 0.0;
+    }
+    if rate_func(1.0) <= alpha {
+        return 1.0;
+    }
+    let (mut lo, mut hi) = (0.0, 1.0);
+    for _ in 0..64 {
+        let mid = (lo + hi) / 2.0;
+        if rate_func(mid) < alpha {
+            lo = mid;
+        } else {
+            hi = mid;
+        }
+    }
+    hi
 }
 
 impl<T: Clone, I: FromIterator<T>> Eval<I> for Lagged<T> {
     fn eval_alpha(&self, alpha: f64) -> I {
-        // -|--
-        //  -|--
-        //   -|--
-        // total_time - unit_time * (1.0 - lag_ratio)  = unit_time * lag_ratio * n
-        // total_time = unit_time * (1.0 + (n - 1) lag_ratio)
-        let unit_time = 1.0 / (1.0 + (self.anims.len() - 1) as f64 * self.lag_ratio);
-        let unit_lagged_time = unit_time * self.lag_ratio;
         self.anims
             .iter()
             .enumerate()
             .map(|(i, anim)| {
-                let start = unit_lagged_time * i as f64;
+                let (start, end) = child_alpha_range(self.anims.len(), self.lag_ratio, i);
 
-                let alpha = (alpha - start) / unit_time;
+                let alpha = (alpha - start) / (end - start);
                 let alpha = alpha.clamp(0.0, 1.0);
                 anim.eval_alpha(alpha)
             })
             .collect()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use ranim_core::utils::rate_functions::smooth;
+
+    use super::*;
+
+    /// Evaluates to its alpha, so that the progress of each child can be observed
+    struct Progress;
+
+    impl Eval<f64> for Progress {
+        fn eval_alpha(&self, alpha: f64) -> f64 {
+            alpha
+        }
+    }
+
+    fn assert_near(a: (f64, f64), b: (f64, f64)) {
+        assert!(
+            (a.0 - b.0).abs() < 1e-9 && (a.1 - b.1).abs() < 1e-9,
+            "{a:?} != {b:?}"
+        );
+    }
+
+    #[test]
+    fn test_lagged_times() {
+        let mut items = vec![0.0; 3];
+        let (anim, times) = items.lagged_with_times(0.5, |_| Progress.into_animation_cell());
+        assert_eq!(times.len(), 3);
+        // Each child takes half of the anim, and starts a quarter after the previous one
+        [(0.0, 0.5), (0.25, 0.75), (0.5, 1.0)]
+            .into_iter()
+            .enumerate()
+            .for_each(|(i, range)| assert_near(times.alpha_range(i).unwrap(), range));
+        assert_eq!(times.alpha_range(3), None);
+
+        let info = anim.with_duration(4.0).at(2.0).info;
+        let ranges = times.sec_ranges(&info);
+        [(2.0, 4.0), (3.0, 5.0), (4.0, 6.0)]
+            .into_iter()
+            .zip(ranges)
+            .for_each(|(expected, range)| assert_near(range, expected));
+    }
+
+    #[test]
+    fn test_lagged_times_with_rate_func() {
+        let mut items = vec![0.0; 4];
+        let (anim, times) = items.lagged_with_times(0.3, |_| Progress.into_animation_cell());
+        let anim = anim.with_duration(3.0).at(1.0).with_rate_func(smooth);
+        let progress = |sec: f64| anim.eval_alpha(anim.info.map_sec_to_alpha(sec).unwrap());
+
+        // Every child starts and ends at its secs under the rate func
+        for (i, (start, end)) in times.sec_ranges(&anim.info).into_iter().enumerate() {
+            assert!(start < end);
+            if start > anim.info.start_sec {
+                assert_eq!(progress(start - 1e-3)[i], 0.0);
+            }
+            assert!(progress(start + 1e-3)[i] > 0.0);
+            assert!(progress(end - 1e-3)[i] < 1.0);
+            assert!((progress(end)[i] - 1.0).abs() < 1e-9);
+        }
+    }
+}
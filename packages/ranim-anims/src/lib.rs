@@ -74,11 +74,17 @@
 pub mod creation;
 /// Fading animation
 pub mod fading;
+/// Flipping animation
+pub mod flipping;
 /// Func animation
 pub mod func;
 /// Lagged animation
 pub mod lagged;
+/// Matrix animation
+pub mod matrix;
 /// Morph animation
 pub mod morph;
 /// Rotating animation
 pub mod rotating;
+/// Spring animation
+pub mod spring;
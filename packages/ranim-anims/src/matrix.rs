@@ -0,0 +1,176 @@
+use ranim_core::{
+    animation::{AnimationCell, Eval},
+    glam::{DMat3, DMat4, DQuat, DVec3},
+    traits::PointsFunc,
+    utils::rate_functions::smooth,
+};
+
+// MARK: Require Trait
+/// The requirement of [`ApplyMatrix`]
+pub trait ApplyMatrixRequirement: PointsFunc + Clone {}
+impl<T: PointsFunc + Clone> ApplyMatrixRequirement for T {}
+
+// MARK: Anim Trait
+/// The methods to create matrix animations for `T` that satisfies [`ApplyMatrixRequirement`]
+pub trait ApplyMatrixAnim: ApplyMatrixRequirement + Sized + 'static {
+    /// Continuously apply the transform from the identity to `target`,
+    /// the item ends as [`PointsFunc::apply_matrix`] with `target`.
+    ///
+    /// This visualizes what a matrix does to the space, e.g. applied to a
+    /// `NumberPlane` and the vectors on it. See [`ApplyMatrix`] for how the
+    /// intermediate transforms are chosen.
+    fn apply_matrix_anim(&mut self, target: DMat4) -> AnimationCell<Self> {
+        ApplyMatrix::new(self.clone(), target)
+            .into_animation_cell()
+            .with_rate_func(smooth)
+            .apply_to(self)
+    }
+}
+
+impl<T: ApplyMatrixRequirement + 'static> ApplyMatrixAnim for T {}
+
+// MARK: Impl
+/// The matrix path of an [`ApplyMatrix`]
+enum MatrixPath {
+    /// `rotation(t) * (I + (stretch - I) * t)` for the linear part, with the translation lerped
+    Polar {
+        rotation: DQuat,
+        stretch: DMat3,
+        translation: DVec3,
+    },
+    /// `I + (target - I) * t`
+    Lerp(DMat4),
+}
+
+/// Matrix animation, which applies a transform going from the identity to the target matrix.
+///
+/// Lerping the matrices directly shrinks a rotation through a degenerate matrix, e.g. the
+/// half turn would collapse the item to a point at the midpoint. Instead, the linear part is
+/// split with the polar decomposition into a rotation and a stretch, the rotation is slerped
+/// and the stretch is lerped, so a rotation keeps the item's shape all the way.
+///
+/// The determinant of a matrix with a reflection has to cross zero on the way, the reflection
+/// is put in the stretch so that the item is flattened along the x axis only once.
+/// Singular and projective matrices are lerped directly.
+pub struct ApplyMatrix<T: ApplyMatrixRequirement> {
+    src: T,
+    path: MatrixPath,
+}
+
+impl<T: ApplyMatrixRequirement> ApplyMatrix<T> {
+    /// Constructor
+    pub fn new(src: T, target: DMat4) -> Self {
+        let is_affine = target.row(3).abs_diff_eq(DMat4::IDENTITY.row(3), 1e-12);
+        let linear = DMat3::from_mat4(target);
+        let path = match polar_decomposition(linear).filter(|_| is_affine) {
+            Some((orthogonal, stretch)) => {
+                // Move the reflection into the stretch, `F * F = I`
+                let (rotation, stretch) = if orthogonal.determinant() < 0.0 {
+                    let flip = DMat3::from_diagonal(DVec3::new(-1.0, 1.0, 1.0));
+                    (orthogonal * flip, flip * stretch)
+                } else {
+                    (orthogonal, stretch)
+                };
+                MatrixPath::Polar {
+                    rotation: DQuat::from_mat3(&rotation).normalize(),
+                    stretch,
+                    translation: target.w_axis.truncate(),
+                }
+            }
+            None => MatrixPath::Lerp(target),
+        };
+        Self { src, path }
+    }
+    /// The matrix at the progress `alpha`
+    pub fn matrix_at(&self, alpha: f64) -> DMat4 {
+        match &self.path {
+            MatrixPath::Polar {
+                rotation,
+                stretch,
+                translation,
+            } => {
+                let rotation = DMat3::from_quat(DQuat::IDENTITY.slerp(*rotation, alpha));
+                let stretch = DMat3::IDENTITY + (*stretch - DMat3::IDENTITY) * alpha;
+                let mut matrix = DMat4::from_mat3(rotation * stretch);
+                matrix.w_axis = (*translation * alpha).extend(1.0);
+                matrix
+            }
+            MatrixPath::Lerp(target) => DMat4::IDENTITY + (*target - DMat4::IDENTITY) * alpha,
+        }
+    }
+}
+
+/// Decompose `m` into `q * s`, where `q` is orthogonal and `s` is symmetric positive definite.
+///
+/// Returns `None` if `m` is (nearly) singular.
+fn polar_decomposition(m: DMat3) -> Option<(DMat3, DMat3)> {
+    if m.determinant().abs() < 1e-9 {
+        return None;
+    }
+    // Newton's iteration `q = (q + q^-T) / 2` converges quadratically to the orthogonal factor
+    let mut q = m;
+    for _ in 0..100 {
+        let next = (q + q.inverse().transpose()) * 0.5;
+        let converged = next.abs_diff_eq(q, 1e-15);
+        q = next;
+        if converged {
+            break;
+        }
+    }
+    let s = q.transpose() * m;
+    // Get rid of the asymmetric numerical error
+    Some((q, (s + s.transpose()) * 0.5))
+}
+
+impl<T: ApplyMatrixRequirement> Eval<T> for ApplyMatrix<T> {
+    fn eval_alpha(&self, alpha: f64) -> T {
+        let mut result = self.src.clone();
+        result.apply_matrix(self.matrix_at(alpha));
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::PI;
+
+    use ranim_core::{components::vpoint::VPointVec, glam::dvec3, traits::Aabb};
+
+    use super::*;
+
+    #[test]
+    fn test_apply_matrix_anim() {
+        let square = VPointVec(vec![
+            dvec3(-1.0, -1.0, 0.0),
+            dvec3(1.0, -1.0, 0.0),
+            dvec3(1.0, 1.0, 0.0),
+            dvec3(-1.0, 1.0, 0.0),
+            dvec3(-1.0, -1.0, 0.0),
+        ]);
+        let rotation = DMat4::from_rotation_z(PI);
+        let shear = DMat4::from_cols_array(&[
+            1.0, 0.0, 0.0, 0.0, //
+            1.0, 1.0, 0.0, 0.0, //
+            0.0, 0.0, 1.0, 0.0, //
+            0.0, 0.0, 0.0, 1.0,
+        ]);
+        let reflection = DMat4::from_scale(dvec3(1.0, -2.0, 1.0));
+        let translated = DMat4::from_translation(dvec3(1.0, 2.0, 0.0)) * shear;
+        let singular = DMat4::from_scale(dvec3(1.0, 0.0, 1.0));
+
+        for target in [rotation, shear, reflection, translated, singular] {
+            let mut item = square.clone();
+            item.apply_matrix_anim(target);
+            let mut expected = square.clone();
+            expected.apply_matrix(target);
+            for (a, b) in item.iter().zip(expected.iter()) {
+                assert!(a.distance(*b) < 1e-9, "{a} != {b}");
+            }
+        }
+
+        // The half turn is a rotation all the way, not collapsing at the midpoint
+        let mid = ApplyMatrix::new(square.clone(), rotation).eval_alpha(0.5);
+        let [min, max] = mid.aabb();
+        assert!((max - min).abs_diff_eq(dvec3(2.0, 2.0, 0.0), 1e-9));
+    }
+}
@@ -1,7 +1,13 @@
+use std::cell::{Cell, RefCell};
+
 use ranim_core::{
     animation::{AnimationCell, Eval},
-    traits::{Alignable, Interpolatable},
-    utils::rate_functions::smooth,
+    glam::DVec3,
+    traits::{
+        Aabb, AabbPoint, Alignable, ArcLengthAlignable, GroupExt, Interpolatable, Opacity,
+        PointsFunc, ScaleTransform, ShiftTransform, ShiftTransformExt,
+    },
+    utils::{math::path_along_arc, rate_functions::smooth},
 };
 
 // ANCHOR: MorphRequirement
@@ -19,6 +25,16 @@ pub trait MorphAnim: MorphRequirement + Sized + 'static {
     fn morph_from(&mut self, src: Self) -> AnimationCell<Self>;
     /// Create a [`Morph`] anim to dst.
     fn morph_to(&mut self, dst: Self) -> AnimationCell<Self>;
+    /// Create a [`MorphThrough`] anim passing through all the `keyframes`,
+    /// each segment takes the same time.
+    fn morph_through(&mut self, keyframes: impl IntoIterator<Item = Self>) -> AnimationCell<Self>;
+    /// Create a [`MorphThrough`] anim passing through all the `keyframes`,
+    /// the i-th segment (ends at `keyframes[i]`) takes a time proportional to `durations[i]`.
+    fn morph_through_with_durations(
+        &mut self,
+        keyframes: impl IntoIterator<Item = Self>,
+        durations: &[f64],
+    ) -> AnimationCell<Self>;
 }
 // ANCHOR_END: MorphAnim
 
@@ -44,9 +60,68 @@ impl<T: MorphRequirement + 'static> MorphAnim for T {
             .with_rate_func(smooth)
             .apply_to(self)
     }
+    fn morph_through(&mut self, keyframes: impl IntoIterator<Item = T>) -> AnimationCell<T> {
+        let keyframes = std::iter::once(self.clone()).chain(keyframes).collect();
+        MorphThrough::new(keyframes)
+            .into_animation_cell()
+            .with_rate_func(smooth)
+            .apply_to(self)
+    }
+    fn morph_through_with_durations(
+        &mut self,
+        keyframes: impl IntoIterator<Item = T>,
+        durations: &[f64],
+    ) -> AnimationCell<T> {
+        let keyframes = std::iter::once(self.clone()).chain(keyframes).collect();
+        MorphThrough::new(keyframes)
+            .with_durations(durations)
+            .into_animation_cell()
+            .with_rate_func(smooth)
+            .apply_to(self)
+    }
 }
 // ANCHOR_END: MorphAnim-Impl
 
+/// The methods to create replacement morph animations.
+///
+/// Different from [`MorphAnim::morph_to`], which morphs `self` and updates it to the target's state
+/// (the source keeps being the item you use afterwards), a replacement morph morphs the source
+/// *into* the target item, which may be of a different type, and the target is the item you
+/// continue with. It is like manim's `ReplacementTransform` vs `Transform`.
+///
+/// Both items are converted into the common type `T` to be morphed, the source is left
+/// untouched and the last state of the animation equals `dst.clone().into()`:
+///
+/// ```rust,ignore
+/// let square = Square::new(2.0);
+/// let mut circle = Circle::new(1.0);
+/// r.timeline_mut(r_item)
+///     .play(square.replacement_morph_to::<VItem, _>(&circle))
+///     // continue as the circle
+///     .play(VItem::from(circle.clone()).morph(|item| { item.shift(DVec3::X); }));
+/// ```
+pub trait ReplacementMorphAnim: Clone + 'static {
+    /// Create a [`Morph`] anim from `self` into `dst` in the common type `T`.
+    fn replacement_morph_to<T, D>(&self, dst: &D) -> AnimationCell<T>
+    where
+        Self: Into<T>,
+        D: Clone + Into<T>,
+        T: MorphRequirement + 'static;
+}
+
+impl<S: Clone + 'static> ReplacementMorphAnim for S {
+    fn replacement_morph_to<T, D>(&self, dst: &D) -> AnimationCell<T>
+    where
+        S: Into<T>,
+        D: Clone + Into<T>,
+        T: MorphRequirement + 'static,
+    {
+        Morph::new(self.clone().into(), dst.clone().into())
+            .into_animation_cell()
+            .with_rate_func(smooth)
+    }
+}
+
 // ANCHOR: Morph
 /// Morph Anim
 pub struct Morph<T: MorphRequirement> {
@@ -54,9 +129,51 @@ pub struct Morph<T: MorphRequirement> {
     dst: T,
     aligned_src: T,
     aligned_dst: T,
+    path_arc: Option<PathArc<T>>,
 }
 // ANCHOR_END: Morph
 
+/// Calls [`PointsFunc::apply_points_func`] of the item, so that it can be stored without the bound.
+type ApplyPointsFunc<T> = fn(&mut T, &dyn Fn(&mut [DVec3]));
+
+/// The arc path of the points of a [`Morph`], see [`Morph::with_path_arc`].
+struct PathArc<T> {
+    angle: f64,
+    axis: DVec3,
+    src_points: Vec<DVec3>,
+    dst_points: Vec<DVec3>,
+    apply_points_func: ApplyPointsFunc<T>,
+}
+
+impl<T: Clone> PathArc<T> {
+    fn points_of(&self, item: &T) -> Vec<DVec3> {
+        let points = RefCell::new(Vec::new());
+        (self.apply_points_func)(&mut item.clone(), &|p: &mut [DVec3]| {
+            points.borrow_mut().extend_from_slice(p)
+        });
+        points.into_inner()
+    }
+    /// Update the points of the path with the aligned src and dst.
+    fn update(&mut self, aligned_src: &T, aligned_dst: &T) {
+        self.src_points = self.points_of(aligned_src);
+        self.dst_points = self.points_of(aligned_dst);
+    }
+    fn apply(&self, item: &mut T, alpha: f64) {
+        // The func may be called multiple times, for example once for each item of a group
+        let offset = Cell::new(0);
+        (self.apply_points_func)(item, &|points: &mut [DVec3]| {
+            let start = offset.get();
+            points
+                .iter_mut()
+                .zip(self.src_points.iter().zip(&self.dst_points).skip(start))
+                .for_each(|(p, (start, end))| {
+                    *p = path_along_arc(*start, *end, self.angle, self.axis, alpha)
+                });
+            offset.set(start + points.len());
+        });
+    }
+}
+
 impl<T: MorphRequirement> Morph<T> {
     /// Constructor
     pub fn new(src: T, dst: T) -> Self {
@@ -70,7 +187,77 @@ impl<T: MorphRequirement> Morph<T> {
             dst,
             aligned_src,
             aligned_dst,
+            path_arc: None,
+        }
+    }
+}
+
+impl<T: MorphRequirement + PointsFunc> Morph<T> {
+    /// Move the points along arcs of `angle` around the z axis instead of straight lines,
+    /// like manim's `path_arc`.
+    ///
+    /// The displacement of each pair of points is rotated around the pair's midpoint by
+    /// `angle * alpha`, so the points reach their destination after turning `angle` radians.
+    /// A positive `angle` goes counterclockwise, and `0.0` is the same as the straight path:
+    ///
+    /// ```rust,ignore
+    /// r.timeline_mut(r_item).play(
+    ///     Morph::new(src.clone(), dst.clone())
+    ///         .with_path_arc(PI / 2.0)
+    ///         .into_animation_cell()
+    ///         .with_rate_func(smooth),
+    /// );
+    /// ```
+    pub fn with_path_arc(self, angle: f64) -> Self {
+        self.with_path_arc_axis(angle, DVec3::Z)
+    }
+    /// Same as [`Morph::with_path_arc`], but the arcs are around `axis`.
+    pub fn with_path_arc_axis(mut self, angle: f64, axis: DVec3) -> Self {
+        let mut path_arc = PathArc {
+            angle,
+            axis,
+            src_points: Vec::new(),
+            dst_points: Vec::new(),
+            apply_points_func: |item: &mut T, f: &dyn Fn(&mut [DVec3])| {
+                item.apply_points_func(f);
+            },
+        };
+        path_arc.update(&self.aligned_src, &self.aligned_dst);
+        self.path_arc = Some(path_arc);
+        self
+    }
+}
+
+impl<T: MorphRequirement + ArcLengthAlignable> Morph<T> {
+    /// Whether to match the points of src and dst by arc length, default is `false`.
+    ///
+    /// By default the points are matched by index, which makes them bunch during the morph
+    /// if the point densities of src and dst differ a lot along their paths (for example, a
+    /// dense curve into a sparse one). With this enabled they are aligned with
+    /// [`ArcLengthAlignable::align_with_by_arc_length`] so that the points at the same fraction
+    /// of the path length are matched:
+    ///
+    /// ```rust,ignore
+    /// r.timeline_mut(r_item).play(
+    ///     Morph::new(dense.clone(), sparse.clone())
+    ///         .with_arc_length_match(true)
+    ///         .into_animation_cell(),
+    /// );
+    /// ```
+    pub fn with_arc_length_match(mut self, enabled: bool) -> Self {
+        let mut aligned_src = self.src.clone();
+        let mut aligned_dst = self.dst.clone();
+        if enabled {
+            aligned_src.align_with_by_arc_length(&mut aligned_dst);
+        } else if !aligned_src.is_aligned(&aligned_dst) {
+            aligned_src.align_with(&mut aligned_dst);
         }
+        if let Some(path_arc) = &mut self.path_arc {
+            path_arc.update(&aligned_src, &aligned_dst);
+        }
+        self.aligned_src = aligned_src;
+        self.aligned_dst = aligned_dst;
+        self
     }
 }
 
@@ -80,7 +267,11 @@ impl<T: MorphRequirement> Eval<T> for Morph<T> {
         if alpha == 0.0 {
             self.src.clone()
         } else if 0.0 < alpha && alpha < 1.0 {
-            self.aligned_src.lerp(&self.aligned_dst, alpha)
+            let mut item = self.aligned_src.lerp(&self.aligned_dst, alpha);
+            if let Some(path_arc) = &self.path_arc {
+                path_arc.apply(&mut item, alpha);
+            }
+            item
         } else if alpha == 1.0 {
             self.dst.clone()
         } else {
@@ -89,3 +280,397 @@ impl<T: MorphRequirement> Eval<T> for Morph<T> {
     }
 }
 // ANCHOR_END: Morph-Eval
+
+/// Morph through a sequence of keyframes
+///
+/// The item moves along a uniform Catmull-Rom spline through the keyframes,
+/// so the motion doesn't stop at every keyframe like chaining multiple [`Morph`]s does.
+/// The spline is evaluated with [`Interpolatable::lerp`] only (Barry-Goldman's pyramidal form),
+/// which means the lerp will be called with `t` outside of `[0, 1]`:
+/// - fields with a linear lerp (points, widths, colors, ...) follow the spline, and may
+///   slightly overshoot between keyframes whose values change direction.
+/// - fields that can't be interpolated (that the lerp picks from one side) switch like in [`Morph`].
+///
+/// All the keyframes are aligned with each other before evaluation,
+/// the exact first and last keyframes are returned at `alpha` `0.0` and `1.0`.
+pub struct MorphThrough<T: MorphRequirement> {
+    keyframes: Vec<T>,
+    aligned_keyframes: Vec<T>,
+    /// The normalized end alpha of each segment
+    segment_ends: Vec<f64>,
+}
+
+impl<T: MorphRequirement> MorphThrough<T> {
+    /// Constructor, every segment takes the same time.
+    ///
+    /// # Panics
+    /// Panics if `keyframes` is empty.
+    pub fn new(keyframes: Vec<T>) -> Self {
+        assert!(
+            !keyframes.is_empty(),
+            "MorphThrough needs at least one keyframe"
+        );
+        let mut aligned_keyframes = keyframes.clone();
+        if let Some((first, rest)) = aligned_keyframes.split_first_mut() {
+            // Align the first with all others, then align all others with the first
+            for keyframe in rest.iter_mut() {
+                if !first.is_aligned(keyframe) {
+                    first.align_with(keyframe);
+                }
+            }
+            for keyframe in rest.iter_mut() {
+                if !keyframe.is_aligned(first) {
+                    keyframe.align_with(first);
+                }
+            }
+        }
+        let segments = keyframes.len().saturating_sub(1).max(1);
+        Self {
+            keyframes,
+            aligned_keyframes,
+            segment_ends: (1..=segments).map(|i| i as f64 / segments as f64).collect(),
+        }
+    }
+    /// Set the relative duration of each segment.
+    ///
+    /// The i-th segment ends at the `i + 1`-th keyframe.
+    ///
+    /// A single keyframe has no segments, so `durations` should be empty, and the anim
+    /// stays at the keyframe.
+    ///
+    /// # Panics
+    /// Panics if the count of `durations` doesn't match the count of segments,
+    /// or any duration is negative, or they are all zero.
+    pub fn with_durations(mut self, durations: &[f64]) -> Self {
+        assert_eq!(
+            durations.len(),
+            self.keyframes.len() - 1,
+            "the count of durations should be the count of keyframes minus one"
+        );
+        if durations.is_empty() {
+            // A single keyframe, which is constant
+            return self;
+        }
+        assert!(durations.iter().all(|d| *d >= 0.0));
+        let total = durations.iter().sum::<f64>();
+        assert!(total > 0.0, "the total duration should be positive");
+        self.segment_ends = durations
+            .iter()
+            .scan(0.0, |acc, d| {
+                *acc += d;
+                Some(*acc / total)
+            })
+            .collect();
+        self
+    }
+}
+
+impl<T: MorphRequirement> Eval<T> for MorphThrough<T> {
+    fn eval_alpha(&self, alpha: f64) -> T {
+        let n = self.aligned_keyframes.len();
+        if alpha <= 0.0 || n == 1 {
+            return self.keyframes[0].clone();
+        } else if alpha >= 1.0 {
+            return self.keyframes[n - 1].clone();
+        }
+
+        let idx = self
+            .segment_ends
+            .iter()
+            .position(|end| alpha < *end)
+            .unwrap_or(self.segment_ends.len() - 1);
+        let start = if idx == 0 {
+            0.0
+        } else {
+            self.segment_ends[idx - 1]
+        };
+        let end = self.segment_ends[idx];
+        let t = if end > start {
+            (alpha - start) / (end - start)
+        } else {
+            1.0
+        };
+
+        let k = &self.aligned_keyframes;
+        let (p0, p1, p2, p3) = (
+            &k[idx.saturating_sub(1)],
+            &k[idx],
+            &k[idx + 1],
+            &k[(idx + 2).min(n - 1)],
+        );
+        // Barry-Goldman pyramidal formulation with knots -1, 0, 1, 2
+        let a1 = p0.lerp(p1, t + 1.0);
+        let a2 = p1.lerp(p2, t);
+        let a3 = p2.lerp(p3, t - 1.0);
+        let b1 = a1.lerp(&a2, (t + 1.0) / 2.0);
+        let b2 = a2.lerp(&a3, t / 2.0);
+        b1.lerp(&b2, t)
+    }
+}
+
+// MARK: GroupMorph
+/// The requirement of [`GroupMorph`]
+pub trait GroupMorphRequirement:
+    MorphRequirement + Opacity + ShiftTransform + ScaleTransform + Aabb
+{
+}
+impl<T: MorphRequirement + Opacity + ShiftTransform + ScaleTransform + Aabb> GroupMorphRequirement
+    for T
+{
+}
+
+/// The methods to create [`GroupMorph`] animations for groups
+pub trait GroupMorphAnim<T>: Sized + 'static {
+    /// Create a [`GroupMorph`] anim to `dst`, which may have a different count of items.
+    fn group_morph_to(&mut self, dst: Vec<T>) -> AnimationCell<Vec<T>>;
+}
+
+impl<T: GroupMorphRequirement + 'static> GroupMorphAnim<T> for Vec<T> {
+    fn group_morph_to(&mut self, dst: Vec<T>) -> AnimationCell<Vec<T>> {
+        GroupMorph::new(self.clone(), dst)
+            .into_animation_cell()
+            .with_rate_func(smooth)
+            .apply_to(self)
+    }
+}
+
+/// Morph between two groups of different counts.
+///
+/// The items are matched by the nearest aabb centers (see [`GroupExt::match_nearest`]),
+/// the matched ones are morphed into each other, and the unmatched ones
+/// grow in (spawn) or shrink out (despawn) at their centers while fading.
+///
+/// Unlike morphing two `Vec`s directly, which pads the shorter one with transparent
+/// duplicates, every item here visibly appears or disappears on its own.
+///
+/// During the anim, the items are ordered like `dst`, followed by the despawning ones.
+pub struct GroupMorph<T: GroupMorphRequirement> {
+    src: Vec<T>,
+    dst: Vec<T>,
+    morphs: Vec<Morph<T>>,
+}
+
+impl<T: GroupMorphRequirement> GroupMorph<T> {
+    /// Constructor
+    pub fn new(src: Vec<T>, dst: Vec<T>) -> Self {
+        let matching = src.match_nearest(&dst);
+        let mut src_of_dst = vec![None; dst.len()];
+        matching
+            .matched
+            .iter()
+            .for_each(|&(i, j)| src_of_dst[j] = Some(i));
+
+        let mut morphs = src_of_dst
+            .into_iter()
+            .zip(&dst)
+            .map(|(i, d)| match i {
+                Some(i) => Morph::new(src[i].clone(), d.clone()),
+                None => Morph::new(vanished(d), d.clone()),
+            })
+            .collect::<Vec<_>>();
+        morphs.extend(
+            matching
+                .unmatched_self
+                .iter()
+                .map(|&i| Morph::new(src[i].clone(), vanished(&src[i]))),
+        );
+        Self { src, dst, morphs }
+    }
+}
+
+/// Shrink the item to its center and make it transparent.
+fn vanished<T: GroupMorphRequirement>(item: &T) -> T {
+    let mut item = item.clone();
+    item.with_origin(AabbPoint::CENTER, |item| {
+        item.scale(DVec3::ZERO);
+    })
+    .set_opacity(0.0);
+    item
+}
+
+impl<T: GroupMorphRequirement> Eval<Vec<T>> for GroupMorph<T> {
+    fn eval_alpha(&self, alpha: f64) -> Vec<T> {
+        if alpha == 0.0 {
+            self.src.clone()
+        } else if alpha == 1.0 {
+            self.dst.clone()
+        } else {
+            self.morphs
+                .iter()
+                .map(|morph| morph.eval_alpha(alpha))
+                .collect()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ranim_core::traits::Interpolatable;
+
+    use super::*;
+
+    fn assert_near(a: DVec3, b: DVec3) {
+        assert!(a.distance(b) < 1e-9, "{a} != {b}");
+    }
+
+    /// A square dot with an opacity, which is enough to observe the group morph
+    #[derive(Debug, Clone, PartialEq)]
+    struct Dot {
+        pos: DVec3,
+        size: f64,
+        opacity: f32,
+    }
+
+    impl Dot {
+        fn new(x: f64) -> Self {
+            Self {
+                pos: DVec3::new(x, 0.0, 0.0),
+                size: 0.5,
+                opacity: 1.0,
+            }
+        }
+    }
+
+    impl Alignable for Dot {
+        fn is_aligned(&self, _other: &Self) -> bool {
+            true
+        }
+        fn align_with(&mut self, _other: &mut Self) {}
+    }
+
+    impl Interpolatable for Dot {
+        fn lerp(&self, target: &Self, t: f64) -> Self {
+            Self {
+                pos: Interpolatable::lerp(&self.pos, &target.pos, t),
+                size: self.size.lerp(&target.size, t),
+                opacity: self.opacity.lerp(&target.opacity, t),
+            }
+        }
+    }
+
+    impl Opacity for Dot {
+        fn set_opacity(&mut self, opacity: f32) -> &mut Self {
+            self.opacity = opacity;
+            self
+        }
+    }
+
+    impl ShiftTransform for Dot {
+        fn shift(&mut self, offset: DVec3) -> &mut Self {
+            self.pos += offset;
+            self
+        }
+    }
+
+    impl ScaleTransform for Dot {
+        fn scale(&mut self, scale: DVec3) -> &mut Self {
+            self.pos *= scale;
+            self.size *= scale.x;
+            self
+        }
+    }
+
+    impl Aabb for Dot {
+        fn aabb(&self) -> [DVec3; 2] {
+            let half = DVec3::splat(self.size / 2.0);
+            [self.pos - half, self.pos + half]
+        }
+    }
+
+    #[test]
+    fn test_group_morph_spawn() {
+        let src = (0..3).map(|i| Dot::new(i as f64)).collect::<Vec<_>>();
+        let dst = (0..5).map(|i| Dot::new(i as f64 + 0.2)).collect::<Vec<_>>();
+        let anim = GroupMorph::new(src.clone(), dst.clone());
+        assert_eq!(anim.eval_alpha(0.0), src);
+        assert_eq!(anim.eval_alpha(1.0), dst);
+
+        let items = anim.eval_alpha(0.5);
+        // Ordered like dst, the matched ones move to their nearest dst
+        assert_eq!(items.len(), 5);
+        (0..3).for_each(|i| {
+            assert_near(items[i].pos, DVec3::new(i as f64 + 0.1, 0.0, 0.0));
+            assert_eq!(items[i].opacity, 1.0);
+        });
+        // The unmatched ones grow in at their centers while fading in
+        (3..5).for_each(|i| {
+            assert_near(items[i].pos, dst[i].pos);
+            assert!((items[i].size - 0.25).abs() < 1e-9);
+            assert_eq!(items[i].opacity, 0.5);
+        });
+    }
+
+    #[test]
+    fn test_group_morph_despawn() {
+        let src = (0..5).map(|i| Dot::new(i as f64)).collect::<Vec<_>>();
+        let dst = (0..3).map(|i| Dot::new(i as f64 + 0.2)).collect::<Vec<_>>();
+        let anim = GroupMorph::new(src.clone(), dst.clone());
+        assert_eq!(anim.eval_alpha(0.0), src);
+        assert_eq!(anim.eval_alpha(1.0), dst);
+
+        let items = anim.eval_alpha(0.5);
+        // Ordered like dst, followed by the despawning ones
+        assert_eq!(items.len(), 5);
+        (0..3).for_each(|i| {
+            assert_near(items[i].pos, DVec3::new(i as f64 + 0.1, 0.0, 0.0));
+            assert_eq!(items[i].opacity, 1.0);
+        });
+        // The unmatched ones shrink out at their centers while fading out
+        (3..5).for_each(|i| {
+            assert_near(items[i].pos, src[i].pos);
+            assert!((items[i].size - 0.25).abs() < 1e-9);
+            assert_eq!(items[i].opacity, 0.5);
+        });
+        let items = anim.eval_alpha(0.9);
+        assert!((3..5).all(|i| items[i].opacity < 0.11));
+    }
+
+    #[test]
+    fn test_morph_through_keyframes() {
+        let keyframes = vec![
+            DVec3::ZERO,
+            DVec3::X,
+            DVec3::new(1.0, 1.0, 0.0),
+            DVec3::new(3.0, -1.0, 0.0),
+        ];
+        let anim = MorphThrough::new(keyframes.clone());
+        // Every segment takes the same time, and the spline passes through every keyframe
+        keyframes.iter().enumerate().for_each(|(i, keyframe)| {
+            assert_near(anim.eval_alpha(i as f64 / 3.0), *keyframe);
+        });
+        // Doesn't stop at the keyframes
+        assert_ne!(anim.eval_alpha(0.3), anim.eval_alpha(1.0 / 3.0));
+    }
+
+    #[test]
+    fn test_morph_through_durations() {
+        let keyframes = vec![DVec3::ZERO, DVec3::X, 2.0 * DVec3::X, 3.0 * DVec3::X];
+        let anim = MorphThrough::new(keyframes.clone()).with_durations(&[1.0, 2.0, 1.0]);
+        // The keyframes are reached at the cumulative durations
+        [0.0, 0.25, 0.75, 1.0]
+            .into_iter()
+            .zip(&keyframes)
+            .for_each(|(alpha, keyframe)| assert_near(anim.eval_alpha(alpha), *keyframe));
+        // Each segment moves between its two keyframes in its own time
+        [(0.1, 0.0, 1.0), (0.5, 1.0, 2.0), (0.9, 2.0, 3.0)]
+            .into_iter()
+            .for_each(|(alpha, start, end)| {
+                let x = anim.eval_alpha(alpha).x;
+                assert!(start < x && x < end, "{x} at {alpha}");
+            });
+        // The middle segment is symmetric, so its middle is the middle of its keyframes
+        assert_near(anim.eval_alpha(0.5), 1.5 * DVec3::X);
+    }
+
+    #[test]
+    fn test_morph_through_single_keyframe() {
+        let anim = MorphThrough::new(vec![DVec3::X]).with_durations(&[]);
+        [0.0, 0.5, 1.0]
+            .into_iter()
+            .for_each(|alpha| assert_eq!(anim.eval_alpha(alpha), DVec3::X));
+
+        let mut item = DVec3::X;
+        let anim = item.morph_through_with_durations([], &[]);
+        assert_eq!(anim.eval_alpha(0.5), DVec3::X);
+    }
+}
@@ -0,0 +1,201 @@
+use ranim_core::{
+    animation::{AnimationCell, Eval},
+    traits::{Alignable, Interpolatable},
+    utils::rate_functions::linear,
+};
+
+// MARK: Require Trait
+/// The requirement of [`Spring`]
+pub trait SpringRequirement: Alignable + Interpolatable + Clone {}
+impl<T: Alignable + Interpolatable + Clone> SpringRequirement for T {}
+
+// MARK: Anim Trait
+/// The methods to create spring animations for `T` that satisfies [`SpringRequirement`]
+pub trait SpringAnim: SpringRequirement + Sized + 'static {
+    /// Create a [`Spring`] anim to dst with the default [`SpringParams`].
+    fn spring_to(&mut self, dst: Self) -> AnimationCell<Self> {
+        self.spring_to_with(dst, SpringParams::default())
+    }
+    /// Create a [`Spring`] anim to dst with the given [`SpringParams`].
+    fn spring_to_with(&mut self, dst: Self, params: SpringParams) -> AnimationCell<Self>;
+}
+
+impl<T: SpringRequirement + 'static> SpringAnim for T {
+    fn spring_to_with(&mut self, dst: T, params: SpringParams) -> AnimationCell<T> {
+        // The spring is the timing, so the rate func is linear
+        Spring::new(self.clone(), dst, params)
+            .into_animation_cell()
+            .with_rate_func(linear)
+            .apply_to(self)
+    }
+}
+
+// MARK: SpringParams
+/// The count of the intervals of the precomputed progress of a [`Spring`]
+const TABLE_SIZE: usize = 256;
+
+/// The parameters of the spring of a [`Spring`] anim, the mass is `1.0`.
+///
+/// The time is measured in the span of the animation, i.e. it goes from `0.0` to `1.0`,
+/// so the same params give the same motion for any duration.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SpringParams {
+    /// The stiffness `k`, the force pulling towards the target is `k` times the distance
+    pub stiffness: f64,
+    /// The damping `c`, the force slowing down the motion is `c` times the velocity
+    pub damping: f64,
+}
+
+impl Default for SpringParams {
+    /// A critically damped spring of stiffness `100.0`
+    fn default() -> Self {
+        Self::critical(100.0)
+    }
+}
+
+impl SpringParams {
+    /// Constructor
+    pub fn new(stiffness: f64, damping: f64) -> Self {
+        Self { stiffness, damping }
+    }
+    /// A critically damped spring, it approaches the target as fast as possible without overshoot.
+    pub fn critical(stiffness: f64) -> Self {
+        Self::with_damping_ratio(stiffness, 1.0)
+    }
+    /// A spring with the given damping ratio, a ratio below `1.0` overshoots and bounces
+    /// around the target, the smaller the more bounces.
+    pub fn with_damping_ratio(stiffness: f64, ratio: f64) -> Self {
+        Self::new(stiffness, 2.0 * ratio * stiffness.sqrt())
+    }
+    /// The damping ratio, `1.0` is critically damped.
+    pub fn damping_ratio(&self) -> f64 {
+        self.damping / (2.0 * self.stiffness.sqrt())
+    }
+    /// The progress from `0.0` to `1.0` of the spring at `TABLE_SIZE + 1` uniform times,
+    /// starting at rest.
+    ///
+    /// The spring may not have settled at the end, the rest of the distance is
+    /// added along the span, so that it ends exactly at `1.0`.
+    fn progress_table(&self) -> Vec<f64> {
+        const SUBSTEPS: usize = 8;
+        let dt = 1.0 / (TABLE_SIZE * SUBSTEPS) as f64;
+        let accel = |x: f64, v: f64| self.stiffness * (1.0 - x) - self.damping * v;
+
+        let (mut x, mut v) = (0.0, 0.0);
+        let mut table = Vec::with_capacity(TABLE_SIZE + 1);
+        table.push(x);
+        for _ in 0..TABLE_SIZE {
+            for _ in 0..SUBSTEPS {
+                // RK4
+                let (k1x, k1v) = (v, accel(x, v));
+                let (k2x, k2v) = (
+                    v + k1v * dt / 2.0,
+                    accel(x + k1x * dt / 2.0, v + k1v * dt / 2.0),
+                );
+                let (k3x, k3v) = (
+                    v + k2v * dt / 2.0,
+                    accel(x + k2x * dt / 2.0, v + k2v * dt / 2.0),
+                );
+                let (k4x, k4v) = (v + k3v * dt, accel(x + k3x * dt, v + k3v * dt));
+                x += (k1x + 2.0 * k2x + 2.0 * k3x + k4x) * dt / 6.0;
+                v += (k1v + 2.0 * k2v + 2.0 * k3v + k4v) * dt / 6.0;
+            }
+            table.push(x);
+        }
+
+        let rest = 1.0 - x;
+        table
+            .iter_mut()
+            .enumerate()
+            .for_each(|(i, x)| *x += rest * i as f64 / TABLE_SIZE as f64);
+        table
+    }
+}
+
+// MARK: Impl
+/// Spring Anim
+///
+/// The item eases to the target driven by a damped spring, which overshoots and settles
+/// if it is underdamped, see [`SpringParams`]. The motion is solved once on construction.
+///
+/// The item is interpolated beyond the target while overshooting,
+/// i.e. [`Interpolatable::lerp`] is called with `t` out of `[0, 1]`.
+pub struct Spring<T: SpringRequirement> {
+    src: T,
+    dst: T,
+    aligned_src: T,
+    aligned_dst: T,
+    progress: Vec<f64>,
+}
+
+impl<T: SpringRequirement> Spring<T> {
+    /// Constructor
+    pub fn new(src: T, dst: T, params: SpringParams) -> Self {
+        let mut aligned_src = src.clone();
+        let mut aligned_dst = dst.clone();
+        if !aligned_src.is_aligned(&aligned_dst) {
+            aligned_src.align_with(&mut aligned_dst);
+        }
+        Self {
+            src,
+            dst,
+            aligned_src,
+            aligned_dst,
+            progress: params.progress_table(),
+        }
+    }
+    /// The progress of the spring at `alpha`, `0.0` at the src and `1.0` at the dst.
+    pub fn progress(&self, alpha: f64) -> f64 {
+        let pos = alpha.clamp(0.0, 1.0) * TABLE_SIZE as f64;
+        let idx = (pos as usize).min(TABLE_SIZE - 1);
+        self.progress[idx].lerp(&self.progress[idx + 1], pos - idx as f64)
+    }
+}
+
+impl<T: SpringRequirement> Eval<T> for Spring<T> {
+    fn eval_alpha(&self, alpha: f64) -> T {
+        if alpha <= 0.0 {
+            self.src.clone()
+        } else if alpha >= 1.0 {
+            self.dst.clone()
+        } else {
+            self.aligned_src
+                .lerp(&self.aligned_dst, self.progress(alpha))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ranim_core::glam::{DVec3, dvec3};
+
+    use super::*;
+
+    fn progresses(params: SpringParams) -> Vec<f64> {
+        let spring = Spring::new(DVec3::ZERO, DVec3::X, params);
+        (0..=100)
+            .map(|i| spring.progress(i as f64 / 100.0))
+            .collect()
+    }
+
+    #[test]
+    fn test_spring() {
+        // Critical damping approaches the target without overshoot
+        let critical = progresses(SpringParams::critical(100.0));
+        assert!(critical.iter().all(|p| (0.0..=1.0).contains(p)));
+        assert!(critical.windows(2).all(|w| w[0] <= w[1]));
+        assert_eq!(critical[0], 0.0);
+        assert!((critical[100] - 1.0).abs() < 1e-12);
+
+        // Underdamped overshoots, but still settles exactly at the target
+        let params = SpringParams::with_damping_ratio(100.0, 0.2);
+        assert!((params.damping_ratio() - 0.2).abs() < 1e-12);
+        let underdamped = progresses(params);
+        assert!(underdamped.iter().any(|p| *p > 1.1));
+        assert!((underdamped[100] - 1.0).abs() < 1e-12);
+
+        let spring = Spring::new(DVec3::ZERO, dvec3(2.0, 0.0, 0.0), params);
+        assert_eq!(spring.eval_alpha(0.0), DVec3::ZERO);
+        assert_eq!(spring.eval_alpha(1.0), dvec3(2.0, 0.0, 0.0));
+    }
+}
@@ -1,8 +1,19 @@
+pub mod list;
 pub mod preview;
 pub mod render;
+pub mod watch;
+
+use std::ops::Range;
 
 use anyhow::Result;
 use clap::{Args, Parser, Subcommand};
+use ranim::{Output, Scene};
+
+/// The maximum fps accepted by `--fps`.
+const MAX_FPS: u32 = 240;
+/// The maximum width or height accepted by `--resolution`, which is
+/// wgpu's default `max_texture_dimension_2d`.
+const MAX_RESOLUTION: u32 = 8192;
 
 #[derive(Args, Debug, Clone, Default)]
 #[group(multiple = false)]
@@ -13,6 +24,109 @@ pub struct TargetArg {
     pub example: Option<String>,
 }
 
+/// Overrides of the scenes' outputs, for quick low-res previews or high-res finals
+/// without editing the code.
+#[derive(Args, Debug, Clone, Default, PartialEq)]
+pub struct OutputOverrides {
+    /// Override the fps of the outputs
+    #[arg(global = true, long, value_parser = parse_fps, help_heading = "Output Overrides")]
+    pub fps: Option<u32>,
+    /// Override the resolution of the outputs, in the form of `<width>x<height>`
+    #[arg(
+        global = true,
+        long,
+        value_parser = parse_resolution,
+        help_heading = "Output Overrides"
+    )]
+    pub resolution: Option<(u32, u32)>,
+    /// Only render the frames in a range of secs, in the form of `<start>..<end>`
+    #[arg(
+        global = true,
+        long,
+        value_parser = parse_time_range,
+        help_heading = "Output Overrides"
+    )]
+    pub time_range: Option<Range<f64>>,
+    /// Render the ghosts of the anims, which are only shown in the preview app by default
+    #[arg(global = true, long, help_heading = "Output Overrides")]
+    pub ghosts: bool,
+}
+
+impl OutputOverrides {
+    /// Apply the overrides to an output.
+    pub fn apply(&self, output: &mut Output) {
+        if let Some(fps) = self.fps {
+            output.fps = fps;
+        }
+        if let Some((width, height)) = self.resolution {
+            output.width = width;
+            output.height = height;
+        }
+        if let Some(range) = &self.time_range {
+            output.time_range = Some(range.clone());
+        }
+        if self.ghosts {
+            output.ghosts = true;
+        }
+    }
+
+    /// Apply the overrides to all outputs of a scene.
+    pub fn apply_to_scene(&self, mut scene: Scene) -> Scene {
+        scene
+            .outputs
+            .iter_mut()
+            .for_each(|output| self.apply(output));
+        scene
+    }
+}
+
+fn parse_fps(s: &str) -> Result<u32, String> {
+    let fps = s
+        .parse::<u32>()
+        .map_err(|err| format!("invalid fps `{s}`: {err}"))?;
+    if !(1..=MAX_FPS).contains(&fps) {
+        return Err(format!("fps should be in 1..={MAX_FPS}, got {fps}"));
+    }
+    Ok(fps)
+}
+
+fn parse_resolution(s: &str) -> Result<(u32, u32), String> {
+    let (width, height) = s
+        .split_once(['x', 'X'])
+        .ok_or_else(|| format!("invalid resolution `{s}`, expected `<width>x<height>`"))?;
+    let parse = |v: &str| {
+        let v = v
+            .trim()
+            .parse::<u32>()
+            .map_err(|err| format!("invalid resolution `{s}`: {err}"))?;
+        if !(1..=MAX_RESOLUTION).contains(&v) {
+            return Err(format!(
+                "resolution should be in 1..={MAX_RESOLUTION} on each axis, got `{s}`"
+            ));
+        }
+        Ok(v)
+    };
+    Ok((parse(width)?, parse(height)?))
+}
+
+fn parse_time_range(s: &str) -> Result<Range<f64>, String> {
+    let (start, end) = s
+        .split_once("..")
+        .ok_or_else(|| format!("invalid time range `{s}`, expected `<start>..<end>`"))?;
+    let parse = |v: &str| {
+        v.trim()
+            .parse::<f64>()
+            .map_err(|err| format!("invalid time range `{s}`: {err}"))
+    };
+    let (start, end) = (parse(start)?, parse(end)?);
+    if start < 0.0 || (start..end).is_empty() {
+        return Err(format!(
+            "time range should satisfy 0 <= start < end, got `{s}`"
+        ));
+    }
+    Ok(start..end)
+}
+
 #[derive(Parser, Debug, Clone, Default)]
 pub struct CliArgs {
     #[arg(global = true, short, long, help_heading = "Cargo Options")]
@@ -24,6 +138,9 @@ pub struct CliArgs {
     #[command(flatten)]
     pub target: TargetArg,
 
+    #[command(flatten)]
+    pub overrides: OutputOverrides,
+
     #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
     pub args: Vec<String>,
 }
@@ -54,6 +171,17 @@ impl Cli {
             } => {
                 render::render_command(&args, &scenes, buffer_count)?;
             }
+            Commands::Watch {
+                scenes,
+                buffer_count,
+                debounce_ms,
+                only_on_success,
+            } => {
+                watch::watch_command(&args, &scenes, buffer_count, debounce_ms, only_on_success)?;
+            }
+            Commands::List { json } => {
+                list::list_command(&args, json)?;
+            }
         }
 
         Ok(())
@@ -74,6 +202,31 @@ pub enum Commands {
         #[arg(long, default_value_t = 2)]
         buffer_count: usize,
     },
+    /// Watch the lib crate, rebuild it and re-render scenes to files when it is changed
+    Watch {
+        /// Optional scene names to render (if not provided, render all scenes)
+        #[arg(num_args = 0..)]
+        scenes: Vec<String>,
+
+        /// Number of GPU readback buffers (higher = more parallelism, more VRAM)
+        #[arg(long, default_value_t = 2)]
+        buffer_count: usize,
+
+        /// Milliseconds to wait for more changes before rebuilding
+        #[arg(long, default_value_t = 500)]
+        debounce_ms: u64,
+
+        /// Only render after a successful build, otherwise a failed build
+        /// re-renders the last successfully built library
+        #[arg(long)]
+        only_on_success: bool,
+    },
+    /// Build the lib crate and load it, then list its scenes and their outputs
+    List {
+        /// Print the scenes as JSON
+        #[arg(long)]
+        json: bool,
+    },
 }
 
 #[cfg(test)]
@@ -107,6 +260,36 @@ mod test {
         assert!(example.is_none());
         assert_eq!(Target::from(cli.args.target.clone()), Target::Lib);
 
+        let cli = parse_args(&["ranim", "watch", "scene_a", "--only-on-success"]).unwrap();
+        let Commands::Watch {
+            scenes,
+            debounce_ms,
+            only_on_success,
+            ..
+        } = &cli.command
+        else {
+            unreachable!()
+        };
+        assert_eq!(scenes, &["scene_a".to_string()]);
+        assert_eq!(*debounce_ms, 500);
+        assert!(only_on_success);
+
+        let cli = parse_args(&["ranim", "watch", "--debounce-ms", "200"]).unwrap();
+        let Commands::Watch {
+            debounce_ms,
+            only_on_success,
+            ..
+        } = &cli.command
+        else {
+            unreachable!()
+        };
+        assert_eq!(*debounce_ms, 200);
+        assert!(!only_on_success);
+
+        let cli = parse_args(&["ranim", "list", "--json", "--lib"]).unwrap();
+        assert!(matches!(cli.command, Commands::List { json: true }));
+        assert!(cli.args.target.lib);
+
         let cli = parse_args(&["ranim", "preview", "--example", "example"]).unwrap();
         assert!(matches!(cli.command, Commands::Preview { scene: None }));
         assert!(cli.args.package.is_none());
@@ -118,4 +301,52 @@ mod test {
             Target::Example("example".to_string())
         );
     }
+
+    #[test]
+    fn test_output_overrides() {
+        let cli =
+            Cli::try_parse_from(["ranim", "render", "--fps", "30", "--resolution", "640x360"])
+                .unwrap();
+        assert_eq!(
+            cli.args.overrides,
+            OutputOverrides {
+                fps: Some(30),
+                resolution: Some((640, 360)),
+                time_range: None,
+                ghosts: false,
+            }
+        );
+        let cli = Cli::try_parse_from(["ranim", "preview", "--resolution", "1280X720"]).unwrap();
+        assert_eq!(cli.args.overrides.fps, None);
+        assert_eq!(cli.args.overrides.resolution, Some((1280, 720)));
+
+        for args in [
+            ["ranim", "render", "--fps", "0"],
+            ["ranim", "render", "--fps", "1000"],
+            ["ranim", "render", "--resolution", "640"],
+            ["ranim", "render", "--resolution", "0x360"],
+            ["ranim", "render", "--resolution", "100000x360"],
+            ["ranim", "render", "--time-range", "2"],
+            ["ranim", "render", "--time-range", "3..2"],
+            ["ranim", "render", "--time-range", "-1..2"],
+        ] {
+            assert!(Cli::try_parse_from(args).is_err(), "{args:?}");
+        }
+
+        let mut output = Output::default();
+        cli.args.overrides.apply(&mut output);
+        assert_eq!((output.width, output.height), (1280, 720));
+        assert_eq!(output.fps, Output::default().fps);
+        assert_eq!(output.time_range, None);
+
+        let cli = Cli::try_parse_from(["ranim", "render", "--time-range", "1.5..3"]).unwrap();
+        assert_eq!(cli.args.overrides.time_range, Some(1.5..3.0));
+        cli.args.overrides.apply(&mut output);
+        assert_eq!(output.time_range, Some(1.5..3.0));
+        assert!(!output.ghosts);
+
+        let cli = Cli::try_parse_from(["ranim", "render", "--ghosts"]).unwrap();
+        cli.args.overrides.apply(&mut output);
+        assert!(output.ghosts);
+    }
 }
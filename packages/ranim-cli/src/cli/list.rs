@@ -0,0 +1,125 @@
+use anyhow::{Context, Result};
+use ranim::Scene;
+use serde_json::json;
+use tracing::info;
+
+use crate::{
+    RanimUserLibraryBuilder, Target,
+    cli::CliArgs,
+    workspace::{Workspace, get_target_package},
+};
+
+pub fn list_command(args: &CliArgs, json: bool) -> Result<()> {
+    info!("Loading workspace...");
+    let workspace = Workspace::current().context("Failed to load workspace")?;
+
+    info!("Getting target package...");
+    let (_, package_name) = get_target_package(&workspace, args);
+    info!("Target package name: {package_name}");
+
+    let target = Target::from(args.target.clone());
+    info!("Target: {target:?}");
+
+    let current_dir = std::env::current_dir().context("Failed to get current directory")?;
+    let mut builder = RanimUserLibraryBuilder::new(
+        workspace.clone(),
+        package_name.clone(),
+        target,
+        args.clone(),
+        current_dir,
+    );
+
+    builder.start_build();
+    let lib = builder
+        .res_rx
+        .recv_blocking()
+        .context("Build process exited unexpectedly")?
+        .with_context(|| {
+            format!("Failed to build package `{package_name}`, see the errors above")
+        })?;
+
+    let scenes = lib.scenes().collect::<Vec<_>>();
+    if json {
+        println!("{}", scenes_json(&scenes));
+    } else if scenes.is_empty() {
+        println!("No scenes found in `{package_name}`");
+    } else {
+        for scene in &scenes {
+            let preview = if scene.preview { " (preview)" } else { "" };
+            println!("{}{preview}", scene.name);
+            for output in &scene.outputs {
+                println!(
+                    "  {}x{} {}fps {} -> {}/{}",
+                    output.width,
+                    output.height,
+                    output.fps,
+                    output.format,
+                    output.dir,
+                    output.file_name(&scene.name)
+                );
+            }
+        }
+    }
+    Ok(())
+}
+
+fn scenes_json(scenes: &[Scene]) -> String {
+    let scenes = scenes
+        .iter()
+        .map(|scene| {
+            let outputs = scene
+                .outputs
+                .iter()
+                .map(|output| {
+                    json!({
+                        "width": output.width,
+                        "height": output.height,
+                        "fps": output.fps,
+                        "format": output.format.to_string(),
+                        "dir": output.dir,
+                        "file_name": output.file_name(&scene.name),
+                        "save_frames": output.save_frames,
+                    })
+                })
+                .collect::<Vec<_>>();
+            json!({
+                "name": scene.name,
+                "preview": scene.preview,
+                "outputs": outputs,
+            })
+        })
+        .collect::<Vec<_>>();
+    serde_json::Value::from(scenes).to_string()
+}
+
+#[cfg(test)]
+mod test {
+    use ranim::{Output, SceneConfig};
+
+    use super::*;
+
+    #[test]
+    fn test_scenes_json() {
+        let scene = |name: &str, outputs: Vec<Output>, preview| Scene {
+            name: name.to_string(),
+            constructor: |_| {},
+            config: SceneConfig::default(),
+            outputs,
+            preview,
+        };
+        let scenes = [
+            scene("hello \"world\"", vec![Output::default()], false),
+            scene("empty", vec![], true),
+        ];
+        assert_eq!(
+            scenes_json(&scenes),
+            concat!(
+                r#"[{"name":"hello \"world\"","preview":false,"outputs":["#,
+                r#"{"width":1920,"height":1080,"fps":60,"format":"mp4","dir":"./output","#,
+                r#""file_name":"hello \"world\"_1920x1080_60.mp4","save_frames":false}]},"#,
+                r#"{"name":"empty","preview":true,"outputs":[]}]"#,
+            )
+        );
+        assert_eq!(scenes_json(&[]), "[]");
+    }
+}
@@ -5,7 +5,7 @@ use std::{
 
 use krates::Kid;
 use notify_debouncer_full::{DebouncedEvent, Debouncer};
-use ranim::cmd::preview::{RanimPreviewApp, RanimPreviewAppCmd};
+use ranim::cmd::preview::{RanimPreviewApp, RanimPreviewAppCmd, Resolution};
 
 use anyhow::Result;
 use async_channel::{Receiver, bounded, unbounded};
@@ -18,23 +18,23 @@ use crate::{
     workspace::{Workspace, get_target_package},
 };
 
-fn watch_krate(
+pub(crate) fn watch_krate(
     workspace: &Workspace,
     kid: &Kid,
+    debounce: Duration,
 ) -> (
     Debouncer<notify::RecommendedWatcher, notify_debouncer_full::RecommendedCache>,
     Receiver<Vec<DebouncedEvent>>,
 ) {
     let (tx, rx) = unbounded();
 
-    let mut debouncer =
-        notify_debouncer_full::new_debouncer(Duration::from_millis(500), None, move |evt| {
-            let Ok(evt) = evt else {
-                return;
-            };
-            _ = tx.try_send(evt)
-        })
-        .expect("Failed to create debounced watcher");
+    let mut debouncer = notify_debouncer_full::new_debouncer(debounce, None, move |evt| {
+        let Ok(evt) = evt else {
+            return;
+        };
+        _ = tx.try_send(evt)
+    })
+    .expect("Failed to create debounced watcher");
 
     // All krates need to be watched, including the main package.
     let mut watch_krates = vec![];
@@ -131,7 +131,7 @@ pub fn preview_command(args: &CliArgs, scene_name: &Option<String>) -> Result<()
     info!("Target: {target:?}");
 
     info!("Watching package...");
-    let (_watcher, rx) = watch_krate(&workspace, &kid);
+    let (_watcher, rx) = watch_krate(&workspace, &kid, Duration::from_millis(500));
 
     let current_dir = std::env::current_dir().expect("Failed to get current directory");
     let mut builder = RanimUserLibraryBuilder::new(
@@ -152,9 +152,11 @@ pub fn preview_command(args: &CliArgs, scene_name: &Option<String>) -> Result<()
 
     let scene = match scene_name {
         Some(scene) => lib.scenes().find(|s| s.name == *scene),
-        None => lib.scenes().next(),
+        None => lib.get_preview_func().ok(),
     }
     .ok_or(anyhow::anyhow!("Failed to find preview scene"))?;
+    let overrides = args.overrides.clone();
+    let scene = overrides.apply_to_scene(scene);
     // error!("Failed to get preview scene, available scenes:");
     // for scene in lib.scenes() {
     //     info!("- {:?}", scene.name);
@@ -162,6 +164,13 @@ pub fn preview_command(args: &CliArgs, scene_name: &Option<String>) -> Result<()
     // panic!("Failed to get preview scene");
     let mut app = RanimPreviewApp::new(scene.constructor, scene.name.clone(), scene.config.clone());
     app.set_clear_color_str(&scene.config.clear_color);
+    app.set_resolution_from_outputs(&scene.outputs);
+    if let Some((width, height)) = overrides.resolution {
+        app.set_resolution(Resolution::new(width, height));
+    }
+    let mut export_config = scene.outputs.first().cloned().unwrap_or_default();
+    overrides.apply(&mut export_config);
+    app.set_export_config(export_config);
     let cmd_tx = app.cmd_tx.clone();
 
     let scene_name = scene_name.clone();
@@ -181,16 +190,17 @@ pub fn preview_command(args: &CliArgs, scene_name: &Option<String>) -> Result<()
             {
                 let scene = match &scene_name {
                     Some(name) => new_lib.scenes().find(|s| s.name == *name),
-                    None => new_lib.scenes().next(),
+                    None => new_lib.get_preview_func().ok(),
                 }
                 .ok_or(anyhow::anyhow!("Failed to find preview scene"));
                 if let Err(err) = scene {
                     error!("Failed to find preview scene: {err}");
                     continue;
                 }
+                let scene = overrides.apply_to_scene(scene.unwrap());
                 let (tx, rx) = bounded(1);
                 cmd_tx
-                    .send_blocking(RanimPreviewAppCmd::ReloadScene(scene.unwrap(), tx))
+                    .send_blocking(RanimPreviewAppCmd::ReloadScene(scene, tx))
                     .unwrap();
                 rx.recv_blocking().unwrap();
                 lib.replace(new_lib);
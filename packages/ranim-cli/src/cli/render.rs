@@ -1,3 +1,5 @@
+use std::path::{Path, PathBuf};
+
 use anyhow::{Context, Result, bail};
 use ranim::{Scene, cmd::render_scene};
 use tracing::{error, info};
@@ -37,32 +39,72 @@ pub fn render_command(args: &CliArgs, scenes: &[String], buffer_count: usize) ->
         .unwrap()
         .context("Failed on initial build")?;
 
-    let all_scenes: Vec<Scene> = lib.scenes().collect::<Vec<_>>();
-    let scenes_to_render: Vec<&Scene> = if scenes.is_empty() {
+    let all_scenes: Vec<Scene> = lib
+        .scenes()
+        .map(|scene| args.overrides.apply_to_scene(scene))
+        .collect::<Vec<_>>();
+    let scenes_to_render = select_scenes(&all_scenes, scenes)?;
+    render_scenes(&scenes_to_render, &current_dir, buffer_count);
+    Ok(())
+}
+
+/// Select the scenes with the given names, or all scenes if `names` is empty.
+///
+/// Errors if any of the names doesn't match a scene, or there is no scene to render.
+pub(crate) fn select_scenes<'a>(
+    all_scenes: &'a [Scene],
+    names: &[String],
+) -> Result<Vec<&'a Scene>> {
+    let available = || all_scenes.iter().map(|s| &s.name).collect::<Vec<_>>();
+
+    let unknown = names
+        .iter()
+        .filter(|name| !all_scenes.iter().any(|scene| &scene.name == *name))
+        .collect::<Vec<_>>();
+    if !unknown.is_empty() {
+        error!("Available scenes: {:?}", available());
+        bail!("No matching scenes found for: {unknown:?}");
+    }
+
+    let scenes_to_render: Vec<&Scene> = if names.is_empty() {
         all_scenes.iter().collect()
     } else {
         all_scenes
             .iter()
-            .filter(|scene| scenes.iter().any(|s| s == &scene.name))
+            .filter(|scene| names.iter().any(|s| s == &scene.name))
             .collect()
     };
 
     if scenes_to_render.is_empty() {
-        if scenes.is_empty() {
-            info!("No scenes found to render");
-        } else {
-            error!("No matching scenes found for: {scenes:?}");
-            error!(
-                "Available scenes: {:?}",
-                all_scenes.iter().map(|s| &s.name).collect::<Vec<_>>()
-            );
-        }
+        info!("No scenes found to render");
         bail!("No scenes to render");
     }
+    Ok(scenes_to_render)
+}
 
-    for scene in scenes_to_render {
+/// Resolves an output dir relative to `current_dir`
+pub(crate) fn output_dir(dir: &str, current_dir: &Path) -> PathBuf {
+    let dir = PathBuf::from(dir);
+    if dir.is_absolute() {
+        dir
+    } else {
+        current_dir.join(dir)
+    }
+}
+
+/// Render the scenes and report the produced outputs.
+pub(crate) fn render_scenes(scenes: &[&Scene], current_dir: &Path, buffer_count: usize) {
+    let mut produced = Vec::new();
+    for scene in scenes {
         info!("Rendering scene: {}", scene.name);
         render_scene(scene, buffer_count);
+        produced.extend(scene.outputs.iter().map(|output| {
+            output_dir(&output.dir, current_dir).join(output.file_name(&scene.name))
+        }));
+    }
+
+    info!("Rendered {} output(s):", produced.len());
+    for path in produced {
+        info!("  {}", path.display());
     }
-    Ok(())
 }
@@ -0,0 +1,146 @@
+use std::{path::PathBuf, time::Duration};
+
+use anyhow::{Context, Result};
+use tracing::{error, info};
+
+use crate::{
+    RanimUserLibraryBuilder, Target,
+    cli::{
+        CliArgs,
+        preview::watch_krate,
+        render::{output_dir, render_scenes, select_scenes},
+    },
+    workspace::{Workspace, get_target_package},
+};
+
+pub fn watch_command(
+    args: &CliArgs,
+    scenes: &[String],
+    buffer_count: usize,
+    debounce_ms: u64,
+    only_on_success: bool,
+) -> Result<()> {
+    info!("Loading workspace...");
+    let workspace = Workspace::current().context("Failed to load workspace")?;
+
+    info!("Getting target package...");
+    let (kid, package_name) = get_target_package(&workspace, args);
+    info!("Target package name: {package_name}");
+
+    let target = Target::from(args.target.clone());
+    info!("Target: {target:?}");
+
+    info!("Watching package...");
+    let (_watcher, rx) = watch_krate(&workspace, &kid, Duration::from_millis(debounce_ms));
+
+    let current_dir = std::env::current_dir().context("Failed to get current directory")?;
+    let mut builder = RanimUserLibraryBuilder::new(
+        workspace.clone(),
+        package_name.clone(),
+        target,
+        args.clone(),
+        current_dir.clone(),
+    );
+
+    // Changes to the build artifacts and the rendered outputs should not trigger a rebuild
+    let target_dir = workspace.target_dir();
+    let mut ignored_dirs = vec![target_dir.clone()];
+
+    info!("Initial build");
+    builder.start_build();
+    let mut lib = None;
+    loop {
+        let res = builder
+            .res_rx
+            .recv_blocking()
+            .context("Build process exited unexpectedly")?;
+        let res_ok = res.is_ok();
+        match res {
+            Ok(new_lib) => {
+                lib = Some(new_lib);
+            }
+            Err(err) => {
+                error!("Build failed: {err}");
+                if only_on_success {
+                    info!("Skipping render until the next successful build");
+                }
+            }
+        }
+        // Without `--only-on-success`, a failed build re-renders the last successful one
+        if let Some(lib) = &lib
+            && (res_ok || !only_on_success)
+        {
+            let all_scenes = lib
+                .scenes()
+                .map(|scene| args.overrides.apply_to_scene(scene))
+                .collect::<Vec<_>>();
+            ignored_dirs = std::iter::once(target_dir.clone())
+                .chain(
+                    all_scenes
+                        .iter()
+                        .flat_map(|scene| &scene.outputs)
+                        .map(|output| output_dir(&output.dir, &current_dir)),
+                )
+                .collect();
+            match select_scenes(&all_scenes, scenes) {
+                Ok(scenes) => render_scenes(&scenes, &current_dir, buffer_count),
+                Err(err) => error!("{err}"),
+            }
+        }
+
+        info!("Waiting for changes...");
+        loop {
+            let events = rx
+                .recv_blocking()
+                .context("File watcher exited unexpectedly")?;
+            let events = events
+                .into_iter()
+                .filter(|event| !is_ignored(&event.paths, &ignored_dirs))
+                .collect::<Vec<_>>();
+            for event in &events {
+                info!("{:?}: {:?}", event.kind, event.paths);
+            }
+            if !events.is_empty() {
+                break;
+            }
+        }
+        // Changes made while rendering are covered by this build
+        while rx.try_recv().is_ok() {}
+        builder.start_build();
+    }
+}
+
+/// Whether all the `paths` of an event are inside one of the `ignored_dirs`
+fn is_ignored(paths: &[PathBuf], ignored_dirs: &[PathBuf]) -> bool {
+    !paths.is_empty()
+        && paths
+            .iter()
+            .all(|path| ignored_dirs.iter().any(|dir| path.starts_with(dir)))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_is_ignored() {
+        let ignored_dirs = [PathBuf::from("/ws/target"), PathBuf::from("/ws/output")];
+        let paths = |paths: &[&str]| paths.iter().map(PathBuf::from).collect::<Vec<_>>();
+
+        assert!(is_ignored(
+            &paths(&["/ws/output/scene_1920x1080_60.mp4"]),
+            &ignored_dirs
+        ));
+        assert!(is_ignored(
+            &paths(&["/ws/target/debug/libscene.so", "/ws/output/scene.png"]),
+            &ignored_dirs
+        ));
+        assert!(!is_ignored(&paths(&["/ws/src/lib.rs"]), &ignored_dirs));
+        assert!(!is_ignored(
+            &paths(&["/ws/output/scene.png", "/ws/src/lib.rs"]),
+            &ignored_dirs
+        ));
+        // Only whole path components are matched
+        assert!(!is_ignored(&paths(&["/ws/output_old/a.rs"]), &ignored_dirs));
+    }
+}
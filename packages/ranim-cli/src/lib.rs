@@ -201,14 +201,18 @@ impl RanimUserLibrary {
         RanimUserLibrarySceneIter { lib: self, idx: 0 }
     }
 
+    /// The scene marked with `#[preview]`, or the first scene if none is marked.
     pub fn get_preview_func(&self) -> Result<Scene> {
-        self.scenes().next().context("no scene found")
+        self.scenes()
+            .find(|scene| scene.preview)
+            .or_else(|| self.scenes().next())
+            .context("no scene found")
     }
 }
 
 impl Drop for RanimUserLibrary {
     fn drop(&mut self) {
-        println!("Dropping RanimUserLibrary...");
+        info!("Dropping RanimUserLibrary...");
 
         drop(self.inner.take());
         std::fs::remove_file(&self.temp_path).unwrap();
@@ -306,10 +310,7 @@ fn get_dylib_path(
 ) -> PathBuf {
     // Construct the dylib path
     let mut target_dir = workspace
-        .krates
-        .workspace_root()
-        .as_std_path()
-        .join("target")
+        .target_dir()
         .join(if args.contains(&"--release".to_string()) {
             "release"
         } else {
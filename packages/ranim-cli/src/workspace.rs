@@ -1,4 +1,4 @@
-use std::sync::Arc;
+use std::{path::PathBuf, sync::Arc};
 
 use anyhow::{Context, Result};
 use ignore::gitignore::Gitignore;
@@ -118,6 +118,14 @@ impl Workspace {
 
         Ok(kid.clone())
     }
+
+    /// The target dir of cargo, `CARGO_TARGET_DIR` if set, or `target` in the workspace root.
+    pub fn target_dir(&self) -> PathBuf {
+        match std::env::var_os("CARGO_TARGET_DIR") {
+            Some(dir) => std::path::absolute(&dir).unwrap_or_else(|_| PathBuf::from(dir)),
+            None => self.krates.workspace_root().as_std_path().join("target"),
+        }
+    }
 }
 
 /// Get the target package.
@@ -103,6 +103,13 @@ pub trait Aabb {
         let [min, max] = self.aabb();
         (max + min) / 2.0
     }
+    /// Get the world point of the Aabb at the given [`AabbPoint`],
+    /// e.g. `AabbPoint(dvec3(-1.0, 1.0, 0.0))` is the top left point.
+    ///
+    /// This is the same as `anchor.locate(self)`.
+    fn aabb_point(&self, anchor: AabbPoint) -> DVec3 {
+        anchor.locate(self)
+    }
 }
 
 impl Aabb for DVec3 {
@@ -118,8 +125,8 @@ impl<T: Aabb> Aabb for [T] {
             .map(|x| x.aabb())
             .reduce(|[acc_min, acc_max], [min, max]| [acc_min.min(min), acc_max.max(max)])
             .unwrap_or([DVec3::ZERO, DVec3::ZERO]);
-        if min == max {
-            warn!("Empty bounding box, is the slice empty?")
+        if self.is_empty() {
+            warn!("Empty bounding box, the slice is empty")
         }
         [min, max]
     }
@@ -130,3 +137,42 @@ impl<T: Aabb> Aabb for Vec<T> {
         self.as_slice().aabb()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use glam::dvec3;
+
+    #[test]
+    fn test_aabb_point() {
+        let aabb = [dvec3(-1.0, 2.0, 0.0), dvec3(3.0, 4.0, 0.0)];
+        let item = aabb.to_vec();
+        for (x, expected_x) in [(-1.0, -1.0), (0.0, 1.0), (1.0, 3.0)] {
+            for (y, expected_y) in [(-1.0, 2.0), (0.0, 3.0), (1.0, 4.0)] {
+                let point = item.aabb_point(AabbPoint(dvec3(x, y, 0.0)));
+                assert_eq!(point, dvec3(expected_x, expected_y, 0.0));
+            }
+        }
+        assert_eq!(item.aabb_point(AabbPoint::CENTER), item.aabb_center());
+    }
+
+    #[test]
+    fn test_anchor_degenerate_aabb() {
+        use crate::traits::ShiftTransformExt;
+
+        let target = dvec3(-2.0, 3.0, 0.0);
+        // A dot, every anchor is the point itself
+        let mut dot = vec![dvec3(1.0, 1.0, 0.0); 3];
+        dot.move_anchor_to(AabbPoint(dvec3(-1.0, 1.0, 0.0)), target);
+        assert!(dot.iter().all(|p| *p == target));
+        dot.move_next_to_padded(&vec![DVec3::ZERO], AabbPoint(DVec3::X), 0.5);
+        assert!(dot.iter().all(|p| *p == dvec3(0.5, 0.0, 0.0)));
+        dot.move_next_to_padded(&vec![DVec3::ZERO], AabbPoint::CENTER, 0.5);
+        assert!(dot.iter().all(|p| *p == DVec3::ZERO));
+
+        // A horizontal line, the y axis is degenerate
+        let mut line = vec![dvec3(0.0, 1.0, 0.0), dvec3(2.0, 1.0, 0.0)];
+        line.move_anchor_to(AabbPoint(dvec3(-1.0, 1.0, 0.0)), target);
+        assert_eq!(line, vec![target, target + DVec3::X * 2.0]);
+    }
+}
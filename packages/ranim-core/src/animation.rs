@@ -34,6 +34,7 @@ pub trait Eval<T> {
             inner: Box::new(self),
             info: AnimationInfo::default(),
             anim_name: std::any::type_name::<Self>().to_string(),
+            ghost_opacity: None,
         }
     }
 }
@@ -131,6 +132,7 @@ pub struct AnimationCell<T> {
     pub info: AnimationInfo,
     // ANCHOR_END: AnimationCell
     anim_name: String,
+    ghost_opacity: Option<f32>,
 }
 
 impl<T> AnimationCell<T> {
@@ -154,6 +156,70 @@ impl<T> AnimationCell<T> {
         self.info = self.info.with_enabled(enabled);
         self
     }
+    /// Get an animation that plays this animation backward.
+    ///
+    /// Evaluating the result at `alpha` is the same as evaluating this at `1.0 - alpha`
+    /// (with this animation's rate func applied), and its rate func is reset to [`linear`],
+    /// the start sec, duration and enabled state are kept.
+    ///
+    /// This doesn't update any item, use [`AnimationCell::apply_to`] to apply the final state:
+    /// ```rust,ignore
+    /// let unwrite = item.write().reversed().apply_to(&mut item);
+    /// ```
+    pub fn reversed(self) -> Self
+    where
+        T: 'static,
+    {
+        let info = self.info.clone().with_rate_func(linear);
+        let anim_name = self.anim_name.clone();
+        let ghost_opacity = self.ghost_opacity;
+        AnimationCell {
+            inner: Box::new(Reversed(self)),
+            info,
+            anim_name,
+            ghost_opacity,
+        }
+    }
+    /// Get an animation that plays this animation and then `next`.
+    ///
+    /// The duration is the sum of both durations, and each of them is evaluated with its own
+    /// rate func during its own part, so `a.chain(b).chain(c)` plays them one after another
+    /// as a single animation. The result's rate func is [`linear`], and the start sec and
+    /// enabled state are kept from this animation.
+    ///
+    /// ```rust,ignore
+    /// r.timeline_mut(r_item).play(item.write().chain(item.unwrite().with_duration(2.0)));
+    /// ```
+    pub fn chain(self, next: AnimationCell<T>) -> Self
+    where
+        T: 'static,
+    {
+        let info = self
+            .info
+            .clone()
+            .with_rate_func(linear)
+            .with_duration(self.info.duration_secs + next.info.duration_secs);
+        AnimationCell {
+            inner: Box::new(Chain(self, next)),
+            info,
+            anim_name: "Chain".to_string(),
+            ghost_opacity: None,
+        }
+    }
+    /// Show faint copies of the start and end states (the states at alpha `0.0` and `1.0`
+    /// before the rate func) while the animation is playing, like the onion skinning of
+    /// animation tools, the opacity of the copies is multiplied by `opacity`.
+    ///
+    /// This is helpful for understanding complex morphs while authoring. The ghosts are only
+    /// shown if the scene is evaluated with them, see [`crate::SealedRanimScene::set_ghosts`],
+    /// which the preview app does, so the final renders are not affected unless it is enabled:
+    /// ```rust,ignore
+    /// r.timeline_mut(r_item).play(item.morph_to(dst).with_ghosts(0.2));
+    /// ```
+    pub fn with_ghosts(mut self, opacity: f32) -> Self {
+        self.ghost_opacity = Some(opacity);
+        self
+    }
     /// Apply the animation to the item and return the animation itself
     pub fn apply_to(self, item: &mut T) -> Self {
         self.apply_alpha_to(item, 1.0)
@@ -165,6 +231,31 @@ impl<T> AnimationCell<T> {
     }
 }
 
+/// See [`AnimationCell::reversed`]
+struct Reversed<T>(AnimationCell<T>);
+
+impl<T> Eval<T> for Reversed<T> {
+    fn eval_alpha(&self, alpha: f64) -> T {
+        self.0.eval_alpha(1.0 - alpha)
+    }
+}
+
+/// See [`AnimationCell::chain`]
+struct Chain<T>(AnimationCell<T>, AnimationCell<T>);
+
+impl<T> Eval<T> for Chain<T> {
+    fn eval_alpha(&self, alpha: f64) -> T {
+        let (first, second) = (self.0.info.duration_secs, self.1.info.duration_secs);
+        let sec = alpha * (first + second);
+        if sec < first || second <= 0.0 {
+            let alpha = (sec / first).clamp(0.0, 1.0);
+            self.0.eval_alpha(if alpha.is_nan() { 1.0 } else { alpha })
+        } else {
+            self.1.eval_alpha(((sec - first) / second).clamp(0.0, 1.0))
+        }
+    }
+}
+
 // ANCHOR: AnimationCell-Eval
 impl<T> Eval<T> for AnimationCell<T> {
     fn eval_alpha(&self, alpha: f64) -> T {
@@ -178,6 +269,8 @@ impl<T> Eval<T> for AnimationCell<T> {
 pub trait CoreItemAnimation {
     /// Get the animation info
     fn anim_info(&self) -> &AnimationInfo;
+    /// Get the mutable animation info
+    fn anim_info_mut(&mut self) -> &mut AnimationInfo;
     /// Get the name of the animation
     fn anim_name(&self) -> &str;
     /// Evaluate to [`DynItem`]
@@ -190,6 +283,10 @@ pub trait CoreItemAnimation {
     }
     /// Evaluate to [`CoreItem`]s
     fn eval_alpha_core_item(&self, alpha: f64) -> Vec<CoreItem>;
+    /// Evaluate the ghosts to [`CoreItem`]s, see [`AnimationCell::with_ghosts`].
+    ///
+    /// They are empty if the animation has no ghosts or it is not playing at `alpha`.
+    fn eval_alpha_ghosts(&self, alpha: f64) -> Vec<CoreItem>;
     /// Evaluate to [`CoreItem`]s at global sec
     fn eval_global_sec_core_item(&self, sec: f64) -> Option<Vec<CoreItem>> {
         self.anim_info()
@@ -207,10 +304,27 @@ impl<T: AnyExtractCoreItem> CoreItemAnimation for AnimationCell<T> {
     fn eval_alpha_core_item(&self, alpha: f64) -> Vec<CoreItem> {
         self.eval_alpha(alpha).extract()
     }
+    fn eval_alpha_ghosts(&self, alpha: f64) -> Vec<CoreItem> {
+        let mut items = Vec::new();
+        if let Some(opacity) = self.ghost_opacity
+            && 0.0 < alpha
+            && alpha < 1.0
+        {
+            for ghost_alpha in [0.0, 1.0] {
+                self.inner.eval_alpha(ghost_alpha).extract_into(&mut items);
+            }
+            items.retain(|item| !matches!(item, CoreItem::CameraFrame(_)));
+            items.iter_mut().for_each(|item| item.fade(opacity));
+        }
+        items
+    }
     // ANCHOR_END: AnimationCell-CoreItemAnimation-eval_alpha
     fn anim_info(&self) -> &AnimationInfo {
         &self.info
     }
+    fn anim_info_mut(&mut self) -> &mut AnimationInfo {
+        &mut self.info
+    }
     fn anim_name(&self) -> &str {
         &self.anim_name
     }
@@ -255,3 +369,120 @@ impl<T: Clone> Eval<T> for Static<T> {
     }
 }
 // ANCHOR_END: Static
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::rate_functions::smooth;
+
+    struct Progress;
+
+    impl Eval<f64> for Progress {
+        fn eval_alpha(&self, alpha: f64) -> f64 {
+            alpha
+        }
+    }
+
+    #[test]
+    fn test_reversed() {
+        let anim = || {
+            Progress
+                .into_animation_cell()
+                .with_rate_func(smooth)
+                .with_duration(2.0)
+                .at(1.0)
+        };
+        let reversed = anim().reversed();
+        let twice = anim().reversed().reversed();
+        assert_eq!(reversed.info.range(), anim().info.range());
+        for alpha in [0.0, 0.1, 0.25, 0.5, 0.8, 1.0] {
+            assert_eq!(reversed.eval_alpha(alpha), anim().eval_alpha(1.0 - alpha));
+            assert!((twice.eval_alpha(alpha) - anim().eval_alpha(alpha)).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_chain() {
+        let first = || {
+            Progress
+                .into_animation_cell()
+                .with_rate_func(smooth)
+                .at(1.0)
+        };
+        let second = || Progress.into_animation_cell().with_duration(3.0);
+        let chain = first().chain(second());
+        assert_eq!(chain.info.range(), 1.0..5.0);
+
+        // Each part keeps its rate func
+        assert_eq!(chain.eval_alpha(0.0), 0.0);
+        assert_eq!(chain.eval_alpha(0.1), smooth(0.4));
+        // The second part starts at the boundary
+        assert_eq!(chain.eval_alpha(0.25), 0.0);
+        assert_eq!(chain.eval_alpha(0.625), 0.5);
+        assert_eq!(chain.eval_alpha(1.0), 1.0);
+
+        // Chaining again appends to the end
+        let chain = first().chain(second()).chain(second().with_duration(4.0));
+        assert_eq!(chain.info.duration_secs, 8.0);
+        assert_eq!(chain.eval_alpha(0.3125), 0.5);
+        assert_eq!(chain.eval_alpha(0.75), 0.5);
+
+        // A zero duration part is evaluated at its end
+        let chain = Progress
+            .into_animation_cell()
+            .with_duration(0.0)
+            .chain(second());
+        assert_eq!(chain.eval_alpha(0.0), 0.0);
+        assert_eq!(chain.eval_alpha(0.5), 0.5);
+    }
+
+    #[test]
+    fn test_ghosts() {
+        use crate::{Extract, components::rgba::Rgba, core_item::vitem::VItem};
+        use glam::Vec4;
+
+        struct Slide;
+
+        impl Eval<VItem> for Slide {
+            fn eval_alpha(&self, alpha: f64) -> VItem {
+                let mut item = VItem::default();
+                item.points.iter_mut().for_each(|p| p.x += alpha as f32);
+                item.fill_rgbas.fill(Rgba(Vec4::ONE));
+                item
+            }
+        }
+        let unwrap_vitem = |item: &CoreItem| match item {
+            CoreItem::VItem(vitem) => vitem.clone(),
+            _ => unreachable!(),
+        };
+
+        // Off by default
+        let anim = Slide.into_animation_cell().with_rate_func(smooth);
+        for alpha in [0.0, 0.3, 0.5, 1.0] {
+            assert!(anim.eval_alpha_ghosts(alpha).is_empty());
+        }
+
+        let anim = Slide
+            .into_animation_cell()
+            .with_rate_func(smooth)
+            .with_ghosts(0.25);
+        // The ghosts don't change the evaluated items
+        for alpha in [0.0, 0.3, 0.5, 1.0] {
+            assert_eq!(
+                anim.eval_alpha_core_item(alpha),
+                anim.eval_alpha(alpha).extract()
+            );
+        }
+        // Only while playing
+        assert!(anim.eval_alpha_ghosts(0.0).is_empty());
+        assert!(anim.eval_alpha_ghosts(1.0).is_empty());
+
+        let ghosts = anim.eval_alpha_ghosts(0.3);
+        assert_eq!(ghosts.len(), 2);
+        let [src, dst] = [0, 1].map(|i| unwrap_vitem(&ghosts[i]));
+        assert_eq!(src.points[0].x, 0.0);
+        assert_eq!(dst.points[0].x, 1.0);
+        assert_eq!(src.fill_rgbas[0].0.w, 0.25);
+        assert_eq!(dst.fill_rgbas[0].0.w, 0.25);
+    }
+}
@@ -7,6 +7,7 @@ pub use ::color::HueDirection;
 
 /// Color preludes
 pub mod prelude {
+    pub use super::{AlphaColorExt, BlendMode};
     pub use super::{color, try_color};
     pub use super::{rgb, rgb8, rgba, rgba8};
 }
@@ -46,3 +47,138 @@ pub fn color(color_str: &str) -> AlphaColor<Srgb> {
 pub fn try_color(color_str: &str) -> Result<AlphaColor<Srgb>, ParseError> {
     parse_color(color_str).map(|c| c.to_alpha_color::<Srgb>())
 }
+
+// MARK: AlphaColorExt
+/// The blend modes of [`AlphaColorExt::blend`], applied to each channel in linear sRGB.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BlendMode {
+    /// `a * b`, always darker
+    Multiply,
+    /// `1 - (1 - a) * (1 - b)`, always lighter
+    Screen,
+    /// [`BlendMode::Multiply`] for dark and [`BlendMode::Screen`] for light channels of the base color
+    Overlay,
+}
+
+impl BlendMode {
+    fn apply(&self, a: f32, b: f32) -> f32 {
+        match self {
+            Self::Multiply => a * b,
+            Self::Screen => 1.0 - (1.0 - a) * (1.0 - b),
+            Self::Overlay => {
+                if a < 0.5 {
+                    2.0 * a * b
+                } else {
+                    1.0 - 2.0 * (1.0 - a) * (1.0 - b)
+                }
+            }
+        }
+    }
+}
+
+/// Helpers to derive colors from an [`AlphaColor<Srgb>`], e.g. a palette from a base color:
+///
+/// ```rust
+/// use ranim_core::color::{palettes::manim, prelude::*};
+///
+/// let base = manim::BLUE_C;
+/// let palette = [base.darken(0.2), base.darken(0.1), base, base.lighten(0.1)];
+/// let gray = base.desaturate(1.0);
+/// let purple = base.mix(manim::RED_C, 0.5);
+/// ```
+///
+/// Lightness and saturation are changed in Oklch, so the hue is kept, and colors are mixed
+/// in Oklab. The alpha is kept, except for [`AlphaColorExt::mix`] which mixes it too.
+///
+/// This trait is implemented for [`AlphaColor<Srgb>`], you should not implement it yourself.
+pub trait AlphaColorExt: Sized {
+    /// Increase the lightness by `amount`, the lightness is in `0.0..=1.0`.
+    fn lighten(self, amount: f32) -> Self;
+    /// Decrease the lightness by `amount`, the lightness is in `0.0..=1.0`.
+    fn darken(self, amount: f32) -> Self {
+        self.lighten(-amount)
+    }
+    /// Scale the chroma by `1.0 + amount`.
+    fn saturate(self, amount: f32) -> Self;
+    /// Scale the chroma by `1.0 - amount`, `1.0` results in a gray.
+    fn desaturate(self, amount: f32) -> Self {
+        self.saturate(-amount)
+    }
+    /// Mix with `other`, `t = 0.0` is `self` and `t = 1.0` is `other`.
+    fn mix(self, other: Self, t: f32) -> Self;
+    /// Blend `other` onto `self` with the [`BlendMode`], weighted by the alpha of `other`.
+    fn blend(self, other: Self, mode: BlendMode) -> Self;
+}
+
+impl AlphaColorExt for AlphaColor<Srgb> {
+    fn lighten(self, amount: f32) -> Self {
+        self.map_in::<Oklch>(|l, c, h, a| [(l + amount).clamp(0.0, 1.0), c, h, a])
+    }
+    fn saturate(self, amount: f32) -> Self {
+        self.map_in::<Oklch>(|l, c, h, a| [l, c * (1.0 + amount).max(0.0), h, a])
+    }
+    fn mix(self, other: Self, t: f32) -> Self {
+        self.convert::<Oklab>()
+            .lerp_rect(other.convert::<Oklab>(), t)
+            .convert()
+    }
+    fn blend(self, other: Self, mode: BlendMode) -> Self {
+        let [r, g, b, alpha] = self.convert::<LinearSrgb>().components;
+        let [or, og, ob, oa] = other.convert::<LinearSrgb>().components;
+        let channel = |a: f32, b: f32| a + (mode.apply(a, b) - a) * oa;
+        AlphaColor::<LinearSrgb>::new([channel(r, or), channel(g, og), channel(b, ob), alpha])
+            .convert()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::color::palettes::manim;
+
+    fn assert_near(a: AlphaColor<Srgb>, b: AlphaColor<Srgb>) {
+        let diff = a
+            .components
+            .iter()
+            .zip(b.components)
+            .fold(0.0f32, |acc, (x, y)| acc.max((x - y).abs()));
+        assert!(diff < 1e-4, "{a:?} != {b:?}");
+    }
+
+    fn hue(color: AlphaColor<Srgb>) -> f32 {
+        color.convert::<Oklch>().components[2]
+    }
+
+    #[test]
+    fn test_color_helpers() {
+        let base = manim::BLUE_C;
+        assert_near(base.lighten(0.0).darken(0.0), base);
+        assert_near(base.saturate(0.0).desaturate(0.0), base);
+        assert_near(base.mix(manim::RED_C, 0.0), base);
+        assert_near(base.mix(manim::RED_C, 1.0), manim::RED_C);
+
+        // The hue is kept when changing the lightness and the saturation
+        for color in [base.lighten(0.1), base.darken(0.2), base.saturate(0.3)] {
+            assert!((hue(color) - hue(base)).abs() < 0.1, "{color:?}");
+        }
+        let lightness = |c: AlphaColor<Srgb>| c.convert::<Oklch>().components[0];
+        assert!(lightness(base.lighten(0.1)) > lightness(base));
+        assert!(lightness(base.darken(0.1)) < lightness(base));
+        assert!(base.desaturate(1.0).convert::<Oklch>().components[1] < 1e-4);
+        // The alpha is kept
+        assert_eq!(base.with_alpha(0.5).lighten(0.2).components[3], 0.5);
+
+        // Blending with white and black
+        let white = AlphaColor::<Srgb>::WHITE;
+        let black = AlphaColor::<Srgb>::BLACK;
+        assert_near(base.blend(white, BlendMode::Multiply), base);
+        assert_near(base.blend(black, BlendMode::Screen), base);
+        assert_near(base.blend(black, BlendMode::Multiply), black);
+        assert_near(base.blend(white, BlendMode::Screen), white);
+        // A transparent layer changes nothing
+        assert_near(
+            base.blend(manim::RED_C.with_alpha(0.0), BlendMode::Overlay),
+            base,
+        );
+    }
+}
@@ -4,124 +4,353 @@ pub use color::palette::css;
 pub mod manim {
     use color::{AlphaColor, Srgb};
 
-    use crate::color::rgb;
+    use crate::color::rgb8;
 
     /// <div style="background-color: #1C758A; width: 10px; padding: 10px; border: 1px solid;"></div>
-    pub const BLUE_E: AlphaColor<Srgb> = rgb(0.11, 0.46, 0.54);
+    pub const BLUE_E: AlphaColor<Srgb> = rgb8(0x1C, 0x75, 0x8A);
     /// <div style="background-color: #29ABCA; width: 10px; padding: 10px; border: 1px solid;"></div>
-    pub const BLUE_D: AlphaColor<Srgb> = rgb(0.16, 0.67, 0.79);
+    pub const BLUE_D: AlphaColor<Srgb> = rgb8(0x29, 0xAB, 0xCA);
     /// <div style="background-color: #58C4DD; width: 10px; padding: 10px; border: 1px solid;"></div>
-    pub const BLUE_C: AlphaColor<Srgb> = rgb(0.35, 0.77, 0.87);
+    pub const BLUE_C: AlphaColor<Srgb> = rgb8(0x58, 0xC4, 0xDD);
     /// <div style="background-color: #9CDCEB; width: 10px; padding: 10px; border: 1px solid;"></div>
-    pub const BLUE_B: AlphaColor<Srgb> = rgb(0.61, 0.86, 0.92);
+    pub const BLUE_B: AlphaColor<Srgb> = rgb8(0x9C, 0xDC, 0xEB);
     /// <div style="background-color: #C7E9F1; width: 10px; padding: 10px; border: 1px solid;"></div>
-    pub const BLUE_A: AlphaColor<Srgb> = rgb(0.78, 0.91, 0.95);
+    pub const BLUE_A: AlphaColor<Srgb> = rgb8(0xC7, 0xE9, 0xF1);
 
     /// <div style="background-color: #49A88F; width: 10px; padding: 10px; border: 1px solid;"></div>
-    pub const TEAL_E: AlphaColor<Srgb> = rgb(0.29, 0.66, 0.56);
+    pub const TEAL_E: AlphaColor<Srgb> = rgb8(0x49, 0xA8, 0x8F);
     /// <div style="background-color: #55C1A7; width: 10px; padding: 10px; border: 1px solid;"></div>
-    pub const TEAL_D: AlphaColor<Srgb> = rgb(0.33, 0.76, 0.66);
+    pub const TEAL_D: AlphaColor<Srgb> = rgb8(0x55, 0xC1, 0xA7);
     /// <div style="background-color: #5CD0B3; width: 10px; padding: 10px; border: 1px solid;"></div>
-    pub const TEAL_C: AlphaColor<Srgb> = rgb(0.36, 0.82, 0.70);
+    pub const TEAL_C: AlphaColor<Srgb> = rgb8(0x5C, 0xD0, 0xB3);
     /// <div style="background-color: #76DDC0; width: 10px; padding: 10px; border: 1px solid;"></div>
-    pub const TEAL_B: AlphaColor<Srgb> = rgb(0.46, 0.87, 0.75);
+    pub const TEAL_B: AlphaColor<Srgb> = rgb8(0x76, 0xDD, 0xC0);
     /// <div style="background-color: #ACEAD7; width: 10px; padding: 10px; border: 1px solid;"></div>
-    pub const TEAL_A: AlphaColor<Srgb> = rgb(0.68, 0.92, 0.84);
+    pub const TEAL_A: AlphaColor<Srgb> = rgb8(0xAC, 0xEA, 0xD7);
 
     /// <div style="background-color: #699C52; width: 10px; padding: 10px; border: 1px solid;"></div>
-    pub const GREEN_E: AlphaColor<Srgb> = rgb(0.41, 0.61, 0.32);
+    pub const GREEN_E: AlphaColor<Srgb> = rgb8(0x69, 0x9C, 0x52);
     /// <div style="background-color: #77B05D; width: 10px; padding: 10px; border: 1px solid;"></div>
-    pub const GREEN_D: AlphaColor<Srgb> = rgb(0.47, 0.69, 0.37);
+    pub const GREEN_D: AlphaColor<Srgb> = rgb8(0x77, 0xB0, 0x5D);
     /// <div style="background-color: #83C167; width: 10px; padding: 10px; border: 1px solid;"></div>
-    pub const GREEN_C: AlphaColor<Srgb> = rgb(0.51, 0.76, 0.40);
+    pub const GREEN_C: AlphaColor<Srgb> = rgb8(0x83, 0xC1, 0x67);
     /// <div style="background-color: #A6CF8C; width: 10px; padding: 10px; border: 1px solid;"></div>
-    pub const GREEN_B: AlphaColor<Srgb> = rgb(0.65, 0.81, 0.55);
+    pub const GREEN_B: AlphaColor<Srgb> = rgb8(0xA6, 0xCF, 0x8C);
     /// <div style="background-color: #C9E2AE; width: 10px; padding: 10px; border: 1px solid;"></div>
-    pub const GREEN_A: AlphaColor<Srgb> = rgb(0.79, 0.89, 0.68);
+    pub const GREEN_A: AlphaColor<Srgb> = rgb8(0xC9, 0xE2, 0xAE);
 
     /// <div style="background-color: #E8C11C; width: 10px; padding: 10px; border: 1px solid;"></div>
-    pub const YELLOW_E: AlphaColor<Srgb> = rgb(0.91, 0.76, 0.11);
+    pub const YELLOW_E: AlphaColor<Srgb> = rgb8(0xE8, 0xC1, 0x1C);
     /// <div style="background-color: #F4D345; width: 10px; padding: 10px; border: 1px solid;"></div>
-    pub const YELLOW_D: AlphaColor<Srgb> = rgb(0.96, 0.83, 0.27);
+    pub const YELLOW_D: AlphaColor<Srgb> = rgb8(0xF4, 0xD3, 0x45);
     /// <div style="background-color: #FFFF00; width: 10px; padding: 10px; border: 1px solid;"></div>
-    pub const YELLOW_C: AlphaColor<Srgb> = rgb(1.00, 1.00, 0.00);
+    pub const YELLOW_C: AlphaColor<Srgb> = rgb8(0xFF, 0xFF, 0x00);
     /// <div style="background-color: #FFEA94; width: 10px; padding: 10px; border: 1px solid;"></div>
-    pub const YELLOW_B: AlphaColor<Srgb> = rgb(1.00, 0.92, 0.58);
+    pub const YELLOW_B: AlphaColor<Srgb> = rgb8(0xFF, 0xEA, 0x94);
     /// <div style="background-color: #FFF1B6; width: 10px; padding: 10px; border: 1px solid;"></div>
-    pub const YELLOW_A: AlphaColor<Srgb> = rgb(1.00, 0.95, 0.71);
+    pub const YELLOW_A: AlphaColor<Srgb> = rgb8(0xFF, 0xF1, 0xB6);
 
     /// <div style="background-color: #C78D46; width: 10px; padding: 10px; border: 1px solid;"></div>
-    pub const GOLD_E: AlphaColor<Srgb> = rgb(0.78, 0.55, 0.28);
+    pub const GOLD_E: AlphaColor<Srgb> = rgb8(0xC7, 0x8D, 0x46);
     /// <div style="background-color: #E1A158; width: 10px; padding: 10px; border: 1px solid;"></div>
-    pub const GOLD_D: AlphaColor<Srgb> = rgb(0.88, 0.63, 0.35);
+    pub const GOLD_D: AlphaColor<Srgb> = rgb8(0xE1, 0xA1, 0x58);
     /// <div style="background-color: #F0AC5F; width: 10px; padding: 10px; border: 1px solid;"></div>
-    pub const GOLD_C: AlphaColor<Srgb> = rgb(0.94, 0.68, 0.37);
+    pub const GOLD_C: AlphaColor<Srgb> = rgb8(0xF0, 0xAC, 0x5F);
     /// <div style="background-color: #F9B775; width: 10px; padding: 10px; border: 1px solid;"></div>
-    pub const GOLD_B: AlphaColor<Srgb> = rgb(0.98, 0.72, 0.46);
+    pub const GOLD_B: AlphaColor<Srgb> = rgb8(0xF9, 0xB7, 0x75);
     /// <div style="background-color: #F7C797; width: 10px; padding: 10px; border: 1px solid;"></div>
-    pub const GOLD_A: AlphaColor<Srgb> = rgb(0.97, 0.78, 0.59);
+    pub const GOLD_A: AlphaColor<Srgb> = rgb8(0xF7, 0xC7, 0x97);
 
     /// <div style="background-color: #CF5044; width: 10px; padding: 10px; border: 1px solid;"></div>
-    pub const RED_E: AlphaColor<Srgb> = rgb(0.81, 0.31, 0.27);
+    pub const RED_E: AlphaColor<Srgb> = rgb8(0xCF, 0x50, 0x44);
     /// <div style="background-color: #E65A4C; width: 10px; padding: 10px; border: 1px solid;"></div>
-    pub const RED_D: AlphaColor<Srgb> = rgb(0.90, 0.35, 0.30);
+    pub const RED_D: AlphaColor<Srgb> = rgb8(0xE6, 0x5A, 0x4C);
     /// <div style="background-color: #FC6255; width: 10px; padding: 10px; border: 1px solid;"></div>
-    pub const RED_C: AlphaColor<Srgb> = rgb(0.99, 0.38, 0.33);
+    pub const RED_C: AlphaColor<Srgb> = rgb8(0xFC, 0x62, 0x55);
     /// <div style="background-color: #FF8080; width: 10px; padding: 10px; border: 1px solid;"></div>
-    pub const RED_B: AlphaColor<Srgb> = rgb(1.00, 0.50, 0.50);
+    pub const RED_B: AlphaColor<Srgb> = rgb8(0xFF, 0x80, 0x80);
     /// <div style="background-color: #F7A1A3; width: 10px; padding: 10px; border: 1px solid;"></div>
-    pub const RED_A: AlphaColor<Srgb> = rgb(0.97, 0.63, 0.64);
+    pub const RED_A: AlphaColor<Srgb> = rgb8(0xF7, 0xA1, 0xA3);
 
     /// <div style="background-color: #94424F; width: 10px; padding: 10px; border: 1px solid;"></div>
-    pub const MAROON_E: AlphaColor<Srgb> = rgb(0.58, 0.26, 0.31);
+    pub const MAROON_E: AlphaColor<Srgb> = rgb8(0x94, 0x42, 0x4F);
     /// <div style="background-color: #A24D61; width: 10px; padding: 10px; border: 1px solid;"></div>
-    pub const MAROON_D: AlphaColor<Srgb> = rgb(0.64, 0.30, 0.38);
+    pub const MAROON_D: AlphaColor<Srgb> = rgb8(0xA2, 0x4D, 0x61);
     /// <div style="background-color: #C55F73; width: 10px; padding: 10px; border: 1px solid;"></div>
-    pub const MAROON_C: AlphaColor<Srgb> = rgb(0.77, 0.37, 0.45);
+    pub const MAROON_C: AlphaColor<Srgb> = rgb8(0xC5, 0x5F, 0x73);
     /// <div style="background-color: #EC92AB; width: 10px; padding: 10px; border: 1px solid;"></div>
-    pub const MAROON_B: AlphaColor<Srgb> = rgb(0.92, 0.57, 0.67);
+    pub const MAROON_B: AlphaColor<Srgb> = rgb8(0xEC, 0x92, 0xAB);
     /// <div style="background-color: #ECABC1; width: 10px; padding: 10px; border: 1px solid;"></div>
-    pub const MAROON_A: AlphaColor<Srgb> = rgb(0.93, 0.67, 0.76);
+    pub const MAROON_A: AlphaColor<Srgb> = rgb8(0xEC, 0xAB, 0xC1);
 
     /// <div style="background-color: #644172; width: 10px; padding: 10px; border: 1px solid;"></div>
-    pub const PURPLE_E: AlphaColor<Srgb> = rgb(0.39, 0.26, 0.45);
+    pub const PURPLE_E: AlphaColor<Srgb> = rgb8(0x64, 0x41, 0x72);
     /// <div style="background-color: #715582; width: 10px; padding: 10px; border: 1px solid;"></div>
-    pub const PURPLE_D: AlphaColor<Srgb> = rgb(0.44, 0.33, 0.51);
+    pub const PURPLE_D: AlphaColor<Srgb> = rgb8(0x71, 0x55, 0x82);
     /// <div style="background-color: #9A72AC; width: 10px; padding: 10px; border: 1px solid;"></div>
-    pub const PURPLE_C: AlphaColor<Srgb> = rgb(0.60, 0.45, 0.68);
+    pub const PURPLE_C: AlphaColor<Srgb> = rgb8(0x9A, 0x72, 0xAC);
     /// <div style="background-color: #B189C6; width: 10px; padding: 10px; border: 1px solid;"></div>
-    pub const PURPLE_B: AlphaColor<Srgb> = rgb(0.69, 0.54, 0.78);
+    pub const PURPLE_B: AlphaColor<Srgb> = rgb8(0xB1, 0x89, 0xC6);
     /// <div style="background-color: #CAA3E8; width: 10px; padding: 10px; border: 1px solid;"></div>
-    pub const PURPLE_A: AlphaColor<Srgb> = rgb(0.79, 0.64, 0.91);
+    pub const PURPLE_A: AlphaColor<Srgb> = rgb8(0xCA, 0xA3, 0xE8);
 
     /// <div style="background-color: #222222; width: 10px; padding: 10px; border: 1px solid;"></div>
-    pub const GREY_E: AlphaColor<Srgb> = rgb(0.13, 0.13, 0.13);
+    pub const GREY_E: AlphaColor<Srgb> = rgb8(0x22, 0x22, 0x22);
     /// <div style="background-color: #444444; width: 10px; padding: 10px; border: 1px solid;"></div>
-    pub const GREY_D: AlphaColor<Srgb> = rgb(0.27, 0.27, 0.27);
+    pub const GREY_D: AlphaColor<Srgb> = rgb8(0x44, 0x44, 0x44);
     /// <div style="background-color: #888888; width: 10px; padding: 10px; border: 1px solid;"></div>
-    pub const GREY_C: AlphaColor<Srgb> = rgb(0.53, 0.53, 0.53);
+    pub const GREY_C: AlphaColor<Srgb> = rgb8(0x88, 0x88, 0x88);
     /// <div style="background-color: #BBBBBB; width: 10px; padding: 10px; border: 1px solid;"></div>
-    pub const GREY_B: AlphaColor<Srgb> = rgb(0.73, 0.73, 0.73);
+    pub const GREY_B: AlphaColor<Srgb> = rgb8(0xBB, 0xBB, 0xBB);
     /// <div style="background-color: #DDDDDD; width: 10px; padding: 10px; border: 1px solid;"></div>
-    pub const GREY_A: AlphaColor<Srgb> = rgb(0.87, 0.87, 0.87);
+    pub const GREY_A: AlphaColor<Srgb> = rgb8(0xDD, 0xDD, 0xDD);
 
     /// <div style="background-color: #FFFFFF; width: 10px; padding: 10px; border: 1px solid;"></div>
-    pub const WHITE: AlphaColor<Srgb> = rgb(1.00, 1.00, 1.00);
+    pub const WHITE: AlphaColor<Srgb> = rgb8(0xFF, 0xFF, 0xFF);
     /// <div style="background-color: #000000; width: 10px; padding: 10px; border: 1px solid;"></div>
-    pub const BLACK: AlphaColor<Srgb> = rgb(0.00, 0.00, 0.00);
+    pub const BLACK: AlphaColor<Srgb> = rgb8(0x00, 0x00, 0x00);
     /// <div style="background-color: #00FF00; width: 10px; padding: 10px; border: 1px solid;"></div>
-    pub const GREEN_SCREEN: AlphaColor<Srgb> = rgb(0.00, 1.00, 0.00);
+    pub const GREEN_SCREEN: AlphaColor<Srgb> = rgb8(0x00, 0xFF, 0x00);
 
     /// <div style="background-color: #736357; width: 10px; padding: 10px; border: 1px solid;"></div>
-    pub const GREY_BROWN: AlphaColor<Srgb> = rgb(0.45, 0.39, 0.34);
+    pub const GREY_BROWN: AlphaColor<Srgb> = rgb8(0x73, 0x63, 0x57);
+    /// <div style="background-color: #8B4513; width: 10px; padding: 10px; border: 1px solid;"></div>
+    pub const DARK_BROWN: AlphaColor<Srgb> = rgb8(0x8B, 0x45, 0x13);
     /// <div style="background-color: #CD853F; width: 10px; padding: 10px; border: 1px solid;"></div>
-    pub const LIGHT_BROWN: AlphaColor<Srgb> = rgb(0.80, 0.52, 0.25);
+    pub const LIGHT_BROWN: AlphaColor<Srgb> = rgb8(0xCD, 0x85, 0x3F);
 
     /// <div style="background-color: #D147BD; width: 10px; padding: 10px; border: 1px solid;"></div>
-    pub const PINK: AlphaColor<Srgb> = rgb(0.82, 0.28, 0.74);
+    pub const PINK: AlphaColor<Srgb> = rgb8(0xD1, 0x47, 0xBD);
     /// <div style="background-color: #DC75CD; width: 10px; padding: 10px; border: 1px solid;"></div>
-    pub const LIGHT_PINK: AlphaColor<Srgb> = rgb(0.86, 0.46, 0.80);
+    pub const LIGHT_PINK: AlphaColor<Srgb> = rgb8(0xDC, 0x75, 0xCD);
 
     /// <div style="background-color: #FF862F; width: 10px; padding: 10px; border: 1px solid;"></div>
-    pub const ORANGE: AlphaColor<Srgb> = rgb(1.00, 0.53, 0.18);
+    pub const ORANGE: AlphaColor<Srgb> = rgb8(0xFF, 0x86, 0x2F);
+
+    /// <div style="background-color: #FF0000; width: 10px; padding: 10px; border: 1px solid;"></div>
+    pub const PURE_RED: AlphaColor<Srgb> = rgb8(0xFF, 0x00, 0x00);
+    /// <div style="background-color: #00FF00; width: 10px; padding: 10px; border: 1px solid;"></div>
+    pub const PURE_GREEN: AlphaColor<Srgb> = rgb8(0x00, 0xFF, 0x00);
+    /// <div style="background-color: #0000FF; width: 10px; padding: 10px; border: 1px solid;"></div>
+    pub const PURE_BLUE: AlphaColor<Srgb> = rgb8(0x00, 0x00, 0xFF);
+
+    // The colors of the manim logo
+    /// <div style="background-color: #ECE7E2; width: 10px; padding: 10px; border: 1px solid;"></div>
+    pub const LOGO_WHITE: AlphaColor<Srgb> = rgb8(0xEC, 0xE7, 0xE2);
+    /// <div style="background-color: #87C2A5; width: 10px; padding: 10px; border: 1px solid;"></div>
+    pub const LOGO_GREEN: AlphaColor<Srgb> = rgb8(0x87, 0xC2, 0xA5);
+    /// <div style="background-color: #525893; width: 10px; padding: 10px; border: 1px solid;"></div>
+    pub const LOGO_BLUE: AlphaColor<Srgb> = rgb8(0x52, 0x58, 0x93);
+    /// <div style="background-color: #E07A5F; width: 10px; padding: 10px; border: 1px solid;"></div>
+    pub const LOGO_RED: AlphaColor<Srgb> = rgb8(0xE0, 0x7A, 0x5F);
+    /// <div style="background-color: #343434; width: 10px; padding: 10px; border: 1px solid;"></div>
+    pub const LOGO_BLACK: AlphaColor<Srgb> = rgb8(0x34, 0x34, 0x34);
+
+    // The aliases of the colors above, as in manim
+    /// The same as [`BLUE_C`]
+    ///
+    /// <div style="background-color: #58C4DD; width: 10px; padding: 10px; border: 1px solid;"></div>
+    pub const BLUE: AlphaColor<Srgb> = BLUE_C;
+    /// The same as [`TEAL_C`]
+    ///
+    /// <div style="background-color: #5CD0B3; width: 10px; padding: 10px; border: 1px solid;"></div>
+    pub const TEAL: AlphaColor<Srgb> = TEAL_C;
+    /// The same as [`GREEN_C`]
+    ///
+    /// <div style="background-color: #83C167; width: 10px; padding: 10px; border: 1px solid;"></div>
+    pub const GREEN: AlphaColor<Srgb> = GREEN_C;
+    /// The same as [`YELLOW_C`]
+    ///
+    /// <div style="background-color: #FFFF00; width: 10px; padding: 10px; border: 1px solid;"></div>
+    pub const YELLOW: AlphaColor<Srgb> = YELLOW_C;
+    /// The same as [`GOLD_C`]
+    ///
+    /// <div style="background-color: #F0AC5F; width: 10px; padding: 10px; border: 1px solid;"></div>
+    pub const GOLD: AlphaColor<Srgb> = GOLD_C;
+    /// The same as [`RED_C`]
+    ///
+    /// <div style="background-color: #FC6255; width: 10px; padding: 10px; border: 1px solid;"></div>
+    pub const RED: AlphaColor<Srgb> = RED_C;
+    /// The same as [`MAROON_C`]
+    ///
+    /// <div style="background-color: #C55F73; width: 10px; padding: 10px; border: 1px solid;"></div>
+    pub const MAROON: AlphaColor<Srgb> = MAROON_C;
+    /// The same as [`PURPLE_C`]
+    ///
+    /// <div style="background-color: #9A72AC; width: 10px; padding: 10px; border: 1px solid;"></div>
+    pub const PURPLE: AlphaColor<Srgb> = PURPLE_C;
+    /// The same as [`GREY_A`]
+    ///
+    /// <div style="background-color: #DDDDDD; width: 10px; padding: 10px; border: 1px solid;"></div>
+    pub const LIGHTER_GREY: AlphaColor<Srgb> = GREY_A;
+    /// The same as [`GREY_B`]
+    ///
+    /// <div style="background-color: #BBBBBB; width: 10px; padding: 10px; border: 1px solid;"></div>
+    pub const LIGHT_GREY: AlphaColor<Srgb> = GREY_B;
+    /// The same as [`GREY_C`]
+    ///
+    /// <div style="background-color: #888888; width: 10px; padding: 10px; border: 1px solid;"></div>
+    pub const GREY: AlphaColor<Srgb> = GREY_C;
+    /// The same as [`GREY_D`]
+    ///
+    /// <div style="background-color: #444444; width: 10px; padding: 10px; border: 1px solid;"></div>
+    pub const DARK_GREY: AlphaColor<Srgb> = GREY_D;
+    /// The same as [`GREY_E`]
+    ///
+    /// <div style="background-color: #222222; width: 10px; padding: 10px; border: 1px solid;"></div>
+    pub const DARKER_GREY: AlphaColor<Srgb> = GREY_E;
+    /// The same as [`BLUE_E`]
+    ///
+    /// <div style="background-color: #1C758A; width: 10px; padding: 10px; border: 1px solid;"></div>
+    pub const DARK_BLUE: AlphaColor<Srgb> = BLUE_E;
+
+    // The `GRAY` spellings of the `GREY` colors, as in manim
+    /// The same as [`GREY_E`]
+    ///
+    /// <div style="background-color: #222222; width: 10px; padding: 10px; border: 1px solid;"></div>
+    pub const GRAY_E: AlphaColor<Srgb> = GREY_E;
+    /// The same as [`GREY_D`]
+    ///
+    /// <div style="background-color: #444444; width: 10px; padding: 10px; border: 1px solid;"></div>
+    pub const GRAY_D: AlphaColor<Srgb> = GREY_D;
+    /// The same as [`GREY_C`]
+    ///
+    /// <div style="background-color: #888888; width: 10px; padding: 10px; border: 1px solid;"></div>
+    pub const GRAY_C: AlphaColor<Srgb> = GREY_C;
+    /// The same as [`GREY_B`]
+    ///
+    /// <div style="background-color: #BBBBBB; width: 10px; padding: 10px; border: 1px solid;"></div>
+    pub const GRAY_B: AlphaColor<Srgb> = GREY_B;
+    /// The same as [`GREY_A`]
+    ///
+    /// <div style="background-color: #DDDDDD; width: 10px; padding: 10px; border: 1px solid;"></div>
+    pub const GRAY_A: AlphaColor<Srgb> = GREY_A;
+    /// The same as [`GREY_BROWN`]
+    ///
+    /// <div style="background-color: #736357; width: 10px; padding: 10px; border: 1px solid;"></div>
+    pub const GRAY_BROWN: AlphaColor<Srgb> = GREY_BROWN;
+    /// The same as [`LIGHTER_GREY`]
+    ///
+    /// <div style="background-color: #DDDDDD; width: 10px; padding: 10px; border: 1px solid;"></div>
+    pub const LIGHTER_GRAY: AlphaColor<Srgb> = LIGHTER_GREY;
+    /// The same as [`LIGHT_GREY`]
+    ///
+    /// <div style="background-color: #BBBBBB; width: 10px; padding: 10px; border: 1px solid;"></div>
+    pub const LIGHT_GRAY: AlphaColor<Srgb> = LIGHT_GREY;
+    /// The same as [`GREY`]
+    ///
+    /// <div style="background-color: #888888; width: 10px; padding: 10px; border: 1px solid;"></div>
+    pub const GRAY: AlphaColor<Srgb> = GREY;
+    /// The same as [`DARK_GREY`]
+    ///
+    /// <div style="background-color: #444444; width: 10px; padding: 10px; border: 1px solid;"></div>
+    pub const DARK_GRAY: AlphaColor<Srgb> = DARK_GREY;
+    /// The same as [`DARKER_GREY`]
+    ///
+    /// <div style="background-color: #222222; width: 10px; padding: 10px; border: 1px solid;"></div>
+    pub const DARKER_GRAY: AlphaColor<Srgb> = DARKER_GREY;
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn test_manim_colors() {
+            // The colors of manim, `manimlib/constants.py`
+            let colors = [
+                (BLUE_E, "#1C758A"),
+                (BLUE_D, "#29ABCA"),
+                (BLUE_C, "#58C4DD"),
+                (BLUE_B, "#9CDCEB"),
+                (BLUE_A, "#C7E9F1"),
+                (TEAL_E, "#49A88F"),
+                (TEAL_D, "#55C1A7"),
+                (TEAL_C, "#5CD0B3"),
+                (TEAL_B, "#76DDC0"),
+                (TEAL_A, "#ACEAD7"),
+                (GREEN_E, "#699C52"),
+                (GREEN_D, "#77B05D"),
+                (GREEN_C, "#83C167"),
+                (GREEN_B, "#A6CF8C"),
+                (GREEN_A, "#C9E2AE"),
+                (YELLOW_E, "#E8C11C"),
+                (YELLOW_D, "#F4D345"),
+                (YELLOW_C, "#FFFF00"),
+                (YELLOW_B, "#FFEA94"),
+                (YELLOW_A, "#FFF1B6"),
+                (GOLD_E, "#C78D46"),
+                (GOLD_D, "#E1A158"),
+                (GOLD_C, "#F0AC5F"),
+                (GOLD_B, "#F9B775"),
+                (GOLD_A, "#F7C797"),
+                (RED_E, "#CF5044"),
+                (RED_D, "#E65A4C"),
+                (RED_C, "#FC6255"),
+                (RED_B, "#FF8080"),
+                (RED_A, "#F7A1A3"),
+                (MAROON_E, "#94424F"),
+                (MAROON_D, "#A24D61"),
+                (MAROON_C, "#C55F73"),
+                (MAROON_B, "#EC92AB"),
+                (MAROON_A, "#ECABC1"),
+                (PURPLE_E, "#644172"),
+                (PURPLE_D, "#715582"),
+                (PURPLE_C, "#9A72AC"),
+                (PURPLE_B, "#B189C6"),
+                (PURPLE_A, "#CAA3E8"),
+                (GREY_E, "#222222"),
+                (GREY_D, "#444444"),
+                (GREY_C, "#888888"),
+                (GREY_B, "#BBBBBB"),
+                (GREY_A, "#DDDDDD"),
+                (WHITE, "#FFFFFF"),
+                (BLACK, "#000000"),
+                (GREEN_SCREEN, "#00FF00"),
+                (GREY_BROWN, "#736357"),
+                (DARK_BROWN, "#8B4513"),
+                (LIGHT_BROWN, "#CD853F"),
+                (PINK, "#D147BD"),
+                (LIGHT_PINK, "#DC75CD"),
+                (ORANGE, "#FF862F"),
+                (PURE_RED, "#FF0000"),
+                (PURE_GREEN, "#00FF00"),
+                (PURE_BLUE, "#0000FF"),
+                (BLUE, "#58C4DD"),
+                (TEAL, "#5CD0B3"),
+                (GREEN, "#83C167"),
+                (YELLOW, "#FFFF00"),
+                (GOLD, "#F0AC5F"),
+                (RED, "#FC6255"),
+                (MAROON, "#C55F73"),
+                (PURPLE, "#9A72AC"),
+                (LIGHTER_GREY, "#DDDDDD"),
+                (LIGHT_GREY, "#BBBBBB"),
+                (GREY, "#888888"),
+                (DARK_GREY, "#444444"),
+                (DARKER_GREY, "#222222"),
+                (DARK_BLUE, "#1C758A"),
+                (LOGO_WHITE, "#ECE7E2"),
+                (LOGO_GREEN, "#87C2A5"),
+                (LOGO_BLUE, "#525893"),
+                (LOGO_RED, "#E07A5F"),
+                (LOGO_BLACK, "#343434"),
+                (GRAY_E, "#222222"),
+                (GRAY_D, "#444444"),
+                (GRAY_C, "#888888"),
+                (GRAY_B, "#BBBBBB"),
+                (GRAY_A, "#DDDDDD"),
+                (GRAY_BROWN, "#736357"),
+                (LIGHTER_GRAY, "#DDDDDD"),
+                (LIGHT_GRAY, "#BBBBBB"),
+                (GRAY, "#888888"),
+                (DARK_GRAY, "#444444"),
+                (DARKER_GRAY, "#222222"),
+            ];
+            for (color, hex) in colors {
+                let [r, g, b, a] = color.to_rgba8().to_u8_array();
+                assert_eq!(format!("#{r:02X}{g:02X}{b:02X}"), hex);
+                assert_eq!(a, 255);
+            }
+        }
+    }
 }
@@ -24,6 +24,33 @@ impl<T: Clone> Clone for PointVec<T> {
 }
 
 impl<T: Component + Interpolatable> PointVec<T> {
+    /// Resample the values to `new_len` values, evenly spaced along the old ones.
+    ///
+    /// Unlike [`VecResizeTrait::resize_preserving_order`], which repeats the values,
+    /// the new values are linearly interpolated between their neighbors, so the values
+    /// change smoothly along the points. The first and the last values are kept.
+    pub fn resample(&mut self, new_len: usize) {
+        if self.is_empty() {
+            self.0.resize_with_default(new_len);
+            return;
+        }
+        if self.len() == new_len {
+            return;
+        }
+        let max_idx = self.len() - 1;
+        self.0 = (0..new_len)
+            .map(|i| {
+                let pos = if new_len > 1 {
+                    i as f64 * max_idx as f64 / (new_len - 1) as f64
+                } else {
+                    0.0
+                };
+                let idx = (pos as usize).min(max_idx);
+                let next = (idx + 1).min(max_idx);
+                self[idx].lerp(&self[next], pos - idx as f64)
+            })
+            .collect();
+    }
     /// Get a partial PointVec within a specified range.
     ///
     /// This will interpolate the values at the start and end indices, and then
@@ -62,6 +89,29 @@ impl<T: Component + Interpolatable> PointVec<T> {
         }
         .into()
     }
+    /// Get a partial PointVec within a specified range, the range wraps around the end.
+    ///
+    /// This keeps the values in step with the points of a closed path,
+    /// see [`VPointVec::get_partial_closed`](vpoint::VPointVec::get_partial_closed):
+    /// a range crossing `1.0` continues from the first value.
+    pub fn get_partial_closed(&self, range: std::ops::Range<f64>) -> Self {
+        let len = range.end - range.start;
+        if len >= 1.0 {
+            return self.clone();
+        }
+        let start = range.start.rem_euclid(1.0);
+        let end = start + len;
+        if end <= 1.0 {
+            return self.get_partial(start..end);
+        }
+
+        // The end of the first part is the start of the second part
+        let mut partial = self.get_partial(start..1.0);
+        partial
+            .0
+            .extend_from_slice(&self.get_partial(0.0..end - 1.0)[1..]);
+        partial
+    }
 }
 
 /// Point
@@ -10,6 +10,10 @@ use crate::{
 };
 
 /// Rgba
+///
+/// The components are in linear sRGB space, which is what the shaders blend in.
+/// Colors are converted to it from any [`AlphaColor`], and the render target
+/// encodes it back to sRGB on output.
 #[repr(C)]
 #[derive(Debug, Clone, Copy, PartialEq, bytemuck::Pod, bytemuck::Zeroable)]
 pub struct Rgba(pub Vec4);
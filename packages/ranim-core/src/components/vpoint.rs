@@ -1,12 +1,15 @@
 use std::cmp::Ordering;
 
+use approx::relative_eq;
 use derive_more::{Deref, DerefMut};
 use glam::DVec3;
 use itertools::Itertools;
 
 use crate::anchor::Aabb;
 use crate::traits::*;
-use crate::utils::bezier::{get_subpath_closed_flag, trim_quad_bezier};
+use crate::utils::bezier::{
+    get_subpath_closed_flag, quad_bezier_eval, quad_bezier_length, trim_quad_bezier,
+};
 use crate::utils::math::interpolate_usize;
 use crate::utils::{avg, resize_preserving_order_with_repeated_indices};
 
@@ -103,6 +106,39 @@ impl transform::ScaleTransform for VPointVec {
     }
 }
 
+impl PointsFunc for VPointVec {
+    fn apply_points_func(&mut self, f: impl for<'a> Fn(&'a mut [DVec3])) -> &mut Self {
+        f(self.as_mut());
+        self
+    }
+}
+
+impl FilledArea for VPointVec {
+    /// The area of the closed subpaths with the even-odd fill rule, i.e. the holes are
+    /// subtracted. The subpaths are measured on their own planes.
+    fn filled_area(&self) -> f64 {
+        if self.is_empty() {
+            return 0.0;
+        }
+        let closed_subpaths = self
+            .get_subpaths()
+            .into_iter()
+            .zip(self.get_subpath_closed_flags())
+            .filter_map(|(sp, closed)| closed.then_some(sp))
+            .collect::<Vec<_>>();
+        if closed_subpaths.is_empty() {
+            return 0.0;
+        }
+        let (outers, holes) = split_holes(closed_subpaths);
+        let area = |sps: &[Vec<DVec3>]| {
+            sps.iter()
+                .map(|sp| subpath_bezier_vector_area(sp).length())
+                .sum::<f64>()
+        };
+        (area(&outers) - area(&holes)).max(0.0)
+    }
+}
+
 impl Alignable for VPointVec {
     fn is_aligned(&self, other: &Self) -> bool {
         self.len() == other.len()
@@ -116,103 +152,7 @@ impl Alignable for VPointVec {
             return;
         }
 
-        let into_closed_subpaths = |subpaths: Vec<Vec<DVec3>>| -> Vec<Vec<DVec3>> {
-            subpaths
-                .into_iter()
-                .map(|sp| {
-                    // should have no zero-length subpath
-                    if !get_subpath_closed_flag(&sp).map(|f| f.1).unwrap() {
-                        let sp_len = sp.len();
-                        let sp_iter = sp.into_iter();
-                        sp_iter
-                            .clone()
-                            .take(sp_len - 1)
-                            .chain(sp_iter.rev())
-                            .collect::<Vec<_>>()
-                    } else {
-                        sp
-                    }
-                })
-                .collect::<Vec<_>>()
-        };
-        let mut sps_self = into_closed_subpaths(self.get_subpaths());
-        let mut sps_other = into_closed_subpaths(other.get_subpaths());
-        let len = sps_self.len().max(sps_other.len());
-        let resize_subpaths = |sps: &mut Vec<Vec<DVec3>>| {
-            if sps.len() != len {
-                let (mut x, idxs) = resize_preserving_order_with_repeated_indices(sps, len);
-                for idx in idxs {
-                    let center = avg(&x[idx]);
-                    x[idx].fill(center);
-                }
-                *sps = x;
-            }
-        };
-        resize_subpaths(&mut sps_self);
-        resize_subpaths(&mut sps_other);
-
-        let points_to_bez_tuples = |points: &[DVec3]| -> Vec<[DVec3; 3]> {
-            points
-                .windows(3)
-                .step_by(2)
-                .map(|w| [w[0], w[1], w[2]])
-                .collect()
-        };
-        let align_points = |points: &[DVec3], len: usize| -> Vec<DVec3> {
-            let bez_tuples = points_to_bez_tuples(points);
-
-            let diff_len = (len - points.len()) / 2;
-            // println!("{:?}", bez_tuples);
-            let mut lens = bez_tuples
-                .iter()
-                .map(|[a, b, c]| {
-                    if (a - b).length_squared() < f64::EPSILON {
-                        0.0
-                    } else {
-                        (c - a).length()
-                    }
-                })
-                .collect::<Vec<_>>();
-            let mut ipc = vec![0usize; bez_tuples.len()];
-
-            for _ in 0..diff_len {
-                // println!("{:?}", lens);
-                let idx = lens
-                    .iter()
-                    .position_max_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal))
-                    .unwrap();
-                ipc[idx] += 1;
-                lens[idx] *= ipc[idx] as f64 / (ipc[idx] + 1) as f64;
-            }
-            // println!("BEZ: {:?}", bez_tuples);
-            // println!("IPC: {:?}", ipc);
-            let new_segs = bez_tuples
-                .into_iter()
-                .zip(ipc)
-                .map(|(bez, ipc)| {
-                    // curve cnt is ipc + 1, anchor cnt is ipc + 2
-                    let alphas = (0..ipc + 2)
-                        .map(|i: usize| i as f64 / (ipc + 1) as f64)
-                        .collect::<Vec<_>>();
-                    let mut new_points = Vec::with_capacity((ipc + 1) * 2 + 1);
-                    new_points.push(bez[0]);
-                    // println!("###bez: {:?}, ipc: {}", bez, ipc);
-                    alphas.iter().tuple_windows().for_each(|(a1, a2)| {
-                        let partial = trim_quad_bezier(&bez, *a1, *a2);
-                        // println!("{} {}: {:?}", a1, a2, partial);
-                        new_points.extend(partial[1..].iter())
-                    });
-                    // println!("{:?}", new_points);
-                    new_points
-                })
-                .collect::<Vec<_>>();
-            let mut new_points = Vec::with_capacity(other.len());
-            new_points.extend_from_slice(&new_segs[0]);
-            for seg in new_segs.into_iter().skip(1) {
-                new_points.extend(&seg[1..]);
-            }
-            new_points
-        };
+        let (mut sps_self, mut sps_other) = aligned_closed_subpaths(self, other);
 
         sps_self
             .iter_mut()
@@ -221,28 +161,415 @@ impl Alignable for VPointVec {
                 // println!("sp align: {} {}", sp_a.len(), sp_b.len());
                 let len = sp_a.len().max(sp_b.len());
                 if sp_a.len() != len {
-                    *sp_a = align_points(sp_a, len)
+                    *sp_a = subdivide_subpath(sp_a, len)
                 }
                 if sp_b.len() != len {
-                    *sp_b = align_points(sp_b, len)
+                    *sp_b = subdivide_subpath(sp_b, len)
                 }
             });
 
-        let sps_to_points = |sps: Vec<Vec<DVec3>>| -> Vec<DVec3> {
-            let mut points = sps
-                .into_iter()
-                .flat_map(|sp| {
-                    let last = *sp.last().unwrap();
-                    sp.into_iter().chain(std::iter::once(last))
-                })
+        self.0 = subpaths_to_points(sps_self);
+        other.0 = subpaths_to_points(sps_other);
+    }
+}
+
+impl ArcLengthAlignable for VPointVec {
+    /// Align two [`VPointVec`]s so that the i-th anchors of them are at the same
+    /// fraction of the arc length of their subpaths.
+    ///
+    /// Every subpath is split at the anchors' arc length fractions of both subpaths,
+    /// so the shapes are kept and the points are matched by arc length instead of by index.
+    fn align_with_by_arc_length(&mut self, other: &mut Self) {
+        if self.is_empty() {
+            self.0 = vec![DVec3::ZERO; 3];
+        }
+        if other.is_empty() {
+            other.0 = vec![DVec3::ZERO; 3];
+        }
+        let (mut sps_self, mut sps_other) = aligned_closed_subpaths(self, other);
+        sps_self
+            .iter_mut()
+            .zip(sps_other.iter_mut())
+            .for_each(|(sp_a, sp_b)| {
+                let fractions_a = arc_length_fractions(sp_a);
+                let fractions_b = arc_length_fractions(sp_b);
+                let mut fractions = fractions_a
+                    .iter()
+                    .chain(fractions_b.iter())
+                    .copied()
+                    .collect::<Vec<_>>();
+                fractions.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+                fractions.dedup_by(|a, b| (*a - *b).abs() < 1e-9);
+                *sp_a = split_subpath_at_fractions(sp_a, &fractions_a, &fractions);
+                *sp_b = split_subpath_at_fractions(sp_b, &fractions_b, &fractions);
+            });
+
+        self.0 = subpaths_to_points(sps_self);
+        other.0 = subpaths_to_points(sps_other);
+    }
+}
+
+/// Subdivide the quad beziers of a subpath so that it has `len` points.
+///
+/// The new anchors are added to the longest beziers first.
+fn subdivide_subpath(points: &[DVec3], len: usize) -> Vec<DVec3> {
+    let bez_tuples = points
+        .windows(3)
+        .step_by(2)
+        .map(|w| [w[0], w[1], w[2]])
+        .collect::<Vec<_>>();
+
+    let diff_len = (len - points.len()) / 2;
+    // println!("{:?}", bez_tuples);
+    let mut lens = bez_tuples
+        .iter()
+        .map(|[a, b, c]| {
+            if (a - b).length_squared() < f64::EPSILON {
+                0.0
+            } else {
+                (c - a).length()
+            }
+        })
+        .collect::<Vec<_>>();
+    let mut ipc = vec![0usize; bez_tuples.len()];
+
+    for _ in 0..diff_len {
+        // println!("{:?}", lens);
+        let idx = lens
+            .iter()
+            .position_max_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal))
+            .unwrap();
+        ipc[idx] += 1;
+        lens[idx] *= ipc[idx] as f64 / (ipc[idx] + 1) as f64;
+    }
+    // println!("BEZ: {:?}", bez_tuples);
+    // println!("IPC: {:?}", ipc);
+    let new_segs = bez_tuples
+        .into_iter()
+        .zip(ipc)
+        .map(|(bez, ipc)| {
+            // curve cnt is ipc + 1, anchor cnt is ipc + 2
+            let alphas = (0..ipc + 2)
+                .map(|i: usize| i as f64 / (ipc + 1) as f64)
                 .collect::<Vec<_>>();
-            points.pop();
-            points
-        };
+            let mut new_points = Vec::with_capacity((ipc + 1) * 2 + 1);
+            new_points.push(bez[0]);
+            // println!("###bez: {:?}, ipc: {}", bez, ipc);
+            alphas.iter().tuple_windows().for_each(|(a1, a2)| {
+                let partial = trim_quad_bezier(&bez, *a1, *a2);
+                // println!("{} {}: {:?}", a1, a2, partial);
+                new_points.extend(partial[1..].iter())
+            });
+            // println!("{:?}", new_points);
+            new_points
+        })
+        .collect::<Vec<_>>();
+    let mut new_points = Vec::with_capacity(len);
+    new_points.extend_from_slice(&new_segs[0]);
+    for seg in new_segs.into_iter().skip(1) {
+        new_points.extend(&seg[1..]);
+    }
+    new_points
+}
+
+/// Get the subpaths of both as closed subpaths, and make them have the same subpath count.
+///
+/// Open subpaths are closed by going back along itself. The outer contours and the holes
+/// (see [`split_holes`]) are paired separately, so that a hole never morphs into an outer
+/// contour, and the paired subpaths of `b` are reversed to match the winding of `a`'s,
+/// which doesn't change the shape under the even-odd fill rule. The added subpaths are
+/// collapsed to the center of the subpath they repeat, or the one they are paired with
+/// if there is nothing to repeat.
+fn aligned_closed_subpaths(a: &VPointVec, b: &VPointVec) -> (Vec<Vec<DVec3>>, Vec<Vec<DVec3>>) {
+    let into_closed_subpaths = |subpaths: Vec<Vec<DVec3>>| -> Vec<Vec<DVec3>> {
+        subpaths
+            .into_iter()
+            .map(|sp| {
+                // should have no zero-length subpath
+                if !get_subpath_closed_flag(&sp).map(|f| f.1).unwrap() {
+                    let sp_len = sp.len();
+                    let sp_iter = sp.into_iter();
+                    sp_iter
+                        .clone()
+                        .take(sp_len - 1)
+                        .chain(sp_iter.rev())
+                        .collect::<Vec<_>>()
+                } else {
+                    sp
+                }
+            })
+            .collect::<Vec<_>>()
+    };
+    let (outers_a, holes_a) = split_holes(into_closed_subpaths(a.get_subpaths()));
+    let (outers_b, holes_b) = split_holes(into_closed_subpaths(b.get_subpaths()));
+    let (mut sps_a, mut sps_b) = aligned_subpath_counts(outers_a, outers_b);
+    let (holes_a, holes_b) = aligned_subpath_counts(holes_a, holes_b);
+    sps_a.extend(holes_a);
+    sps_b.extend(holes_b);
+    for (sp_a, sp_b) in sps_a.iter().zip(sps_b.iter_mut()) {
+        if subpath_vector_area(sp_a).dot(subpath_vector_area(sp_b)) < 0.0 {
+            sp_b.reverse();
+        }
+    }
+    (sps_a, sps_b)
+}
+
+/// Make the two lists of subpaths have the same count, see [`aligned_closed_subpaths`].
+fn aligned_subpath_counts(
+    mut sps_a: Vec<Vec<DVec3>>,
+    mut sps_b: Vec<Vec<DVec3>>,
+) -> (Vec<Vec<DVec3>>, Vec<Vec<DVec3>>) {
+    let collapsed = |sps: &[Vec<DVec3>]| {
+        sps.iter()
+            .map(|sp| vec![avg(sp); sp.len()])
+            .collect::<Vec<_>>()
+    };
+    if sps_a.is_empty() {
+        return (collapsed(&sps_b), sps_b);
+    }
+    if sps_b.is_empty() {
+        let sps_b = collapsed(&sps_a);
+        return (sps_a, sps_b);
+    }
+    let len = sps_a.len().max(sps_b.len());
+    let resize_subpaths = |sps: &mut Vec<Vec<DVec3>>| {
+        if sps.len() != len {
+            let (mut x, idxs) = resize_preserving_order_with_repeated_indices(sps, len);
+            for idx in idxs {
+                let center = avg(&x[idx]);
+                x[idx].fill(center);
+            }
+            *sps = x;
+        }
+    };
+    resize_subpaths(&mut sps_a);
+    resize_subpaths(&mut sps_b);
+    (sps_a, sps_b)
+}
 
-        self.0 = sps_to_points(sps_self);
-        other.0 = sps_to_points(sps_other);
+/// Split the closed subpaths into the outer contours and the holes, keeping their order.
+///
+/// A subpath is a hole if it is inside an odd number of the other subpaths, which is
+/// what the even-odd fill rule leaves unfilled.
+fn split_holes(subpaths: Vec<Vec<DVec3>>) -> (Vec<Vec<DVec3>>, Vec<Vec<DVec3>>) {
+    // The plane of the largest subpath
+    let normal = subpaths
+        .iter()
+        .map(|sp| subpath_vector_area(sp))
+        .max_by(|a, b| a.length_squared().total_cmp(&b.length_squared()))
+        .and_then(|area| area.try_normalize())
+        .unwrap_or(DVec3::Z);
+    let is_hole = subpaths
+        .iter()
+        .enumerate()
+        .map(|(i, sp)| {
+            let containing = subpaths
+                .iter()
+                .enumerate()
+                .filter(|(j, other)| *j != i && subpath_contains(other, sp[0], normal))
+                .count();
+            containing % 2 == 1
+        })
+        .collect::<Vec<_>>();
+    let (mut outers, mut holes) = (Vec::new(), Vec::new());
+    for (sp, is_hole) in subpaths.into_iter().zip(is_hole) {
+        if is_hole {
+            holes.push(sp);
+        } else {
+            outers.push(sp);
+        }
     }
+    (outers, holes)
+}
+
+/// The vector area of a closed subpath, with the points treated as a polygon.
+///
+/// Its length is the area, and its direction is the normal of the winding by the right hand rule.
+fn subpath_vector_area(subpath: &[DVec3]) -> DVec3 {
+    let Some(&origin) = subpath.first() else {
+        return DVec3::ZERO;
+    };
+    subpath
+        .windows(2)
+        .map(|w| (w[0] - origin).cross(w[1] - origin))
+        .sum::<DVec3>()
+        / 2.0
+}
+
+/// The vector area of a closed subpath of quad beziers, like [`subpath_vector_area`]
+/// but with the curved segments measured exactly.
+fn subpath_bezier_vector_area(subpath: &[DVec3]) -> DVec3 {
+    let Some(&origin) = subpath.first() else {
+        return DVec3::ZERO;
+    };
+    // The integral of `p × dp / 2` over a quad bezier `(a, h, b)`
+    subpath
+        .windows(3)
+        .step_by(2)
+        .map(|w| {
+            let (a, h, b) = (w[0] - origin, w[1] - origin, w[2] - origin);
+            (2.0 * a.cross(h) + 2.0 * h.cross(b) + a.cross(b)) / 6.0
+        })
+        .sum()
+}
+
+/// Whether `point` is inside the closed subpath projected on the plane of `normal`,
+/// with the points treated as a polygon.
+fn subpath_contains(subpath: &[DVec3], point: DVec3, normal: DVec3) -> bool {
+    let (u, v) = normal.any_orthonormal_pair();
+    let project = |p: DVec3| (p.dot(u), p.dot(v));
+    let (px, py) = project(point);
+    subpath
+        .windows(2)
+        .map(|w| (project(w[0]), project(w[1])))
+        .filter(|&((ax, ay), (bx, by))| {
+            (ay > py) != (by > py) && px < ax + (py - ay) / (by - ay) * (bx - ax)
+        })
+        .count()
+        % 2
+        == 1
+}
+
+/// Join the subpaths back into points, the reverse of [`VPointVec::get_subpaths`].
+fn subpaths_to_points(sps: Vec<Vec<DVec3>>) -> Vec<DVec3> {
+    let mut points = sps
+        .into_iter()
+        .flat_map(|sp| {
+            let last = *sp.last().unwrap();
+            sp.into_iter().chain(std::iter::once(last))
+        })
+        .collect::<Vec<_>>();
+    points.pop();
+    points
+}
+
+/// The lengths of the quad beziers of a subpath.
+///
+/// If the subpath has no length, every bezier is treated as length `1.0`.
+fn subpath_bezier_lengths(subpath: &[DVec3]) -> (Vec<[DVec3; 3]>, Vec<f64>, bool) {
+    let beziers = subpath
+        .windows(3)
+        .step_by(2)
+        .map(|w| [w[0], w[1], w[2]])
+        .collect::<Vec<_>>();
+    let lengths = beziers.iter().map(quad_bezier_length).collect::<Vec<_>>();
+    if lengths.iter().sum::<f64>() <= f64::EPSILON {
+        let lengths = vec![1.0; beziers.len()];
+        (beziers, lengths, true)
+    } else {
+        (beziers, lengths, false)
+    }
+}
+
+/// The arc length fraction of every anchor of a subpath, starts with `0.0` and ends with `1.0`.
+fn arc_length_fractions(subpath: &[DVec3]) -> Vec<f64> {
+    let (_, lengths, _) = subpath_bezier_lengths(subpath);
+    let total = lengths.iter().sum::<f64>();
+    let mut fractions = Vec::with_capacity(lengths.len() + 1);
+    let mut acc = 0.0;
+    fractions.push(0.0);
+    for len in &lengths {
+        acc += len;
+        fractions.push(acc / total);
+    }
+    *fractions.last_mut().unwrap() = 1.0;
+    fractions
+}
+
+/// The param of a quad bezier where the arc length from its start is `length`.
+fn quad_bezier_t_at_length(bezier: &[DVec3; 3], length: f64) -> f64 {
+    let (mut lo, mut hi) = (0.0, 1.0);
+    for _ in 0..32 {
+        let mid = (lo + hi) / 2.0;
+        if quad_bezier_length(&trim_quad_bezier(bezier, 0.0, mid)) < length {
+            lo = mid;
+        } else {
+            hi = mid;
+        }
+    }
+    (lo + hi) / 2.0
+}
+
+/// The bezier index and the param in it of every arc length `alpha` of the total length
+/// of the consecutive `beziers`, whose lengths are `lengths`.
+///
+/// Returns `None` if the beziers have no length.
+fn beziers_at_arc_lengths(
+    beziers: &[[DVec3; 3]],
+    lengths: &[f64],
+    alphas: impl IntoIterator<Item = f64>,
+) -> Option<Vec<(usize, f64)>> {
+    let ends = lengths
+        .iter()
+        .scan(0.0, |acc, len| {
+            *acc += len;
+            Some(*acc)
+        })
+        .collect::<Vec<_>>();
+    let total = ends.last().copied().unwrap_or_default();
+    if total <= f64::EPSILON {
+        return None;
+    }
+
+    let located = alphas
+        .into_iter()
+        .map(|alpha| {
+            let length = alpha * total;
+            let idx = ends
+                .partition_point(|end| *end < length)
+                .min(beziers.len() - 1);
+            let start = ends[idx] - lengths[idx];
+            let t = if lengths[idx] <= f64::EPSILON || length <= start {
+                0.0
+            } else if length >= ends[idx] {
+                1.0
+            } else {
+                quad_bezier_t_at_length(&beziers[idx], length - start)
+            };
+            (idx, t)
+        })
+        .collect();
+    Some(located)
+}
+
+/// Split a subpath into `fractions.len() - 1` quad beziers, the i-th of which spans
+/// `fractions[i]..fractions[i + 1]` of the arc length.
+///
+/// `anchor_fractions` is the [`arc_length_fractions`] of the subpath, which should be
+/// contained in `fractions`, so every new bezier is a part of an original one.
+fn split_subpath_at_fractions(
+    subpath: &[DVec3],
+    anchor_fractions: &[f64],
+    fractions: &[f64],
+) -> Vec<DVec3> {
+    let (beziers, lengths, degenerated) = subpath_bezier_lengths(subpath);
+    // The param of the given arc length fraction in the idx-th bezier
+    let local_t = |idx: usize, fraction: f64| -> f64 {
+        let (start, end) = (anchor_fractions[idx], anchor_fractions[idx + 1]);
+        let target = ((fraction - start) / (end - start)).clamp(0.0, 1.0);
+        if degenerated || target == 0.0 || target == 1.0 {
+            return target;
+        }
+        quad_bezier_t_at_length(&beziers[idx], target * lengths[idx])
+    };
+
+    let mut points = Vec::with_capacity(fractions.len() * 2 - 1);
+    points.push(subpath[0]);
+    for (f0, f1) in fractions.iter().tuple_windows() {
+        let mid = (f0 + f1) / 2.0;
+        let idx = anchor_fractions
+            .windows(2)
+            .position(|w| w[0] <= mid && mid < w[1])
+            .unwrap_or(beziers.len() - 1);
+        let (t0, t1) = (local_t(idx, *f0), local_t(idx, *f1));
+        let bezier = if t1 <= 0.0 {
+            [beziers[idx][0]; 3]
+        } else {
+            trim_quad_bezier(&beziers[idx], t0, t1)
+        };
+        points.extend_from_slice(&bezier[1..]);
+    }
+    points
 }
 
 // fn extend_subpath_with_n(mut subpath: Vec<DVec3>, n: usize) -> Vec<DVec3> {
@@ -293,11 +620,193 @@ impl VPointVec {
 
         subpaths
     }
+    /// The total arc length of the subpaths, the jumps between them are not counted.
+    pub fn arc_length(&self) -> f64 {
+        self.get_subpaths()
+            .iter()
+            .flat_map(|subpath| subpath.windows(3).step_by(2))
+            .map(|w| quad_bezier_length(&[w[0], w[1], w[2]]))
+            .sum()
+    }
+    /// Sample `n` points uniformly spaced by arc length along the path,
+    /// the first and the last ones are the start and the end of the path.
+    ///
+    /// The jumps between subpaths are skipped, so the points are distributed among the
+    /// subpaths proportionally to their lengths. For a closed path the last point is the
+    /// same as the first one.
+    pub fn sample_points(&self, n: usize) -> Vec<DVec3> {
+        let beziers = self
+            .get_subpaths()
+            .iter()
+            .flat_map(|subpath| subpath.windows(3).step_by(2))
+            .map(|w| [w[0], w[1], w[2]])
+            .collect::<Vec<_>>();
+        let lengths = beziers.iter().map(quad_bezier_length).collect::<Vec<_>>();
+        let alphas = (0..n).map(|i| {
+            if n == 1 {
+                0.0
+            } else {
+                i as f64 / (n - 1) as f64
+            }
+        });
+        match beziers_at_arc_lengths(&beziers, &lengths, alphas) {
+            Some(located) => located
+                .into_iter()
+                .map(|(idx, t)| quad_bezier_eval(&beziers[idx], t))
+                .collect(),
+            None => self.first().map(|p| vec![*p; n]).unwrap_or_default(),
+        }
+    }
+    /// The segment, and the param in it, where the arc length from the start of the path
+    /// is `alpha` of the total, `alpha` is clamped to `[0, 1]`.
+    ///
+    /// The jumps between subpaths have no length. A position on an anchor is at the end
+    /// of the segment before it, i.e. the param is `1.0`, except for the start of the path.
+    pub fn segment_at_arc_length(&self, alpha: f64) -> (usize, f64) {
+        let seg_cnt = self.len() / 2;
+        let beziers = (0..seg_cnt)
+            .map(|i| *self.get_seg(i).unwrap())
+            .collect::<Vec<_>>();
+        let lengths = beziers
+            .iter()
+            .map(|b| {
+                if b[0] == b[1] {
+                    0.0
+                } else {
+                    quad_bezier_length(b)
+                }
+            })
+            .collect::<Vec<_>>();
+        let alpha = alpha.clamp(0.0, 1.0);
+        beziers_at_arc_lengths(&beziers, &lengths, [alpha])
+            .map(|located| located[0])
+            .unwrap_or((0, alpha))
+    }
+    /// Subdivide every subpath to have at least `n` quad bezier segments.
+    ///
+    /// The new anchors are added to the longest segments first and the shape is kept exactly,
+    /// subpaths that already have enough segments are not changed. So this gives more anchors
+    /// to the pointwise attrs and the alignment, but a curve approximating a shape doesn't
+    /// get closer to it, that needs re-sampling the shape.
+    pub fn with_min_curve_resolution(mut self, n: usize) -> Self {
+        let min_len = 2 * n + 1;
+        if self.is_empty() {
+            return self;
+        }
+        let subpaths = self.get_subpaths();
+        if subpaths.iter().all(|sp| sp.len() >= min_len) {
+            return self;
+        }
+        let subpaths = subpaths
+            .into_iter()
+            .map(|sp| {
+                if sp.len() < min_len {
+                    subdivide_subpath(&sp, min_len)
+                } else {
+                    sp
+                }
+            })
+            .collect();
+        self.0 = subpaths_to_points(subpaths);
+        self
+    }
+    /// Whether each subpath is closed, i.e. its end is connected back to its start.
+    ///
+    /// The closed subpaths are filled when rendering, the open ones are not.
+    pub fn get_subpath_closed_flags(&self) -> Vec<bool> {
+        if self.is_empty() {
+            return vec![];
+        }
+        self.get_subpaths()
+            .iter()
+            .map(|sp| get_subpath_closed_flag(sp).is_some_and(|(_, closed)| closed))
+            .collect()
+    }
+    /// Close the subpath at `idx` with a line from its end back to its start.
+    ///
+    /// If the next subpath already leads from its end back to its start, like the one split
+    /// off by [`VPointVec::open_subpath`], the two are joined instead.
+    ///
+    /// Nothing happens if the subpath is already closed or out of range.
+    pub fn close_subpath(&mut self, idx: usize) -> &mut Self {
+        self.map_subpaths(idx, |subpaths| {
+            let sp = &subpaths[idx];
+            let (start, end) = (sp[0], *sp.last().unwrap());
+            if sp.len() < 3 || get_subpath_closed_flag(sp).is_some_and(|(_, closed)| closed) {
+                return;
+            }
+            if let Some(next) = subpaths.get(idx + 1)
+                && next.len() >= 3
+                && next[0] == end
+                && relative_eq!(*next.last().unwrap(), start)
+            {
+                let next = subpaths.remove(idx + 1);
+                subpaths[idx].extend_from_slice(&next[1..]);
+            } else {
+                subpaths[idx].extend([(start + end) / 2.0, start]);
+            }
+        })
+    }
+    /// Open the subpath at `idx`, so that its end is no longer connected back to its start.
+    ///
+    /// The segments coming back to the start from the start itself are degenerate or
+    /// duplicated returns, they are removed. Then the last segment, which is the real edge
+    /// closing the subpath, is split off into a subpath of its own, so no geometry is lost.
+    ///
+    /// Nothing happens if the subpath is already open, has only one segment or is out of range.
+    pub fn open_subpath(&mut self, idx: usize) -> &mut Self {
+        self.map_subpaths(idx, |subpaths| {
+            let sp = &mut subpaths[idx];
+            let start = sp[0];
+            while sp.len() > 3 && get_subpath_closed_flag(sp).is_some_and(|(_, closed)| closed) {
+                let last_seg = sp.split_off(sp.len() - 3);
+                sp.push(last_seg[0]);
+                if !relative_eq!(last_seg[0], start) {
+                    subpaths.insert(idx + 1, last_seg);
+                    break;
+                }
+            }
+        })
+    }
+    fn map_subpaths(&mut self, idx: usize, f: impl FnOnce(&mut Vec<Vec<DVec3>>)) -> &mut Self {
+        if self.is_empty() {
+            return self;
+        }
+        let mut subpaths = self.get_subpaths();
+        if idx < subpaths.len() {
+            f(&mut subpaths);
+            self.0 = subpaths_to_points(subpaths);
+        }
+        self
+    }
     /// Get the segment
     pub fn get_seg(&self, idx: usize) -> Option<&[DVec3; 3]> {
         self.get(idx * 2..idx * 2 + 3)
             .and_then(|seg| seg.try_into().ok())
     }
+    /// Map `alpha`, a fraction of the drawn segments, to the fraction of all the segments
+    /// used by [`VPointVec::get_partial`].
+    ///
+    /// The segment from the end of a subpath to the start of the next one is a jump which
+    /// is not drawn, skipping the jumps makes a partial of a multi-subpath item progress
+    /// from one subpath to the next without pausing at the jumps.
+    pub fn skip_subpath_jumps(&self, alpha: f64) -> f64 {
+        let seg_cnt = self.len() / 2;
+        let is_jump = |i: usize| self[2 * i] == self[2 * i + 1];
+        let drawn_cnt = (0..seg_cnt).filter(|&i| !is_jump(i)).count();
+        if drawn_cnt == 0 || drawn_cnt == seg_cnt {
+            return alpha;
+        }
+        let target = alpha.clamp(0.0, 1.0) * drawn_cnt as f64;
+        let mut drawn = 0.0;
+        for i in (0..seg_cnt).filter(|&i| !is_jump(i)) {
+            if target <= drawn + 1.0 {
+                return (i as f64 + target - drawn) / seg_cnt as f64;
+            }
+            drawn += 1.0;
+        }
+        1.0
+    }
     /// Get closed path flags
     pub fn get_closepath_flags(&self) -> Vec<bool> {
         let len = self.len();
@@ -334,7 +843,12 @@ impl VPointVec {
         let rotate_angle = cur_v.angle_between(v);
         let mut rotate_axis = cur_v.cross(v);
         if rotate_axis.length_squared() <= f64::EPSILON {
-            rotate_axis = DVec3::Z;
+            // Parallel or opposite, the half turn needs an axis perpendicular to the path
+            rotate_axis = if cur_v.dot(DVec3::Z).abs() <= f64::EPSILON {
+                DVec3::Z
+            } else {
+                cur_v.any_orthonormal_vector()
+            };
         }
         rotate_axis = rotate_axis.normalize();
         self.with_origin(cur_start, |x| {
@@ -385,6 +899,32 @@ impl VPointVec {
             VPointVec(partial)
         }
     }
+
+    /// Get partial of the vpoint as a closed path, the range wraps around the closure.
+    ///
+    /// If the path is closed (its end is its start), a range crossing `1.0` like `0.75..1.25`
+    /// (or `-0.25..0.25`) continues from the end of the path to its start, so the partial is
+    /// continuous around the closure. A range covering the whole path gives the whole path.
+    ///
+    /// For an open path the range is clamped to `0.0..=1.0`, same as [`VPointVec::get_partial`].
+    pub fn get_partial_closed(&self, range: std::ops::Range<f64>) -> Self {
+        let len = range.end - range.start;
+        if len >= 1.0 {
+            return self.clone();
+        }
+        let start = range.start.rem_euclid(1.0);
+        let end = start + len;
+        if end <= 1.0 || self.first() != self.last() {
+            return self.get_partial(start..end.min(1.0));
+        }
+
+        // The end of the first part is the start of the second part
+        let mut partial = self.get_partial(start..1.0);
+        partial
+            .0
+            .extend_from_slice(&self.get_partial(0.0..end - 1.0)[1..]);
+        partial
+    }
 }
 
 #[cfg(test)]
@@ -396,7 +936,7 @@ mod test {
 
     use crate::{
         components::vpoint::VPointVec,
-        traits::{Aabb, RotateTransform},
+        traits::{Aabb, ArcLengthAlignable, RotateTransform},
     };
 
     fn assert_dvec3_eq(a: DVec3, b: DVec3) {
@@ -446,6 +986,58 @@ mod test {
         assert_eq!(sps[1], vec![DVec3::Z]);
     }
 
+    #[test]
+    fn test_open_and_close_subpath() {
+        // A closed unit square, its left edge goes from (0, 1) back to the start
+        let square = VPointVec(vec![
+            dvec3(0.0, 0.0, 0.0),
+            dvec3(0.5, 0.0, 0.0),
+            dvec3(1.0, 0.0, 0.0),
+            dvec3(1.0, 0.5, 0.0),
+            dvec3(1.0, 1.0, 0.0),
+            dvec3(0.5, 1.0, 0.0),
+            dvec3(0.0, 1.0, 0.0),
+            dvec3(0.0, 0.5, 0.0),
+            dvec3(0.0, 0.0, 0.0),
+        ]);
+
+        // The left edge is split off instead of removed
+        let mut opened = square.clone();
+        opened.open_subpath(0);
+        assert_eq!(opened.get_subpath_closed_flags(), vec![false, false]);
+        let expected = vec![square[..7].to_vec(), square[6..].to_vec()];
+        assert_eq!(opened.get_subpaths(), expected);
+        // Opening again changes nothing
+        opened.open_subpath(0);
+        assert_eq!(opened.get_subpaths(), expected);
+        // Closing joins it back
+        opened.close_subpath(0);
+        assert_eq!(opened, square);
+
+        // A duplicated return from the start to the start is removed
+        let mut looped = square.clone();
+        looped.extend([dvec3(-0.5, -0.5, 0.0), DVec3::ZERO]);
+        looped.open_subpath(0);
+        assert_eq!(looped.get_subpaths(), expected);
+
+        // An open subpath is closed with a line
+        let mut open = VPointVec(square[..7].to_vec());
+        open.close_subpath(0);
+        assert_eq!(open.get_subpath_closed_flags(), vec![true]);
+        assert_points_eq(
+            &open[6..],
+            &[dvec3(0.0, 1.0, 0.0), dvec3(0.0, 0.5, 0.0), DVec3::ZERO],
+        );
+        // Only the subpath at `idx` is changed
+        let mut two = square.clone();
+        // The end anchor repeated as a handle starts a new subpath
+        two.push(DVec3::ZERO);
+        two.extend(square.iter().map(|p| p + DVec3::X * 2.0));
+        two.open_subpath(1);
+        assert_eq!(two.get_subpath_closed_flags(), vec![true, false, false]);
+        assert_eq!(two.get_subpaths()[0], square.0);
+    }
+
     #[test]
     fn test_get_partial_full_range() {
         let points = VPointVec(vec![
@@ -515,6 +1107,48 @@ mod test {
         assert_dvec3_eq(partial[2], dvec3(2.0, 2.0, 2.0));
     }
 
+    #[test]
+    fn test_get_partial_closed() {
+        let corners = [
+            dvec3(-1.0, -1.0, 0.0),
+            dvec3(1.0, -1.0, 0.0),
+            dvec3(1.0, 1.0, 0.0),
+            dvec3(-1.0, 1.0, 0.0),
+        ];
+        let mut points = vec![corners[0]];
+        for i in 0..4 {
+            let (a, b) = (corners[i], corners[(i + 1) % 4]);
+            points.extend([(a + b) / 2.0, b]);
+        }
+        let square = VPointVec(points);
+
+        assert_eq!(square.get_partial_closed(0.0..1.0), square);
+        assert_eq!(square.get_partial_closed(0.3..1.3), square);
+        assert_points_eq(
+            &square.get_partial_closed(0.0..0.5),
+            &square.get_partial(0.0..0.5),
+        );
+
+        // The last side and then the first side, through the closure at the first corner
+        let expected = [
+            corners[3],
+            (corners[3] + corners[0]) / 2.0,
+            corners[0],
+            (corners[0] + corners[1]) / 2.0,
+            corners[1],
+        ];
+        assert_points_eq(&square.get_partial_closed(0.75..1.25), &expected);
+        assert_points_eq(&square.get_partial_closed(-0.25..0.25), &expected);
+        assert_points_eq(&square.get_partial_closed(1.75..2.25), &expected);
+
+        // An open path doesn't wrap
+        let open = VPointVec(square[..7].to_vec());
+        assert_points_eq(
+            &open.get_partial_closed(0.5..1.25),
+            &open.get_partial(0.5..1.0),
+        );
+    }
+
     #[test]
     fn test_rotate() {
         let mut points = VPointVec(vec![
@@ -636,4 +1270,132 @@ mod test {
         assert_dvec3_eq(min, DVec3::ZERO);
         assert_dvec3_eq(max, DVec3::ZERO);
     }
+
+    #[test]
+    fn test_align_with_by_arc_length() {
+        // A line with a single segment, and a line whose anchors are crowded at the start
+        let polyline = |xs: &[f64], y: f64| {
+            let mut points = vec![dvec3(xs[0], y, 0.0)];
+            for (a, b) in xs.iter().zip(&xs[1..]) {
+                points.push(dvec3((a + b) / 2.0, y, 0.0));
+                points.push(dvec3(*b, y, 0.0));
+            }
+            VPointVec(points)
+        };
+        let mut sparse = polyline(&[0.0, 4.0], 0.0);
+        let mut dense = polyline(&[0.0, 0.1, 0.2, 0.3, 4.0], 1.0);
+        sparse.align_with_by_arc_length(&mut dense);
+
+        assert_eq!(sparse.len(), dense.len());
+        for (a, b) in sparse.iter().zip(dense.iter()) {
+            assert_float_absolute_eq!(a.x, b.x, 1e-6);
+            assert_float_absolute_eq!(a.y, 0.0, 1e-10);
+            assert_float_absolute_eq!(b.y, 1.0, 1e-10);
+        }
+        // The anchors of the dense line are kept
+        for x in [0.1, 0.2, 0.3] {
+            assert!(dense.iter().step_by(2).any(|p| (p.x - x).abs() < 1e-9));
+        }
+    }
+
+    #[test]
+    fn test_with_min_curve_resolution() {
+        // Two subpaths, with 2 and 5 segments
+        let points = VPointVec(vec![
+            dvec3(0., 0., 0.),
+            dvec3(1., 1., 0.),
+            dvec3(2., 0., 0.),
+            dvec3(3., -1., 0.),
+            dvec3(4., 0., 0.),
+            dvec3(4., 0., 0.),
+            dvec3(5., 0., 0.),
+            dvec3(5.5, 0., 0.),
+            dvec3(6., 0., 0.),
+            dvec3(6.5, 0., 0.),
+            dvec3(7., 0., 0.),
+            dvec3(7.5, 0., 0.),
+            dvec3(8., 0., 0.),
+            dvec3(8.5, 0., 0.),
+            dvec3(9., 0., 0.),
+            dvec3(9.5, 0., 0.),
+            dvec3(10., 0., 0.),
+        ]);
+        let seg_cnts = |points: &VPointVec| -> Vec<usize> {
+            points
+                .get_subpaths()
+                .iter()
+                .map(|sp| (sp.len() - 1) / 2)
+                .collect()
+        };
+        assert_eq!(seg_cnts(&points), vec![2, 5]);
+
+        let subdivided = points.clone().with_min_curve_resolution(4);
+        assert_eq!(seg_cnts(&subdivided), vec![4, 5]);
+        let [min, max] = subdivided.aabb();
+        let [expected_min, expected_max] = points.aabb();
+        assert_dvec3_eq(min, expected_min);
+        assert_dvec3_eq(max, expected_max);
+
+        // No-op when the resolution already suffices
+        assert_eq!(points.clone().with_min_curve_resolution(2), points);
+    }
+
+    #[test]
+    fn test_align_holes() {
+        use super::{subpath_contains, subpaths_to_points};
+        use crate::traits::{Alignable, Interpolatable};
+
+        // A closed square subpath, counter-clockwise or clockwise
+        let square = |center: DVec3, half_size: f64, ccw: bool| {
+            let mut corners = [(-1., -1.), (1., -1.), (1., 1.), (-1., 1.)]
+                .map(|(x, y)| center + dvec3(x, y, 0.) * half_size)
+                .to_vec();
+            if !ccw {
+                corners.reverse();
+            }
+            corners.push(corners[0]);
+            let mut sp = vec![corners[0]];
+            for w in corners.windows(2) {
+                sp.extend([(w[0] + w[1]) / 2., w[1]]);
+            }
+            sp
+        };
+        let ring_a = VPointVec(subpaths_to_points(vec![
+            square(DVec3::ZERO, 2., true),
+            square(DVec3::ZERO, 1., true),
+        ]));
+        // The hole goes first and winds the other way, and the outer has more segments
+        let ring_b = VPointVec(subpaths_to_points(vec![
+            square(DVec3::X, 1., false),
+            super::subdivide_subpath(&square(DVec3::X, 3., true), 17),
+        ]));
+
+        let (mut a, mut b) = (ring_a.clone(), ring_b.clone());
+        a.align_with(&mut b);
+        assert!(a.is_aligned(&b));
+        let filled = |points: &VPointVec, p: DVec3| {
+            points
+                .get_subpaths()
+                .iter()
+                .filter(|sp| subpath_contains(sp, p, DVec3::Z))
+                .count()
+                % 2
+                == 1
+        };
+        for t in [0.0, 0.25, 0.5, 0.75, 1.0] {
+            let points = a.lerp(&b, t);
+            let hole_center = DVec3::X * t;
+            assert!(!filled(&points, hole_center), "t = {t}");
+            assert!(filled(&points, hole_center + DVec3::X * 1.5), "t = {t}");
+            assert!(filled(&points, hole_center - DVec3::Y * 1.5), "t = {t}");
+        }
+        // The ends keep their shapes
+        for (aligned, original) in [(&a, &ring_a), (&b, &ring_b)] {
+            let [min, max] = aligned.aabb();
+            let [expected_min, expected_max] = original.aabb();
+            assert_dvec3_eq(min, expected_min);
+            assert_dvec3_eq(max, expected_max);
+        }
+        assert!(!filled(&b, DVec3::X) && filled(&b, DVec3::new(3.5, 0., 0.)));
+    }
 }
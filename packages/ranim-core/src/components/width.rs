@@ -16,6 +16,31 @@ impl Width {
     pub fn min(self, other: Self) -> Self {
         Self(self.0.min(other.0))
     }
+
+    /// Clamp every width in `widths` to be at least `min`
+    ///
+    /// Zero widths are kept, since they mean the stroke is intentionally hidden.
+    pub fn clamp_min(widths: &mut [Width], min: f32) {
+        widths
+            .iter_mut()
+            .filter(|w| w.0 > 0.0)
+            .for_each(|w| *w = w.max(min.into()));
+    }
+
+    /// Linearly taper `widths` from `start` to `end` along the path
+    ///
+    /// The first width will be `start` and the last will be `end`.
+    pub fn taper(widths: &mut [Width], start: f32, end: f32) {
+        let n = widths.len();
+        widths.iter_mut().enumerate().for_each(|(i, w)| {
+            let t = if n > 1 {
+                i as f64 / (n - 1) as f64
+            } else {
+                0.0
+            };
+            *w = Self(start.lerp(&end, t));
+        });
+    }
 }
 
 impl Default for Width {
@@ -29,3 +54,38 @@ impl Interpolatable for Width {
         Self(self.0.lerp(&target.0, t))
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_taper() {
+        let mut widths = vec![Width::default(); 5];
+        Width::taper(&mut widths, 1.0, 0.0);
+        assert_eq!(widths, [1.0, 0.75, 0.5, 0.25, 0.0].map(Width).to_vec());
+
+        let mut widths = vec![Width::default(); 1];
+        Width::taper(&mut widths, 0.5, 0.0);
+        assert_eq!(widths, vec![Width(0.5)]);
+    }
+
+    #[test]
+    fn test_clamp_min_after_scale() {
+        let mut widths = vec![Width(0.02); 4];
+        widths.iter_mut().for_each(|w| w.0 *= 0.001);
+        Width::clamp_min(&mut widths, 0.005);
+        assert_eq!(widths, vec![Width(0.005); 4]);
+
+        let mut widths = vec![Width(0.02); 4];
+        Width::clamp_min(&mut widths, 0.005);
+        assert_eq!(widths, vec![Width(0.02); 4]);
+    }
+
+    #[test]
+    fn test_clamp_min_keeps_zero() {
+        let mut widths = vec![Width(0.0), Width(0.001), Width(0.0)];
+        Width::clamp_min(&mut widths, 0.005);
+        assert_eq!(widths, vec![Width(0.0), Width(0.005), Width(0.0)]);
+    }
+}
@@ -1,6 +1,6 @@
 // MARK: CameraFrame
 
-use glam::{DMat4, DVec3, dvec2};
+use glam::{DMat4, DVec2, DVec3, dvec2};
 
 use crate::{
     Extract,
@@ -9,6 +9,70 @@ use crate::{
     prelude::{Alignable, Interpolatable},
 };
 
+/// How [`CameraFrame::zoom_to_rect`] frames a rectangle of a different aspect ratio
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RectFit {
+    /// The whole rectangle is visible, with margins on two sides (default)
+    #[default]
+    Fit,
+    /// The rectangle covers the whole frame, with two sides of it cropped
+    Fill,
+}
+
+/// A region of the output a [`CameraFrame`] renders to, see [`CameraFrame::viewport`].
+///
+/// The values are fractions of the output size, the origin is the top-left corner
+/// and the y axis points down.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ViewportRect {
+    /// The left edge
+    pub x: f64,
+    /// The top edge
+    pub y: f64,
+    /// The width
+    pub width: f64,
+    /// The height
+    pub height: f64,
+}
+
+impl ViewportRect {
+    /// The whole output
+    pub const FULL: Self = Self::new(0.0, 0.0, 1.0, 1.0);
+
+    /// Constructor
+    pub const fn new(x: f64, y: f64, width: f64, height: f64) -> Self {
+        Self {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+    /// The left half of the output
+    pub const fn left_half() -> Self {
+        Self::new(0.0, 0.0, 0.5, 1.0)
+    }
+    /// The right half of the output
+    pub const fn right_half() -> Self {
+        Self::new(0.5, 0.0, 0.5, 1.0)
+    }
+    /// The aspect ratio of the region in an output of the given aspect ratio
+    pub fn aspect_ratio(&self, output_aspect_ratio: f64) -> f64 {
+        output_aspect_ratio * self.width / self.height
+    }
+}
+
+impl Interpolatable for ViewportRect {
+    fn lerp(&self, target: &Self, t: f64) -> Self {
+        Self::new(
+            self.x.lerp(&target.x, t),
+            self.y.lerp(&target.y, t),
+            self.width.lerp(&target.width, t),
+            self.height.lerp(&target.height, t),
+        )
+    }
+}
+
 /// The data of a camera
 ///
 /// The [`CameraFrame`] has a [`CameraFrame::perspective_blend`] property (default is `0.0`),
@@ -23,12 +87,29 @@ pub struct CameraFrame {
     pub facing: DVec3,
 
     // far > near
-    /// The near pane
+    /// The near plane, default value: `-1000.0`
+    ///
+    /// The planes are distances along [`CameraFrame::facing`] from [`CameraFrame::pos`],
+    /// geometry out of `near..far` is clipped.
+    /// The orthographic projection uses them as is, while the perspective projection
+    /// clamps the near plane to at least `0.1` (so the default is `0.1..1000.0` for it).
+    /// See [`CameraFrame::set_clip_planes`].
     pub near: f64,
-    /// The far pane
+    /// The far plane, default value: `1000.0`, see [`CameraFrame::near`]
     pub far: f64,
+    /// An optional world-space axis-aligned box `[min, max]`, default value: `None`
+    ///
+    /// When set, everything outside of it is not rendered, see [`CameraFrame::set_clip_region`].
+    pub clip_region: Option<[DVec3; 2]>,
     /// The perspective blend value in [0.0, 1.0]
     pub perspective_blend: f64,
+    /// The region of the output the camera renders to, default value: `None`
+    ///
+    /// `None` renders to the whole output. The first camera of a scene is the main camera,
+    /// the cameras after it are only rendered if they have a viewport, e.g. for split screens
+    /// or an inset overview. They are drawn in the order of their timelines, each one over the
+    /// ones before it, and its region is cleared before. See [`CameraFrame::set_viewport`].
+    pub viewport: Option<ViewportRect>,
 
     /// **Ortho**: Top - Bottom
     pub frame_height: f64,
@@ -56,6 +137,28 @@ impl Interpolatable for CameraFrame {
             fovy: self.fovy.lerp(&target.fovy, t),
             near: self.near.lerp(&target.near, t),
             far: self.far.lerp(&target.far, t),
+            clip_region: match (self.clip_region, target.clip_region) {
+                (Some([a_min, a_max]), Some([b_min, b_max])) => {
+                    Some([a_min.lerp(b_min, t), a_max.lerp(b_max, t)])
+                }
+                (a, b) => {
+                    if t < 1.0 {
+                        a
+                    } else {
+                        b
+                    }
+                }
+            },
+            viewport: match (self.viewport, target.viewport) {
+                (Some(a), Some(b)) => Some(a.lerp(&b, t)),
+                (a, b) => {
+                    if t < 1.0 {
+                        a
+                    } else {
+                        b
+                    }
+                }
+            },
             frame_height: self.frame_height.lerp(&target.frame_height, t),
             perspective_blend: self
                 .perspective_blend
@@ -81,7 +184,9 @@ impl Default for CameraFrame {
 
             near: -1000.0,
             far: 1000.0,
+            clip_region: None,
             perspective_blend: 0.0,
+            viewport: None,
 
             scale: 1.0,
             frame_height: 8.0,
@@ -132,6 +237,100 @@ impl CameraFrame {
         )
     }
 
+    /// The size of a pixel in world units at the frame plane, given the output height in pixels
+    ///
+    /// This is based on the orthographic frame size.
+    pub fn world_per_pixel(&self, height: u32) -> f64 {
+        self.frame_height * self.scale / height as f64
+    }
+
+    /// Set the [`CameraFrame::frame_height`] so that the frame is `width` wide
+    /// at the given aspect ratio.
+    pub fn set_frame_width(&mut self, width: f64, aspect_ratio: f64) -> &mut Self {
+        self.frame_height = width / aspect_ratio;
+        self
+    }
+
+    /// Frame the world rectangle of `width` by `height` at `center` for the output `aspect_ratio`,
+    /// by moving [`CameraFrame::pos`] and setting [`CameraFrame::scale`].
+    ///
+    /// The rectangle lies in the frame plane, i.e. `width` is along the right of the camera and
+    /// `height` is along [`CameraFrame::up`]. The camera only moves in its own frame plane,
+    /// its facing and distance are kept. If the aspect ratios differ, `fit` decides whether the
+    /// rectangle is contained in or covers the frame, see [`RectFit`].
+    /// This only affects the orthographic projection.
+    pub fn zoom_to_rect(
+        &mut self,
+        center: DVec3,
+        width: f64,
+        height: f64,
+        aspect_ratio: f64,
+        fit: RectFit,
+    ) -> &mut Self {
+        let facing = self.facing.normalize();
+        let offset = center - self.pos;
+        self.pos += offset - facing * offset.dot(facing);
+
+        let height = match fit {
+            RectFit::Fit => height.max(width / aspect_ratio),
+            RectFit::Fill => height.min(width / aspect_ratio),
+        };
+        self.scale = height / self.frame_height;
+        self
+    }
+
+    /// Map a world point to the pixel coordinates of an output of `width` by `height` pixels,
+    /// the origin is the top-left corner and the y axis points down.
+    pub fn world_to_screen(&self, point: DVec3, width: u32, height: u32) -> DVec2 {
+        let (width, height) = (width as f64, height as f64);
+        let ndc = self
+            .view_projection_matrix(width / height)
+            .project_point3(point);
+        dvec2((ndc.x + 1.0) / 2.0 * width, (1.0 - ndc.y) / 2.0 * height)
+    }
+
+    /// Set the near and far clipping planes, see [`CameraFrame::near`].
+    ///
+    /// Animating them (e.g. the far plane) can be used to create reveal effects.
+    pub fn set_clip_planes(&mut self, near: f64, far: f64) -> &mut Self {
+        self.near = near;
+        self.far = far;
+        self
+    }
+
+    /// Set the near and far clipping planes and return the modified `Self`.
+    pub fn with_clip_planes(mut self, near: f64, far: f64) -> Self {
+        self.set_clip_planes(near, far);
+        self
+    }
+
+    /// Set the world-space clip region `[min, max]`, `None` to render everything.
+    ///
+    /// This works for both orthographic and perspective projections.
+    pub fn set_clip_region(&mut self, region: Option<[DVec3; 2]>) -> &mut Self {
+        self.clip_region = region;
+        self
+    }
+
+    /// Set the region of the output the camera renders to, `None` for the whole output,
+    /// see [`CameraFrame::viewport`].
+    pub fn set_viewport(&mut self, viewport: Option<ViewportRect>) -> &mut Self {
+        self.viewport = viewport;
+        self
+    }
+
+    /// Set the viewport and return the modified `Self`, see [`CameraFrame::set_viewport`].
+    pub fn with_viewport(mut self, viewport: ViewportRect) -> Self {
+        self.set_viewport(Some(viewport));
+        self
+    }
+
+    /// Whether the world-space point is inside of the [`CameraFrame::clip_region`].
+    pub fn is_in_clip_region(&self, point: DVec3) -> bool {
+        self.clip_region
+            .is_none_or(|[min, max]| point.cmpge(min).all() && point.cmple(max).all())
+    }
+
     /// Use the given frame aspect ratio to construct a perspective matrix
     pub fn perspective_mat(&self, aspect_ratio: f64) -> DMat4 {
         let near = self.near.max(0.1);
@@ -257,6 +456,136 @@ impl CameraFrame {
     }
 }
 
+/// The count of samples used by [`CameraFrame::follow`] to smooth the motion
+const FOLLOW_SAMPLES: usize = 240;
+
+impl CameraFrame {
+    /// Create an animation that keeps the target centered in the frame as it moves.
+    ///
+    /// `target` gives the [`crate::anchor::Aabb`] of the followed item at each progress `alpha`,
+    /// returning `None` means the item is hidden, and the camera freezes until it returns.
+    ///
+    /// `lag` is the time constant of the smoothing, as a fraction of the animation's duration.
+    /// With `0.0` the camera snaps to the target, otherwise it eases toward the target
+    /// from where it's currently looking at.
+    ///
+    /// The camera only moves in its own frame plane, its facing and distance are kept.
+    ///
+    /// # Example
+    /// ```ignore
+    /// r.timeline_mut(r_cam).play(
+    ///     cam.follow(|alpha| Some([dvec3(alpha * 8.0, 0.0, 0.0); 2]), 0.1)
+    ///        .with_duration(4.0),
+    /// );
+    /// ```
+    pub fn follow(
+        &mut self,
+        target: impl Fn(f64) -> Option<[DVec3; 2]>,
+        lag: f64,
+    ) -> AnimationCell<Self> {
+        self.follow_impl(target, lag, None)
+    }
+
+    /// Same as [`CameraFrame::follow`], but also scales the frame to fit the target.
+    ///
+    /// The target's extent is measured along the camera's up and right vectors,
+    /// the `margin` is a fraction of the target's size kept around it.
+    /// This only affects the orthographic projection.
+    pub fn follow_fit(
+        &mut self,
+        target: impl Fn(f64) -> Option<[DVec3; 2]>,
+        lag: f64,
+        aspect_ratio: f64,
+        margin: f64,
+    ) -> AnimationCell<Self> {
+        self.follow_impl(target, lag, Some((aspect_ratio, margin)))
+    }
+
+    fn follow_impl(
+        &mut self,
+        target: impl Fn(f64) -> Option<[DVec3; 2]>,
+        lag: f64,
+        fit: Option<(f64, f64)>,
+    ) -> AnimationCell<Self> {
+        let up = self.up.normalize();
+        let right = self.facing.cross(up).normalize();
+        let target_state = |[min, max]: [DVec3; 2]| {
+            let center = (min + max) / 2.0;
+            let scale = fit.map(|(aspect_ratio, margin)| {
+                let corners = (0..8).map(|i| {
+                    DVec3::new(
+                        if i & 1 == 0 { min.x } else { max.x },
+                        if i & 2 == 0 { min.y } else { max.y },
+                        if i & 4 == 0 { min.z } else { max.z },
+                    )
+                });
+                let extent = |dir: DVec3| {
+                    let (lo, hi) = corners
+                        .clone()
+                        .map(|p| p.dot(dir))
+                        .fold((f64::MAX, f64::MIN), |(lo, hi), x| (lo.min(x), hi.max(x)));
+                    hi - lo
+                };
+                let size = extent(up).max(extent(right) / aspect_ratio);
+                size * (1.0 + margin) / self.frame_height
+            });
+            (center, scale)
+        };
+
+        // Exponential smoothing over the sampled target states
+        let dt = 1.0 / FOLLOW_SAMPLES as f64;
+        let k = if lag > 0.0 {
+            1.0 - (-dt / lag).exp()
+        } else {
+            1.0
+        };
+        let mut state = (self.pos, self.scale);
+        let samples = (0..=FOLLOW_SAMPLES)
+            .map(|i| {
+                if let Some(aabb) = target(i as f64 * dt) {
+                    let (center, scale) = target_state(aabb);
+                    state.0 += (center - state.0) * k;
+                    if let Some(scale) = scale.filter(|s| *s > 0.0) {
+                        state.1 += (scale - state.1) * k;
+                    }
+                }
+                state
+            })
+            .collect::<Vec<_>>();
+
+        struct Follow {
+            src: CameraFrame,
+            samples: Vec<(DVec3, f64)>,
+        }
+
+        impl Eval<CameraFrame> for Follow {
+            fn eval_alpha(&self, alpha: f64) -> CameraFrame {
+                let x = alpha.clamp(0.0, 1.0) * (self.samples.len() - 1) as f64;
+                let (i, t) = (x.floor() as usize, x.fract());
+                let (a, b) = (
+                    self.samples[i],
+                    self.samples[(i + 1).min(self.samples.len() - 1)],
+                );
+                let (center, scale) = (a.0.lerp(b.0, t), a.1.lerp(&b.1, t));
+
+                let mut result = self.src.clone();
+                let facing = result.facing.normalize();
+                let offset = center - result.pos;
+                result.pos += offset - facing * offset.dot(facing);
+                result.scale = scale;
+                result
+            }
+        }
+
+        Follow {
+            src: self.clone(),
+            samples,
+        }
+        .into_animation_cell()
+        .apply_to(self)
+    }
+}
+
 impl CameraFrame {
     /// Center the canvas in the frame when [`CameraFrame::perspective_blend`] is `1.0`
     pub fn center_canvas_in_frame(
@@ -290,6 +619,7 @@ impl CameraFrame {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::traits::With;
     use glam::dvec3;
 
     #[test]
@@ -349,4 +679,104 @@ mod tests {
         assert!(new_camera.up.angle_between(camera.up) < 1e-10);
         assert!(new_camera.facing.angle_between(camera.facing) < 1e-10);
     }
+
+    #[test]
+    fn test_follow() {
+        let path = |alpha: f64| Some([dvec3(alpha * 4.0, 1.0, 0.0); 2]);
+
+        // Snaps to the target, keeps the distance along facing
+        let mut camera = CameraFrame::new().with(|c| c.pos = dvec3(0.0, 0.0, 8.0));
+        let anim = camera.follow(path, 0.0);
+        let end = anim.eval_alpha(1.0);
+        assert!(end.pos.distance(dvec3(4.0, 1.0, 8.0)) < 1e-6);
+        assert!(camera.pos.distance(end.pos) < 1e-6);
+
+        // Eases toward the target
+        let mut camera = CameraFrame::new();
+        let end = camera.follow(path, 0.1).eval_alpha(1.0);
+        assert!(end.pos.x > 0.0 && end.pos.x < 4.0);
+
+        // Freezes while the target is hidden
+        let mut camera = CameraFrame::new();
+        let anim = camera.follow(|alpha| if alpha <= 0.5 { path(alpha) } else { None }, 0.0);
+        assert!(anim.eval_alpha(0.5).pos.distance(dvec3(2.0, 1.0, 0.0)) < 1e-6);
+        assert!(anim.eval_alpha(1.0).pos.distance(dvec3(2.0, 1.0, 0.0)) < 1e-6);
+
+        // Fits the target's height
+        let mut camera = CameraFrame::new();
+        let end = camera
+            .follow_fit(
+                |_| Some([dvec3(-1.0, -2.0, 0.0), dvec3(1.0, 2.0, 0.0)]),
+                0.0,
+                16.0 / 9.0,
+                0.0,
+            )
+            .eval_alpha(1.0);
+        assert!((end.scale * end.frame_height - 4.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_zoom_to_rect() {
+        let (width, height) = (1920, 1080);
+        let aspect_ratio = width as f64 / height as f64;
+        let assert_screen = |camera: &CameraFrame, point: DVec3, expected: DVec2| {
+            let screen = camera.world_to_screen(point, width, height);
+            assert!(screen.distance(expected) < 1e-6, "{screen} != {expected}");
+        };
+
+        // A wide rectangle touches the left and right edges
+        let mut camera = CameraFrame::new().with(|c| c.pos = dvec3(0.0, 0.0, 8.0));
+        camera.zoom_to_rect(dvec3(3.0, 2.0, 0.0), 4.0, 1.0, aspect_ratio, RectFit::Fit);
+        assert!(camera.pos.distance(dvec3(3.0, 2.0, 8.0)) < 1e-10);
+        assert_screen(&camera, dvec3(1.0, 2.5, 0.0), dvec2(0.0, 300.0));
+        assert_screen(&camera, dvec3(5.0, 1.5, 0.0), dvec2(1920.0, 780.0));
+
+        // Filling it, the top and bottom edges are touched instead
+        camera.zoom_to_rect(dvec3(3.0, 2.0, 0.0), 4.0, 1.0, aspect_ratio, RectFit::Fill);
+        assert_screen(&camera, dvec3(3.0, 2.5, 0.0), dvec2(960.0, 0.0));
+        assert_screen(&camera, dvec3(3.0, 1.5, 0.0), dvec2(960.0, 1080.0));
+        assert_screen(&camera, dvec3(1.0, 2.0, 0.0), dvec2(960.0 - 2160.0, 540.0));
+
+        // The rectangle is in the frame plane of a rotated camera
+        let mut camera = CameraFrame::new().with(|c| c.up = DVec3::NEG_X);
+        camera.zoom_to_rect(DVec3::ZERO, 2.0, 1.0, aspect_ratio, RectFit::Fill);
+        assert_screen(&camera, dvec3(-0.5, 0.0, 0.0), dvec2(960.0, 0.0));
+        assert_screen(&camera, dvec3(0.0, 1.0, 0.0), dvec2(960.0 + 1080.0, 540.0));
+    }
+
+    #[test]
+    fn test_clip_planes_and_region() {
+        // Points out of the planes are out of the clip space in both projections
+        let camera = CameraFrame::new()
+            .with(|c| c.pos = dvec3(0.0, 0.0, 10.0))
+            .with_clip_planes(1.0, 5.0);
+        let in_clip = |camera: &CameraFrame, z: f64| {
+            let p = camera
+                .view_projection_matrix(16.0 / 9.0)
+                .project_point3(dvec3(0.0, 0.0, z));
+            (0.0..=1.0).contains(&p.z)
+        };
+        for blend in [0.0, 1.0] {
+            let camera = camera.clone().with(|c| c.perspective_blend = blend);
+            assert!(in_clip(&camera, 7.0));
+            assert!(!in_clip(&camera, 9.5));
+            assert!(!in_clip(&camera, 4.0));
+        }
+
+        let mut camera = CameraFrame::new();
+        assert!(camera.is_in_clip_region(dvec3(100.0, 0.0, 0.0)));
+        camera.set_clip_region(Some([DVec3::splat(-1.0), DVec3::splat(1.0)]));
+        assert!(camera.is_in_clip_region(dvec3(1.0, 0.5, 0.0)));
+        assert!(!camera.is_in_clip_region(dvec3(1.5, 0.0, 0.0)));
+
+        // The region is interpolated
+        let target = camera
+            .clone()
+            .with(|c| c.clip_region = Some([DVec3::splat(-3.0), DVec3::splat(3.0)]));
+        let mid = camera.lerp(&target, 0.5);
+        assert_eq!(
+            mid.clip_region,
+            Some([DVec3::splat(-2.0), DVec3::splat(2.0)])
+        );
+    }
 }
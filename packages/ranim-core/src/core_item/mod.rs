@@ -11,6 +11,7 @@ use dyn_clone::DynClone;
 
 use crate::{
     Extract,
+    components::rgba::Rgba,
     core_item::{camera_frame::CameraFrame, mesh_item::MeshItem, vitem::VItem},
 };
 
@@ -36,6 +37,65 @@ pub enum CoreItem {
 pub trait AnyExtractCoreItem: Any + Extract<Target = CoreItem> + DynClone {}
 impl<T: Extract<Target = CoreItem> + Any + DynClone> AnyExtractCoreItem for T {}
 
+impl CoreItem {
+    /// Shift the item along the z axis, camera frames are left untouched.
+    pub(crate) fn shift_z(&mut self, dz: f32) {
+        match self {
+            CoreItem::CameraFrame(_) => {}
+            CoreItem::VItem(vitem) => vitem.points.iter_mut().for_each(|p| p.z += dz),
+            CoreItem::MeshItem(mesh_item) => {
+                mesh_item.transform =
+                    glam::Mat4::from_translation(glam::Vec3::Z * dz) * mesh_item.transform;
+            }
+        }
+    }
+
+    /// Shift the item, camera frames are left untouched.
+    pub(crate) fn shift(&mut self, offset: glam::DVec3) {
+        let offset = offset.as_vec3();
+        match self {
+            CoreItem::CameraFrame(_) => {}
+            CoreItem::VItem(vitem) => vitem
+                .points
+                .iter_mut()
+                .for_each(|p| *p += offset.extend(0.0)),
+            CoreItem::MeshItem(mesh_item) => {
+                mesh_item.transform = glam::Mat4::from_translation(offset) * mesh_item.transform;
+            }
+        }
+    }
+
+    /// Multiply the opacity of the item by `factor`, camera frames are left untouched.
+    pub(crate) fn fade(&mut self, factor: f32) {
+        let fade = |rgba: &mut Rgba| rgba.0.w *= factor;
+        match self {
+            CoreItem::CameraFrame(_) => {}
+            CoreItem::VItem(vitem) => {
+                vitem.fill_rgbas.iter_mut().for_each(fade);
+                vitem.stroke_rgbas.iter_mut().for_each(fade);
+            }
+            CoreItem::MeshItem(mesh_item) => mesh_item.vertex_colors.iter_mut().for_each(fade),
+        }
+    }
+
+    /// The world space points of the item, camera frames have none.
+    pub(crate) fn world_points(&self) -> Vec<glam::DVec3> {
+        match self {
+            CoreItem::CameraFrame(_) => Vec::new(),
+            CoreItem::VItem(vitem) => vitem
+                .points
+                .iter()
+                .map(|p| p.truncate().as_dvec3())
+                .collect(),
+            CoreItem::MeshItem(mesh_item) => mesh_item
+                .points
+                .iter()
+                .map(|p| mesh_item.transform.transform_point3(*p).as_dvec3())
+                .collect(),
+        }
+    }
+}
+
 dyn_clone::clone_trait_object!(AnyExtractCoreItem);
 
 /// A dynamic item, basically type erased [`AnyExtractCoreItem`]
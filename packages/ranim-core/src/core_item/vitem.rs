@@ -28,6 +28,102 @@ pub fn vitem_normal_from_points(points: &[Vec4]) -> Vec3 {
     }
 }
 
+/// The pattern used to fill a vitem.
+///
+/// The pattern is evaluated in the item's projection plane, and it only
+/// modulates the alpha of the fill colors, so the fill opacity and the
+/// even-odd rule are respected.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum FillPattern {
+    /// Fill the whole area
+    #[default]
+    Solid,
+    /// Parallel lines rotated by `angle` (in radians), `spacing` apart and `width` wide.
+    Hatch {
+        /// The angle of the lines in radians
+        angle: f32,
+        /// The distance between two lines
+        spacing: f32,
+        /// The width of the lines
+        width: f32,
+    },
+    /// Two sets of perpendicular [`FillPattern::Hatch`] lines.
+    CrossHatch {
+        /// The angle of the first set of lines in radians
+        angle: f32,
+        /// The distance between two lines
+        spacing: f32,
+        /// The width of the lines
+        width: f32,
+    },
+    /// Dots of `radius` on a square grid `spacing` apart.
+    Dots {
+        /// The distance between two dots
+        spacing: f32,
+        /// The radius of the dots
+        radius: f32,
+    },
+}
+
+impl FillPattern {
+    /// Diagonal hatch lines, rotated by 45 degrees.
+    pub fn diagonal(spacing: f32, width: f32) -> Self {
+        Self::Hatch {
+            angle: std::f32::consts::FRAC_PI_4,
+            spacing,
+            width,
+        }
+    }
+    /// Horizontal hatch lines.
+    pub fn horizontal(spacing: f32, width: f32) -> Self {
+        Self::Hatch {
+            angle: 0.0,
+            spacing,
+            width,
+        }
+    }
+    /// Vertical hatch lines.
+    pub fn vertical(spacing: f32, width: f32) -> Self {
+        Self::Hatch {
+            angle: std::f32::consts::FRAC_PI_2,
+            spacing,
+            width,
+        }
+    }
+    /// Diagonal cross hatch lines, rotated by 45 degrees.
+    pub fn cross_hatch(spacing: f32, width: f32) -> Self {
+        Self::CrossHatch {
+            angle: std::f32::consts::FRAC_PI_4,
+            spacing,
+            width,
+        }
+    }
+    /// Dots on a square grid.
+    pub fn dots(spacing: f32, radius: f32) -> Self {
+        Self::Dots { spacing, radius }
+    }
+    /// The pattern packed as `(kind, spacing, width or radius, angle)` for the shader.
+    ///
+    /// The kind is `0` for [`FillPattern::Solid`], `1` for [`FillPattern::Hatch`],
+    /// `2` for [`FillPattern::CrossHatch`] and `3` for [`FillPattern::Dots`].
+    pub fn to_vec4(&self) -> Vec4 {
+        match *self {
+            Self::Solid => Vec4::ZERO,
+            Self::Hatch {
+                angle,
+                spacing,
+                width,
+            } => Vec4::new(1.0, spacing, width, angle),
+            Self::CrossHatch {
+                angle,
+                spacing,
+                width,
+            } => Vec4::new(2.0, spacing, width, angle),
+            Self::Dots { spacing, radius } => Vec4::new(3.0, spacing, radius, 0.0),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 /// A primitive for rendering a vitem.
 pub struct VItem {
@@ -35,7 +131,10 @@ pub struct VItem {
     /// If `None`, the normal will be computed from the first three points at render time.
     pub normal: Option<Vec3>,
     /// The points of the item in world space.
-    /// (x, y, z, is_closed)
+    /// (x, y, z, flags)
+    ///
+    /// The flags are of the segment starting at the point: `1.0` is added if it is filled,
+    /// i.e. it is in a closed subpath, and `2.0` is added if its stroke is not drawn.
     pub points: Vec<Vec4>,
     /// Fill rgbas, see [`Rgba`].
     pub fill_rgbas: Vec<Rgba>,
@@ -43,6 +142,8 @@ pub struct VItem {
     pub stroke_rgbas: Vec<Rgba>,
     /// Stroke widths, see [`Width`].
     pub stroke_widths: Vec<Width>,
+    /// The pattern of the fill, see [`FillPattern`].
+    pub fill_pattern: FillPattern,
 }
 
 impl Default for VItem {
@@ -53,6 +154,7 @@ impl Default for VItem {
             stroke_widths: vec![Width::default(); 2],
             stroke_rgbas: vec![Rgba::default(); 2],
             fill_rgbas: vec![Rgba::default(); 2],
+            fill_pattern: FillPattern::Solid,
         }
     }
 }
@@ -34,12 +34,17 @@ pub mod prelude {
     pub use crate::color::prelude::*;
     pub use crate::traits::*;
 
-    pub use crate::core_item::camera_frame::CameraFrame;
-    pub use crate::timeline::{TimelineFunc, TimelinesFunc};
-    pub use crate::{RanimScene, TimeMark, TimelineId};
+    pub use crate::core_item::camera_frame::{CameraFrame, RectFit, ViewportRect};
+    pub use crate::timeline::{AnimationTemplate, TimelineFunc, TimelinesFunc};
+    pub use crate::{Bundle, ItemId, RanimScene, TimeMark, TimelineGroup, TimelineId};
 }
 
-use crate::{animation::StaticAnim, core_item::CoreItem, timeline::Timeline};
+use crate::{
+    anchor::{AabbPoint, Locate},
+    animation::{AnimationCell, StaticAnim},
+    core_item::{AnyExtractCoreItem, CoreItem, camera_frame::CameraFrame},
+    timeline::Timeline,
+};
 
 /// Extract a [`Extract::Target`] from reference.
 pub trait Extract {
@@ -67,10 +72,54 @@ where
     }
 }
 
-use crate::timeline::{AnimationInfo, TimelineFunc, TimelinesFunc};
-use tracing::trace;
+/// A bundle of items in different types, extracted in the tuple order.
+///
+/// Tuples cannot implement [`Extract`] directly because of the blanket impl
+/// for iterables, so wrap them with [`Bundle`] to insert a heterogeneous
+/// composite item as one timeline:
+///
+/// ```rust,ignore
+/// let r_graph = r.insert(Bundle((axes, curve, label)));
+/// ```
+///
+/// Arrays of the same item type can be extracted directly.
+#[derive(Debug, Clone, Default, PartialEq, derive_more::Deref, derive_more::DerefMut)]
+pub struct Bundle<T>(pub T);
+
+macro_rules! impl_extract_bundle {
+    ($(($T:ident, $s:ident)),*) => {
+        impl<$($T: Extract<Target = CoreItem>),*> Extract for Bundle<($($T,)*)> {
+            type Target = CoreItem;
+            fn extract_into(&self, buf: &mut Vec<Self::Target>) {
+                let ($($s,)*) = &self.0;
+                $($s.extract_into(buf);)*
+            }
+        }
+    }
+}
+variadics_please::all_tuples!(impl_extract_bundle, 1, 12, T, s);
+
+impl<T: traits::Interpolatable> traits::Interpolatable for Bundle<T> {
+    fn lerp(&self, target: &Self, t: f64) -> Self {
+        Self(self.0.lerp(&target.0, t))
+    }
+}
+
+use crate::timeline::{AnimationInfo, AnimationTemplate, TimelineFunc, TimelinesFunc};
+use itertools::Either;
+use tracing::{trace, warn};
 
-use std::fmt::Debug;
+use std::{
+    borrow::Borrow,
+    fmt::{Debug, Write},
+    hash::{DefaultHasher, Hash, Hasher},
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+/// A callback invoked with its second when the evaluation passes it,
+/// see [`RanimScene::insert_callback`].
+pub type TimeCallback = Arc<dyn Fn(f64) + Send + Sync>;
 
 /// TimeMark
 #[derive(Debug, Clone)]
@@ -90,24 +139,151 @@ impl TimelineId {
     }
 }
 
+/// The id of a timeline inserted with an item of type `T`, returned by [`RanimScene::insert`].
+///
+/// It derefs to the [`TimelineId`], and can be used to index the timeline just like it.
+/// The difference is that when it is paired with an item in a [`TimelineQuery`] like
+/// `r.timeline_mut(&mut (r_item, item))`, the item must be of type `T`:
+///
+/// ```compile_fail
+/// use ranim_core::{RanimScene, prelude::*};
+///
+/// let mut r = RanimScene::new();
+/// let cam = CameraFrame::default();
+/// let r_cam: ItemId<CameraFrame> = r.insert(cam);
+/// // A `CameraFrame` timeline paired with a `f64`
+/// let (timeline, x) = r.timeline_mut((r_cam, 1.0));
+/// ```
+///
+/// If the item is converted into another type, use [`ItemId::map`] to change the type.
+///
+/// It is `Copy`, `Send` and `Sync`, and holds no reference into the scene, so it can be moved
+/// into closures that outlive the construction, like the ones of [`RanimScene::always_redraw`],
+/// to read the timeline with [`RedrawContext::state`]. It is only an index of the timeline in
+/// the scene that inserted it, so it should not be used with other scenes.
+pub struct ItemId<T> {
+    id: TimelineId,
+    _phantom: std::marker::PhantomData<fn() -> T>,
+}
+
+impl<T> ItemId<T> {
+    fn new(id: TimelineId) -> Self {
+        Self {
+            id,
+            _phantom: std::marker::PhantomData,
+        }
+    }
+    /// Get the inner id of the [`TimelineId`].
+    pub fn id(&self) -> usize {
+        self.id.0
+    }
+    /// Get the [`TimelineId`].
+    pub fn timeline_id(&self) -> TimelineId {
+        self.id
+    }
+    /// Change the item type, for example after converting a `Square` into a `VItem`.
+    pub fn map<U>(self) -> ItemId<U> {
+        ItemId::new(self.id)
+    }
+}
+
+impl<T> Clone for ItemId<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for ItemId<T> {}
+
+impl<T> Debug for ItemId<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("ItemId").field(&self.id.0).finish()
+    }
+}
+
+impl<T> PartialEq for ItemId<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl<T> Eq for ItemId<T> {}
+
+impl<T> std::hash::Hash for ItemId<T> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.id.hash(state);
+    }
+}
+
+impl<T> std::ops::Deref for ItemId<T> {
+    type Target = TimelineId;
+    fn deref(&self) -> &Self::Target {
+        &self.id
+    }
+}
+
+impl<T> AsRef<TimelineId> for ItemId<T> {
+    fn as_ref(&self) -> &TimelineId {
+        &self.id
+    }
+}
+
+impl<T> From<ItemId<T>> for TimelineId {
+    fn from(value: ItemId<T>) -> Self {
+        value.id
+    }
+}
+
+/// A timeline id that can be paired with an item of type `T` in a [`TimelineQuery`].
+///
+/// [`TimelineId`] can be paired with any item, while [`ItemId<T>`] can only be paired with `T`.
+pub trait TimelineIdFor<T>: AsRef<TimelineId> {}
+impl<T> TimelineIdFor<T> for TimelineId {}
+impl<T> TimelineIdFor<T> for ItemId<T> {}
+
 // MARK: RanimScene
 /// The main struct that offers the ranim's API, and encodes animations
 #[derive(Default)]
 pub struct RanimScene {
     pub(crate) timelines: Vec<Timeline>,
     pub(crate) time_marks: Vec<(f64, TimeMark)>,
+    pub(crate) callbacks: Vec<(f64, TimeCallback)>,
+    pub(crate) asset_paths: Vec<PathBuf>,
+    pub(crate) layers: Vec<Layer>,
+    pub(crate) attachments: Vec<Attachment>,
+    pub(crate) redraws: Vec<Redraw>,
 }
 
 impl RanimScene {
     /// Seals the scene to [`SealedRanimScene`].
     pub fn seal(mut self) -> SealedRanimScene {
+        self.validate();
         let total_secs = self.timelines.max_total_secs();
         self.timelines.forward_to(total_secs);
         self.timelines.seal();
+        let mut z_offsets = vec![0.0; self.timelines.len()];
+        for layer in &self.layers {
+            for id in &layer.timelines {
+                z_offsets[id.0] = layer.z_offset();
+            }
+        }
+        self.callbacks.sort_by(|(a, _), (b, _)| a.total_cmp(b));
+        // Parents are resolved before their children
+        let depth = |attachment: &Attachment| {
+            std::iter::successors(Some(attachment), |a| self.attachment_of(a.parent)).count()
+        };
+        let mut attachments = self.attachments.clone();
+        attachments.sort_by_cached_key(depth);
         SealedRanimScene {
             total_secs,
             timelines: self.timelines,
             time_marks: self.time_marks,
+            callbacks: self.callbacks,
+            asset_paths: self.asset_paths,
+            z_offsets,
+            attachments,
+            redraws: self.redraws,
+            ghosts: false,
         }
     }
     /// Create a new [`RanimScene`]
@@ -131,7 +307,7 @@ impl RanimScene {
     pub fn insert<T: Extract<Target = CoreItem> + Clone + 'static>(
         &mut self,
         item: T,
-    ) -> TimelineId {
+    ) -> ItemId<T> {
         self.insert_at(item, 0.0)
     }
 
@@ -140,15 +316,109 @@ impl RanimScene {
         &mut self,
         item: T,
         sec: f64,
-    ) -> TimelineId {
-        self.insert_with(|t| {
+    ) -> ItemId<T> {
+        ItemId::new(self.insert_with(|t| {
             t.forward_to(sec);
             t.play(item.show());
-        })
+        }))
+    }
+
+    /// Insert a [`CameraFrame`] configured by `f` at `0.0` sec and play [`StaticAnim::show`] on it.
+    ///
+    /// The configured state is shown from `0.0` sec, so the first frame is already correct,
+    /// without a separate update after inserting the default camera:
+    ///
+    /// ```rust
+    /// use ranim_core::{RanimScene, core_item::CoreItem, glam::DVec3, prelude::*};
+    ///
+    /// let mut r = RanimScene::new();
+    /// let r_cam = r.insert_camera_with(|cam| {
+    ///     cam.pos = DVec3::Z * 5.0;
+    ///     cam.perspective_blend = 1.0;
+    /// });
+    /// r.timeline_mut(r_cam).forward(1.0);
+    ///
+    /// let scene = r.seal();
+    /// let (_, item) = scene.eval_at_sec(0.0).next().unwrap();
+    /// let CoreItem::CameraFrame(cam) = item else {
+    ///     panic!("not a camera frame");
+    /// };
+    /// assert_eq!(cam.pos, DVec3::Z * 5.0);
+    /// assert_eq!(cam.perspective_blend, 1.0);
+    /// ```
+    pub fn insert_camera_with(&mut self, f: impl FnOnce(&mut CameraFrame)) -> ItemId<CameraFrame> {
+        let mut cam = CameraFrame::default();
+        f(&mut cam);
+        self.insert(cam)
+    }
+
+    /// Insert a timeline for each item at `0.0` sec and play [`StaticAnim::show`] on them.
+    pub fn insert_group<T: Extract<Target = CoreItem> + Clone + 'static>(
+        &mut self,
+        items: impl IntoIterator<Item = T>,
+    ) -> Vec<ItemId<T>> {
+        self.insert_group_at(items, 0.0)
+    }
+
+    /// Insert a timeline for each item at the given sec and play [`StaticAnim::show`] on them.
+    ///
+    /// Unlike inserting the whole group with [`RanimScene::insert_at`], each item gets its own
+    /// timeline, so they can be animated separately afterwards. Together with
+    /// [`Timeline::play_at`] this places things at absolute secs, regardless of the cursors:
+    ///
+    /// ```rust,ignore
+    /// let dots = r.insert_group_at(dots, 1.5);
+    /// r.timeline_mut(dots[0]).play_at(dot.fade_out(), 3.0);
+    /// ```
+    pub fn insert_group_at<T: Extract<Target = CoreItem> + Clone + 'static>(
+        &mut self,
+        items: impl IntoIterator<Item = T>,
+        sec: f64,
+    ) -> Vec<ItemId<T>> {
+        items
+            .into_iter()
+            .map(|item| self.insert_at(item, sec))
+            .collect()
     }
 
-    /// Insert a empty timeline and call `f` on it.
-    pub fn insert_with(&mut self, mut f: impl FnMut(&mut Timeline)) -> TimelineId {
+    /// Insert a empty timeline and call `f` on it, which is a one-call way to build the whole
+    /// anim sequence of a self-contained item:
+    ///
+    /// ```rust,ignore
+    /// r.insert_with(|t| {
+    ///     t.play(vitems.clone().show())
+    ///         .forward(1.0)
+    ///         .play(vitems.morph(|x| x.apply_complex_map(|c| c.exp())).with_duration(5.0))
+    ///         .forward(1.0);
+    /// });
+    /// ```
+    ///
+    /// The timeline isn't bound to an item type, the item is whatever the first anim plays,
+    /// so the returned [`TimelineId`] can be used with [`RanimScene::timeline_mut`] afterwards:
+    ///
+    /// ```rust
+    /// use ranim_core::{
+    ///     RanimScene, animation::StaticAnim, core_item::CoreItem, glam::DVec3, prelude::*,
+    /// };
+    ///
+    /// let mut r = RanimScene::new();
+    /// let cam = CameraFrame {
+    ///     pos: DVec3::Z * 5.0,
+    ///     ..Default::default()
+    /// };
+    /// let r_cam = r.insert_with(|t| {
+    ///     t.play(cam.show()).forward(1.0).hide().forward(1.0);
+    /// });
+    /// r.timeline_mut(r_cam).show().forward(1.0);
+    ///
+    /// let scene = r.seal();
+    /// assert_eq!(scene.total_secs(), 3.0);
+    /// let (_, item) = scene.eval_at_sec(0.5).next().unwrap();
+    /// assert_eq!(item, CoreItem::CameraFrame(cam));
+    /// // Hidden between 1.0 and 2.0 sec
+    /// assert!(scene.eval_at_sec(1.5).next().is_none());
+    /// ```
+    pub fn insert_with(&mut self, f: impl FnOnce(&mut Timeline)) -> TimelineId {
         let id = TimelineId(self.timelines.len());
         let mut timeline = Timeline::new();
         f(&mut timeline);
@@ -156,6 +426,42 @@ impl RanimScene {
         id
     }
 
+    /// Get the [`LayerMut`] of the layer with the given name, the layer is created if not exists.
+    ///
+    /// Newly created layers are stacked in creation order with orders `1, 2, 3, ...`,
+    /// so they are in front of the items inserted directly into the scene (order `0`).
+    /// Use [`LayerMut::set_order`] to change it, for example to put a background behind everything:
+    ///
+    /// ```rust,ignore
+    /// r.layer("background").set_order(-1).insert(grid);
+    /// r.layer("foreground").insert(annotation);
+    /// ```
+    pub fn layer(&mut self, name: impl AsRef<str>) -> LayerMut<'_> {
+        let name = name.as_ref();
+        let idx = match self.layers.iter().position(|layer| layer.name == name) {
+            Some(idx) => idx,
+            None => {
+                let order = self
+                    .layers
+                    .iter()
+                    .map(|layer| layer.order)
+                    .fold(0, i32::max)
+                    + 1;
+                self.layers.push(Layer {
+                    name: name.to_string(),
+                    order,
+                    timelines: vec![],
+                });
+                self.layers.len() - 1
+            }
+        };
+        LayerMut { scene: self, idx }
+    }
+    /// Get reference of all layers
+    pub fn layers(&self) -> &[Layer] {
+        &self.layers
+    }
+
     /// Get reference of all timelines
     pub fn timelines(&self) -> &[Timeline] {
         trace!("timelines");
@@ -166,6 +472,36 @@ impl RanimScene {
         trace!("timelines_mut");
         &mut self.timelines
     }
+    /// The current sec of the scene, i.e. the furthest-forward cursor among all timelines.
+    ///
+    /// Each timeline has its own cursor which is moved by playing or forwarding it,
+    /// this is the max of them, which equals every cursor after [`TimelinesFunc::sync`].
+    /// Returns `0.0` if there is no timeline.
+    ///
+    /// ```
+    /// use ranim_core::{RanimScene, TimeMark};
+    ///
+    /// let mut r = RanimScene::new();
+    /// let t = r.insert_empty();
+    /// r.timeline_mut(t).forward(2.0);
+    /// // Capture a picture at "now"
+    /// r.insert_time_mark(r.current_sec(), TimeMark::Capture("now.png".to_string()));
+    /// assert_eq!(r.current_sec(), 2.0);
+    /// ```
+    pub fn current_sec(&self) -> f64 {
+        self.timelines
+            .iter()
+            .map(|timeline| timeline.cur_sec())
+            .fold(0.0, f64::max)
+    }
+    /// The state of the item of the timeline at `sec`, or `None` if it is not shown,
+    /// see [`Timeline::state_at`].
+    ///
+    /// # Panics
+    /// Panics if the item of the timeline is not a `T`.
+    pub fn state_at<T: AnyExtractCoreItem>(&self, id: ItemId<T>, sec: f64) -> Option<T> {
+        self.timelines.get(id.id())?.state_at(sec)
+    }
     /// Get the reference of timeline(s) by the [`TimelineIndex`].
     pub fn timeline<'a, T: TimelineIndex<'a>>(&'a self, index: T) -> T::RefOutput {
         index.get_index_ref(&self.timelines)
@@ -174,10 +510,318 @@ impl RanimScene {
     pub fn timeline_mut<'a, T: TimelineIndex<'a>>(&'a mut self, index: T) -> T::MutOutput {
         index.get_index_mut(&mut self.timelines)
     }
+    /// Plays an anim created by `anim_func` with the current state of each item on its timeline,
+    /// see [`Timeline::play_with`].
+    ///
+    /// It returns the timelines of the items as a [`TimelineGroupMut`], so they can be
+    /// synced or continued together. This replaces the loop over the ids of a group:
+    ///
+    /// ```
+    /// use ranim_core::{
+    ///     RanimScene,
+    ///     animation::{Eval, StaticAnim},
+    ///     core_item::vitem::VItem,
+    /// };
+    ///
+    /// /// Fades in a VItem by scaling up its alphas
+    /// struct FadeIn(VItem);
+    /// impl Eval<VItem> for FadeIn {
+    ///     fn eval_alpha(&self, alpha: f64) -> VItem {
+    ///         let mut item = self.0.clone();
+    ///         let rgbas = item.fill_rgbas.iter_mut().chain(item.stroke_rgbas.iter_mut());
+    ///         rgbas.for_each(|rgba| rgba.0.w *= alpha as f32);
+    ///         item
+    ///     }
+    /// }
+    ///
+    /// let mut r = RanimScene::new();
+    /// let ids = r.insert_group(vec![VItem::default(); 3]);
+    /// // Every item fades in at the same time, then waits for the others
+    /// r.play_each(&ids, |item| FadeIn(item).into_animation_cell().with_duration(2.0))
+    ///     .sync();
+    /// assert_eq!(r.current_sec(), 2.0);
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if nothing is played on a timeline yet, or an item is not a `T`.
+    pub fn play_each<T: AnyExtractCoreItem>(
+        &mut self,
+        ids: impl IntoIterator<Item = impl Borrow<ItemId<T>>>,
+        anim_func: impl FnMut(T) -> AnimationCell<T>,
+    ) -> TimelineGroupMut<'_> {
+        let group = TimelineGroup::new(ids.into_iter().map(|id| *id.borrow()));
+        let mut timelines = self.timeline_mut(&group);
+        timelines.play_with(anim_func);
+        timelines
+    }
+    /// Forward the timelines of `ids` to the max current sec among them, the other timelines
+    /// are untouched, see [`TimelineGroupMut::sync`].
+    ///
+    /// Unlike syncing all the timelines with [`TimelinesFunc::sync`], this keeps independent
+    /// tracks from waiting for each other:
+    ///
+    /// ```
+    /// use ranim_core::{RanimScene, timeline::TimelineFunc};
+    ///
+    /// let mut r = RanimScene::new();
+    /// let [a, b, c] = [(); 3].map(|_| r.insert_empty());
+    /// r.timeline_mut(a).forward(2.0);
+    /// r.timeline_mut(c).forward(1.0);
+    /// r.sync_up_to(&[a, b]);
+    /// assert_eq!(r.timeline(b).cur_sec(), 2.0);
+    /// assert_eq!(r.timeline(c).cur_sec(), 1.0);
+    /// ```
+    ///
+    /// Nothing happens if `ids` is empty.
+    ///
+    /// # Panics
+    /// Panics if an id is out of range.
+    pub fn sync_up_to(&mut self, ids: &[impl Into<TimelineId> + Copy]) -> &mut Self {
+        let group = TimelineGroup::new(ids.iter().copied());
+        self.timeline_mut(&group).sync();
+        self
+    }
+    /// Scale the time of the whole scene by `factor`, see [`Timeline::scale_time`].
+    ///
+    /// All the anims, time marks and callbacks are scaled uniformly, so the timelines stay
+    /// in sync. A `factor` of `0.5` plays the scene twice as fast, which is handy for
+    /// exporting a quick preview without editing the durations of the anims.
+    ///
+    /// # Panics
+    /// Panics if `factor` is not positive and finite.
+    pub fn scale_time(&mut self, factor: f64) -> &mut Self {
+        assert!(
+            factor > 0.0 && factor.is_finite(),
+            "the time scale factor must be positive and finite, got {factor}"
+        );
+        self.timelines.iter_mut().for_each(|t| {
+            t.scale_time(factor);
+        });
+        self.time_marks
+            .iter_mut()
+            .for_each(|(sec, _)| *sec *= factor);
+        self.callbacks
+            .iter_mut()
+            .for_each(|(sec, _)| *sec *= factor);
+        self.attachments
+            .iter_mut()
+            .for_each(|attachment| attachment.base_sec *= factor);
+        self
+    }
     /// Inserts an [`TimeMark`]
     pub fn insert_time_mark(&mut self, sec: f64, time_mark: TimeMark) {
         self.time_marks.push((sec, time_mark));
     }
+    /// Inserts a callback, which is invoked with `sec` when the evaluation passes `sec`.
+    ///
+    /// This can be used to trigger external actions synced to the animation, like logging
+    /// or driving another system. The callbacks are invoked by [`SealedRanimScene::fire_callbacks`],
+    /// which is called by the renderer for each frame, and by the preview app when the
+    /// playhead moves forward.
+    ///
+    /// The callback must be `Send + Sync`, because the sealed scene may be evaluated on
+    /// another thread than the one that built it. It is invoked synchronously on the
+    /// evaluating thread, so it should return quickly. It may also be invoked more than once,
+    /// for example once for each output, or each time the preview passes `sec` again.
+    ///
+    /// ```
+    /// use ranim_core::RanimScene;
+    ///
+    /// let mut r = RanimScene::new();
+    /// let t = r.insert_empty();
+    /// r.timeline_mut(t).forward(2.0);
+    /// r.insert_callback(1.0, |sec| println!("reached {sec}s"));
+    /// ```
+    pub fn insert_callback(&mut self, sec: f64, callback: impl Fn(f64) + Send + Sync + 'static) {
+        self.callbacks.push((sec, Arc::new(callback)));
+    }
+    /// Check the scene for common mistakes, returns the warnings.
+    ///
+    /// The warnings are also emitted through [`tracing::warn`], and this is
+    /// called in [`RanimScene::seal`]. These are not errors, the scene can still be sealed.
+    pub fn validate(&self) -> Vec<String> {
+        let mut warnings = Vec::new();
+
+        if self.timelines.is_empty() {
+            warnings.push("the scene has no timelines".to_string());
+        } else {
+            let total_secs = self.timelines.max_total_secs();
+            if total_secs <= 0.0 {
+                warnings.push(
+                    "the scene has zero duration, use `forward` to give it a duration".to_string(),
+                );
+            }
+            for (sec, time_mark) in &self.time_marks {
+                if *sec < 0.0 || *sec > total_secs {
+                    warnings.push(format!(
+                        "time mark {time_mark:?} at {sec}s is out of the scene's duration [0, {total_secs}]s"
+                    ));
+                }
+            }
+            for (sec, _) in &self.callbacks {
+                if *sec < 0.0 || *sec > total_secs {
+                    warnings.push(format!(
+                        "callback at {sec}s is out of the scene's duration [0, {total_secs}]s"
+                    ));
+                }
+            }
+        }
+
+        for (id, timeline) in self.timelines.iter().enumerate() {
+            if timeline.start_sec().is_none() {
+                warnings.push(format!("timeline {id} is created but never shown"));
+            }
+        }
+
+        let mut capture_names = std::collections::HashSet::new();
+        for (_, time_mark) in &self.time_marks {
+            let TimeMark::Capture(name) = time_mark;
+            if !capture_names.insert(name) {
+                warnings.push(format!("capture name {name:?} is used more than once"));
+            }
+        }
+
+        for warning in &warnings {
+            warn!("{warning}");
+        }
+        warnings
+    }
+    /// Read an asset file to string, and record its path.
+    ///
+    /// The recorded paths are watched by the preview app, modifying them
+    /// re-runs the scene constructor without recompiling the scene.
+    ///
+    /// This works for any text asset, like SVGs and typst sources:
+    ///
+    /// ```rust,ignore
+    /// let svg = SvgItem::new(r.read_asset("assets/logo.svg").unwrap());
+    /// let text = TypstText::new(&r.read_asset("assets/title.typ").unwrap());
+    /// ```
+    pub fn read_asset(&mut self, path: impl AsRef<Path>) -> std::io::Result<String> {
+        let path = path.as_ref();
+        if !self.asset_paths.iter().any(|p| p == path) {
+            self.asset_paths.push(path.to_path_buf());
+        }
+        std::fs::read_to_string(path)
+    }
+    /// Attach the items of the `child` timeline to the items of the `parent` timeline.
+    ///
+    /// When evaluating, the child's items are shifted as a whole, so that the negative
+    /// `anchor` of their bounding box is put on the `anchor` of the parent's bounding box,
+    /// with a distance of `padding` along the direction of `anchor`, just like
+    /// [`ShiftTransformExt::move_next_to_padded`](crate::traits::ShiftTransformExt::move_next_to_padded).
+    /// So the child follows the parent through any animation, without updating it manually.
+    ///
+    /// The offset is computed from the child's state at its current sec when attaching, so the
+    /// child's own animations still apply, relative to the attached position: a child shifted
+    /// by `v` since then is put at `v` from its attached position. When the child is not
+    /// shown at that sec, its state at the evaluated sec is used instead.
+    ///
+    /// Attachments can be chained, parents are always resolved before their children.
+    /// When the parent has no items at a sec, the child is left where it is.
+    ///
+    /// ```
+    /// use ranim_core::{RanimScene, anchor::AabbPoint, core_item::vitem::VItem, glam::DVec3};
+    ///
+    /// let mut r = RanimScene::new();
+    /// let r_item = r.insert(VItem::default());
+    /// let r_label = r.insert(VItem::default());
+    /// // Put the label above the item
+    /// r.attach(r_label, r_item, AabbPoint(DVec3::Y), 0.1);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `child` is already attached, or if the attachment forms a cycle.
+    pub fn attach(
+        &mut self,
+        child: impl Into<TimelineId>,
+        parent: impl Into<TimelineId>,
+        anchor: AabbPoint,
+        padding: f64,
+    ) {
+        let (child, parent) = (child.into(), parent.into());
+        if let Some(attachment) = self.attachment_of(child) {
+            panic!(
+                "timeline {} is already attached to timeline {}",
+                child.0, attachment.parent.0
+            );
+        }
+        let ancestors = std::iter::successors(Some(parent), |id| {
+            self.attachment_of(*id).map(|attachment| attachment.parent)
+        });
+        for ancestor in ancestors {
+            if ancestor == child {
+                panic!(
+                    "attaching timeline {} to timeline {} forms a cycle",
+                    child.0, parent.0
+                );
+            }
+        }
+        let base_sec = self.timelines[child.0].cur_sec();
+        self.attachments.push(Attachment {
+            child,
+            parent,
+            anchor,
+            padding,
+            base_sec,
+        });
+    }
+    /// Get the attachments added by [`RanimScene::attach`]
+    pub fn attachments(&self) -> &[Attachment] {
+        &self.attachments
+    }
+    fn attachment_of(&self, child: TimelineId) -> Option<&Attachment> {
+        self.attachments.iter().find(|a| a.child == child)
+    }
+
+    /// Insert a timeline whose item is rebuilt by `f` at every evaluated sec, like manim's
+    /// `always_redraw`, and show it from the current sec of the scene.
+    ///
+    /// `f` reads the states of the other timelines at the sec with [`RedrawContext::state`],
+    /// like the values of `ValueTracker`s, so the item follows them through their animations.
+    /// All the timelines are evaluated before the redrawn items, so a redrawn item always sees
+    /// the states at the same sec. The states of other redrawn items can't be read, and
+    /// [`RanimScene::attach`] applies after the redraw, so a redrawn item can be attached.
+    ///
+    /// The timeline can be shown and hidden like any other, the item is only rebuilt when shown.
+    ///
+    /// ```
+    /// use ranim_core::{RanimScene, core_item::{CoreItem, camera_frame::CameraFrame}};
+    ///
+    /// let mut r = RanimScene::new();
+    /// let r_cam = r.insert(CameraFrame::default());
+    /// // Another camera which follows the first one, 1.0 unit higher
+    /// r.always_redraw(move |ctx| {
+    ///     let mut cam = ctx.state(r_cam).unwrap_or_default();
+    ///     cam.pos.y += 1.0;
+    ///     cam
+    /// });
+    /// r.timeline_mut(r_cam).forward(2.0);
+    ///
+    /// let scene = r.seal();
+    /// let (_, item) = scene.eval_at_sec(1.0).nth(1).unwrap();
+    /// let CoreItem::CameraFrame(cam) = item else {
+    ///     panic!("not a camera frame");
+    /// };
+    /// assert_eq!(cam.pos.y, CameraFrame::default().pos.y + 1.0);
+    /// ```
+    pub fn always_redraw<T: AnyExtractCoreItem + Clone>(
+        &mut self,
+        f: impl Fn(&RedrawContext) -> T + 'static,
+    ) -> ItemId<T> {
+        let sec = self.current_sec();
+        let item = f(&RedrawContext {
+            timelines: &self.timelines,
+            sec,
+        });
+        let id = self.insert_at(item, sec);
+        self.redraws.push(Redraw {
+            timeline: *id,
+            f: Arc::new(move |ctx| f(ctx).extract()),
+        });
+        id
+    }
 }
 
 /// The information of an [`Timeline`].
@@ -188,6 +832,56 @@ pub struct TimelineInfo {
     pub animation_infos: Vec<AnimationInfo>,
 }
 
+impl TimelineInfo {
+    /// Find the "same" animation of `sec` in `old` within `new`, and map `sec` onto it.
+    ///
+    /// An animation is identified by its timeline id, its name, and the occurrence index of that
+    /// name in the timeline, so an animation can still be matched after the animations before it
+    /// changed their durations. The offset from the start of the matched animation is preserved
+    /// (clamped to the new duration).
+    ///
+    /// Animations other than the static ones are preferred, returns `None` if nothing is matched.
+    pub fn remap_sec(old: &[TimelineInfo], new: &[TimelineInfo], sec: f64) -> Option<f64> {
+        let is_static = |info: &AnimationInfo| info.anim_name.contains("::Static<");
+        // (timeline_id, anim_name, occurrence, offset, is_static)
+        let mut candidates = old
+            .iter()
+            .filter_map(|timeline| {
+                let (idx, info) = timeline
+                    .animation_infos
+                    .iter()
+                    .enumerate()
+                    .rfind(|(_, info)| info.range.contains(&sec))?;
+                let occurrence = timeline.animation_infos[..idx]
+                    .iter()
+                    .filter(|x| x.anim_name == info.anim_name)
+                    .count();
+                Some((
+                    timeline.id,
+                    info.anim_name.as_str(),
+                    occurrence,
+                    sec - info.range.start,
+                    is_static(info),
+                ))
+            })
+            .collect::<Vec<_>>();
+        candidates.sort_by_key(|(.., is_static)| *is_static);
+
+        candidates
+            .into_iter()
+            .find_map(|(id, anim_name, occurrence, offset, _)| {
+                let timeline = new.iter().find(|t| t.id == id)?;
+                let info = timeline
+                    .animation_infos
+                    .iter()
+                    .filter(|x| x.anim_name == anim_name)
+                    .nth(occurrence)?;
+                let duration = info.range.end - info.range.start;
+                Some(info.range.start + offset.min(duration))
+            })
+    }
+}
+
 impl Debug for RanimScene {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.write_fmt(format_args!("Timeline: {} timelines", self.timelines.len()))?;
@@ -195,7 +889,191 @@ impl Debug for RanimScene {
     }
 }
 
+// MARK: Layer
+/// The z distance between two adjacent layer orders.
+pub const LAYER_Z_STEP: f64 = 0.01;
+
+/// A named layer of timelines in a [`RanimScene`], see [`RanimScene::layer`].
+///
+/// All items of a layer are shifted along the z axis by `order * LAYER_Z_STEP`,
+/// so a layer with a larger order is rendered in front of the ones with smaller orders.
+/// Layers give the coarse ordering, and the z coordinates of items give the fine ordering
+/// within a layer, as long as the z differences inside a layer are smaller than [`LAYER_Z_STEP`].
+///
+/// The offset is along the world z axis, so it matches the render order only for cameras
+/// facing the z axis, like the default [`CameraFrame`](crate::core_item::camera_frame::CameraFrame).
+#[derive(Debug, Clone)]
+pub struct Layer {
+    name: String,
+    order: i32,
+    timelines: Vec<TimelineId>,
+}
+
+impl Layer {
+    /// Get the name of the layer
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+    /// Get the order of the layer
+    pub fn order(&self) -> i32 {
+        self.order
+    }
+    /// Get the timelines in the layer
+    pub fn timelines(&self) -> &[TimelineId] {
+        &self.timelines
+    }
+    /// Get the z offset of the layer
+    pub fn z_offset(&self) -> f64 {
+        self.order as f64 * LAYER_Z_STEP
+    }
+}
+
+/// A mutable handle of a [`Layer`], timelines inserted through it belong to the layer.
+pub struct LayerMut<'a> {
+    scene: &'a mut RanimScene,
+    idx: usize,
+}
+
+impl LayerMut<'_> {
+    fn layer_mut(&mut self) -> &mut Layer {
+        &mut self.scene.layers[self.idx]
+    }
+    /// Set the order of the layer
+    pub fn set_order(&mut self, order: i32) -> &mut Self {
+        self.layer_mut().order = order;
+        self
+    }
+    /// Insert a timeline at `0.0` sec into the layer, see [`RanimScene::insert`].
+    pub fn insert<T: Extract<Target = CoreItem> + Clone + 'static>(
+        &mut self,
+        item: T,
+    ) -> ItemId<T> {
+        self.insert_at(item, 0.0)
+    }
+    /// Insert a timeline at the given sec into the layer, see [`RanimScene::insert_at`].
+    pub fn insert_at<T: Extract<Target = CoreItem> + Clone + 'static>(
+        &mut self,
+        item: T,
+        sec: f64,
+    ) -> ItemId<T> {
+        let id = self.scene.insert_at(item, sec);
+        self.layer_mut().timelines.push(*id);
+        id
+    }
+    /// Insert a timeline for each item at the given sec into the layer, see [`RanimScene::insert_group_at`].
+    pub fn insert_group_at<T: Extract<Target = CoreItem> + Clone + 'static>(
+        &mut self,
+        items: impl IntoIterator<Item = T>,
+        sec: f64,
+    ) -> Vec<ItemId<T>> {
+        let ids = self.scene.insert_group_at(items, sec);
+        self.layer_mut().timelines.extend(ids.iter().map(|id| **id));
+        ids
+    }
+    /// Insert a timeline into the layer and call `f` on it, see [`RanimScene::insert_with`].
+    pub fn insert_with(&mut self, f: impl FnMut(&mut Timeline)) -> TimelineId {
+        let id = self.scene.insert_with(f);
+        self.layer_mut().timelines.push(id);
+        id
+    }
+}
+
+// MARK: Attachment
+/// An attachment of a timeline's items to another timeline's items, see [`RanimScene::attach`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Attachment {
+    /// The timeline whose items follow the parent
+    pub child: TimelineId,
+    /// The timeline whose items are followed
+    pub parent: TimelineId,
+    /// The point of the parent's bounding box to attach to
+    pub anchor: AabbPoint,
+    /// The distance from the parent along the direction of `anchor`
+    pub padding: f64,
+    /// The sec of the child's state that is put on the attached position
+    pub base_sec: f64,
+}
+
+impl Attachment {
+    /// Shift the child's items by the offset that puts the `base_points` of the child
+    /// to their attached position on the parent's points.
+    ///
+    /// When `base_points` is empty, the child's current points are used instead.
+    fn resolve(
+        &self,
+        parent_points: &[glam::DVec3],
+        base_points: &[glam::DVec3],
+        child: &mut [CoreItem],
+    ) {
+        let child_points;
+        let base_points = if base_points.is_empty() {
+            child_points = child
+                .iter()
+                .flat_map(CoreItem::world_points)
+                .collect::<Vec<_>>();
+            child_points.as_slice()
+        } else {
+            base_points
+        };
+        if parent_points.is_empty() || base_points.is_empty() {
+            return;
+        }
+        let target =
+            self.anchor.locate(parent_points) + self.anchor.0.normalize_or_zero() * self.padding;
+        let offset = target - AabbPoint(-self.anchor.0).locate(base_points);
+        child.iter_mut().for_each(|item| item.shift(offset));
+    }
+}
+
+// MARK: Redraw
+/// The states of the timelines at a sec, which the item of [`RanimScene::always_redraw`]
+/// is rebuilt with.
+pub struct RedrawContext<'a> {
+    timelines: &'a [Timeline],
+    sec: f64,
+}
+
+impl RedrawContext<'_> {
+    /// The sec to rebuild the item at
+    pub fn sec(&self) -> f64 {
+        self.sec
+    }
+    /// The state of the item of the timeline at the sec, or `None` if it is not shown,
+    /// see [`Timeline::state_at`].
+    ///
+    /// # Panics
+    /// Panics if the item of the timeline is not a `T`.
+    ///
+    /// `None` is also returned if the timeline is not in the scene, i.e. the id is from
+    /// another scene.
+    pub fn state<T: AnyExtractCoreItem>(&self, id: ItemId<T>) -> Option<T> {
+        self.timelines.get(id.id())?.state_at(self.sec)
+    }
+    /// The [`CoreItem`]s of the timeline at the sec, which are empty if it is not shown.
+    ///
+    /// Unlike [`RedrawContext::state`], the type of the item is not needed, which is useful to
+    /// follow any item, e.g. by the bounding box of its points.
+    pub fn items(&self, id: impl Into<TimelineId>) -> Vec<CoreItem> {
+        self.timelines
+            .get(id.into().0)
+            .and_then(|timeline| timeline.dyn_state_at(self.sec))
+            .map(|item| item.extract())
+            .unwrap_or_default()
+    }
+}
+
+/// A timeline whose items are rebuilt at every evaluated sec, see [`RanimScene::always_redraw`].
+#[derive(Clone)]
+pub(crate) struct Redraw {
+    timeline: TimelineId,
+    #[allow(clippy::type_complexity)]
+    f: Arc<dyn Fn(&RedrawContext) -> Vec<CoreItem>>,
+}
+
 // MARK: SealedRanimScene
+/// The samples per second evaluated for [`SealedRanimScene::content_hash`].
+pub const CONTENT_HASH_SAMPLE_RATE: f64 = 30.0;
+
 /// The sealed [`RanimScene`].
 ///
 /// the timelines and time marks cannot be modified after sealed. And
@@ -204,6 +1082,16 @@ pub struct SealedRanimScene {
     pub(crate) total_secs: f64,
     pub(crate) timelines: Vec<Timeline>,
     pub(crate) time_marks: Vec<(f64, TimeMark)>,
+    /// Sorted by the sec
+    pub(crate) callbacks: Vec<(f64, TimeCallback)>,
+    pub(crate) asset_paths: Vec<PathBuf>,
+    /// The z offset of each timeline from its layer
+    pub(crate) z_offsets: Vec<f64>,
+    /// Sorted so that parents come before their children
+    pub(crate) attachments: Vec<Attachment>,
+    pub(crate) redraws: Vec<Redraw>,
+    /// Whether to evaluate the ghosts of the anims
+    pub(crate) ghosts: bool,
 }
 
 impl SealedRanimScene {
@@ -211,10 +1099,64 @@ impl SealedRanimScene {
     pub fn total_secs(&self) -> f64 {
         self.total_secs
     }
+    /// Scale the time of the sealed scene by `factor`, see [`RanimScene::scale_time`].
+    ///
+    /// # Panics
+    /// Panics if `factor` is not positive and finite.
+    pub fn scale_time(&mut self, factor: f64) -> &mut Self {
+        assert!(
+            factor > 0.0 && factor.is_finite(),
+            "the time scale factor must be positive and finite, got {factor}"
+        );
+        self.timelines.iter_mut().for_each(|t| {
+            t.scale_time(factor);
+        });
+        self.time_marks
+            .iter_mut()
+            .for_each(|(sec, _)| *sec *= factor);
+        self.callbacks
+            .iter_mut()
+            .for_each(|(sec, _)| *sec *= factor);
+        self.attachments
+            .iter_mut()
+            .for_each(|attachment| attachment.base_sec *= factor);
+        self.total_secs *= factor;
+        self
+    }
+    /// Whether to evaluate the ghosts of the anims played with
+    /// [`AnimationCell::with_ghosts`], default is `false`.
+    ///
+    /// The ghosts are placed below the items of their timelines, and the timelines
+    /// redrawn by [`RanimScene::always_redraw`] have no ghosts.
+    pub fn set_ghosts(&mut self, enabled: bool) -> &mut Self {
+        self.ghosts = enabled;
+        self
+    }
     /// Get time marks
     pub fn time_marks(&self) -> &[(f64, TimeMark)] {
         &self.time_marks
     }
+    /// Get the asset paths read by [`RanimScene::read_asset`]
+    pub fn asset_paths(&self) -> &[PathBuf] {
+        &self.asset_paths
+    }
+    /// Get the callbacks inserted by [`RanimScene::insert_callback`], sorted by their secs
+    pub fn callbacks(&self) -> &[(f64, TimeCallback)] {
+        &self.callbacks
+    }
+    /// Invoke the callbacks whose sec is in `(from, to]` in the order of their secs,
+    /// returns the count of invoked callbacks.
+    ///
+    /// Use `f64::NEG_INFINITY` as `from` to include the callbacks at the very beginning.
+    pub fn fire_callbacks(&self, from: f64, to: f64) -> usize {
+        let start = self.callbacks.partition_point(|(sec, _)| *sec <= from);
+        let end = self.callbacks.partition_point(|(sec, _)| *sec <= to);
+        let callbacks = self.callbacks.get(start..end).unwrap_or_default();
+        for (sec, callback) in callbacks {
+            callback(*sec);
+        }
+        callbacks.len()
+    }
 
     /// Get the iterator of timelines
     pub fn timelines_iter(&self) -> impl Iterator<Item = &Timeline> {
@@ -241,20 +1183,115 @@ impl SealedRanimScene {
     }
 
     /// Eval primitives
+    ///
+    /// The order of the items is deterministic: they are ordered by their timeline ids (i.e. the
+    /// order the timelines are inserted), and then by the order they are extracted from the item
+    /// of the timeline. It doesn't depend on anything else, like the order of the anims' submission.
     pub fn eval_at_sec(&self, target_sec: f64) -> impl Iterator<Item = ((usize, usize), CoreItem)> {
-        self.timelines_iter()
+        let items =
+            (0..self.timelines.len()).map(move |t_id| self.eval_timeline_at_sec(t_id, target_sec));
+        // The attachments need all the timelines evaluated first, the others are evaluated lazily
+        let items = if self.attachments.is_empty() {
+            Either::Left(items)
+        } else {
+            let mut items = items.collect::<Vec<_>>();
+            for attachment in &self.attachments {
+                let parent_points = items[attachment.parent.0]
+                    .iter()
+                    .flat_map(|(_, res)| res)
+                    .flat_map(CoreItem::world_points)
+                    .collect::<Vec<_>>();
+                let base_points = self
+                    .eval_timeline_at_sec(attachment.child.0, attachment.base_sec)
+                    .iter()
+                    .flat_map(|(_, res)| res)
+                    .flat_map(CoreItem::world_points)
+                    .collect::<Vec<_>>();
+                if let Some((_, child_items)) = items[attachment.child.0].as_mut() {
+                    attachment.resolve(&parent_points, &base_points, child_items);
+                }
+            }
+            Either::Right(items.into_iter())
+        };
+
+        items
             .enumerate()
-            .filter_map(move |(t_id, t)| {
-                t.eval_primitives_at_sec(target_sec)
-                    .map(move |(a_id, res)| res.into_iter().map(move |x| ((t_id, a_id), x)))
+            .filter_map(move |(t_id, res)| {
+                let dz = self.z_offsets[t_id] as f32;
+                res.map(move |(a_id, res)| {
+                    let ghosts =
+                        if self.ghosts && !self.redraws.iter().any(|r| r.timeline.0 == t_id) {
+                            self.timelines[t_id].eval_ghosts_at_sec(a_id, target_sec)
+                        } else {
+                            Vec::new()
+                        };
+                    ghosts.into_iter().chain(res).map(move |mut x| {
+                        if dz != 0.0 {
+                            x.shift_z(dz);
+                        }
+                        ((t_id, a_id), x)
+                    })
+                })
             })
             .flatten()
     }
 
+    /// The items of the timeline at `sec`, rebuilt by its [`RanimScene::always_redraw`] if any.
+    fn eval_timeline_at_sec(&self, t_id: usize, sec: f64) -> Option<(usize, Vec<CoreItem>)> {
+        let mut res = self.timelines[t_id].eval_primitives_at_sec(sec);
+        // The redrawn items read the states of the timelines, which are evaluated independently
+        if let Some((_, items)) = res.as_mut()
+            && let Some(redraw) = self.redraws.iter().find(|r| r.timeline.0 == t_id)
+        {
+            *items = (redraw.f)(&RedrawContext {
+                timelines: &self.timelines,
+                sec,
+            });
+        }
+        res
+    }
+
     /// Eval primitives
     pub fn eval_at_alpha(&self, alpha: f64) -> impl Iterator<Item = ((usize, usize), CoreItem)> {
         self.eval_at_sec(self.total_secs() * alpha)
     }
+    /// A hash of the content of the scene, the same scenes have the same hash.
+    ///
+    /// The scene is evaluated with [`SealedRanimScene::eval_at_sec`] at
+    /// [`CONTENT_HASH_SAMPLE_RATE`] samples per second (and at the end), and the ids and all the
+    /// data of the items are hashed, together with the total secs and the time marks. The
+    /// callbacks are not included.
+    ///
+    /// It can be used to skip re-rendering unchanged scenes, the hash is stable between runs
+    /// of the same build, but may change between versions of ranim or Rust.
+    pub fn content_hash(&self) -> u64 {
+        struct HashWriter<'a>(&'a mut DefaultHasher);
+        impl std::fmt::Write for HashWriter<'_> {
+            fn write_str(&mut self, s: &str) -> std::fmt::Result {
+                self.0.write(s.as_bytes());
+                Ok(())
+            }
+        }
+
+        let mut hasher = DefaultHasher::new();
+        self.total_secs.to_bits().hash(&mut hasher);
+        for (sec, mark) in &self.time_marks {
+            sec.to_bits().hash(&mut hasher);
+            write!(HashWriter(&mut hasher), "{mark:?}").unwrap();
+        }
+        let samples = (self.total_secs * CONTENT_HASH_SAMPLE_RATE).ceil() as usize;
+        for i in 0..=samples {
+            let sec = (i as f64 / CONTENT_HASH_SAMPLE_RATE).min(self.total_secs);
+            for (id, item) in self.eval_at_sec(sec) {
+                id.hash(&mut hasher);
+                // The Debug output includes all the fields, and is exact for floats
+                write!(HashWriter(&mut hasher), "{item:?}").unwrap();
+            }
+            // Separate the samples
+            hasher.write_u8(0xff);
+        }
+        hasher.finish()
+    }
 }
 
 // MARK: TimelineIndex
@@ -300,6 +1337,20 @@ pub trait TimelineQuery<'a> {
     fn ressemble_mut(self, timeline: &'a mut Timeline) -> Self::RessembleMutResult;
 }
 
+impl<'a, T> TimelineQuery<'a> for ItemId<T> {
+    type RessembleResult = &'a Timeline;
+    type RessembleMutResult = &'a mut Timeline;
+    fn id(&self) -> TimelineId {
+        self.id
+    }
+    fn ressemble(self, timeline: &'a Timeline) -> Self::RessembleResult {
+        timeline
+    }
+    fn ressemble_mut(self, timeline: &'a mut Timeline) -> Self::RessembleMutResult {
+        timeline
+    }
+}
+
 impl<'a> TimelineQuery<'a> for TimelineId {
     type RessembleResult = &'a Timeline;
     type RessembleMutResult = &'a mut Timeline;
@@ -314,7 +1365,7 @@ impl<'a> TimelineQuery<'a> for TimelineId {
     }
 }
 
-impl<'a, TI: AsRef<TimelineId>, T> TimelineQuery<'a> for (TI, T) {
+impl<'a, TI: TimelineIdFor<T>, T> TimelineQuery<'a> for (TI, T) {
     type RessembleResult = (&'a Timeline, T);
     type RessembleMutResult = (&'a mut Timeline, T);
     fn id(&self) -> TimelineId {
@@ -328,7 +1379,7 @@ impl<'a, TI: AsRef<TimelineId>, T> TimelineQuery<'a> for (TI, T) {
     }
 }
 
-impl<'a: 'b, 'b, TI: AsRef<TimelineId>, T> TimelineQuery<'a> for &'b (TI, T) {
+impl<'a: 'b, 'b, TI: TimelineIdFor<T>, T> TimelineQuery<'a> for &'b (TI, T) {
     type RessembleResult = (&'b Timeline, &'b T);
     type RessembleMutResult = (&'b mut Timeline, &'b T);
     fn id(&self) -> TimelineId {
@@ -342,7 +1393,7 @@ impl<'a: 'b, 'b, TI: AsRef<TimelineId>, T> TimelineQuery<'a> for &'b (TI, T) {
     }
 }
 
-impl<'a: 'b, 'b, TI: AsRef<TimelineId>, T> TimelineQuery<'a> for &'b mut (TI, T) {
+impl<'a: 'b, 'b, TI: TimelineIdFor<T>, T> TimelineQuery<'a> for &'b mut (TI, T) {
     type RessembleResult = (&'b Timeline, &'b mut T);
     type RessembleMutResult = (&'b mut Timeline, &'b mut T);
     fn id(&self) -> TimelineId {
@@ -446,3 +1497,796 @@ impl<'a, TI: TimelineQuery<'a>, const N: usize> TimelineIndex<'a> for [TI; N] {
         Ok(res)
     }
 }
+
+// MARK: TimelineGroup
+/// A group of timelines controlled by one handle, like a "mobject group" in manim.
+///
+/// Indexing a [`RanimScene`] with `&TimelineGroup` returns a [`TimelineGroupMut`],
+/// which broadcasts the operations to all the timelines of the group:
+///
+/// ```rust,ignore
+/// let group = TimelineGroup::new(r.insert_group(items));
+/// // Every item is shifted from its own current state
+/// r.timeline_mut(&group)
+///     .play_with(|item: VItem| item.morph(|x| x.shift(DVec3::X).discard()))
+///     .sync();
+/// // Access a single child by its index in the group
+/// r.timeline_mut(&group)[0].forward(1.0);
+/// ```
+///
+/// Duplicated ids are removed, the first occurrence is kept.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct TimelineGroup(Vec<TimelineId>);
+
+impl TimelineGroup {
+    /// Construct a group from timeline ids, e.g. the ids returned by [`RanimScene::insert_group`].
+    pub fn new(ids: impl IntoIterator<Item = impl Into<TimelineId>>) -> Self {
+        let mut group = Vec::new();
+        for id in ids.into_iter().map(Into::into) {
+            if !group.contains(&id) {
+                group.push(id);
+            }
+        }
+        Self(group)
+    }
+    /// The ids of the timelines in the group
+    pub fn ids(&self) -> &[TimelineId] {
+        &self.0
+    }
+    /// The count of the timelines in the group
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+    /// Whether the group is empty
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl<I: Into<TimelineId>> FromIterator<I> for TimelineGroup {
+    fn from_iter<T: IntoIterator<Item = I>>(iter: T) -> Self {
+        Self::new(iter)
+    }
+}
+
+/// The mutable timelines of a [`TimelineGroup`], in the order of the group.
+///
+/// The methods are applied to every timeline, and it derefs to the timelines
+/// to access a single child.
+pub struct TimelineGroupMut<'a>(Vec<&'a mut Timeline>);
+
+impl<'a> std::ops::Deref for TimelineGroupMut<'a> {
+    type Target = [&'a mut Timeline];
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl std::ops::DerefMut for TimelineGroupMut<'_> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl TimelineGroupMut<'_> {
+    /// Plays an anim created by `anim_func` with the current state of each item,
+    /// see [`Timeline::play_with`].
+    ///
+    /// # Panics
+    /// Panics if nothing is played on a timeline yet, or an item is not a `T`.
+    pub fn play_with<T: AnyExtractCoreItem>(
+        &mut self,
+        mut anim_func: impl FnMut(T) -> AnimationCell<T>,
+    ) -> &mut Self {
+        self.0.iter_mut().for_each(|timeline| {
+            timeline.play_with(&mut anim_func);
+        });
+        self
+    }
+    /// Plays the [`AnimationTemplate`] on every timeline, see [`Timeline::play_template`].
+    pub fn play_template<T: AnyExtractCoreItem>(
+        &mut self,
+        template: &AnimationTemplate<T>,
+    ) -> &mut Self {
+        self.0.iter_mut().for_each(|timeline| {
+            timeline.play_template(template);
+        });
+        self
+    }
+    /// Forward every timeline by `secs`
+    pub fn forward(&mut self, secs: f64) -> &mut Self {
+        self.0.iter_mut().for_each(|timeline| {
+            timeline.forward(secs);
+        });
+        self
+    }
+    /// Forward every timeline to `target_sec`
+    pub fn forward_to(&mut self, target_sec: f64) -> &mut Self {
+        self.0.iter_mut().for_each(|timeline| {
+            timeline.forward_to(target_sec);
+        });
+        self
+    }
+    /// Forward every timeline to the max current sec of the group, other timelines are untouched.
+    pub fn sync(&mut self) -> &mut Self {
+        let max_sec = self
+            .0
+            .iter()
+            .map(|timeline| timeline.cur_sec())
+            .fold(f64::NEG_INFINITY, f64::max);
+        if max_sec.is_finite() {
+            self.forward_to(max_sec);
+        }
+        self
+    }
+    /// Show every timeline
+    pub fn show(&mut self) -> &mut Self {
+        self.0.iter_mut().for_each(|timeline| {
+            timeline.show();
+        });
+        self
+    }
+    /// Hide every timeline
+    pub fn hide(&mut self) -> &mut Self {
+        self.0.iter_mut().for_each(|timeline| {
+            timeline.hide();
+        });
+        self
+    }
+}
+
+impl<'a> TimelineIndex<'a> for &TimelineGroup {
+    type RefOutput = Vec<&'a Timeline>;
+    type MutOutput = TimelineGroupMut<'a>;
+    fn get_index_ref(self, timelines: &'a [Timeline]) -> Self::RefOutput {
+        self.0.iter().map(|id| &timelines[id.0]).collect()
+    }
+    fn get_index_mut(self, timelines: &'a mut [Timeline]) -> Self::MutOutput {
+        // The ids are deduplicated, so every timeline is taken at most once
+        let mut slots = timelines.iter_mut().map(Some).collect::<Vec<_>>();
+        TimelineGroupMut(
+            self.0
+                .iter()
+                .map(|id| slots[id.0].take().expect("timeline id out of range"))
+                .collect(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::core_item::camera_frame::CameraFrame;
+    use glam::DVec3;
+
+    fn timeline_info(id: usize, anims: &[(&str, f64, f64)]) -> TimelineInfo {
+        TimelineInfo {
+            id,
+            animation_infos: anims
+                .iter()
+                .map(|(name, start, end)| AnimationInfo {
+                    anim_name: name.to_string(),
+                    range: *start..*end,
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_remap_sec() {
+        const STATIC: &str = "ranim_core::animation::Static<Foo>";
+        let old = [
+            timeline_info(
+                0,
+                &[("Fade", 0.0, 1.0), ("Morph", 1.0, 2.0), ("Fade", 2.0, 3.0)],
+            ),
+            timeline_info(1, &[(STATIC, 0.0, 3.0)]),
+        ];
+        // The first anim becomes longer
+        let new = [
+            timeline_info(
+                0,
+                &[("Fade", 0.0, 2.0), ("Morph", 2.0, 3.0), ("Fade", 3.0, 4.0)],
+            ),
+            timeline_info(1, &[(STATIC, 0.0, 4.0)]),
+        ];
+        assert_eq!(TimelineInfo::remap_sec(&old, &new, 1.5), Some(2.5));
+        assert_eq!(TimelineInfo::remap_sec(&old, &new, 2.5), Some(3.5));
+
+        // The matched anim becomes shorter
+        let new = [timeline_info(
+            0,
+            &[("Fade", 0.0, 1.0), ("Morph", 1.0, 1.2), ("Fade", 1.2, 2.2)],
+        )];
+        assert_eq!(TimelineInfo::remap_sec(&old, &new, 1.5), Some(1.2));
+
+        // Only the static anim can be matched
+        let new = [timeline_info(1, &[(STATIC, 0.0, 4.0)])];
+        assert_eq!(TimelineInfo::remap_sec(&old, &new, 1.5), Some(1.5));
+
+        assert_eq!(TimelineInfo::remap_sec(&old, &[], 1.5), None);
+    }
+
+    #[test]
+    fn test_read_asset() {
+        let path = concat!(env!("CARGO_MANIFEST_DIR"), "/Cargo.toml");
+        let mut r = RanimScene::new();
+        assert!(r.read_asset(path).unwrap().contains("ranim-core"));
+        r.read_asset(path).unwrap();
+        assert!(r.read_asset("not_exist.svg").is_err());
+        r.insert_empty();
+
+        let r = r.seal();
+        assert_eq!(
+            r.asset_paths(),
+            &[PathBuf::from(path), PathBuf::from("not_exist.svg")]
+        );
+    }
+
+    #[test]
+    fn test_validate() {
+        let mut r = RanimScene::new();
+        assert_eq!(r.validate(), vec!["the scene has no timelines"]);
+
+        r.insert_empty();
+        r.insert_with(|t| {
+            t.play(CameraFrame::default().show()).forward(1.0);
+        });
+        r.insert_time_mark(0.5, TimeMark::Capture("a.png".to_string()));
+        r.insert_time_mark(0.5, TimeMark::Capture("a.png".to_string()));
+        r.insert_time_mark(2.0, TimeMark::Capture("b.png".to_string()));
+        let warnings = r.validate();
+        assert_eq!(warnings.len(), 3);
+        assert!(warnings[0].contains("b.png"));
+        assert!(warnings[1].contains("timeline 0"));
+        assert!(warnings[2].contains("a.png"));
+
+        let mut r = RanimScene::new();
+        r.insert_with(|t| {
+            t.play(CameraFrame::default().show());
+        });
+        assert_eq!(r.validate().len(), 1);
+    }
+
+    #[test]
+    fn test_callbacks() {
+        use std::sync::Mutex;
+
+        let fired = Arc::new(Mutex::new(Vec::new()));
+        let mut r = RanimScene::new();
+        r.insert_with(|t| {
+            t.play(CameraFrame::default().show()).forward(2.0);
+        });
+        for sec in [1.5, 0.0, 1.0, 3.0] {
+            let fired = fired.clone();
+            r.insert_callback(sec, move |sec| fired.lock().unwrap().push(sec));
+        }
+        assert_eq!(r.validate().len(), 1);
+        let scene = r.seal();
+        let secs = scene
+            .callbacks()
+            .iter()
+            .map(|(sec, _)| *sec)
+            .collect::<Vec<_>>();
+        assert_eq!(secs, [0.0, 1.0, 1.5, 3.0]);
+
+        // Frames at 0.0, 0.5, 1.0, 1.5, 2.0
+        let mut prev = f64::NEG_INFINITY;
+        let counts = [0.0, 0.5, 1.0, 1.5, 2.0].map(|sec| {
+            let cnt = scene.fire_callbacks(prev, sec);
+            prev = sec;
+            cnt
+        });
+        assert_eq!(counts, [1, 0, 1, 1, 0]);
+        assert_eq!(*fired.lock().unwrap(), [0.0, 1.0, 1.5]);
+
+        assert_eq!(scene.fire_callbacks(1.5, 0.5), 0);
+    }
+
+    #[test]
+    fn test_extract_bundle() {
+        use crate::core_item::vitem::VItem;
+
+        let camera_frame = CameraFrame::default();
+        let vitem = VItem::default();
+        let bundle = Bundle((
+            vitem.clone(),
+            camera_frame.clone(),
+            [vitem.clone(), vitem.clone()],
+        ));
+        assert_eq!(
+            bundle.extract(),
+            vec![
+                CoreItem::VItem(vitem.clone()),
+                CoreItem::CameraFrame(camera_frame.clone()),
+                CoreItem::VItem(vitem.clone()),
+                CoreItem::VItem(vitem.clone()),
+            ]
+        );
+        assert_eq!(
+            Bundle((camera_frame.clone(),)).extract(),
+            camera_frame.extract()
+        );
+
+        let mut r = RanimScene::new();
+        let r_bundle = r.insert(bundle);
+        r.timelines_mut().forward(1.0);
+        let r = r.seal();
+        assert_eq!(
+            r.eval_at_sec(0.5).map(|(id, _)| id).collect::<Vec<_>>(),
+            vec![(r_bundle.id(), 1); 4]
+        );
+    }
+
+    #[test]
+    fn test_absolute_placement() {
+        use crate::core_item::vitem::VItem;
+
+        let mut r = RanimScene::new();
+        let r_cam = r.insert(CameraFrame::default());
+        r.timeline_mut(r_cam).forward(1.0);
+        let r_group = r.insert_group_at(vec![VItem::default(); 3], 1.5);
+        assert_eq!(r_group.len(), 3);
+
+        // Regardless of the cursor of the camera timeline
+        r.timeline_mut(r_group[0])
+            .play_at(VItem::default().show().with_duration(0.5), 3.0);
+        assert_eq!(r.timeline(r_group[0]).cur_sec(), 3.5);
+        // Earlier than the cursor, played at the cursor
+        r.timeline_mut(r_group[0])
+            .play_at(VItem::default().show().with_duration(0.5), 2.0);
+        assert_eq!(r.timeline(r_group[0]).cur_sec(), 4.0);
+
+        r.timelines_mut().sync();
+        assert!(r.timelines().iter().all(|t| t.cur_sec() == 4.0));
+
+        let r = r.seal();
+        let timelines = r.timelines_iter().collect::<Vec<_>>();
+        for id in &r_group {
+            assert_eq!(timelines[id.id()].start_sec(), Some(1.5));
+        }
+        let infos = timelines[r_group[0].id()].get_animation_infos();
+        assert_eq!(
+            infos
+                .iter()
+                .map(|info| info.range.clone())
+                .filter(|range| !range.is_empty())
+                .collect::<Vec<_>>(),
+            vec![1.5..3.0, 3.0..3.5, 3.5..4.0]
+        );
+        let ids_at = |sec| {
+            r.eval_at_sec(sec)
+                .map(|((id, _), _)| id)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(ids_at(1.0), vec![r_cam.id()]);
+        assert_eq!(ids_at(1.5).len(), 4);
+    }
+
+    #[test]
+    fn test_redraw_reference() {
+        use crate::core_item::vitem::VItem;
+
+        let mut r = RanimScene::new();
+        let cam = CameraFrame::default();
+        let mut moved = cam.clone();
+        moved.pos.x = 2.0;
+        let r_cam = r.insert(cam.clone());
+        let r_vitem = r.insert(VItem::default());
+        // Ids are `Copy`, moved into the updater and read at every evaluated sec
+        let r_follower = r.always_redraw(move |ctx| {
+            let mut follower = ctx.state(r_cam).unwrap_or_default();
+            follower.pos.y = ctx.items(r_vitem).len() as f64;
+            follower
+        });
+        // Read while constructing
+        assert_eq!(r.state_at(r_follower, 0.0).map(|x| x.pos.y), Some(1.0));
+        r.timeline_mut(r_cam)
+            .forward(1.0)
+            .play(moved.show().with_duration(1.0));
+        r.timeline_mut(r_vitem).forward(1.0).hide();
+        assert_eq!(r.state_at(r_cam, 1.5), Some(moved.clone()));
+
+        let scene = r.seal();
+        let follower_at = |sec| {
+            scene
+                .eval_at_sec(sec)
+                .find_map(|((id, _), item)| match item {
+                    CoreItem::CameraFrame(cam) if id == r_follower.id() => Some(cam),
+                    _ => None,
+                })
+                .unwrap()
+        };
+        assert_eq!((follower_at(0.5).pos.x, follower_at(0.5).pos.y), (0.0, 1.0));
+        assert_eq!((follower_at(1.5).pos.x, follower_at(1.5).pos.y), (2.0, 0.0));
+
+        // An id from another scene with more timelines is not found
+        let r_foreign = ItemId::<CameraFrame>::new(TimelineId(10));
+        let ctx = RedrawContext {
+            timelines: &scene.timelines,
+            sec: 0.5,
+        };
+        assert_eq!(ctx.state(r_foreign), None);
+        assert!(ctx.items(r_foreign).is_empty());
+    }
+
+    #[test]
+    fn test_layer() {
+        use crate::core_item::vitem::VItem;
+
+        let mut r = RanimScene::new();
+        let r_default = r.insert(VItem::default());
+        let r_fg = r.layer("foreground").insert(VItem::default());
+        let r_bg = r.layer("background").set_order(-1).insert(VItem::default());
+        let r_cam = r.layer("background").insert(CameraFrame::default());
+        assert_eq!(r.layers().len(), 2);
+        assert_eq!(r.layers()[0].order(), 1);
+        assert_eq!(r.layers()[1].timelines(), &[*r_bg, *r_cam]);
+
+        r.timelines_mut().forward(1.0);
+        let r = r.seal();
+        let items = r.eval_at_sec(0.5).collect::<Vec<_>>();
+        let z_of = |id: ItemId<VItem>| {
+            items
+                .iter()
+                .find_map(|((t_id, _), item)| match item {
+                    CoreItem::VItem(vitem) if *t_id == id.id() => Some(vitem.points[0].z),
+                    _ => None,
+                })
+                .unwrap()
+        };
+        assert_eq!(z_of(r_default), 0.0);
+        assert!(z_of(r_bg) < z_of(r_default));
+        assert!(z_of(r_default) < z_of(r_fg));
+        assert!(
+            items
+                .iter()
+                .any(|(_, item)| item == &CoreItem::CameraFrame(CameraFrame::default()))
+        );
+    }
+
+    #[test]
+    fn test_timeline_group() {
+        let mut r = RanimScene::new();
+        let cams = [0.0, 1.0, 2.0].map(|x| {
+            let mut cam = CameraFrame::default();
+            cam.pos.x = x;
+            cam
+        });
+        let ids = r.insert_group(cams);
+        let r_other = r.insert(CameraFrame::default());
+        let group = TimelineGroup::new(ids.iter().copied().chain([ids[0]]));
+        assert_eq!(group.len(), 3);
+
+        // Broadcast a shift, each from its own state
+        r.timeline_mut(&group).play_with(|mut cam: CameraFrame| {
+            cam.pos += DVec3::Y;
+            cam.show()
+        });
+        r.timeline_mut(&group)[1].forward(1.0);
+        r.timeline_mut(&group).sync().forward(0.5);
+
+        let secs = r
+            .timeline(&group)
+            .iter()
+            .map(|t| t.cur_sec())
+            .collect::<Vec<_>>();
+        assert_eq!(secs, vec![secs[1]; 3]);
+        assert_eq!(r.timeline(r_other).cur_sec(), 0.0);
+
+        assert_eq!(secs[0], 1.5);
+        r.timelines_mut().sync();
+        let r = r.seal();
+        let mut positions = r
+            .eval_at_sec(1.4)
+            .filter_map(|(_, item)| match item {
+                CoreItem::CameraFrame(cam) => Some(cam.pos),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        positions.retain(|pos| pos.y == 1.0);
+        assert_eq!(
+            positions,
+            vec![
+                DVec3::new(0.0, 1.0, 0.0),
+                DVec3::new(1.0, 1.0, 0.0),
+                DVec3::new(2.0, 1.0, 0.0),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_sync_up_to() {
+        let mut r = RanimScene::new();
+        let r_a = r.insert(CameraFrame::default());
+        let r_b = r.insert_empty();
+        let r_c = r.insert_empty();
+        r.timeline_mut(r_a).forward(2.0);
+        r.timeline_mut(r_b).forward(0.5);
+        r.timeline_mut(r_c).forward(1.0);
+
+        r.sync_up_to(&[TimelineId::from(r_a), r_b]);
+        let secs = [r_a.into(), r_b, r_c].map(|id: TimelineId| r.timeline(id).cur_sec());
+        assert_eq!(secs, [2.0, 2.0, 1.0]);
+
+        // An empty subset changes nothing
+        r.sync_up_to(&[] as &[TimelineId]);
+        r.sync_up_to(&[r_c]);
+        let secs = [r_a.into(), r_b, r_c].map(|id: TimelineId| r.timeline(id).cur_sec());
+        assert_eq!(secs, [2.0, 2.0, 1.0]);
+    }
+
+    #[test]
+    fn test_animation_template() {
+        let shift = |offset: DVec3| {
+            move |mut cam: CameraFrame| {
+                cam.pos += offset;
+                cam.show().with_duration(1.0)
+            }
+        };
+        let template = AnimationTemplate::new()
+            .then(shift(DVec3::X))
+            .then_forward(0.5)
+            .then(shift(DVec3::Y));
+        assert_eq!(template.len(), 3);
+
+        let mut r = RanimScene::new();
+        let r_a = r.insert_camera_with(|cam| cam.pos = DVec3::ZERO);
+        let r_b = r.insert_camera_with(|cam| cam.pos = DVec3::Z * 5.0);
+        r.timeline_mut(r_b).forward(1.0);
+        r.timeline_mut(r_a).play_template(&template);
+        r.timeline_mut(r_b).play_template(&template);
+        assert_eq!(r.timeline(r_a).cur_sec(), 2.5);
+        assert_eq!(r.timeline(r_b).cur_sec(), 3.5);
+
+        // Each item is bound to its own state
+        let pos_at = |r: &SealedRanimScene, id: ItemId<CameraFrame>, sec: f64| {
+            r.eval_at_sec(sec)
+                .find_map(|((t_id, _), item)| match item {
+                    CoreItem::CameraFrame(cam) if t_id == id.id() => Some(cam.pos),
+                    _ => None,
+                })
+                .unwrap()
+        };
+        r.timelines_mut().sync();
+        let r = r.seal();
+        assert_eq!(pos_at(&r, r_a, 1.2), DVec3::X);
+        assert_eq!(pos_at(&r, r_a, 2.5), DVec3::X + DVec3::Y);
+        assert_eq!(pos_at(&r, r_b, 0.5), DVec3::Z * 5.0);
+        assert_eq!(pos_at(&r, r_b, 3.5), DVec3::Z * 5.0 + DVec3::X + DVec3::Y);
+    }
+
+    #[test]
+    fn test_attach() {
+        use crate::core_item::vitem::VItem;
+
+        let square = |center: DVec3| VItem {
+            points: [(-1.0, -1.0), (1.0, -1.0), (1.0, 1.0)]
+                .map(|(x, y)| (center + DVec3::new(x, y, 0.0)).as_vec3().extend(1.0))
+                .to_vec(),
+            ..Default::default()
+        };
+        let shifted = |mut vitem: VItem, offset: DVec3| {
+            let offset = offset.as_vec3().extend(0.0);
+            vitem.points.iter_mut().for_each(|p| *p += offset);
+            vitem
+        };
+
+        let mut r = RanimScene::new();
+        let r_parent = r.insert(square(DVec3::ZERO));
+        let r_label = r.insert(square(DVec3::X * 10.0));
+        let r_sub_label = r.insert(square(DVec3::ZERO));
+        // Attached out of order, the parent is still resolved first
+        r.attach(r_sub_label, r_label, AabbPoint(DVec3::X), 0.0);
+        r.attach(r_label, r_parent, AabbPoint(DVec3::Y), 0.5);
+        r.timeline_mut(r_parent).forward(1.0);
+        r.timeline_mut(r_parent)
+            .play_with(|vitem| shifted(vitem, DVec3::new(3.0, -2.0, 0.0)).show());
+        r.timeline_mut(r_parent).forward(1.0);
+        r.timelines_mut().sync();
+
+        let r = r.seal();
+        let aabb_at = |id: ItemId<VItem>, sec: f64| {
+            let points = r
+                .eval_at_sec(sec)
+                .filter(|((t_id, _), _)| *t_id == id.id())
+                .flat_map(|(_, item)| item.world_points())
+                .collect::<Vec<_>>();
+            crate::anchor::Aabb::aabb(points.as_slice())
+        };
+        // The label is above the parent, and the sub label is on the right of the label
+        for (sec, center) in [(0.5, DVec3::ZERO), (1.5, DVec3::new(3.0, -2.0, 0.0))] {
+            let [parent_min, parent_max] = aabb_at(r_parent, sec);
+            let [label_min, label_max] = aabb_at(r_label, sec);
+            let [sub_min, _] = aabb_at(r_sub_label, sec);
+            assert_eq!((parent_min + parent_max) / 2.0, center);
+            assert_eq!(
+                label_min,
+                DVec3::new(center.x - 1.0, parent_max.y + 0.5, 0.0)
+            );
+            assert_eq!(sub_min, DVec3::new(label_max.x, label_min.y, 0.0));
+        }
+    }
+
+    #[test]
+    fn test_attach_child_anim() {
+        use crate::core_item::vitem::VItem;
+
+        let vitem = |points: [(f32, f32); 3]| VItem {
+            points: points.map(|(x, y)| glam::vec4(x, y, 0.0, 1.0)).to_vec(),
+            ..Default::default()
+        };
+        let shifted = |mut vitem: VItem, x: f32, y: f32| {
+            vitem
+                .points
+                .iter_mut()
+                .for_each(|p| *p += glam::vec4(x, y, 0.0, 0.0));
+            vitem
+        };
+
+        let mut r = RanimScene::new();
+        let r_parent = r.insert(vitem([(-1.0, -1.0), (1.0, -1.0), (1.0, 1.0)]));
+        let r_label = r.insert(vitem([(5.0, 5.0), (6.0, 5.0), (6.0, 6.0)]));
+        r.attach(r_label, r_parent, AabbPoint(DVec3::Y), 0.0);
+        r.timeline_mut(r_parent).forward(1.0);
+        r.timeline_mut(r_parent)
+            .play_with(|vitem| shifted(vitem, 4.0, 0.0).show());
+        r.timeline_mut(r_label).forward(2.0);
+        r.timeline_mut(r_label)
+            .play_with(|vitem| shifted(vitem, 0.0, 2.0).show());
+        r.timeline_mut(r_label).forward(1.0);
+        r.timelines_mut().sync();
+
+        let r = r.seal();
+        let aabb_at = |id: ItemId<VItem>, sec: f64| {
+            let points = r
+                .eval_at_sec(sec)
+                .filter(|((t_id, _), _)| *t_id == id.id())
+                .flat_map(|(_, item)| item.world_points())
+                .collect::<Vec<_>>();
+            crate::anchor::Aabb::aabb(points.as_slice())
+        };
+        // The label follows the parent, and keeps its own shift relative to it
+        for (sec, parent_x, label_y) in [(0.5, 0.0, 0.0), (1.5, 4.0, 0.0), (2.5, 4.0, 2.0)] {
+            let [parent_min, parent_max] = aabb_at(r_parent, sec);
+            let [label_min, _] = aabb_at(r_label, sec);
+            assert_eq!(parent_min.x, parent_x - 1.0);
+            assert_eq!(
+                label_min,
+                DVec3::new(parent_x - 0.5, parent_max.y + label_y, 0.0)
+            );
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "forms a cycle")]
+    fn test_attach_cycle() {
+        let mut r = RanimScene::new();
+        let [a, b, c] = [(); 3].map(|_| r.insert(CameraFrame::default()));
+        r.attach(b, a, AabbPoint::CENTER, 0.0);
+        r.attach(c, b, AabbPoint::CENTER, 0.0);
+        r.attach(a, c, AabbPoint::CENTER, 0.0);
+    }
+
+    #[test]
+    fn test_content_hash() {
+        use crate::core_item::vitem::VItem;
+
+        let build = |offset: f32| {
+            let mut r = RanimScene::new();
+            let r_cam = r.insert(CameraFrame::default());
+            let r_vitem = r.insert(VItem::default());
+            r.timeline_mut(r_cam).forward(1.0);
+            r.timeline_mut(r_vitem).play_with(|mut vitem: VItem| {
+                vitem.points.iter_mut().for_each(|p| p.x += offset);
+                vitem.show()
+            });
+            r.insert_time_mark(0.5, TimeMark::Capture("a.png".to_string()));
+            r.seal()
+        };
+        assert_eq!(build(1.0).content_hash(), build(1.0).content_hash());
+        assert_ne!(build(1.0).content_hash(), build(2.0).content_hash());
+    }
+
+    #[test]
+    fn test_scale_time() {
+        use crate::animation::Eval;
+
+        struct Shift(CameraFrame);
+        impl Eval<CameraFrame> for Shift {
+            fn eval_alpha(&self, alpha: f64) -> CameraFrame {
+                let mut cam = self.0.clone();
+                cam.pos += DVec3::X * alpha;
+                cam
+            }
+        }
+
+        let build = || {
+            let mut r = RanimScene::new();
+            let r_a = r.insert(CameraFrame::default());
+            let r_b = r.insert(CameraFrame::default());
+            r.timeline_mut(r_a)
+                .play_with(|cam| Shift(cam).into_animation_cell().with_duration(2.0));
+            r.timelines_mut().sync();
+            r.timeline_mut(r_b)
+                .play_with(|cam| Shift(cam).into_animation_cell().with_duration(1.0));
+            r.timelines_mut().sync();
+            r.timeline_mut(r_a).forward(0.5);
+            r.insert_time_mark(1.0, TimeMark::Capture("a.png".to_string()));
+            r.insert_callback(2.0, |_| {});
+            r
+        };
+        let original = build().seal();
+        let mut sped = build();
+        sped.scale_time(0.5);
+        let sped = sped.seal();
+        let mut sealed_sped = build().seal();
+        sealed_sped.scale_time(0.5);
+
+        for r in [&sped, &sealed_sped] {
+            assert_eq!(r.total_secs(), original.total_secs() / 2.0);
+            assert_eq!(r.time_marks()[0].0, 0.5);
+            assert_eq!(r.callbacks()[0].0, 1.0);
+            for i in 0..=7 {
+                let t = i as f64 * 0.25;
+                assert_eq!(
+                    r.eval_at_sec(t).collect::<Vec<_>>(),
+                    original.eval_at_sec(2.0 * t).collect::<Vec<_>>(),
+                    "at {t}s"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_ghosts() {
+        use crate::{animation::Eval, core_item::vitem::VItem};
+
+        struct Slide(VItem);
+        impl Eval<VItem> for Slide {
+            fn eval_alpha(&self, alpha: f64) -> VItem {
+                let mut item = self.0.clone();
+                item.points.iter_mut().for_each(|p| p.x += alpha as f32);
+                item
+            }
+        }
+
+        let build = |ghosts: bool| {
+            let mut r = RanimScene::new();
+            let r_cam = r.insert(CameraFrame::default());
+            let r_vitem = r.insert(VItem::default());
+            r.timeline_mut(r_vitem)
+                .play_with(|item| Slide(item).into_animation_cell().with_ghosts(0.25));
+            r.timelines_mut().sync();
+            r.timeline_mut(r_cam).forward(0.5);
+            let mut r = r.seal();
+            r.set_ghosts(ghosts);
+            (r, r_vitem)
+        };
+        let (plain, _) = build(false);
+        let (ghosted, r_vitem) = build(true);
+
+        // Off by default, and the same as without ghosts when no anim is playing
+        let mut r = RanimScene::new();
+        r.insert(CameraFrame::default());
+        assert!(!r.seal().ghosts);
+        for sec in [0.0, 1.0, 1.5] {
+            assert_eq!(
+                ghosted.eval_at_sec(sec).collect::<Vec<_>>(),
+                plain.eval_at_sec(sec).collect::<Vec<_>>(),
+                "at {sec}s"
+            );
+        }
+
+        // The ghosts are below the item while playing
+        let items = ghosted
+            .eval_at_sec(0.5)
+            .filter(|((id, _), _)| *id == r_vitem.id())
+            .map(|(_, item)| item)
+            .collect::<Vec<_>>();
+        assert_eq!(items.len(), 3);
+        let plain_items = plain.eval_at_sec(0.5).collect::<Vec<_>>();
+        assert_eq!(plain_items.len(), 2);
+        assert_eq!(items[2], plain_items[1].1);
+    }
+}
@@ -1,4 +1,4 @@
-use std::cell::RefCell;
+use std::{borrow::Cow, cell::RefCell};
 
 use crate::{
     animation::{AnimationCell, CoreItemAnimation},
@@ -112,33 +112,88 @@ impl CoreItemStore {
         Self::default()
     }
 
-    /// Update the inner store with the given iterator
-    pub fn update(&mut self, items: impl Iterator<Item = ((usize, usize), CoreItem)>) {
-        self.camera_frame_ids.clear();
-        self.camera_frames.clear();
-
-        self.vitem_ids.clear();
-        self.vitems.clear();
+    /// The [`CameraFrame`]s, or a default [`CameraFrame`] if there is none.
+    ///
+    /// There is no camera frame when the camera is not shown at the evaluated sec, e.g. it is
+    /// inserted later or hidden, the default one is used so that the store can still be rendered.
+    pub fn camera_frames_or_default(&self) -> Cow<'_, [CameraFrame]> {
+        if self.camera_frames.is_empty() {
+            Cow::Owned(vec![CameraFrame::default()])
+        } else {
+            Cow::Borrowed(&self.camera_frames)
+        }
+    }
 
-        self.mesh_item_ids.clear();
-        self.mesh_items.clear();
+    /// Update the inner store with the given iterator, returns whether the store is changed.
+    ///
+    /// This is a full replace, the items not in `items` are removed from the store.
+    /// But the items are compared with the previous ones with the same position and id,
+    /// only the changed ones are written, so when this returns `false`, the downstream
+    /// processing (like re-rendering) of the store can be skipped.
+    pub fn update(&mut self, items: impl Iterator<Item = ((usize, usize), CoreItem)>) -> bool {
+        let mut changed = false;
+        let (mut camera_frame_cnt, mut vitem_cnt, mut mesh_item_cnt) = (0, 0, 0);
         for (id, item) in items {
-            match item {
+            changed |= match item {
                 CoreItem::CameraFrame(x) => {
-                    self.camera_frame_ids.push(id);
-                    self.camera_frames.push(x);
+                    camera_frame_cnt += 1;
+                    update_slot(
+                        &mut self.camera_frame_ids,
+                        &mut self.camera_frames,
+                        camera_frame_cnt - 1,
+                        id,
+                        x,
+                    )
                 }
                 CoreItem::VItem(x) => {
-                    self.vitem_ids.push(id);
-                    self.vitems.push(x);
+                    vitem_cnt += 1;
+                    update_slot(&mut self.vitem_ids, &mut self.vitems, vitem_cnt - 1, id, x)
                 }
                 CoreItem::MeshItem(x) => {
-                    self.mesh_item_ids.push(id);
-                    self.mesh_items.push(x);
+                    mesh_item_cnt += 1;
+                    update_slot(
+                        &mut self.mesh_item_ids,
+                        &mut self.mesh_items,
+                        mesh_item_cnt - 1,
+                        id,
+                        x,
+                    )
                 }
-            }
+            };
+        }
+
+        changed |= camera_frame_cnt != self.camera_frames.len()
+            || vitem_cnt != self.vitems.len()
+            || mesh_item_cnt != self.mesh_items.len();
+        self.camera_frame_ids.truncate(camera_frame_cnt);
+        self.camera_frames.truncate(camera_frame_cnt);
+        self.vitem_ids.truncate(vitem_cnt);
+        self.vitems.truncate(vitem_cnt);
+        self.mesh_item_ids.truncate(mesh_item_cnt);
+        self.mesh_items.truncate(mesh_item_cnt);
+        changed
+    }
+}
+
+/// Write the item to `idx` if it differs from the stored one, returns whether it is written.
+fn update_slot<T: PartialEq>(
+    ids: &mut Vec<(usize, usize)>,
+    items: &mut Vec<T>,
+    idx: usize,
+    id: (usize, usize),
+    item: T,
+) -> bool {
+    if idx < items.len() {
+        if ids[idx] == id && items[idx] == item {
+            return false;
         }
+        ids[idx] = id;
+        items[idx] = item;
+    } else {
+        ids.push(id);
+        items.push(item);
     }
+    true
 }
 
 #[cfg(test)]
@@ -169,4 +224,58 @@ mod tests {
         );
         drop(store);
     }
+
+    #[test]
+    fn test_core_item_store_update() {
+        let vitem = |x: f32| {
+            CoreItem::VItem(VItem {
+                points: vec![glam::vec4(x, 0.0, 0.0, 0.0)],
+                ..Default::default()
+            })
+        };
+        let mut store = CoreItemStore::new();
+        assert!(!store.update(std::iter::empty()));
+
+        let items = || {
+            [
+                ((0, 0), CoreItem::CameraFrame(CameraFrame::default())),
+                ((1, 0), vitem(0.0)),
+                ((2, 0), vitem(1.0)),
+            ]
+            .into_iter()
+        };
+        assert!(store.update(items()));
+        assert!(!store.update(items()));
+        assert_eq!(store.vitems.len(), 2);
+
+        // Changed item
+        assert!(store.update(items().map(|(id, x)| if id == (2, 0) {
+            (id, vitem(2.0))
+        } else {
+            (id, x)
+        })));
+        assert_eq!(
+            store.vitems[1],
+            VItem {
+                points: vec![glam::vec4(2.0, 0.0, 0.0, 0.0)],
+                ..Default::default()
+            }
+        );
+        // Changed id
+        assert!(store.update(items().map(|(id, x)| if id == (2, 0) {
+            ((2, 1), x)
+        } else {
+            (id, x)
+        })));
+        assert_eq!(store.vitem_ids, vec![(1, 0), (2, 1)]);
+        // Removed item
+        assert!(store.update(items().take(2)));
+        assert_eq!(store.vitems.len(), 1);
+        assert!(store.update(std::iter::empty()));
+        assert!(store.camera_frames.is_empty());
+        assert_eq!(
+            store.camera_frames_or_default().as_ref(),
+            [CameraFrame::default()]
+        );
+    }
 }
@@ -5,6 +5,7 @@ use crate::{
     animation::{AnimationCell, CoreItemAnimation, Eval, Static},
     core_item::{AnyExtractCoreItem, CoreItem, DynItem},
 };
+use tracing::warn;
 
 // ANCHOR: Timeline
 /// A timeline for a animations.
@@ -16,6 +17,10 @@ pub struct Timeline {
     /// The start time of the planning static anim.
     /// When it is some, it means that it is showing and has a planning static anim.
     planning_static_start_sec: Option<f64>,
+    /// The index of the last anim played with [`Timeline::play`] in `anims`.
+    last_played_idx: Option<usize>,
+    /// The indices of the static anims submitted by the timeline in `anims`, in order.
+    static_idxs: Vec<usize>,
 }
 // ANCHOR_END: Timeline
 
@@ -53,12 +58,27 @@ impl Timeline {
         }
         self
     }
+    /// Scale the time of the timeline by `factor`, i.e. the start secs and the durations of
+    /// the anims, and the current sec are multiplied by `factor`.
+    pub fn scale_time(&mut self, factor: f64) -> &mut Self {
+        for anim in &mut self.anims {
+            let info = anim.anim_info_mut();
+            info.start_sec *= factor;
+            info.duration_secs *= factor;
+        }
+        self.cur_sec *= factor;
+        if let Some(start) = &mut self.planning_static_start_sec {
+            *start *= factor;
+        }
+        self
+    }
     fn _submit_planning_static_anim(&mut self) -> bool {
         // println!("{:?}", self.planning_static_start_sec);
         if let (Some(start), Some(last_anim)) =
             (self.planning_static_start_sec.take(), self.anims.last())
         {
             let state = last_anim.eval_alpha_dyn(1.0);
+            self.static_idxs.push(self.anims.len());
             self.anims.push(Box::new(
                 Static(state)
                     .into_animation_cell()
@@ -70,10 +90,146 @@ impl Timeline {
         }
         false
     }
-    // /// Plays an anim with `anim_func`.
-    // pub fn play_with(&mut self, anim_func: impl FnOnce(T) -> AnimationCell<T>) -> &mut Self {
-    //     self.play(anim_func(self.state.clone()))
-    // }
+    /// Merge the adjacent static anims submitted by the timeline into one, so that
+    /// evaluating has less anims to scan.
+    ///
+    /// A submitted static anim holds the end state of the anim before it, so the static anims
+    /// that directly follow each other (contiguous and with the same enabled state) hold the
+    /// same state, and merging them doesn't change the evaluation result.
+    fn compress(&mut self) {
+        if self.static_idxs.len() < 2 {
+            return;
+        }
+        let anims = std::mem::take(&mut self.anims);
+        let mut static_idxs: Vec<usize> = Vec::with_capacity(self.static_idxs.len());
+        let mut idx_map = Vec::with_capacity(anims.len());
+        for (idx, anim) in anims.into_iter().enumerate() {
+            let is_static = self.static_idxs.binary_search(&idx).is_ok();
+            if is_static
+                && let Some(&last_idx) = static_idxs.last()
+                && last_idx + 1 == self.anims.len()
+            {
+                let (last, info) = (self.anims[last_idx].anim_info(), anim.anim_info());
+                let (start, end) = (last.range().start, info.range().end);
+                if last.enabled == info.enabled && (last.range().end - info.start_sec).abs() < 1e-9
+                {
+                    let merged = Static(self.anims[last_idx].eval_alpha_dyn(1.0))
+                        .into_animation_cell()
+                        .at(start)
+                        .with_duration(end - start)
+                        .with_enabled(info.enabled);
+                    self.anims[last_idx] = Box::new(merged);
+                    idx_map.push(last_idx);
+                    continue;
+                }
+            }
+            if is_static {
+                static_idxs.push(self.anims.len());
+            }
+            idx_map.push(self.anims.len());
+            self.anims.push(anim);
+        }
+        self.static_idxs = static_idxs;
+        self.last_played_idx = self.last_played_idx.map(|idx| idx_map[idx]);
+    }
+    /// Evaluate the anim at `idx` at `alpha` and downcast it to `T`
+    fn state_of<T: AnyExtractCoreItem>(&self, idx: usize, alpha: f64) -> T {
+        downcast_item(self.anims[idx].eval_alpha_dyn(alpha))
+    }
+    /// The state of the item at `sec`, or `None` if the item is not shown at `sec`.
+    ///
+    /// It can be called while constructing, when the item is showing after the last played
+    /// anim, the state at the secs after it is the end state of the anim.
+    /// This is useful to read a value animated on another timeline, like a `ValueTracker`.
+    ///
+    /// # Panics
+    /// Panics if the item is not a `T`.
+    pub fn state_at<T: AnyExtractCoreItem>(&self, sec: f64) -> Option<T> {
+        self.dyn_state_at(sec).map(downcast_item)
+    }
+    /// The type erased state of the item at `sec`, see [`Timeline::state_at`].
+    pub fn dyn_state_at(&self, sec: f64) -> Option<DynItem> {
+        if let (Some(end), Some(_)) = (self.end_sec(), self.planning_static_start_sec)
+            && sec >= end
+        {
+            // The planning static anim holds the end state of the last anim
+            return Some(self.anims[self.anims.len() - 1].eval_alpha_dyn(1.0));
+        }
+        self.eval_at_sec(sec).map(|(_, item)| item)
+    }
+    /// The current state of the item, i.e. the end of the last played anim.
+    ///
+    /// # Panics
+    /// Panics if nothing is played on the timeline yet, or the item is not a `T`.
+    pub fn cur_state<T: AnyExtractCoreItem>(&self) -> T {
+        let idx = self
+            .last_played_idx
+            .expect("nothing is played on the timeline yet");
+        self.state_of::<T>(idx, 1.0)
+    }
+    /// Plays an anim created by `anim_func` with the current state of the item.
+    ///
+    /// # Panics
+    /// Panics if nothing is played on the timeline yet, or the item is not a `T`.
+    pub fn play_with<T: AnyExtractCoreItem>(
+        &mut self,
+        anim_func: impl FnOnce(T) -> AnimationCell<T>,
+    ) -> &mut Self {
+        self.play_with_prev(|_, cur| anim_func(cur))
+    }
+    /// Plays an anim created by `anim_func` with the state of the item at the start of the
+    /// previous anim and the current state of the item, i.e. `anim_func(prev, cur)`.
+    ///
+    /// This is useful for relative anims, like repeating the last movement.
+    /// The previous anim is the last anim played with [`Timeline::play`], for a timeline created
+    /// with [`crate::RanimScene::insert`] it is the `show` anim at the start, so `prev` equals `cur`.
+    ///
+    /// ```
+    /// use ranim_core::{
+    ///     RanimScene, animation::{Eval, StaticAnim}, glam::DVec3, prelude::*,
+    /// };
+    ///
+    /// struct MoveTo(CameraFrame, CameraFrame);
+    /// impl Eval<CameraFrame> for MoveTo {
+    ///     fn eval_alpha(&self, alpha: f64) -> CameraFrame {
+    ///         self.0.lerp(&self.1, alpha)
+    ///     }
+    /// }
+    ///
+    /// let mut r = RanimScene::new();
+    /// let r_cam = r.insert(CameraFrame::default());
+    /// r.timeline_mut(r_cam)
+    ///     .play_with(|cam: CameraFrame| {
+    ///         let mut dst = cam.clone();
+    ///         dst.pos += DVec3::X * 2.0;
+    ///         MoveTo(cam, dst).into_animation_cell()
+    ///     })
+    ///     // Move a further 2 units, without knowing where it started and ended
+    ///     .play_with_prev(|prev: CameraFrame, cur: CameraFrame| {
+    ///         let mut dst = cur.clone();
+    ///         dst.pos += cur.pos - prev.pos;
+    ///         MoveTo(cur, dst).into_animation_cell()
+    ///     })
+    ///     .play_with(|cam: CameraFrame| {
+    ///         assert_eq!(cam.pos, CameraFrame::default().pos + DVec3::X * 4.0);
+    ///         cam.show()
+    ///     });
+    /// assert_eq!(r.timeline(r_cam).cur_sec(), 2.0);
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if nothing is played on the timeline yet, or the item is not a `T`.
+    pub fn play_with_prev<T: AnyExtractCoreItem>(
+        &mut self,
+        anim_func: impl FnOnce(T, T) -> AnimationCell<T>,
+    ) -> &mut Self {
+        let idx = self
+            .last_played_idx
+            .expect("nothing is played on the timeline yet");
+        let prev = self.state_of::<T>(idx, 0.0);
+        let cur = self.state_of::<T>(idx, 1.0);
+        self.play(anim_func(prev, cur))
+    }
     /// Plays an anim.
     pub fn play<T: AnyExtractCoreItem>(&mut self, anim: AnimationCell<T>) -> &mut Self {
         self._submit_planning_static_anim();
@@ -81,11 +237,56 @@ impl Timeline {
         let duration = anim.info.duration_secs;
         self.anims
             .push(Box::new(anim.at(self.cur_sec).with_duration(duration)));
+        self.last_played_idx = Some(self.anims.len() - 1);
         self.cur_sec += duration;
         // self.update(res);
         self.show();
         self
     }
+    /// Plays an anim starting at the absolute `sec`, instead of the current sec.
+    ///
+    /// The timeline is forwarded to `sec` before playing (see [`Timeline::forward_to`]),
+    /// so if the item is showing it keeps its state until `sec`. After this the current sec
+    /// of the timeline is the end of the anim, so a following [`TimelinesFunc::sync`] forwards
+    /// the other timelines to it.
+    ///
+    /// The anims of a timeline cannot overlap, so if `sec` is earlier than the current sec,
+    /// a warning is emitted and the anim is played at the current sec.
+    pub fn play_at<T: AnyExtractCoreItem>(
+        &mut self,
+        anim: AnimationCell<T>,
+        sec: f64,
+    ) -> &mut Self {
+        if sec < self.cur_sec {
+            warn!(
+                "play_at {sec}s is earlier than the current sec {}s of the timeline, playing at the current sec",
+                self.cur_sec
+            );
+        }
+        self.forward_to(sec);
+        self.play(anim)
+    }
+    /// Plays the anims recorded in an [`AnimationTemplate`] one after another,
+    /// each created with the current state of the item, see [`Timeline::play_with`].
+    ///
+    /// # Panics
+    /// Panics if nothing is played on the timeline yet, or the item is not a `T`.
+    pub fn play_template<T: AnyExtractCoreItem>(
+        &mut self,
+        template: &AnimationTemplate<T>,
+    ) -> &mut Self {
+        for step in &template.steps {
+            match step {
+                TemplateStep::Play(anim_func) => {
+                    self.play_with(anim_func);
+                }
+                TemplateStep::Forward(secs) => {
+                    self.forward(*secs);
+                }
+            }
+        }
+        self
+    }
     /// Evaluate the state at `alpha`
     pub fn eval_at_alpha(&self, alpha: f64) -> Option<(usize, DynItem)> {
         let (Some(start), Some(end)) = (self.start_sec(), self.end_sec()) else {
@@ -121,6 +322,91 @@ impl Timeline {
                 }
             })
     }
+    /// Evaluate the ghosts of the `idx`-th anim at `target_sec`, see [`AnimationCell::with_ghosts`].
+    pub fn eval_ghosts_at_sec(&self, idx: usize, target_sec: f64) -> Vec<CoreItem> {
+        let anim = &self.anims[idx];
+        anim.anim_info()
+            .map_sec_to_alpha(target_sec)
+            .map(|alpha| anim.eval_alpha_ghosts(alpha))
+            .unwrap_or_default()
+    }
+}
+
+/// Downcast the item to `T`
+///
+/// The static anims submitted by the timeline hold a [`DynItem`], which is unwrapped first.
+fn downcast_item<T: AnyExtractCoreItem>(item: DynItem) -> T {
+    let mut item: Box<dyn Any> = item.0;
+    while let Some(inner) = item.downcast_ref::<DynItem>() {
+        item = inner.0.clone();
+    }
+    *item.downcast::<T>().unwrap_or_else(|_| {
+        panic!(
+            "the item of the timeline is not a {}",
+            std::any::type_name::<T>()
+        )
+    })
+}
+
+// MARK: AnimationTemplate
+enum TemplateStep<T> {
+    Play(Box<dyn Fn(T) -> AnimationCell<T>>),
+    Forward(f64),
+}
+
+/// A recorded sequence of anims, which can be played on any timeline of a `T`
+/// with [`Timeline::play_template`].
+///
+/// Each anim is recorded as a function of the item's state, and it is bound to the
+/// current state of the target item when played, so one template works for many items:
+///
+/// ```rust,ignore
+/// let highlight_swap = AnimationTemplate::new()
+///     .then(|item: VItem| item.morph(|x| x.set_color(manim::RED_C).discard()))
+///     .then(|item: VItem| item.morph(|x| x.shift(DVec3::X).discard()))
+///     .then_forward(0.5)
+///     .then(|item: VItem| item.morph(|x| x.set_color(manim::WHITE).discard()));
+/// r.timeline_mut(r_a).play_template(&highlight_swap);
+/// r.timeline_mut(r_b).play_template(&highlight_swap);
+/// ```
+pub struct AnimationTemplate<T> {
+    steps: Vec<TemplateStep<T>>,
+}
+
+impl<T> Default for AnimationTemplate<T> {
+    fn default() -> Self {
+        Self { steps: Vec::new() }
+    }
+}
+
+impl<T> AnimationTemplate<T> {
+    /// Create an empty template
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Record an anim created by `anim_func` with the state of the item at this step
+    pub fn then(mut self, anim_func: impl Fn(T) -> AnimationCell<T> + 'static) -> Self {
+        self.steps.push(TemplateStep::Play(Box::new(anim_func)));
+        self
+    }
+    /// Record a forward of `secs`, see [`Timeline::forward`]
+    pub fn then_forward(mut self, secs: f64) -> Self {
+        self.steps.push(TemplateStep::Forward(secs));
+        self
+    }
+    /// Append the steps of another template
+    pub fn extend(mut self, other: Self) -> Self {
+        self.steps.extend(other.steps);
+        self
+    }
+    /// The count of the recorded steps
+    pub fn len(&self) -> usize {
+        self.steps.len()
+    }
+    /// Whether nothing is recorded
+    pub fn is_empty(&self) -> bool {
+        self.steps.is_empty()
+    }
 }
 
 impl TimelineFunc for Timeline {
@@ -132,6 +418,7 @@ impl TimelineFunc for Timeline {
     }
     fn seal(&mut self) {
         self._submit_planning_static_anim();
+        self.compress();
     }
     fn cur_sec(&self) -> f64 {
         self.cur_sec
@@ -263,3 +550,63 @@ pub struct AnimationInfo {
     /// The time range of the animation
     pub range: std::ops::Range<f64>,
 }
+
+#[cfg(test)]
+mod tests {
+    use glam::DVec3;
+
+    use super::*;
+    use crate::{animation::StaticAnim, core_item::camera_frame::CameraFrame};
+
+    struct Shift(CameraFrame);
+    impl Eval<CameraFrame> for Shift {
+        fn eval_alpha(&self, alpha: f64) -> CameraFrame {
+            let mut frame = self.0.clone();
+            frame.pos += DVec3::X * alpha;
+            frame
+        }
+    }
+
+    #[test]
+    fn test_compress() {
+        let build = || {
+            let mut t = Timeline::new();
+            t.play(CameraFrame::default().show());
+            for _ in 0..4 {
+                t.forward(0.3).hide().show();
+            }
+            t.play(Shift(CameraFrame::default()).into_animation_cell());
+            for _ in 0..3 {
+                t.forward(0.2).hide().show();
+            }
+            t.hide()
+                .forward(0.5)
+                .show()
+                .forward(0.5)
+                .hide()
+                .show()
+                .forward(0.1);
+            t
+        };
+
+        let mut uncompressed = build();
+        uncompressed._submit_planning_static_anim();
+        let mut compressed = build();
+        compressed.seal();
+        // show, static, shift, static, static
+        assert_eq!(uncompressed.anims.len(), 13);
+        assert_eq!(compressed.anims.len(), 5);
+        assert_eq!(compressed.range_sec(), uncompressed.range_sec());
+        assert_eq!(compressed.last_played_idx, Some(2));
+
+        let end = compressed.end_sec().unwrap();
+        for i in 0..=100 {
+            let sec = end * i as f64 / 100.0;
+            let (a, b) = (
+                compressed.eval_primitives_at_sec(sec).map(|(_, x)| x),
+                uncompressed.eval_primitives_at_sec(sec).map(|(_, x)| x),
+            );
+            assert_eq!(a, b, "at {sec}s");
+        }
+    }
+}
@@ -12,7 +12,7 @@ pub use transform::*;
 
 pub use crate::anchor::{Aabb, AabbPoint, Locate};
 
-use std::ops::Range;
+use std::{marker::PhantomData, ops::Range};
 
 use color::{AlphaColor, ColorSpace, OpaqueColor, Srgb};
 use glam::{
@@ -66,6 +66,29 @@ pub trait Discard {
 
 impl<T> Discard for T {}
 
+// MARK: Become
+/// A trait for instantly replacing a value with another one, without animation.
+///
+/// This trait is automatically implemented for `T: Clone`, so groups (`Vec<T>`) can also use it.
+/// Since the whole value is replaced (including all of its components),
+/// the result is always consistent, even if `other` has a different subpath count.
+///
+/// # Example
+/// ```ignore
+/// let mut square = VItem::from(Square::new(1.0));
+/// square.become_(&VItem::from(Circle::new(1.0)));
+/// r.timeline_mut(r_square).play(square.morph_to(triangle));
+/// ```
+pub trait Become: Clone {
+    /// Replace `self` with a clone of `other`
+    fn become_(&mut self, other: &Self) -> &mut Self {
+        self.clone_from(other);
+        self
+    }
+}
+
+impl<T: Clone> Become for T {}
+
 // MARK: Interpolatable
 /// A trait for interpolating to values
 ///
@@ -197,26 +220,40 @@ impl<T: Opacity + Alignable + Clone> Alignable for Vec<T> {
         self.len() == other.len() && self.iter().zip(other).all(|(a, b)| a.is_aligned(b))
     }
     fn align_with(&mut self, other: &mut Self) {
-        let len = self.len().max(other.len());
+        align_vec_len(self, other);
+        self.iter_mut()
+            .zip(other)
+            .for_each(|(a, b)| a.align_with(b));
+    }
+}
 
-        let transparent_repeated = |items: &mut Vec<T>, repeat_idxs: Vec<usize>| {
-            for idx in repeat_idxs {
-                items[idx].set_opacity(0.0);
-            }
-        };
-        if self.len() != len {
-            let (mut items, idxs) = resize_preserving_order_with_repeated_indices(self, len);
-            transparent_repeated(&mut items, idxs);
-            *self = items;
-        }
-        if other.len() != len {
-            let (mut items, idxs) = resize_preserving_order_with_repeated_indices(other, len);
-            transparent_repeated(&mut items, idxs);
-            *other = items;
+/// Resize two vecs to the same length, the repeated items are transparent.
+fn align_vec_len<T: Opacity + Clone>(a: &mut Vec<T>, b: &mut Vec<T>) {
+    let len = a.len().max(b.len());
+
+    let transparent_repeated = |items: &mut Vec<T>, repeat_idxs: Vec<usize>| {
+        for idx in repeat_idxs {
+            items[idx].set_opacity(0.0);
         }
+    };
+    if a.len() != len {
+        let (mut items, idxs) = resize_preserving_order_with_repeated_indices(a, len);
+        transparent_repeated(&mut items, idxs);
+        *a = items;
+    }
+    if b.len() != len {
+        let (mut items, idxs) = resize_preserving_order_with_repeated_indices(b, len);
+        transparent_repeated(&mut items, idxs);
+        *b = items;
+    }
+}
+
+impl<T: Opacity + ArcLengthAlignable + Clone> ArcLengthAlignable for Vec<T> {
+    fn align_with_by_arc_length(&mut self, other: &mut Self) {
+        align_vec_len(self, other);
         self.iter_mut()
             .zip(other)
-            .for_each(|(a, b)| a.align_with(b));
+            .for_each(|(a, b)| a.align_with_by_arc_length(b));
     }
 }
 
@@ -234,6 +271,23 @@ pub trait Alignable: Clone {
     fn align_with(&mut self, other: &mut Self);
 }
 
+/// A trait for aligning items by arc length.
+///
+/// [`Alignable::align_with`] matches the points of two items by their indices, which makes
+/// the points bunch during the interpolation if the point densities of the two items
+/// differ a lot along their paths. Aligning by arc length matches the points at the same
+/// fraction of the path length instead.
+///
+/// The default implementation is [`Alignable::align_with`], for items without paths.
+pub trait ArcLengthAlignable: Alignable {
+    /// Aligning two items by arc length
+    fn align_with_by_arc_length(&mut self, other: &mut Self) {
+        self.align_with(other);
+    }
+}
+
+impl ArcLengthAlignable for DVec3 {}
+
 impl Alignable for DVec3 {
     fn align_with(&mut self, _other: &mut Self) {}
     fn is_aligned(&self, _other: &Self) -> bool {
@@ -269,6 +323,43 @@ pub trait Partial {
     fn get_partial_closed(&self, range: Range<f64>) -> Self;
 }
 
+/// A group is drawn child by child, each child takes an equal share of the range in order.
+///
+/// The children out of the range are dropped, and the ones fully in the range are kept as is.
+impl<T: Partial + Clone> Partial for Vec<T> {
+    fn get_partial(&self, range: Range<f64>) -> Self {
+        partial_children(self, range, T::get_partial)
+    }
+    fn get_partial_closed(&self, range: Range<f64>) -> Self {
+        partial_children(self, range, T::get_partial_closed)
+    }
+}
+
+/// Get the part of each child of `items` in `range`, child `i` spans `i / n..(i + 1) / n`.
+fn partial_children<T: Clone>(
+    items: &[T],
+    range: Range<f64>,
+    get_partial: impl Fn(&T, Range<f64>) -> T,
+) -> Vec<T> {
+    let n = items.len() as f64;
+    items
+        .iter()
+        .enumerate()
+        .filter_map(|(i, item)| {
+            let (start, end) = (i as f64 / n, (i + 1) as f64 / n);
+            if range.end <= start || range.start >= end {
+                return None;
+            }
+            let local = ((range.start - start) * n).max(0.0)..((range.end - start) * n).min(1.0);
+            Some(if local == (0.0..1.0) {
+                item.clone()
+            } else {
+                get_partial(item, local)
+            })
+        })
+        .collect()
+}
+
 // MARK: Empty
 /// A trait for items that can be empty
 pub trait Empty {
@@ -276,6 +367,12 @@ pub trait Empty {
     fn empty() -> Self;
 }
 
+impl<T> Empty for Vec<T> {
+    fn empty() -> Self {
+        Vec::new()
+    }
+}
+
 // MARK: FillColor
 /// A trait for items that have fill color
 pub trait FillColor {
@@ -303,6 +400,20 @@ impl<T: FillColor> FillColor for [T] {
     }
 }
 
+impl<T: FillColor> FillColor for Vec<T> {
+    fn fill_color(&self) -> AlphaColor<Srgb> {
+        self.as_slice().fill_color()
+    }
+    fn set_fill_color(&mut self, color: AlphaColor<Srgb>) -> &mut Self {
+        self.as_mut_slice().set_fill_color(color);
+        self
+    }
+    fn set_fill_opacity(&mut self, opacity: f32) -> &mut Self {
+        self.as_mut_slice().set_fill_opacity(opacity);
+        self
+    }
+}
+
 // MARK: StrokeColor
 /// A trait for items that have stroke color
 pub trait StrokeColor {
@@ -332,6 +443,20 @@ impl<T: StrokeColor> StrokeColor for [T] {
     }
 }
 
+impl<T: StrokeColor> StrokeColor for Vec<T> {
+    fn stroke_color(&self) -> AlphaColor<Srgb> {
+        self.as_slice().stroke_color()
+    }
+    fn set_stroke_color(&mut self, color: AlphaColor<Srgb>) -> &mut Self {
+        self.as_mut_slice().set_stroke_color(color);
+        self
+    }
+    fn set_stroke_opacity(&mut self, opacity: f32) -> &mut Self {
+        self.as_mut_slice().set_stroke_opacity(opacity);
+        self
+    }
+}
+
 // MARK: StrokeWidth
 /// A trait for items have stroke width
 pub trait StrokeWidth {
@@ -344,6 +469,16 @@ pub trait StrokeWidth {
     fn set_stroke_width(&mut self, width: f32) -> &mut Self {
         self.apply_stroke_func(|widths| widths.fill(width.into()))
     }
+    /// Clamping the stroke width of an item to be at least `min`
+    ///
+    /// Useful after scaling an item down with its stroke, so that the stroke stays visible.
+    fn clamp_min_stroke_width(&mut self, min: f32) -> &mut Self {
+        self.apply_stroke_func(|widths| Width::clamp_min(widths, min))
+    }
+    /// Tapering the stroke width of an item from `start` to `end` along its path
+    fn set_stroke_width_taper(&mut self, start: f32, end: f32) -> &mut Self {
+        self.apply_stroke_func(|widths| Width::taper(widths, start, end))
+    }
 }
 
 impl<T: StrokeWidth> StrokeWidth for [T] {
@@ -361,6 +496,16 @@ impl<T: StrokeWidth> StrokeWidth for [T] {
     }
 }
 
+impl<T: StrokeWidth> StrokeWidth for Vec<T> {
+    fn stroke_width(&self) -> f32 {
+        self.as_slice().stroke_width()
+    }
+    fn apply_stroke_func(&mut self, f: impl for<'a> Fn(&'a mut [Width])) -> &mut Self {
+        self.as_mut_slice().apply_stroke_func(f);
+        self
+    }
+}
+
 // MARK: Color
 /// A trait for items that have both fill color and stroke color
 ///
@@ -372,6 +517,19 @@ pub trait Color: FillColor + StrokeColor {
         self.set_stroke_color(color);
         self
     }
+    /// Setting the fill opacity and the stroke opacity of an item in one call
+    ///
+    /// Unlike [`Opacity::set_opacity`], which sets both to the same value, this is useful for
+    /// translucent fills with solid outlines:
+    ///
+    /// ```rust,ignore
+    /// square.set_color(manim::BLUE_C).set_opacities(0.5, 1.0);
+    /// ```
+    fn set_opacities(&mut self, fill: f32, stroke: f32) -> &mut Self {
+        self.set_fill_opacity(fill);
+        self.set_stroke_opacity(stroke);
+        self
+    }
 }
 
 impl<T: FillColor + StrokeColor + ?Sized> Color for T {}
@@ -398,6 +556,35 @@ pub trait PointsFunc {
         self
     }
 
+    /// Applying a transform matrix to an item, e.g. a shear, a reflection or a combination of them.
+    ///
+    /// The points are transformed as homogeneous coordinates, so a projective matrix also works.
+    fn apply_matrix(&mut self, matrix: DMat4) -> &mut Self {
+        self.apply_point_func(|p| *p = matrix.project_point3(*p));
+        self
+    }
+
+    /// Applying a transform matrix to an item about an anchor.
+    ///
+    /// The anchor is used as the origin of the transform, so it stays in place
+    /// under a linear transform:
+    ///
+    /// ```rust,ignore
+    /// // Shear the square about its bottom left corner
+    /// let shear = DMat4::from_cols_array(&[
+    ///     1.0, 0.0, 0.0, 0.0, //
+    ///     0.5, 1.0, 0.0, 0.0, //
+    ///     0.0, 0.0, 1.0, 0.0, //
+    ///     0.0, 0.0, 0.0, 1.0,
+    /// ]);
+    /// square.apply_matrix_about(shear, AabbPoint(dvec3(-1.0, -1.0, 0.0)));
+    /// ```
+    fn apply_matrix_about(&mut self, matrix: DMat4, anchor: impl Locate<Self>) -> &mut Self {
+        let origin = anchor.locate(self);
+        self.apply_point_func(|p| *p = matrix.project_point3(*p - origin) + origin);
+        self
+    }
+
     /// Applying point function to an item
     fn apply_point_func(&mut self, f: impl Fn(&mut DVec3)) -> &mut Self {
         self.apply_points_func(|points| {
@@ -560,3 +747,248 @@ pub trait ArrangeSlice<T: transform::ShiftTransformExt>: AsMut<[T]> {
 }
 
 impl<T: transform::ShiftTransformExt, E: AsMut<[T]>> ArrangeSlice<T> for E {}
+
+// MARK: Group
+/// Helpers for groups, which are simply `Vec<T>` in ranim.
+///
+/// Since a group is a [`Vec`], all of its methods are still available.
+pub trait GroupExt<T>: Sized {
+    /// Return the inner items of the group.
+    fn ungroup(self) -> Vec<T>;
+    /// Partition the group into two groups, the items that satisfy the `predicate`
+    /// and the others, keeping the order.
+    fn split_by(self, predicate: impl FnMut(&T) -> bool) -> (Vec<T>, Vec<T>);
+    /// Match the items of the group with the items of `other` by the nearest aabb centers.
+    ///
+    /// The closest pairs are matched first, so every item is matched unless the
+    /// counts differ, see [`GroupMatching`].
+    fn match_nearest(&self, other: &[T]) -> GroupMatching
+    where
+        T: Aabb;
+}
+
+/// The result of [`GroupExt::match_nearest`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct GroupMatching {
+    /// The matched `(self index, other index)` pairs, sorted by the other index
+    pub matched: Vec<(usize, usize)>,
+    /// The indices of `self` that have no match, in order
+    pub unmatched_self: Vec<usize>,
+    /// The indices of `other` that have no match, in order
+    pub unmatched_other: Vec<usize>,
+}
+
+impl<T> GroupExt<T> for Vec<T> {
+    fn ungroup(self) -> Vec<T> {
+        self
+    }
+    fn split_by(self, predicate: impl FnMut(&T) -> bool) -> (Vec<T>, Vec<T>) {
+        self.into_iter().partition(predicate)
+    }
+    fn match_nearest(&self, other: &[T]) -> GroupMatching
+    where
+        T: Aabb,
+    {
+        let centers = |items: &[T]| {
+            items
+                .iter()
+                .map(|item| AabbPoint::CENTER.locate(item))
+                .collect::<Vec<_>>()
+        };
+        let (self_centers, other_centers) = (centers(self), centers(other));
+
+        let mut pairs = (0..self.len())
+            .flat_map(|i| (0..other.len()).map(move |j| (i, j)))
+            .map(|(i, j)| (self_centers[i].distance_squared(other_centers[j]), i, j))
+            .collect::<Vec<_>>();
+        pairs.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+        let mut self_matched = vec![false; self.len()];
+        let mut other_matched = vec![false; other.len()];
+        let mut matched = Vec::with_capacity(self.len().min(other.len()));
+        for (_, i, j) in pairs {
+            if !self_matched[i] && !other_matched[j] {
+                self_matched[i] = true;
+                other_matched[j] = true;
+                matched.push((i, j));
+            }
+        }
+        matched.sort_by_key(|&(_, j)| j);
+
+        let unmatched = |flags: Vec<bool>| {
+            flags
+                .into_iter()
+                .enumerate()
+                .filter_map(|(idx, matched)| (!matched).then_some(idx))
+                .collect()
+        };
+        GroupMatching {
+            matched,
+            unmatched_self: unmatched(self_matched),
+            unmatched_other: unmatched(other_matched),
+        }
+    }
+}
+
+/// The nesting depth of a group, see [`FlattenGroup`].
+///
+/// It is inferred by the compiler, so it never needs to be written out.
+pub struct GroupLeaf;
+
+/// The nesting depth of a group of groups of depth `D`, see [`FlattenGroup`].
+pub struct GroupNested<D>(PhantomData<D>);
+
+/// Flatten a nested group of any depth into one group of its leaf items `T`.
+///
+/// The leaf type decides how deep it flattens, so it usually needs an annotation:
+///
+/// ```rust
+/// # use ranim_core::traits::FlattenGroup;
+/// let nested = vec![vec![vec![1, 2], vec![]], vec![vec![3]]];
+/// let flattened: Vec<i32> = nested.flatten();
+/// assert_eq!(flattened, vec![1, 2, 3]);
+/// ```
+///
+/// The [`crate::Extract`] result of the flattened group is the same as the nested one's.
+pub trait FlattenGroup<T, Depth> {
+    /// Flatten the group.
+    fn flatten(self) -> Vec<T>
+    where
+        Self: Sized,
+    {
+        let mut items = Vec::new();
+        self.flatten_into(&mut items);
+        items
+    }
+    /// Push the leaf items of the group into `items`, in order.
+    fn flatten_into(self, items: &mut Vec<T>);
+}
+
+impl<T> FlattenGroup<T, GroupLeaf> for Vec<T> {
+    fn flatten_into(self, items: &mut Vec<T>) {
+        items.extend(self);
+    }
+}
+
+impl<T, G: FlattenGroup<T, D>, D> FlattenGroup<T, GroupNested<D>> for Vec<G> {
+    fn flatten_into(self, items: &mut Vec<T>) {
+        self.into_iter().for_each(|group| group.flatten_into(items));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Extract, core_item::camera_frame::CameraFrame};
+
+    #[test]
+    fn test_group_flatten() {
+        let item = |x: f64| CameraFrame::new().with(|c| c.pos = dvec3(x, 0.0, 0.0));
+        let nested = vec![
+            vec![vec![item(0.0), item(1.0)], vec![]],
+            vec![vec![item(2.0)]],
+        ];
+        let flattened: Vec<CameraFrame> = nested.clone().flatten();
+        assert_eq!(flattened.len(), 3);
+        assert_eq!(flattened.extract(), nested.extract());
+
+        // 4 levels deep, with empty groups at every level
+        let deep = vec![
+            vec![],
+            vec![vec![], vec![vec![item(0.0)], vec![]]],
+            vec![vec![vec![item(1.0), item(2.0)]], vec![vec![item(3.0)]]],
+        ];
+        let deep_flattened: Vec<CameraFrame> = deep.clone().flatten();
+        assert_eq!(
+            deep_flattened,
+            vec![item(0.0), item(1.0), item(2.0), item(3.0)]
+        );
+        assert_eq!(deep_flattened.extract(), deep.extract());
+
+        // Flattening a flat group keeps it as is
+        let flat: Vec<CameraFrame> = deep_flattened.clone().flatten();
+        assert_eq!(flat, deep_flattened);
+
+        let (left, right) = flattened.split_by(|c| c.pos.x < 1.5);
+        assert_eq!(left.ungroup(), vec![item(0.0), item(1.0)]);
+        assert_eq!(right, vec![item(2.0)]);
+    }
+
+    #[test]
+    fn test_group_match_nearest() {
+        let points = |xs: &[f64]| xs.iter().map(|&x| dvec3(x, 0.0, 0.0)).collect::<Vec<_>>();
+
+        // 3 -> 5, two new items appear
+        let src = points(&[0.0, 1.0, 2.0]);
+        let dst = points(&[-1.0, 0.1, 1.1, 2.1, 3.0]);
+        let matching = src.match_nearest(&dst);
+        assert_eq!(matching.matched, vec![(0, 1), (1, 2), (2, 3)]);
+        assert!(matching.unmatched_self.is_empty());
+        assert_eq!(matching.unmatched_other, vec![0, 4]);
+
+        // 5 -> 3, the farthest items disappear
+        let matching = dst.match_nearest(&src);
+        assert_eq!(matching.matched, vec![(1, 0), (2, 1), (3, 2)]);
+        assert_eq!(matching.unmatched_self, vec![0, 4]);
+        assert!(matching.unmatched_other.is_empty());
+    }
+
+    #[test]
+    fn test_apply_matrix() {
+        let points = || {
+            [
+                dvec3(0.0, 0.0, 0.0),
+                dvec3(1.0, 0.0, 0.0),
+                dvec3(1.0, 1.0, 0.0),
+            ]
+        };
+
+        // Shear along x: (x, y) -> (x + 0.5y, y)
+        let shear = DMat4::from_cols(
+            glam::DVec4::X,
+            glam::dvec4(0.5, 1.0, 0.0, 0.0),
+            glam::DVec4::Z,
+            glam::DVec4::W,
+        );
+        let mut sheared = points();
+        sheared.apply_matrix(shear);
+        assert_eq!(
+            sheared,
+            [
+                dvec3(0.0, 0.0, 0.0),
+                dvec3(1.0, 0.0, 0.0),
+                dvec3(1.5, 1.0, 0.0)
+            ]
+        );
+
+        // Reflection about the y axis
+        let reflection = DMat4::from_scale(dvec3(-1.0, 1.0, 1.0));
+        let mut reflected = points();
+        reflected.apply_matrix(reflection);
+        assert_eq!(
+            reflected,
+            [
+                dvec3(0.0, 0.0, 0.0),
+                dvec3(-1.0, 0.0, 0.0),
+                dvec3(-1.0, 1.0, 0.0)
+            ]
+        );
+
+        // Reflection about the line x = 1, which keeps the anchor in place
+        let mut reflected = points();
+        reflected.apply_matrix_about(reflection, dvec3(1.0, 0.0, 0.0));
+        assert_eq!(
+            reflected,
+            [
+                dvec3(2.0, 0.0, 0.0),
+                dvec3(1.0, 0.0, 0.0),
+                dvec3(1.0, 1.0, 0.0)
+            ]
+        );
+
+        // Combined with a translation
+        let mut moved = points();
+        moved.apply_matrix(DMat4::from_translation(DVec3::Y) * shear);
+        assert_eq!(moved[2], dvec3(1.5, 2.0, 0.0));
+    }
+}
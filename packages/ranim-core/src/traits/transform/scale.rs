@@ -2,8 +2,12 @@ use std::cmp::Ordering;
 
 use glam::{DVec3, dvec3};
 use itertools::Itertools;
+use tracing::warn;
 
-use crate::{anchor::Aabb, traits::StrokeWidth};
+use crate::{
+    anchor::Aabb,
+    traits::{ShiftTransform, StrokeWidth},
+};
 
 /// A hint for scaling the mobject.
 #[derive(Debug, Clone, Copy)]
@@ -20,6 +24,55 @@ pub enum ScaleHint {
     PorportionalY(f64),
     /// Scale the mobject's Z axe, while other axes are scaled accordingly.
     PorportionalZ(f64),
+    /// Scale the mobject proportionally, so that the area of its bounding box on the xy plane
+    /// (i.e. `width * height`) is the given value.
+    ///
+    /// Use [`ScaleHint::filled_area`] to measure on the filled area instead.
+    /// Items with a zero bounding box area are left unscaled.
+    Area(f64),
+}
+
+impl ScaleHint {
+    /// A [`ScaleHint::Area`] that scales `item` so that its [`FilledArea::filled_area`] is `area`.
+    ///
+    /// The hint is calculated from the current state of `item`, so it should be used
+    /// for scaling `item` right away:
+    ///
+    /// ```rust,ignore
+    /// item.scale_to(ScaleHint::filled_area(&item, 4.0));
+    /// ```
+    ///
+    /// Items with a zero filled area are left unscaled.
+    pub fn filled_area<T: FilledArea + Aabb + ?Sized>(item: &T, area: f64) -> Self {
+        let size = item.aabb_size();
+        let aabb_area = size.x * size.y;
+        let filled_area = item.filled_area();
+        if filled_area <= f64::EPSILON {
+            warn!("the filled area is zero, the item is left unscaled");
+            return Self::Area(aabb_area);
+        }
+        Self::Area(aabb_area * area / filled_area)
+    }
+}
+
+/// The area of the region filled when rendering, used by [`ScaleHint::filled_area`].
+///
+/// This trait is automatically implemented for `[T]` where `T: FilledArea`, which sums up the areas.
+pub trait FilledArea {
+    /// Get the filled area.
+    fn filled_area(&self) -> f64;
+}
+
+impl<T: FilledArea> FilledArea for [T] {
+    fn filled_area(&self) -> f64 {
+        self.iter().map(FilledArea::filled_area).sum()
+    }
+}
+
+impl<T: FilledArea> FilledArea for Vec<T> {
+    fn filled_area(&self) -> f64 {
+        self.as_slice().filled_area()
+    }
 }
 
 /// Scaling operations.
@@ -72,10 +125,23 @@ pub trait ScaleTransformExt: ScaleTransform {
             ScaleHint::PorportionalX(v) => DVec3::splat(v / aabb_size.x),
             ScaleHint::PorportionalY(v) => DVec3::splat(v / aabb_size.y),
             ScaleHint::PorportionalZ(v) => DVec3::splat(v / aabb_size.z),
+            ScaleHint::Area(v) => {
+                let area = aabb_size.x * aabb_size.y;
+                if area <= f64::EPSILON {
+                    warn!("the bounding box area is zero, the item is left unscaled");
+                    DVec3::ONE
+                } else {
+                    DVec3::splat((v / area).sqrt())
+                }
+            }
         }
     }
     /// Scale the item to a given hint (at origin).
     ///
+    /// The hint is measured on [`Aabb::aabb`], which doesn't include the stroke,
+    /// and the stroke widths are left untouched.
+    /// Use [`ScaleTransformStrokeExt::scale_to_with_stroke`] to scale the stroke along.
+    ///
     /// See [`ScaleHint`] for more details.
     fn scale_to(&mut self, hint: ScaleHint) -> &mut Self
     where
@@ -119,23 +185,29 @@ pub trait ScaleTransformExt: ScaleTransform {
 impl<T: ScaleTransform + ?Sized> ScaleTransformExt for T {}
 
 /// A trait for scaling operations with stroke width.
+///
+/// [`ScaleTransform::scale`] only scales the geometry, the stroke widths are left untouched.
+/// The methods in this trait additionally multiply the stroke widths by the largest scale
+/// factor (the one that deviates the most from `1.0`), so a 2x scaled item also gets a 2x
+/// thick stroke.
+///
+/// Use [`ScaleTransformStrokeExt::with_scale_stroke`] to choose between the two behaviors
+/// with a flag while still using all the methods from [`ScaleTransformExt`].
 pub trait ScaleTransformStrokeExt: ScaleTransform + StrokeWidth {
     /// Scale the item with stroke width (at origin).
     fn scale_with_stroke(&mut self, scale: DVec3) -> &mut Self {
         self.scale(scale);
 
-        let scales = [scale.x, scale.y, scale.z];
-        let idx = scales
-            .iter()
-            .map(|x: &f64| if *x > 1.0 { *x } else { 1.0 / *x })
-            .position_max_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal))
-            .unwrap_or(0);
-        let scale = scales[idx];
+        let scale = stroke_scale_factor(scale);
         self.apply_stroke_func(|widths| widths.iter_mut().for_each(|w| w.0 *= scale as f32));
         self
     }
     /// Scale the item to a given hint with stroke width.
     ///
+    /// Same as [`ScaleTransformExt::scale_to`], the hint is measured on [`Aabb::aabb`],
+    /// which doesn't include the stroke, but the stroke widths are scaled
+    /// like [`ScaleTransformStrokeExt::scale_with_stroke`].
+    ///
     /// See [`ScaleHint`] for more details.
     fn scale_to_with_stroke(&mut self, hint: ScaleHint) -> &mut Self
     where
@@ -144,6 +216,61 @@ pub trait ScaleTransformStrokeExt: ScaleTransform + StrokeWidth {
         let scale = self.calc_scale_ratio(hint);
         self.scale_with_stroke(scale)
     }
+    /// Get a [`ScaleStroke`] view of the item, whose scaling operations
+    /// scale the stroke widths along if `scale_stroke` is `true`.
+    ///
+    /// ```ignore
+    /// item.with_scale_stroke(true).scale_to(ScaleHint::PorportionalY(2.0));
+    /// ```
+    fn with_scale_stroke(&mut self, scale_stroke: bool) -> ScaleStroke<'_, Self> {
+        ScaleStroke {
+            inner: self,
+            scale_stroke,
+        }
+    }
 }
 
 impl<T: ScaleTransform + StrokeWidth + ?Sized> ScaleTransformStrokeExt for T {}
+
+/// The factor applied to stroke widths by [`ScaleTransformStrokeExt::scale_with_stroke`].
+fn stroke_scale_factor(scale: DVec3) -> f64 {
+    let scales = [scale.x, scale.y, scale.z];
+    let idx = scales
+        .iter()
+        .map(|x: &f64| if *x > 1.0 { *x } else { 1.0 / *x })
+        .position_max_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal))
+        .unwrap_or(0);
+    scales[idx]
+}
+
+/// A view of an item with an explicit stroke scaling option.
+///
+/// See [`ScaleTransformStrokeExt::with_scale_stroke`].
+pub struct ScaleStroke<'a, T: ?Sized> {
+    inner: &'a mut T,
+    scale_stroke: bool,
+}
+
+impl<T: ScaleTransform + StrokeWidth + ?Sized> ScaleTransform for ScaleStroke<'_, T> {
+    fn scale(&mut self, scale: DVec3) -> &mut Self {
+        if self.scale_stroke {
+            self.inner.scale_with_stroke(scale);
+        } else {
+            self.inner.scale(scale);
+        }
+        self
+    }
+}
+
+impl<T: ShiftTransform + ?Sized> ShiftTransform for ScaleStroke<'_, T> {
+    fn shift(&mut self, shift: DVec3) -> &mut Self {
+        self.inner.shift(shift);
+        self
+    }
+}
+
+impl<T: Aabb + ?Sized> Aabb for ScaleStroke<'_, T> {
+    fn aabb(&self) -> [DVec3; 2] {
+        self.inner.aabb()
+    }
+}
@@ -80,10 +80,37 @@ pub trait ShiftTransformExt: ShiftTransform {
     where
         AabbPoint: Locate<Self>,
     {
-        let neg_anchor = AabbPoint(-anchor.0);
+        self.move_anchor_to_anchor_of(AabbPoint(-anchor.0), target, anchor, padding)
+    }
+    /// Put `anchor` of self on `target_anchor` of target, with a distance of `padding`
+    /// along the direction of `target_anchor`.
+    ///
+    /// This generalizes [`ShiftTransformExt::move_next_to_padded`], which uses the negative
+    /// of `target_anchor` as `anchor`. For example, to put the left edge of a square on the
+    /// right edge of a circle, with their top edges aligned:
+    ///
+    /// ```rust,ignore
+    /// square.move_anchor_to_anchor_of(
+    ///     AabbPoint(dvec3(-1.0, 1.0, 0.0)),
+    ///     &circle,
+    ///     AabbPoint(dvec3(1.0, 1.0, 0.0)),
+    ///     0.0,
+    /// );
+    /// ```
+    fn move_anchor_to_anchor_of<T: Aabb + ?Sized>(
+        &mut self,
+        anchor: AabbPoint,
+        target: &T,
+        target_anchor: AabbPoint,
+        padding: f64,
+    ) -> &mut Self
+    where
+        AabbPoint: Locate<Self>,
+    {
         self.move_anchor_to(
-            neg_anchor,
-            Locate::<T>::locate(&anchor, target) + anchor.0.normalize() * padding,
+            anchor,
+            Locate::<T>::locate(&target_anchor, target)
+                + target_anchor.0.normalize_or_zero() * padding,
         )
     }
 }
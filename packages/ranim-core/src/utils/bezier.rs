@@ -223,6 +223,16 @@ pub fn quad_bezier_eval(bezier: &[DVec3; 3], t: f64) -> DVec3 {
     p0.lerp(p1, t)
 }
 
+/// Get the arc length of a quad bezier, approximated with a polyline of 16 segments
+pub fn quad_bezier_length(bezier: &[DVec3; 3]) -> f64 {
+    const SEGMENTS: usize = 16;
+    (0..=SEGMENTS)
+        .map(|i| quad_bezier_eval(bezier, i as f64 / SEGMENTS as f64))
+        .tuple_windows()
+        .map(|(a, b)| a.distance(b))
+        .sum()
+}
+
 /// Approx a cubic bezier with quadratic bezier
 ///
 /// [Vec3; 4] is [p1, h1, h2, p2]
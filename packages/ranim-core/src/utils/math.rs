@@ -1,4 +1,4 @@
-use glam::{DVec2, DVec3, IVec2, dvec2};
+use glam::{DQuat, DVec2, DVec3, IVec2, dvec2};
 
 /// Cross product of 2d points
 pub fn cross2d(a: DVec2, b: DVec2) -> f64 {
@@ -6,8 +6,19 @@ pub fn cross2d(a: DVec2, b: DVec2) -> f64 {
 }
 
 /// Get the intersection point of two ray
+///
+/// See [`line_intersection`] for the edge cases.
 pub fn intersection(p1: DVec3, v1: DVec3, p2: DVec3, v2: DVec3) -> Option<DVec3> {
-    // println!("p1: {:?}, v1: {:?}, p2: {:?}, v2: {:?}", p1, v1, p2, v2);
+    line_intersection(p1, v1, p2, v2).map(|(t, _)| p1 + v1 * t)
+}
+
+/// Get the parameters `(t, s)` of the intersection point `p1 + v1 * t == p2 + v2 * s`
+/// of two lines.
+///
+/// Returns `None` if:
+/// - the lines are parallel, including the collinear ones (which have infinite intersections).
+/// - the lines are skew (don't intersect in 3D).
+pub fn line_intersection(p1: DVec3, v1: DVec3, p2: DVec3, v2: DVec3) -> Option<(f64, f64)> {
     let cross = v1.cross(v2);
     let denom = cross.length_squared();
     if denom < f64::EPSILON {
@@ -22,12 +33,81 @@ pub fn intersection(p1: DVec3, v1: DVec3, p2: DVec3, v2: DVec3) -> Option<DVec3>
     let point2 = p2 + v2 * s;
 
     if (point1 - point2).length_squared() < f64::EPSILON {
-        Some(point1)
+        Some((t, s))
     } else {
         None
     }
 }
 
+/// Get the intersection point of segment `a0`-`a1` and segment `b0`-`b1`.
+///
+/// Touching at the endpoints counts as intersecting.
+///
+/// Edge cases:
+/// - parallel segments don't intersect.
+/// - collinear overlapping segments returns the point of the overlap closest to `a0`.
+/// - degenerate segments (a single point) intersect if the point lies on the other segment.
+pub fn segment_intersection(a0: DVec3, a1: DVec3, b0: DVec3, b1: DVec3) -> Option<DVec3> {
+    const EPS: f64 = 1e-9;
+    let (va, vb) = (a1 - a0, b1 - b0);
+    if let Some((t, s)) = line_intersection(a0, va, b0, vb) {
+        return ((-EPS..=1.0 + EPS).contains(&t) && (-EPS..=1.0 + EPS).contains(&s))
+            .then(|| a0 + va * t.clamp(0.0, 1.0));
+    }
+
+    // Parallel, collinear or degenerate
+    let on_segment = |p: DVec3, s0: DVec3, s1: DVec3| -> Option<f64> {
+        let v = s1 - s0;
+        let len_sq = v.length_squared();
+        if len_sq < f64::EPSILON {
+            return (p.distance_squared(s0) < f64::EPSILON).then_some(0.0);
+        }
+        let t = (p - s0).dot(v) / len_sq;
+        ((-EPS..=1.0 + EPS).contains(&t) && (s0 + v * t).distance_squared(p) < f64::EPSILON)
+            .then_some(t)
+    };
+    // The closest point of the overlap to `a0` is either `a0` itself or an endpoint of `b`
+    if on_segment(a0, b0, b1).is_some() {
+        return Some(a0);
+    }
+    [b0, b1]
+        .into_iter()
+        .filter_map(|p| on_segment(p, a0, a1).map(|t| (t, p)))
+        .min_by(|a, b| a.0.total_cmp(&b.0))
+        .map(|(_, p)| p)
+}
+
+/// Get the parameters `(t_enter, t_exit)` where the ray `origin + dir * t` (`t >= 0`)
+/// enters and exits the axis-aligned bounding box `[min, max]`.
+///
+/// Edge cases:
+/// - if the origin is inside the box, `t_enter` is `0.0`.
+/// - if the ray only touches the box (at an edge or a corner), `t_enter == t_exit`.
+/// - if a component of `dir` is zero, the ray intersects only if the origin is within
+///   the box on that axis.
+/// - returns `None` if the ray misses the box or the box is behind the ray.
+pub fn ray_bbox_intersection(origin: DVec3, dir: DVec3, bbox: [DVec3; 2]) -> Option<(f64, f64)> {
+    let [min, max] = bbox;
+    let (mut t_enter, mut t_exit) = (0.0f64, f64::INFINITY);
+    for axis in 0..3 {
+        let (o, d) = (origin[axis], dir[axis]);
+        if d.abs() < f64::EPSILON {
+            if o < min[axis] || o > max[axis] {
+                return None;
+            }
+            continue;
+        }
+        let (t0, t1) = ((min[axis] - o) / d, (max[axis] - o) / d);
+        let (t0, t1) = if t0 <= t1 { (t0, t1) } else { (t1, t0) };
+        t_enter = t_enter.max(t0);
+        t_exit = t_exit.min(t1);
+        if t_enter > t_exit {
+            return None;
+        }
+    }
+    Some((t_enter, t_exit))
+}
+
 /// A rectangle in 2D space
 #[derive(Debug, Clone, Copy)]
 pub struct Rect {
@@ -96,6 +176,24 @@ pub fn interpolate_usize(a: usize, b: usize, t: f64) -> (usize, f64) {
     (a + p.floor() as usize, p.fract())
 }
 
+/// Get the point at `t` on the arc path from `start` to `end`, like manim's `path_arc`.
+///
+/// The arc is centered so that rotating `start` around `axis` by `angle` results in `end`,
+/// that is, the displacement is rotated around the pair's midpoint by `angle * t`.
+/// A positive `angle` goes counterclockwise when looking against `axis`.
+///
+/// When `angle` is (nearly) zero, or `start` equals `end`, this is the same as the straight path
+/// `start.lerp(end, t)`.
+pub fn path_along_arc(start: DVec3, end: DVec3, angle: f64, axis: DVec3, t: f64) -> DVec3 {
+    let half = (end - start) * 0.5;
+    let axis = axis.normalize_or_zero();
+    if angle.abs() < 1e-6 || axis == DVec3::ZERO || half.length_squared() < f64::EPSILON {
+        return start.lerp(end, t);
+    }
+    let center = start + half + axis.cross(half) / (angle / 2.0).tan();
+    center + DQuat::from_axis_angle(axis, angle * t) * (start - center)
+}
+
 #[cfg(test)]
 mod test {
     use core::f64;
@@ -125,6 +223,28 @@ mod test {
         test(interpolate_usize(0, 2, 1.0), (2, 0.0));
     }
 
+    #[test]
+    fn test_path_along_arc() {
+        use glam::dvec3;
+        let (start, end) = (dvec3(-1.0, 0.0, 0.0), dvec3(1.0, 2.0, 0.0));
+
+        for t in [0.0, 0.3, 0.5, 1.0] {
+            let p = path_along_arc(start, end, 0.0, DVec3::Z, t);
+            assert!((p - start.lerp(end, t)).length() < 1e-9);
+        }
+        for angle in [f64::consts::FRAC_PI_2, f64::consts::PI, -f64::consts::PI] {
+            assert!((path_along_arc(start, end, angle, DVec3::Z, 0.0) - start).length() < 1e-9);
+            assert!((path_along_arc(start, end, angle, DVec3::Z, 1.0) - end).length() < 1e-9);
+        }
+
+        // A half circle around the midpoint, counterclockwise
+        let (start, end) = (dvec3(-1.0, 0.0, 0.0), dvec3(1.0, 0.0, 0.0));
+        let p = path_along_arc(start, end, f64::consts::PI, DVec3::Z, 0.5);
+        assert!((p - dvec3(0.0, -1.0, 0.0)).length() < 1e-9);
+        let p = path_along_arc(start, end, -f64::consts::PI, DVec3::Z, 0.5);
+        assert!((p - dvec3(0.0, 1.0, 0.0)).length() < 1e-9);
+    }
+
     #[test]
     fn test_intersection() {
         use glam::dvec3;
@@ -164,4 +284,133 @@ mod test {
         let v2 = dvec3(1.0, 0.0, -1.0);
         assert!(intersection(p1, v1, p2, v2).is_none());
     }
+
+    #[test]
+    fn test_line_intersection() {
+        use glam::dvec3;
+
+        // The parameters on both lines
+        let (t, s) = line_intersection(
+            dvec3(0.0, 0.0, 0.0),
+            dvec3(2.0, 0.0, 0.0),
+            dvec3(1.0, 1.0, 0.0),
+            dvec3(0.0, -4.0, 0.0),
+        )
+        .unwrap();
+        assert!((t - 0.5).abs() < 1e-12 && (s - 0.25).abs() < 1e-12);
+        // Intersections out of [0, 1] are still found for lines
+        let (t, s) = line_intersection(
+            dvec3(0.0, 0.0, 0.0),
+            dvec3(1.0, 0.0, 0.0),
+            dvec3(3.0, -1.0, 0.0),
+            dvec3(0.0, -1.0, 0.0),
+        )
+        .unwrap();
+        assert!((t - 3.0).abs() < 1e-12 && (s + 1.0).abs() < 1e-12);
+
+        // Parallel and collinear
+        let v = dvec3(1.0, 1.0, 0.0);
+        assert!(line_intersection(DVec3::ZERO, v, DVec3::X, v).is_none());
+        assert!(line_intersection(DVec3::ZERO, v, v * 2.0, -v).is_none());
+    }
+
+    #[test]
+    fn test_segment_intersection() {
+        use glam::dvec3;
+
+        // Crossing
+        assert_eq!(
+            segment_intersection(
+                dvec3(-1.0, 0.0, 0.0),
+                dvec3(1.0, 0.0, 0.0),
+                dvec3(0.0, -1.0, 0.0),
+                dvec3(0.0, 1.0, 0.0),
+            ),
+            Some(DVec3::ZERO)
+        );
+        // The lines intersect, but not the segments
+        assert!(
+            segment_intersection(
+                dvec3(-1.0, 0.0, 0.0),
+                dvec3(1.0, 0.0, 0.0),
+                dvec3(0.0, 1.0, 0.0),
+                dvec3(0.0, 2.0, 0.0),
+            )
+            .is_none()
+        );
+        // Touching at an endpoint
+        assert_eq!(
+            segment_intersection(
+                dvec3(0.0, 0.0, 0.0),
+                dvec3(1.0, 0.0, 0.0),
+                dvec3(1.0, 0.0, 0.0),
+                dvec3(1.0, 1.0, 0.0),
+            ),
+            Some(dvec3(1.0, 0.0, 0.0))
+        );
+        // Parallel
+        assert!(
+            segment_intersection(
+                dvec3(0.0, 0.0, 0.0),
+                dvec3(1.0, 0.0, 0.0),
+                dvec3(0.0, 1.0, 0.0),
+                dvec3(1.0, 1.0, 0.0),
+            )
+            .is_none()
+        );
+        // Collinear overlapping, the closest point to a0
+        assert_eq!(
+            segment_intersection(
+                dvec3(0.0, 0.0, 0.0),
+                dvec3(2.0, 0.0, 0.0),
+                dvec3(3.0, 0.0, 0.0),
+                dvec3(1.0, 0.0, 0.0),
+            ),
+            Some(dvec3(1.0, 0.0, 0.0))
+        );
+        assert_eq!(
+            segment_intersection(
+                dvec3(1.0, 0.0, 0.0),
+                dvec3(2.0, 0.0, 0.0),
+                dvec3(0.0, 0.0, 0.0),
+                dvec3(3.0, 0.0, 0.0),
+            ),
+            Some(dvec3(1.0, 0.0, 0.0))
+        );
+        // Collinear disjoint
+        assert!(
+            segment_intersection(
+                dvec3(0.0, 0.0, 0.0),
+                dvec3(1.0, 0.0, 0.0),
+                dvec3(2.0, 0.0, 0.0),
+                dvec3(3.0, 0.0, 0.0),
+            )
+            .is_none()
+        );
+    }
+
+    #[test]
+    fn test_ray_bbox_intersection() {
+        use glam::dvec3;
+
+        let bbox = [dvec3(-1.0, -1.0, -1.0), dvec3(1.0, 1.0, 1.0)];
+        // Hit from outside
+        assert_eq!(
+            ray_bbox_intersection(dvec3(-3.0, 0.0, 0.0), DVec3::X, bbox),
+            Some((2.0, 4.0))
+        );
+        // Origin inside
+        assert_eq!(
+            ray_bbox_intersection(DVec3::ZERO, DVec3::Y, bbox),
+            Some((0.0, 1.0))
+        );
+        // Box behind the ray
+        assert!(ray_bbox_intersection(dvec3(-3.0, 0.0, 0.0), -DVec3::X, bbox).is_none());
+        // Parallel to an axis, outside the slab
+        assert!(ray_bbox_intersection(dvec3(-3.0, 2.0, 0.0), DVec3::X, bbox).is_none());
+        // Touching a corner
+        let (t_enter, t_exit) =
+            ray_bbox_intersection(dvec3(-2.0, 0.0, 0.0), dvec3(1.0, 1.0, 0.0), bbox).unwrap();
+        assert!((t_enter - 1.0).abs() < 1e-12 && (t_exit - 1.0).abs() < 1e-12);
+    }
 }
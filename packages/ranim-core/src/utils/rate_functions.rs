@@ -17,6 +17,29 @@ pub fn smooth(t: f64) -> f64 {
     t * t * t * (10.0 * s * s + 5.0 * s * t + t * t)
 }
 
+/// Smootherstep rate function, an alias of [`smooth`]
+///
+/// Ken Perlin's 5th-order smoothstep `t * t * t * (t * (6.0 * t - 15.0) + 10.0)` is the
+/// same curve as [`smooth`], it has zero first and second derivatives at both ends.
+pub use smooth as smootherstep;
+
+/// Smooth rate function with a custom inflection
+///
+/// A normalized sigmoid, the larger `inflection` is, the steeper the curve is around `t = 0.5`.
+/// This matches manim's `smooth(t, inflection)`, whose default inflection is `10.0`.
+///
+/// Since rate functions are `fn(f64) -> f64`, use it with a non-capturing closure:
+///
+/// ```rust,ignore
+/// anim.with_rate_func(|t| smooth_with(t, 5.0))
+/// ```
+#[inline]
+pub fn smooth_with(t: f64, inflection: f64) -> f64 {
+    let sigmoid = |x: f64| 1.0 / (1.0 + (-x).exp());
+    let error = sigmoid(-inflection / 2.0);
+    ((sigmoid(inflection * (t - 0.5)) - error) / (1.0 - 2.0 * error)).clamp(0.0, 1.0)
+}
+
 /// Ease-in quad rate function
 ///
 /// t * t
@@ -75,3 +98,41 @@ pub fn ease_in_out_cubic(t: f64) -> f64 {
         1.0 - 4.0 * (t - 1.0) * (t - 1.0) * (t - 1.0)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn assert_endpoints(f: impl Fn(f64) -> f64) {
+        assert!(f(0.0).abs() < 1e-9);
+        assert!((f(1.0) - 1.0).abs() < 1e-9);
+        assert!((f(0.5) - 0.5).abs() < 1e-9);
+    }
+
+    fn assert_monotonic(f: impl Fn(f64) -> f64) {
+        let values = (0..=100).map(|i| f(i as f64 / 100.0)).collect::<Vec<_>>();
+        assert!(values.windows(2).all(|w| w[0] <= w[1]));
+    }
+
+    #[test]
+    fn test_smooth() {
+        assert_endpoints(smooth);
+        assert_monotonic(smooth);
+        for i in 0..=10 {
+            let t = i as f64 / 10.0;
+            let smootherstep = t * t * t * (t * (6.0 * t - 15.0) + 10.0);
+            assert!((smooth(t) - smootherstep).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_smooth_with() {
+        for inflection in [1.0, 5.0, 10.0, 20.0] {
+            assert_endpoints(|t| smooth_with(t, inflection));
+            assert_monotonic(|t| smooth_with(t, inflection));
+        }
+        // Larger inflection is steeper around the middle
+        assert!(smooth_with(0.4, 20.0) < smooth_with(0.4, 5.0));
+        assert!(smooth_with(0.6, 20.0) > smooth_with(0.6, 5.0));
+    }
+}
@@ -33,8 +33,14 @@ pub struct EllipticArc {
     pub stroke_rgba: AlphaColor<Srgb>,
     /// Stroke width
     pub stroke_width: f32,
+    /// The count of the quad bezier segments sampled from the ellipse,
+    /// see [`EllipticArc::with_min_curve_resolution`]
+    pub resolution: usize,
 }
 
+/// The default [`EllipticArc::resolution`]
+pub const DEFAULT_ARC_RESOLUTION: usize = 8;
+
 impl EllipticArc {
     /// Creates a new elliptic arc.
     pub fn new(start_angle: f64, angle: f64, radius: DVec2) -> Self {
@@ -46,26 +52,43 @@ impl EllipticArc {
             angle,
             stroke_rgba: AlphaColor::WHITE,
             stroke_width: DEFAULT_STROKE_WIDTH,
+            resolution: DEFAULT_ARC_RESOLUTION,
         }
     }
+    /// Sample at least `n` segments from the ellipse when converting to a [`VItem`].
+    ///
+    /// Each segment is a quad bezier approximating a part of the ellipse, a thin stroke of
+    /// a large (or scaled up) arc with too few segments deviates from the ellipse visibly.
+    /// More segments follow the ellipse more closely, nothing changes if there are already
+    /// at least `n` segments.
+    ///
+    /// ```
+    /// use ranim_items::vitem::{VItem, geometry::{Circle, EllipticArc}};
+    ///
+    /// let circle = VItem::from(EllipticArc::from(Circle::new(100.0)).with_min_curve_resolution(64));
+    /// ```
+    pub fn with_min_curve_resolution(mut self, n: usize) -> Self {
+        self.resolution = self.resolution.max(n);
+        self
+    }
 
     fn generate_vpoints(&self) -> Vec<DVec3> {
-        const NUM_SEGMENTS: usize = 8;
-        let len = 2 * NUM_SEGMENTS + 1;
-
         let &EllipticArc {
             axes,
             center,
             radius,
             start_angle,
             angle,
+            resolution,
             ..
         } = self;
+        let num_segments = resolution.max(1);
+        let len = 2 * num_segments + 1;
 
         let (u, v) = (axes.0.normalize(), axes.1.normalize());
         let DVec2 { x: rx, y: ry } = radius;
         let mut vpoints = (0..len)
-            .map(|i| i as f64 / NUM_SEGMENTS as f64 / 2. * angle + start_angle)
+            .map(|i| i as f64 / num_segments as f64 / 2. * angle + start_angle)
             .map(|theta| {
                 let (mut x, mut y) = (theta.cos(), theta.sin());
                 if x.abs() < 1.8e-7 {
@@ -78,7 +101,7 @@ impl EllipticArc {
             })
             .collect::<Vec<_>>();
 
-        let k = (angle / NUM_SEGMENTS as f64 / 2.).cos();
+        let k = (angle / num_segments as f64 / 2.).cos();
         vpoints.iter_mut().skip(1).step_by(2).for_each(|p| *p /= k);
         vpoints.shift(center);
         vpoints
@@ -103,6 +126,7 @@ impl From<Arc> for EllipticArc {
             angle,
             stroke_rgba,
             stroke_width,
+            resolution: DEFAULT_ARC_RESOLUTION,
         }
     }
 }
@@ -125,6 +149,7 @@ impl From<Circle> for EllipticArc {
             angle: TAU,
             stroke_rgba,
             stroke_width,
+            resolution: DEFAULT_ARC_RESOLUTION,
         }
     }
 }
@@ -163,6 +188,7 @@ impl From<Ellipse> for EllipticArc {
             angle: TAU,
             stroke_rgba,
             stroke_width,
+            resolution: DEFAULT_ARC_RESOLUTION,
         }
     }
 }
@@ -220,3 +246,39 @@ impl RotateTransform for EllipticArc {
         self
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_with_min_curve_resolution() {
+        // The max distance of the bezier midpoints to the unit circle
+        let max_error = |arc: EllipticArc| {
+            let vpoints = arc.generate_vpoints();
+            vpoints
+                .iter()
+                .step_by(2)
+                .for_each(|p| assert!((p.length() - 1.0).abs() < 1e-9));
+            let seg_cnt = vpoints.len() / 2;
+            let error = vpoints
+                .windows(3)
+                .step_by(2)
+                .map(|w| ((w[0] + 2.0 * w[1] + w[2]) / 4.0).length() - 1.0)
+                .fold(0.0, f64::max);
+            (seg_cnt, error)
+        };
+
+        let circle = EllipticArc::from(Circle::new(1.0));
+        let (seg_cnt, coarse) = max_error(circle.clone());
+        assert_eq!(seg_cnt, DEFAULT_ARC_RESOLUTION);
+        let (seg_cnt, fine) = max_error(circle.clone().with_min_curve_resolution(32));
+        assert_eq!(seg_cnt, 32);
+        // The error of a quad bezier arc is about the 4th power of its angle
+        assert!(fine < coarse / 100.0, "{fine} {coarse}");
+
+        // Already enough segments
+        let (seg_cnt, _) = max_error(circle.with_min_curve_resolution(4));
+        assert_eq!(seg_cnt, DEFAULT_ARC_RESOLUTION);
+    }
+}
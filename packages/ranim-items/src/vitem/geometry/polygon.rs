@@ -387,6 +387,10 @@ impl Polygon {
             fill_rgba: AlphaColor::TRANSPARENT,
         }
     }
+    /// Convert to a [`VItem`] with rounded corners, see [`VItem::round_corners`].
+    pub fn round_corners(&self, radius: f64) -> VItem {
+        VItem::from(self.clone()).with(|vitem| vitem.round_corners(radius).discard())
+    }
 }
 
 // MARK: Traits impl
@@ -0,0 +1,720 @@
+use std::{fmt, iter::Peekable, str::Chars};
+
+use color::{AlphaColor, Srgb};
+use ranim_core::{
+    Extract,
+    anchor::Aabb,
+    color,
+    core_item::CoreItem,
+    glam,
+    traits::{FillColor, Opacity, StrokeColor},
+};
+
+use crate::vitem::{VItem, extract_vitems_with_background, svg::SvgItem, typst::try_typst_svg};
+
+// MARK: ### MathTex ###
+/// A math formula written in LaTeX, compiled through typst.
+///
+/// The LaTeX is translated into typst math with [`latex_to_typst_math`] and compiled with
+/// [`try_typst_svg`] as a display formula, like manim's `MathTex`:
+///
+/// ```rust,ignore
+/// let formula = MathTex::new(r"\sum_{i=1}^{n} i = \frac{n(n+1)}{2}");
+/// ```
+///
+/// Only a subset of LaTeX math is supported, see [`latex_to_typst_math`].
+#[derive(
+    Clone, ranim_macros::ShiftTransform, ranim_macros::RotateTransform, ranim_macros::ScaleTransform,
+)]
+pub struct MathTex(Vec<VItem>);
+
+impl From<MathTex> for Vec<VItem> {
+    fn from(value: MathTex) -> Self {
+        value.0
+    }
+}
+
+impl MathTex {
+    /// Creates a new MathTex from a LaTeX math string (without the surrounding `$`).
+    ///
+    /// # Panics
+    /// Panics if the LaTeX is not supported or fails to compile,
+    /// see [`MathTex::try_new`] for the fallible version.
+    pub fn new(latex: &str) -> Self {
+        Self::try_new(latex).unwrap_or_else(|err| panic!("failed to create MathTex: {err}"))
+    }
+    /// Creates a new MathTex from a LaTeX math string (without the surrounding `$`),
+    /// returns an error if the LaTeX is not supported or fails to compile.
+    pub fn try_new(latex: &str) -> Result<Self, LatexError> {
+        let math = latex_to_typst_math(latex)?;
+        let svg = try_typst_svg(&format!("$ {math} $")).map_err(LatexError::Typst)?;
+        Ok(Self(SvgItem::new(svg).into()))
+    }
+}
+
+impl Aabb for MathTex {
+    fn aabb(&self) -> [glam::DVec3; 2] {
+        self.0.aabb()
+    }
+}
+
+impl FillColor for MathTex {
+    fn fill_color(&self) -> AlphaColor<Srgb> {
+        self.0[0].fill_color()
+    }
+    fn set_fill_color(&mut self, color: AlphaColor<Srgb>) -> &mut Self {
+        self.0.set_fill_color(color);
+        self
+    }
+    fn set_fill_opacity(&mut self, opacity: f32) -> &mut Self {
+        self.0.set_fill_opacity(opacity);
+        self
+    }
+}
+
+impl StrokeColor for MathTex {
+    fn stroke_color(&self) -> AlphaColor<Srgb> {
+        self.0[0].stroke_color()
+    }
+    fn set_stroke_color(&mut self, color: AlphaColor<Srgb>) -> &mut Self {
+        self.0.set_stroke_color(color);
+        self
+    }
+    fn set_stroke_opacity(&mut self, opacity: f32) -> &mut Self {
+        self.0.set_stroke_opacity(opacity);
+        self
+    }
+}
+
+impl Opacity for MathTex {
+    fn set_opacity(&mut self, opacity: f32) -> &mut Self {
+        self.0.set_fill_opacity(opacity);
+        self.0.set_stroke_opacity(opacity);
+        self
+    }
+}
+
+impl Extract for MathTex {
+    type Target = CoreItem;
+    fn extract_into(&self, buf: &mut Vec<Self::Target>) {
+        extract_vitems_with_background(&self.0, buf);
+    }
+}
+
+// MARK: ### LatexError ###
+/// The error of translating and compiling LaTeX, see [`MathTex::try_new`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum LatexError {
+    /// A command that is not supported, like `\newcommand`.
+    UnsupportedCommand(String),
+    /// An environment that is not supported, like `align`.
+    UnsupportedEnvironment(String),
+    /// A `{` without `}`, or a `}` without `{`.
+    UnbalancedBraces,
+    /// A `\begin{..}` without the matching `\end{..}`, or the other way around.
+    UnmatchedEnvironment(String),
+    /// A `&` or `\\` outside of an environment.
+    UnexpectedSeparator(char),
+    /// A command or a `_`/`^` without its argument.
+    MissingArgument(String),
+    /// The translated typst math fails to compile.
+    Typst(String),
+}
+
+impl fmt::Display for LatexError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnsupportedCommand(cmd) => write!(f, "unsupported LaTeX command `\\{cmd}`"),
+            Self::UnsupportedEnvironment(env) => {
+                write!(f, "unsupported LaTeX environment `{env}`")
+            }
+            Self::UnbalancedBraces => write!(f, "unbalanced braces"),
+            Self::UnmatchedEnvironment(env) => write!(f, "unmatched environment `{env}`"),
+            Self::UnexpectedSeparator(c) => {
+                write!(f, "unexpected `{c}` outside of a matrix or cases")
+            }
+            Self::MissingArgument(cmd) => write!(f, "missing argument of `{cmd}`"),
+            Self::Typst(err) => write!(f, "failed to compile the translated typst: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for LatexError {}
+
+// MARK: ### Translation ###
+/// Translates LaTeX math into typst math.
+///
+/// The supported subset is:
+/// - letters, digits and the common operators and punctuation
+/// - sub/superscripts: `x_i`, `x^{2}`, `\sum_{i=1}^{n}`
+/// - fractions and roots: `\frac`, `\dfrac`, `\tfrac`, `\binom`, `\sqrt`, `\sqrt[n]`
+/// - greek letters: `\alpha`, `\varphi`, `\Omega`, ...
+/// - big operators: `\sum`, `\prod`, `\int`, `\oint`, `\lim`, ...
+/// - functions: `\sin`, `\log`, `\max`, `\operatorname{..}`, ...
+/// - relations, arrows and other symbols: `\leq`, `\to`, `\in`, `\infty`, `\cdot`, `\ldots`, ...
+/// - styles and accents: `\mathbf`, `\mathbb`, `\mathcal`, `\text`, `\hat`, `\vec`, `\overline`, ...
+/// - spacing: `\,`, `\;`, `\quad`, `\qquad`, and `\left`/`\right` (the delimiters are kept)
+/// - environments: `matrix`, `pmatrix`, `bmatrix`, `Bmatrix`, `vmatrix`, `Vmatrix` and `cases`
+///
+/// Anything else is an error instead of a wrong output.
+pub fn latex_to_typst_math(latex: &str) -> Result<String, LatexError> {
+    let mut parser = Parser {
+        chars: latex.chars().peekable(),
+    };
+    let (tokens, stop) = parser.parse_seq()?;
+    match stop {
+        Stop::Eof => Ok(tokens.join(" ")),
+        Stop::CloseBrace => Err(LatexError::UnbalancedBraces),
+        Stop::CloseBracket => unreachable!(),
+        Stop::Ampersand => Err(LatexError::UnexpectedSeparator('&')),
+        Stop::NewRow => Err(LatexError::UnexpectedSeparator('\\')),
+        Stop::End(env) => Err(LatexError::UnmatchedEnvironment(env)),
+    }
+}
+
+/// Where a sequence stops
+enum Stop {
+    Eof,
+    CloseBrace,
+    /// Only when parsing the optional argument of `\sqrt`
+    CloseBracket,
+    Ampersand,
+    NewRow,
+    End(String),
+}
+
+struct Parser<'a> {
+    chars: Peekable<Chars<'a>>,
+}
+
+impl Parser<'_> {
+    fn skip_whitespace(&mut self) {
+        while self.chars.next_if(|c| c.is_whitespace()).is_some() {}
+    }
+
+    /// Parse a sequence of atoms into typst tokens until a stop.
+    fn parse_seq(&mut self) -> Result<(Vec<String>, Stop), LatexError> {
+        self.parse_seq_until(None)
+    }
+
+    fn parse_seq_until(&mut self, close: Option<char>) -> Result<(Vec<String>, Stop), LatexError> {
+        let mut tokens: Vec<String> = Vec::new();
+        loop {
+            self.skip_whitespace();
+            let Some(c) = self.chars.peek().copied() else {
+                return Ok((tokens, Stop::Eof));
+            };
+            if Some(c) == close {
+                self.chars.next();
+                return Ok((tokens, Stop::CloseBracket));
+            }
+            match c {
+                '}' => {
+                    self.chars.next();
+                    return Ok((tokens, Stop::CloseBrace));
+                }
+                '&' => {
+                    self.chars.next();
+                    return Ok((tokens, Stop::Ampersand));
+                }
+                '\'' => {
+                    self.chars.next();
+                    let base = tokens.pop().unwrap_or_default();
+                    tokens.push(format!("{base}'"));
+                }
+                '_' | '^' => {
+                    self.chars.next();
+                    let arg = self
+                        .parse_arg()?
+                        .ok_or_else(|| LatexError::MissingArgument(c.to_string()))?;
+                    // Attach to the previous token directly, an empty base needs a placeholder
+                    let base = tokens.pop().unwrap_or_else(|| "\"\"".to_string());
+                    tokens.push(format!("{base}{c}({arg})"));
+                }
+                '\\' => {
+                    self.chars.next();
+                    match self.parse_command_name().as_str() {
+                        "\\" => return Ok((tokens, Stop::NewRow)),
+                        "end" => {
+                            let env = self.parse_raw_group("end")?;
+                            return Ok((tokens, Stop::End(env)));
+                        }
+                        name => {
+                            if let Some(token) = self.parse_command(name)? {
+                                tokens.push(token);
+                            }
+                        }
+                    }
+                }
+                _ => {
+                    if let Some(token) = self.parse_arg()? {
+                        tokens.push(token);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Parse a single argument: a group, a command or a char (with a number being one char).
+    ///
+    /// Returns `None` for the tokens that produce nothing, like `\left`.
+    fn parse_arg(&mut self) -> Result<Option<String>, LatexError> {
+        self.skip_whitespace();
+        let Some(c) = self.chars.next() else {
+            return Ok(None);
+        };
+        let token = match c {
+            '{' => {
+                let (tokens, stop) = self.parse_seq()?;
+                if !matches!(stop, Stop::CloseBrace) {
+                    return Err(LatexError::UnbalancedBraces);
+                }
+                tokens.join(" ")
+            }
+            '}' => return Err(LatexError::UnbalancedBraces),
+            '\\' => {
+                let name = self.parse_command_name();
+                return self.parse_command(&name);
+            }
+            '0'..='9' => {
+                let mut number = c.to_string();
+                while let Some(c) = self.chars.next_if(|c| c.is_ascii_digit() || *c == '.') {
+                    number.push(c);
+                }
+                number
+            }
+            // Single letters, so that `ab` isn't a typst variable named `ab`
+            c if c.is_alphabetic() => c.to_string(),
+            // Special in typst math
+            '/' | ',' | ';' | '"' | '#' | '$' => format!("\\{c}"),
+            '~' => "space".to_string(),
+            '&' => return Err(LatexError::UnexpectedSeparator('&')),
+            c => c.to_string(),
+        };
+        Ok(Some(token))
+    }
+
+    /// Parse the name of a command after the `\`, a word or a single symbol.
+    fn parse_command_name(&mut self) -> String {
+        let mut name = String::new();
+        while let Some(c) = self.chars.next_if(|c| c.is_ascii_alphabetic()) {
+            name.push(c);
+        }
+        if name.is_empty()
+            && let Some(c) = self.chars.next()
+        {
+            name.push(c);
+        }
+        name
+    }
+
+    /// Parse a `{..}` group as raw text, for `\text` and environment names.
+    fn parse_raw_group(&mut self, cmd: &str) -> Result<String, LatexError> {
+        self.skip_whitespace();
+        if self.chars.next_if_eq(&'{').is_none() {
+            return Err(LatexError::MissingArgument(format!("\\{cmd}")));
+        }
+        let mut text = String::new();
+        let mut depth = 0;
+        loop {
+            match self.chars.next() {
+                Some('}') if depth == 0 => return Ok(text),
+                Some(c) => {
+                    match c {
+                        '{' => depth += 1,
+                        '}' => depth -= 1,
+                        _ => {}
+                    }
+                    text.push(c);
+                }
+                None => return Err(LatexError::UnbalancedBraces),
+            }
+        }
+    }
+
+    /// Parse a required argument of `cmd`.
+    fn required_arg(&mut self, cmd: &str) -> Result<String, LatexError> {
+        self.parse_arg()?
+            .ok_or_else(|| LatexError::MissingArgument(format!("\\{cmd}")))
+    }
+
+    /// Translate a command (after its name) and consume its arguments.
+    fn parse_command(&mut self, name: &str) -> Result<Option<String>, LatexError> {
+        if let Some(symbol) = symbol(name) {
+            return Ok(Some(symbol.to_string()));
+        }
+        let token = match name {
+            "frac" | "dfrac" | "tfrac" | "cfrac" => {
+                let num = self.required_arg(name)?;
+                let den = self.required_arg(name)?;
+                format!("frac({num}, {den})")
+            }
+            "binom" => {
+                let n = self.required_arg(name)?;
+                let k = self.required_arg(name)?;
+                format!("binom({n}, {k})")
+            }
+            "sqrt" => {
+                self.skip_whitespace();
+                if self.chars.next_if_eq(&'[').is_some() {
+                    let (index, stop) = self.parse_seq_until(Some(']'))?;
+                    if !matches!(stop, Stop::CloseBracket) {
+                        return Err(LatexError::MissingArgument("\\sqrt[".to_string()));
+                    }
+                    let radicand = self.required_arg(name)?;
+                    format!("root({}, {radicand})", index.join(" "))
+                } else {
+                    format!("sqrt({})", self.required_arg(name)?)
+                }
+            }
+            "text" | "textrm" | "textnormal" | "mbox" => {
+                let text = self.parse_raw_group(name)?;
+                format!("\"{}\"", text.replace('\\', "\\\\").replace('"', "\\\""))
+            }
+            "operatorname" => {
+                let text = self.parse_raw_group(name)?;
+                format!(
+                    "op(\"{}\")",
+                    text.replace('\\', "\\\\").replace('"', "\\\"")
+                )
+            }
+            "left" | "right" | "big" | "Big" | "bigg" | "Bigg" => {
+                // The delimiter follows and is kept, except the empty one `.`
+                self.skip_whitespace();
+                self.chars.next_if_eq(&'.');
+                return Ok(None);
+            }
+            "\\" => return Err(LatexError::UnexpectedSeparator('\\')),
+            "begin" => {
+                let env = self.parse_raw_group(name)?;
+                return self.parse_environment(&env).map(Some);
+            }
+            "end" => {
+                return Err(LatexError::UnmatchedEnvironment(
+                    self.parse_raw_group(name)?,
+                ));
+            }
+            "!" | "displaystyle" | "textstyle" | "limits" | "nolimits" => return Ok(None),
+            _ => {
+                let Some(func) = style_or_accent(name) else {
+                    return Err(LatexError::UnsupportedCommand(name.to_string()));
+                };
+                format!("{func}({})", self.required_arg(name)?)
+            }
+        };
+        Ok(Some(token))
+    }
+
+    /// Parse the content of an environment until its `\end`.
+    fn parse_environment(&mut self, env: &str) -> Result<String, LatexError> {
+        let delim = match env {
+            "matrix" => Some("#none"),
+            "pmatrix" => Some("\"(\""),
+            "bmatrix" => Some("\"[\""),
+            "Bmatrix" => Some("\"{\""),
+            "vmatrix" => Some("\"|\""),
+            "Vmatrix" => Some("\"||\""),
+            "cases" => None,
+            _ => return Err(LatexError::UnsupportedEnvironment(env.to_string())),
+        };
+        let mut rows: Vec<Vec<String>> = vec![Vec::new()];
+        loop {
+            let (tokens, stop) = self.parse_seq()?;
+            rows.last_mut().unwrap().push(tokens.join(" "));
+            match stop {
+                Stop::Ampersand => {}
+                Stop::NewRow => rows.push(Vec::new()),
+                Stop::End(end) if end == env => break,
+                Stop::End(end) => return Err(LatexError::UnmatchedEnvironment(end)),
+                Stop::Eof => return Err(LatexError::UnmatchedEnvironment(env.to_string())),
+                Stop::CloseBrace | Stop::CloseBracket => return Err(LatexError::UnbalancedBraces),
+            }
+        }
+        // A trailing `\\`
+        if rows.len() > 1
+            && rows
+                .last()
+                .is_some_and(|row| row.iter().all(|c| c.is_empty()))
+        {
+            rows.pop();
+        }
+        Ok(match delim {
+            Some(delim) => format!(
+                "mat(delim: {delim}, {})",
+                rows.iter()
+                    .map(|row| row.join(", "))
+                    .collect::<Vec<_>>()
+                    .join("; ")
+            ),
+            None => format!(
+                "cases({})",
+                rows.iter()
+                    .map(|row| row.join(" & "))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+        })
+    }
+}
+
+/// The typst of the commands without arguments
+fn symbol(name: &str) -> Option<&'static str> {
+    Some(match name {
+        // Greek
+        "alpha" => "alpha",
+        "beta" => "beta",
+        "gamma" => "gamma",
+        "delta" => "delta",
+        "epsilon" => "epsilon.alt",
+        "varepsilon" => "epsilon",
+        "zeta" => "zeta",
+        "eta" => "eta",
+        "theta" => "theta",
+        "vartheta" => "theta.alt",
+        "iota" => "iota",
+        "kappa" => "kappa",
+        "lambda" => "lambda",
+        "mu" => "mu",
+        "nu" => "nu",
+        "xi" => "xi",
+        "omicron" => "omicron",
+        "pi" => "pi",
+        "varpi" => "pi.alt",
+        "rho" => "rho",
+        "varrho" => "rho.alt",
+        "sigma" => "sigma",
+        "varsigma" => "sigma.alt",
+        "tau" => "tau",
+        "upsilon" => "upsilon",
+        "phi" => "phi.alt",
+        "varphi" => "phi",
+        "chi" => "chi",
+        "psi" => "psi",
+        "omega" => "omega",
+        "Gamma" => "Gamma",
+        "Delta" => "Delta",
+        "Theta" => "Theta",
+        "Lambda" => "Lambda",
+        "Xi" => "Xi",
+        "Pi" => "Pi",
+        "Sigma" => "Sigma",
+        "Upsilon" => "Upsilon",
+        "Phi" => "Phi",
+        "Psi" => "Psi",
+        "Omega" => "Omega",
+        // Big operators
+        "sum" => "sum",
+        "prod" => "product",
+        "coprod" => "product.co",
+        "int" => "integral",
+        "iint" => "integral.double",
+        "iiint" => "integral.triple",
+        "oint" => "integral.cont",
+        "bigcup" => "union.big",
+        "bigcap" => "inter.big",
+        // Functions
+        "sin" => "sin",
+        "cos" => "cos",
+        "tan" => "tan",
+        "cot" => "cot",
+        "sec" => "sec",
+        "csc" => "csc",
+        "arcsin" => "arcsin",
+        "arccos" => "arccos",
+        "arctan" => "arctan",
+        "sinh" => "sinh",
+        "cosh" => "cosh",
+        "tanh" => "tanh",
+        "log" => "log",
+        "lg" => "lg",
+        "ln" => "ln",
+        "exp" => "exp",
+        "lim" => "lim",
+        "liminf" => "liminf",
+        "limsup" => "limsup",
+        "max" => "max",
+        "min" => "min",
+        "sup" => "sup",
+        "inf" => "inf",
+        "det" => "det",
+        "dim" => "dim",
+        "ker" => "ker",
+        "deg" => "deg",
+        "gcd" => "gcd",
+        "arg" => "arg",
+        // Binary operators
+        "cdot" => "dot.op",
+        "times" => "times",
+        "div" => "div",
+        "pm" => "plus.minus",
+        "mp" => "minus.plus",
+        "ast" => "ast",
+        "star" => "star",
+        "circ" => "compose",
+        "cup" => "union",
+        "cap" => "inter",
+        "setminus" => "without",
+        "oplus" => "plus.circle",
+        "otimes" => "times.circle",
+        "wedge" | "land" => "and",
+        "vee" | "lor" => "or",
+        // Relations
+        "leq" | "le" => "<=",
+        "geq" | "ge" => ">=",
+        "neq" | "ne" => "!=",
+        "ll" => "<<",
+        "gg" => ">>",
+        "approx" => "approx",
+        "equiv" => "equiv",
+        "sim" => "tilde.op",
+        "simeq" => "tilde.eq",
+        "cong" => "tilde.equiv",
+        "propto" => "prop",
+        "in" => "in",
+        "notin" => "in.not",
+        "ni" => "in.rev",
+        "subset" => "subset",
+        "subseteq" => "subset.eq",
+        "supset" => "supset",
+        "supseteq" => "supset.eq",
+        "perp" => "perp",
+        "parallel" => "parallel",
+        "mid" => "divides",
+        // Arrows
+        "to" | "rightarrow" => "->",
+        "gets" | "leftarrow" => "<-",
+        "leftrightarrow" => "<->",
+        "Rightarrow" | "implies" => "=>",
+        "Leftarrow" => "arrow.l.double",
+        "Leftrightarrow" | "iff" => "<=>",
+        "mapsto" => "|->",
+        "uparrow" => "arrow.t",
+        "downarrow" => "arrow.b",
+        // Other symbols
+        "infty" => "infinity",
+        "partial" => "partial",
+        "nabla" => "nabla",
+        "forall" => "forall",
+        "exists" => "exists",
+        "neg" | "lnot" => "not",
+        "emptyset" | "varnothing" => "emptyset",
+        "ell" => "ell",
+        "prime" => "prime",
+        "angle" => "angle",
+        "degree" => "degree",
+        "ldots" | "dots" => "dots.h",
+        "cdots" => "dots.c",
+        "vdots" => "dots.v",
+        "ddots" => "dots.down",
+        "langle" => "angle.l",
+        "rangle" => "angle.r",
+        "lfloor" => "floor.l",
+        "rfloor" => "floor.r",
+        "lceil" => "ceil.l",
+        "rceil" => "ceil.r",
+        "|" => "bar.v.double",
+        "{" => "\\{",
+        "}" => "\\}",
+        "%" => "%",
+        "$" => "\\$",
+        "#" => "\\#",
+        "&" => "\\&",
+        "_" => "\\_",
+        // Spacing
+        "," => "thin",
+        ":" | ">" => "med",
+        ";" => "thick",
+        " " => "space",
+        "quad" => "quad",
+        "qquad" => "wide",
+        _ => return None,
+    })
+}
+
+/// The typst function of the style and accent commands with one argument
+fn style_or_accent(name: &str) -> Option<&'static str> {
+    Some(match name {
+        "mathbf" | "boldsymbol" | "bm" => "bold",
+        "mathit" => "italic",
+        "mathrm" => "upright",
+        "mathbb" => "bb",
+        "mathcal" => "cal",
+        "mathfrak" => "frak",
+        "mathsf" => "sans",
+        "mathtt" => "mono",
+        "hat" | "widehat" => "hat",
+        "tilde" | "widetilde" => "tilde",
+        "bar" => "macron",
+        "dot" => "dot",
+        "ddot" => "dot.double",
+        "vec" => "arrow",
+        "overline" => "overline",
+        "underline" => "underline",
+        "overbrace" => "overbrace",
+        "underbrace" => "underbrace",
+        _ => return None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_latex_to_typst_math() {
+        let cases = [
+            (r"\frac{a}{b}", "frac(a, b)"),
+            (r"x^2 + y_{ij}", "x^(2) + y_(i j)"),
+            (r"\sum_{i=1}^{n} i", "sum_(i = 1)^(n) i"),
+            (r"\alpha + \Omega", "alpha + Omega"),
+            (r"\sqrt[3]{x}", "root(3, x)"),
+            (r"\text{if } x", "\"if \" x"),
+            (r"f(x, y)", "f ( x \\, y )"),
+            (r"\left( \frac{1}{2} \right)", "( frac(1, 2) )"),
+            (
+                r"\begin{pmatrix} 1 & 0 \\ 0 & 1 \end{pmatrix}",
+                "mat(delim: \"(\", 1, 0; 0, 1)",
+            ),
+            (
+                r"\begin{cases} x & x > 0 \\ 0 & \text{else} \end{cases}",
+                "cases(x & x > 0, 0 & \"else\")",
+            ),
+        ];
+        for (latex, typst) in cases {
+            assert_eq!(latex_to_typst_math(latex).unwrap(), typst, "{latex}");
+        }
+
+        assert_eq!(
+            latex_to_typst_math(r"\newcommand{x}"),
+            Err(LatexError::UnsupportedCommand("newcommand".to_string()))
+        );
+        assert_eq!(
+            latex_to_typst_math(r"\frac{a}{b"),
+            Err(LatexError::UnbalancedBraces)
+        );
+        assert_eq!(
+            latex_to_typst_math(r"\begin{align} x \end{align}"),
+            Err(LatexError::UnsupportedEnvironment("align".to_string()))
+        );
+        assert_eq!(
+            latex_to_typst_math(r"a & b"),
+            Err(LatexError::UnexpectedSeparator('&'))
+        );
+    }
+
+    #[test]
+    fn test_math_tex() {
+        for latex in [
+            r"\frac{a}{b}",
+            r"\sum_{i=1}^{n} i = \frac{n(n+1)}{2}",
+            r"e^{i\pi} + 1 = 0",
+            r"\int_0^\infty e^{-x^2} \, dx = \frac{\sqrt{\pi}}{2}",
+            r"\begin{bmatrix} a & b \\ c & d \end{bmatrix}",
+            r"f'(x) = \vec{v} \cdot \hat{n} \leq \|x\|",
+            r"\begin{cases} x & x \geq 0 \\ -x & \text{otherwise} \end{cases}",
+        ] {
+            let math_tex = MathTex::try_new(latex).unwrap_or_else(|err| panic!("{latex}: {err}"));
+            assert!(!Vec::<VItem>::from(math_tex).is_empty(), "{latex}");
+        }
+    }
+}
@@ -0,0 +1,224 @@
+use color::{AlphaColor, Srgb};
+use ranim_core::{
+    Extract, color,
+    core_item::CoreItem,
+    glam::{DVec3, dvec3},
+    traits::{Aabb, Opacity, ShiftTransform, StrokeColor},
+    utils::bezier::PathBuilder,
+};
+
+use crate::vitem::{VItem, table::Table, typst::TypstText};
+
+// MARK: ### Matrix ###
+/// A matrix, which arranges the entries into rows and columns between a pair of brackets.
+///
+/// The entries are laid out like a [`Table`] without lines, and the brackets are sized
+/// to the content:
+///
+/// ```rust,ignore
+/// let mut matrix = Matrix::from_typst([["1", "2"], ["3", "4"]]);
+///
+/// // Change the value of an entry, the old entry transforms into the new one on its own
+/// // timeline, so it is hidden in the matrix
+/// let old = matrix.replace_entry(0, 1, TypstText::new("5"));
+/// let new = matrix.get_entry(0, 1).unwrap().clone();
+/// matrix.get_entry_mut(0, 1).unwrap().set_opacity(0.0);
+/// let r_matrix = r.insert(matrix);
+/// let r_entry = r.insert(vec![old.clone()]);
+/// r.timeline_mut(r_entry).play(old.fade_transform_to(new));
+/// ```
+#[derive(Clone)]
+pub struct Matrix<T> {
+    entries: Table<T>,
+    brackets: [VItem; 2],
+}
+
+impl<T: Aabb + ShiftTransform> Matrix<T> {
+    /// The default padding between the entry and the cell's edges
+    pub const DEFAULT_PADDING: f64 = 0.2;
+    /// The default width of the brackets' horizontal ticks
+    pub const DEFAULT_BRACKET_WIDTH: f64 = 0.15;
+
+    /// Constructor, the matrix is centered at the origin.
+    pub fn new(rows: impl IntoIterator<Item = impl IntoIterator<Item = T>>) -> Self {
+        let entries = Table::new(rows)
+            .with_padding(Self::DEFAULT_PADDING)
+            .with_lines(false);
+        let brackets = brackets(entries.aabb(), Self::DEFAULT_BRACKET_WIDTH);
+        Self { entries, brackets }
+    }
+    /// Replace the entry at row `r` and column `c`, returning the old one.
+    ///
+    /// The new entry is centered in the cell, without changing the layout of the matrix.
+    /// To animate the change, transform the returned entry into the new one.
+    ///
+    /// # Panics
+    /// Panics if the entry is out of range.
+    pub fn replace_entry(&mut self, r: usize, c: usize, mut entry: T) -> T {
+        let [min, max] = entry.aabb();
+        entry.shift(self.entries.cell_center(r, c) - (min + max) / 2.0);
+        let old = self
+            .entries
+            .get_cell_mut(r, c)
+            .unwrap_or_else(|| panic!("entry ({r}, {c}) is out of range"));
+        std::mem::replace(old, entry)
+    }
+}
+
+impl Matrix<TypstText> {
+    /// Construct a matrix of [`TypstText`] entries from typst strings.
+    pub fn from_typst<S: AsRef<str>>(
+        rows: impl IntoIterator<Item = impl IntoIterator<Item = S>>,
+    ) -> Self {
+        Self::new(
+            rows.into_iter()
+                .map(|row| row.into_iter().map(|s| TypstText::new(s.as_ref()))),
+        )
+    }
+}
+
+impl<T> Matrix<T> {
+    /// The count of rows
+    pub fn nrows(&self) -> usize {
+        self.entries.nrows()
+    }
+    /// The count of columns
+    pub fn ncols(&self) -> usize {
+        self.entries.ncols()
+    }
+    /// The entry at row `r` and column `c`
+    pub fn get_entry(&self, r: usize, c: usize) -> Option<&T> {
+        self.entries.get_cell(r, c)
+    }
+    /// The mutable entry at row `r` and column `c`
+    pub fn get_entry_mut(&mut self, r: usize, c: usize) -> Option<&mut T> {
+        self.entries.get_cell_mut(r, c)
+    }
+    /// All the entries, row by row
+    pub fn get_entries(&self) -> Vec<&T> {
+        self.get_rows().into_iter().flatten().collect()
+    }
+    /// The entries of each row
+    pub fn get_rows(&self) -> Vec<&[T]> {
+        (0..self.nrows())
+            .filter_map(|r| self.entries.get_row(r))
+            .collect()
+    }
+    /// The entries of each column
+    pub fn get_columns(&self) -> Vec<Vec<&T>> {
+        (0..self.ncols()).map(|c| self.entries.get_col(c)).collect()
+    }
+    /// The left and right brackets
+    pub fn get_brackets(&self) -> &[VItem; 2] {
+        &self.brackets
+    }
+    /// The mutable left and right brackets
+    pub fn get_brackets_mut(&mut self) -> &mut [VItem; 2] {
+        &mut self.brackets
+    }
+}
+
+/// The left and right square brackets around `aabb`, with ticks of `width`
+fn brackets([min, max]: [DVec3; 2], width: f64) -> [VItem; 2] {
+    let bracket = |x: f64, dir: f64| {
+        VItem::from_vpoints(
+            PathBuilder::new()
+                .move_to(dvec3(x + dir * width, max.y, min.z))
+                .line_to(dvec3(x, max.y, min.z))
+                .line_to(dvec3(x, min.y, min.z))
+                .line_to(dvec3(x + dir * width, min.y, min.z))
+                .vpoints()
+                .into(),
+        )
+    };
+    [bracket(min.x, 1.0), bracket(max.x, -1.0)]
+}
+
+// MARK: Traits impl
+impl<T> Aabb for Matrix<T> {
+    fn aabb(&self) -> [DVec3; 2] {
+        [self.entries.aabb(), self.brackets.aabb()].concat().aabb()
+    }
+}
+
+impl<T: ShiftTransform> ShiftTransform for Matrix<T> {
+    fn shift(&mut self, shift: DVec3) -> &mut Self {
+        self.entries.shift(shift);
+        self.brackets.shift(shift);
+        self
+    }
+}
+
+impl<T: Opacity> Opacity for Matrix<T> {
+    fn set_opacity(&mut self, opacity: f32) -> &mut Self {
+        self.entries.set_opacity(opacity);
+        self.brackets.set_opacity(opacity);
+        self
+    }
+}
+
+impl<T> StrokeColor for Matrix<T> {
+    fn stroke_color(&self) -> AlphaColor<Srgb> {
+        self.brackets[0].stroke_color()
+    }
+    fn set_stroke_color(&mut self, color: AlphaColor<Srgb>) -> &mut Self {
+        self.brackets.set_stroke_color(color);
+        self
+    }
+    fn set_stroke_opacity(&mut self, opacity: f32) -> &mut Self {
+        self.brackets.set_stroke_opacity(opacity);
+        self
+    }
+}
+
+impl<T: Extract<Target = CoreItem>> Extract for Matrix<T> {
+    type Target = CoreItem;
+    fn extract_into(&self, buf: &mut Vec<Self::Target>) {
+        self.entries.extract_into(buf);
+        self.brackets.extract_into(buf);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vitem::geometry::Square;
+
+    fn assert_near(a: DVec3, b: DVec3) {
+        assert!((a - b).length() < 1e-9, "{a} != {b}");
+    }
+
+    #[test]
+    fn test_matrix_brackets() {
+        let mut matrix = Matrix::new([
+            [Square::new(1.0), Square::new(1.0)],
+            [Square::new(1.0), Square::new(1.0)],
+        ]);
+        let half = 1.0 + 2.0 * Matrix::<Square>::DEFAULT_PADDING;
+        let width = Matrix::<Square>::DEFAULT_BRACKET_WIDTH;
+
+        let [left, right] = matrix.get_brackets();
+        let [min, max] = left.aabb();
+        assert_near(min, dvec3(-half, -half, 0.0));
+        assert_near(max, dvec3(-half + width, half, 0.0));
+        let [min, max] = right.aabb();
+        assert_near(min, dvec3(half - width, -half, 0.0));
+        assert_near(max, dvec3(half, half, 0.0));
+        assert_near(matrix.aabb()[0], dvec3(-half, -half, 0.0));
+        assert_near(matrix.aabb()[1], dvec3(half, half, 0.0));
+
+        assert_eq!(matrix.get_entries().len(), 4);
+        assert_eq!(matrix.get_rows().len(), 2);
+        assert_eq!(matrix.get_columns()[1].len(), 2);
+
+        matrix.shift(DVec3::X);
+        assert_near(
+            matrix.get_brackets()[0].aabb()[0],
+            dvec3(1.0 - half, -half, 0.0),
+        );
+
+        let old = matrix.replace_entry(0, 1, Square::new(0.5));
+        assert_near(old.center, dvec3(1.0 + half / 2.0, half / 2.0, 0.0));
+        assert_near(matrix.get_entry(0, 1).unwrap().center, old.center);
+    }
+}
@@ -10,23 +10,42 @@
 // pub mod arrow;
 /// Geometry items
 pub mod geometry;
+/// LaTeX math items
+pub mod latex;
+/// Matrix items
+pub mod matrix;
+/// Number plane items
+pub mod number_plane;
 /// Svg item
 pub mod svg;
+/// Table items
+pub mod table;
 /// Simple text items
 pub mod text;
 /// Typst items
 pub mod typst;
 
 use color::{AlphaColor, Srgb, palette::css};
-use glam::{DVec3, Vec4, vec4};
+use std::{f64::consts::PI, sync::OnceLock};
+
+use glam::{DQuat, DVec3, Vec4, vec4};
+use itertools::Itertools;
 use ranim_core::anchor::Aabb;
 use ranim_core::core_item::CoreItem;
-use ranim_core::{Extract, color, glam};
+use ranim_core::{
+    Extract, color, glam,
+    utils::bezier::{PathBuilder, trim_quad_bezier},
+};
 
 use ranim_core::{
     components::{PointVec, VecResizeTrait, rgba::Rgba, vpoint::VPointVec, width::Width},
-    prelude::{Alignable, Empty, FillColor, Opacity, Partial, StrokeWidth},
-    traits::{PointsFunc, RotateTransform, ScaleTransform, ShiftTransform, StrokeColor},
+    prelude::{
+        Alignable, ArcLengthAlignable, Empty, FillColor, Interpolatable, Opacity, Partial,
+        StrokeWidth,
+    },
+    traits::{
+        FilledArea, PointsFunc, RotateTransform, ScaleTransform, ShiftTransform, StrokeColor,
+    },
 };
 
 /// A vectorized item.
@@ -37,6 +56,12 @@ use ranim_core::{
 /// - [`VItem::stroke_rgbas`]: the stroke colors of the item, see [`Rgba`].
 /// - [`VItem::fill_rgbas`]: the fill colors of the item, see [`Rgba`].
 ///
+/// Whether the fill and the stroke of each subpath are drawn is set by
+/// [`VItem::draw_modes`], see [`VItem::set_subpath_draw_mode`].
+///
+/// Optionally, a [`BackgroundStroke`] can be drawn behind it, see [`VItem::set_background_stroke`],
+/// and the fill can be a [`FillPattern`], see [`VItem::set_fill_pattern`].
+///
 /// You can construct a [`VItem`] from a list of VPoints, see [`VPointVec`]:
 ///
 /// ```rust
@@ -52,6 +77,8 @@ pub struct VItem {
     /// If `None`, the normal will be computed from the first three points at render time.
     pub normal: Option<DVec3>,
     /// vpoints data
+    ///
+    /// The [`Aabb`] of the item is cached, call [`VItem::invalidate_aabb`] after mutating this directly.
     pub vpoints: VPointVec,
     /// stroke widths
     pub stroke_widths: PointVec<Width>,
@@ -59,6 +86,42 @@ pub struct VItem {
     pub stroke_rgbas: PointVec<Rgba>,
     /// fill rgbas
     pub fill_rgbas: PointVec<Rgba>,
+    /// draw modes, see [`VItem::set_subpath_draw_mode`]
+    pub draw_modes: PointVec<SubpathDrawMode>,
+    /// The stroke drawn behind the fill and the stroke, see [`VItem::set_background_stroke`]
+    pub background_stroke: Option<BackgroundStroke>,
+    /// The pattern of the fill, see [`VItem::set_fill_pattern`]
+    pub fill_pattern: FillPattern,
+    aabb_cache: AabbCache,
+}
+
+/// The cached [`Aabb`] of a [`VItem`], it is ignored when comparing items.
+#[derive(Debug, Clone, Default)]
+struct AabbCache(OnceLock<[DVec3; 2]>);
+
+impl PartialEq for AabbCache {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+/// A stroke drawn behind a [`VItem`], used to create halos/outlines that
+/// keep thin text or lines legible over busy backgrounds.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BackgroundStroke {
+    /// The color of the stroke
+    pub rgba: Rgba,
+    /// The width of the stroke
+    pub width: Width,
+}
+
+impl Interpolatable for BackgroundStroke {
+    fn lerp(&self, target: &Self, t: f64) -> Self {
+        Self {
+            rgba: self.rgba.lerp(&target.rgba, t),
+            width: self.width.lerp(&target.width, t),
+        }
+    }
 }
 
 impl ranim_core::traits::Interpolatable for VItem {
@@ -74,6 +137,21 @@ impl ranim_core::traits::Interpolatable for VItem {
             stroke_widths: self.stroke_widths.lerp(&target.stroke_widths, t),
             stroke_rgbas: self.stroke_rgbas.lerp(&target.stroke_rgbas, t),
             fill_rgbas: self.fill_rgbas.lerp(&target.fill_rgbas, t),
+            draw_modes: self.draw_modes.lerp(&target.draw_modes, t),
+            // A missing background stroke fades in/out from a transparent one
+            background_stroke: match (self.background_stroke, target.background_stroke) {
+                (Some(a), Some(b)) => Some(a.lerp(&b, t)),
+                (Some(a), None) => Some(a.lerp(&a.with_transparent(), t)),
+                (None, Some(b)) => Some(b.with_transparent().lerp(&b, t)),
+                (None, None) => None,
+            },
+            // A pattern can't be interpolated, so it switches at the half way
+            fill_pattern: if t < 0.5 {
+                self.fill_pattern
+            } else {
+                target.fill_pattern
+            },
+            aabb_cache: AabbCache::default(),
         }
     }
 }
@@ -81,19 +159,28 @@ impl ranim_core::traits::Interpolatable for VItem {
 impl PointsFunc for VItem {
     fn apply_points_func(&mut self, f: impl Fn(&mut [DVec3])) -> &mut Self {
         self.vpoints.apply_points_func(f);
+        self.invalidate_aabb();
         self
     }
 }
 
 impl Aabb for VItem {
+    /// The aabb is cached until the points are mutated, see [`VItem::invalidate_aabb`].
     fn aabb(&self) -> [DVec3; 2] {
-        self.vpoints.aabb()
+        *self.aabb_cache.0.get_or_init(|| self.vpoints.aabb())
+    }
+}
+
+impl FilledArea for VItem {
+    fn filled_area(&self) -> f64 {
+        self.vpoints.filled_area()
     }
 }
 
 impl ShiftTransform for VItem {
     fn shift(&mut self, shift: DVec3) -> &mut Self {
         self.vpoints.shift(shift);
+        self.invalidate_aabb();
         self
     }
 }
@@ -101,6 +188,7 @@ impl ShiftTransform for VItem {
 impl RotateTransform for VItem {
     fn rotate_on_axis(&mut self, axis: DVec3, angle: f64) -> &mut Self {
         self.vpoints.rotate_on_axis(axis, angle);
+        self.invalidate_aabb();
         if let Some(ref mut n) = self.normal {
             *n = DVec3::rotate_axis(*n, axis, angle);
         }
@@ -111,6 +199,7 @@ impl RotateTransform for VItem {
 impl ScaleTransform for VItem {
     fn scale(&mut self, scale: DVec3) -> &mut Self {
         self.vpoints.scale(scale);
+        self.invalidate_aabb();
         self
     }
 }
@@ -123,9 +212,16 @@ impl ScaleTransform for VItem {
 // }
 
 /// Default stroke width
-pub use ranim_core::core_item::vitem::DEFAULT_STROKE_WIDTH;
+pub use ranim_core::core_item::vitem::{DEFAULT_STROKE_WIDTH, FillPattern};
 
 impl VItem {
+    /// Clear the cached [`Aabb`] of the item.
+    ///
+    /// The methods of the item do this themselves, it is only needed after
+    /// mutating [`VItem::vpoints`] directly.
+    pub fn invalidate_aabb(&mut self) {
+        self.aabb_cache.0.take();
+    }
     /// Close the VItem
     pub fn close(&mut self) -> &mut Self {
         if self.vpoints.last() != self.vpoints.first() && !self.vpoints.is_empty() {
@@ -135,15 +231,144 @@ impl VItem {
         }
         self
     }
+    /// Close every subpath with a line from its end back to its start, so that it is filled.
+    ///
+    /// Unlike [`VItem::close`], which connects the end of the last subpath to the start of
+    /// the first one, each subpath is closed on its own, see [`VPointVec::close_subpath`].
+    pub fn close_path(&mut self) -> &mut Self {
+        // Closing a subpath may join the next one into it
+        let mut idx = 0;
+        while idx < self.vpoints.get_subpath_closed_flags().len() {
+            self.close_subpath(idx);
+            idx += 1;
+        }
+        self
+    }
+    /// Open every subpath by breaking the connection from its end back to its start,
+    /// so that it is not filled, see [`VPointVec::open_subpath`].
+    pub fn open_path(&mut self) -> &mut Self {
+        // Opening a subpath may split a new one off after it
+        for idx in (0..self.vpoints.get_subpath_closed_flags().len()).rev() {
+            self.open_subpath(idx);
+        }
+        self
+    }
+    /// Close the subpath at `idx`, see [`VPointVec::close_subpath`].
+    pub fn close_subpath(&mut self, idx: usize) -> &mut Self {
+        let Some(anchors) = self.subpath_anchor_ranges().get(idx).cloned() else {
+            return self;
+        };
+        let subpath_cnt = self.vpoints.get_subpaths().len();
+        let anchor_cnt = self.vpoints.len().div_ceil(2);
+        self.vpoints.close_subpath(idx);
+        if self.vpoints.get_subpaths().len() < subpath_cnt {
+            // Joined with the next subpath, whose first anchor is the same as the end
+            self.splice_attrs(anchors.end..anchors.end + 1, None);
+        } else if self.vpoints.len().div_ceil(2) > anchor_cnt {
+            // The closing line ends with the attrs of the end
+            self.splice_attrs(anchors.end..anchors.end, Some(anchors.end - 1));
+        }
+        self.invalidate_aabb();
+        self
+    }
+    /// Open the subpath at `idx`, see [`VPointVec::open_subpath`].
+    pub fn open_subpath(&mut self, idx: usize) -> &mut Self {
+        let Some(anchors) = self.subpath_anchor_ranges().get(idx).cloned() else {
+            return self;
+        };
+        let subpath_cnt = self.vpoints.get_subpaths().len();
+        self.vpoints.open_subpath(idx);
+        let new_anchors = self.subpath_anchor_ranges()[idx].clone();
+        if self.vpoints.get_subpaths().len() > subpath_cnt {
+            // The split off edge starts with a copy of the new end of the subpath
+            let kept = anchors.start + new_anchors.len() + 1;
+            self.splice_attrs(kept..anchors.end, Some(kept - 2));
+        } else {
+            self.splice_attrs(new_anchors.end..anchors.end, None);
+        }
+        self.invalidate_aabb();
+        self
+    }
+    /// Whether each subpath is closed, see [`VPointVec::get_subpath_closed_flags`].
+    pub fn subpath_closed_flags(&self) -> Vec<bool> {
+        self.vpoints.get_subpath_closed_flags()
+    }
+    /// Set whether the fill and the stroke of the subpath at `idx` are drawn, see [`SubpathDrawMode`].
+    ///
+    /// Nothing happens if `idx` is out of range.
+    pub fn set_subpath_draw_mode(&mut self, idx: usize, mode: SubpathDrawMode) -> &mut Self {
+        if let Some(anchors) = self.subpath_anchor_ranges().get(idx) {
+            self.draw_modes[anchors.clone()].fill(mode);
+        }
+        self
+    }
+    /// The [`SubpathDrawMode`] of each subpath, see [`VItem::set_subpath_draw_mode`].
+    pub fn subpath_draw_modes(&self) -> Vec<SubpathDrawMode> {
+        self.subpath_anchor_ranges()
+            .into_iter()
+            .map(|anchors| {
+                self.draw_modes
+                    .get(anchors.start)
+                    .copied()
+                    .unwrap_or_default()
+            })
+            .collect()
+    }
+    /// The range of the anchors, and the attributes, of each subpath.
+    fn subpath_anchor_ranges(&self) -> Vec<std::ops::Range<usize>> {
+        if self.vpoints.is_empty() {
+            return vec![];
+        }
+        // The subpaths are joined by repeating the end anchor as a handle,
+        // so the anchors of the subpaths are contiguous
+        let mut anchor_offset = 0;
+        self.vpoints
+            .get_subpaths()
+            .iter()
+            .map(|subpath| {
+                let anchors = anchor_offset..anchor_offset + subpath.len().div_ceil(2);
+                anchor_offset = anchors.end;
+                anchors
+            })
+            .collect()
+    }
+    /// Remove the attrs of the anchors in `removed`, then repeat the attrs at `repeated`.
+    fn splice_attrs(&mut self, removed: std::ops::Range<usize>, repeated: Option<usize>) {
+        fn splice<T: Clone>(
+            attrs: &mut Vec<T>,
+            removed: std::ops::Range<usize>,
+            repeated: Option<usize>,
+        ) {
+            attrs.drain(removed.start.min(attrs.len())..removed.end.min(attrs.len()));
+            if let Some(idx) = repeated.filter(|&idx| idx < attrs.len()) {
+                attrs.insert(idx + 1, attrs[idx].clone());
+            }
+        }
+        splice(&mut self.stroke_rgbas, removed.clone(), repeated);
+        splice(&mut self.stroke_widths, removed.clone(), repeated);
+        splice(&mut self.fill_rgbas, removed.clone(), repeated);
+        splice(&mut self.draw_modes, removed, repeated);
+        // In case the attrs were out of step with the anchors
+        self.resize_attrs_preserving_order();
+    }
+    fn resize_attrs_preserving_order(&mut self) {
+        let len = self.vpoints.len().div_ceil(2);
+        self.stroke_rgbas.resize_preserving_order(len);
+        self.stroke_widths.resize_preserving_order(len);
+        self.fill_rgbas.resize_preserving_order(len);
+        self.draw_modes.resize_preserving_order(len);
+    }
     /// Shrink to center
     pub fn shrink(&mut self) -> &mut Self {
         let bb = self.aabb();
         self.vpoints.0 = vec![bb[1]; self.vpoints.len()];
+        self.invalidate_aabb();
         self
     }
     /// Set the vpoints of the VItem
     pub fn set_points(&mut self, vpoints: Vec<DVec3>) {
         self.vpoints.0 = vpoints;
+        self.invalidate_aabb();
     }
     /// Get anchor points
     pub fn get_anchor(&self, idx: usize) -> Option<&DVec3> {
@@ -163,38 +388,456 @@ impl VItem {
         let stroke_widths = vec![DEFAULT_STROKE_WIDTH.into(); vpoints.len().div_ceil(2)];
         let stroke_rgbas = vec![vec4(1.0, 1.0, 1.0, 1.0).into(); vpoints.len().div_ceil(2)];
         let fill_rgbas = vec![vec4(0.0, 0.0, 0.0, 0.0).into(); vpoints.len().div_ceil(2)];
+        let draw_modes = vec![SubpathDrawMode::Both; vpoints.len().div_ceil(2)];
         Self {
             normal: None,
             vpoints: VPointVec(vpoints),
             stroke_rgbas: stroke_rgbas.into(),
             stroke_widths: stroke_widths.into(),
             fill_rgbas: fill_rgbas.into(),
+            draw_modes: draw_modes.into(),
+            background_stroke: None,
+            fill_pattern: FillPattern::Solid,
+            aabb_cache: AabbCache::default(),
+        }
+    }
+    /// Become the `range` part of `other`, with the subpaths drawn in the given [`PartialMode`].
+    ///
+    /// [`Partial::get_partial`] is the same as [`PartialMode::Sequential`].
+    pub fn become_partial(
+        &mut self,
+        other: &Self,
+        range: std::ops::Range<f64>,
+        mode: PartialMode,
+    ) -> &mut Self {
+        *self = match mode {
+            PartialMode::Sequential => other.get_partial(range),
+            PartialMode::Simultaneous => other.get_partial_simultaneous(range),
+        };
+        self
+    }
+    fn get_partial_simultaneous(&self, range: std::ops::Range<f64>) -> Self {
+        let subpaths = self.vpoints.get_subpaths();
+        if subpaths.len() <= 1 {
+            return self.get_partial(range);
         }
+        let mut partial: Option<Self> = None;
+        let mut anchor_offset = 0;
+        for subpath in subpaths {
+            // The subpaths are joined by repeating the end anchor as a handle,
+            // so the anchors, and the attributes, of the subpaths are contiguous
+            let attrs = anchor_offset..anchor_offset + subpath.len().div_ceil(2);
+            anchor_offset = attrs.end;
+            let is_drawn = subpath.len() >= 3;
+            let mut sub = Self {
+                vpoints: VPointVec(subpath),
+                stroke_widths: self.stroke_widths[attrs.clone()].to_vec().into(),
+                stroke_rgbas: self.stroke_rgbas[attrs.clone()].to_vec().into(),
+                fill_rgbas: self.fill_rgbas[attrs.clone()].to_vec().into(),
+                draw_modes: self.draw_modes[attrs].to_vec().into(),
+                aabb_cache: AabbCache::default(),
+                ..self.clone()
+            };
+            if is_drawn {
+                sub = sub.get_partial(range.clone());
+            }
+            match partial.as_mut() {
+                None => partial = Some(sub),
+                Some(partial) => {
+                    let end = *partial.vpoints.last().unwrap();
+                    partial.vpoints.push(end);
+                    partial.vpoints.extend(sub.vpoints.iter());
+                    partial.stroke_widths.extend(sub.stroke_widths.iter());
+                    partial.stroke_rgbas.extend(sub.stroke_rgbas.iter());
+                    partial.fill_rgbas.extend(sub.fill_rgbas.iter());
+                    partial.draw_modes.extend(sub.draw_modes.iter());
+                }
+            }
+        }
+        partial.unwrap()
     }
     /// Extend vpoints of the VItem
     pub fn extend_vpoints(&mut self, vpoints: &[DVec3]) {
         self.vpoints.extend(vpoints.to_vec());
+        self.invalidate_aabb();
 
         let len = self.vpoints.len();
         self.fill_rgbas.resize_with_last(len.div_ceil(2));
         self.stroke_rgbas.resize_with_last(len.div_ceil(2));
         self.stroke_widths.resize_with_last(len.div_ceil(2));
+        self.draw_modes.resize_with_last(len.div_ceil(2));
+    }
+    /// Append the subpaths of `other` after the ones of the item, with their attributes.
+    ///
+    /// The normal, the background stroke and the fill pattern of the item are kept.
+    pub fn append(&mut self, other: &Self) -> &mut Self {
+        if let Some(end) = self.vpoints.last().copied() {
+            self.vpoints.push(end);
+        }
+        self.vpoints.extend(other.vpoints.iter());
+        self.stroke_widths.extend(other.stroke_widths.iter());
+        self.stroke_rgbas.extend(other.stroke_rgbas.iter());
+        self.fill_rgbas.extend(other.fill_rgbas.iter());
+        self.draw_modes.extend(other.draw_modes.iter());
+        self.invalidate_aabb();
+        self
     }
 
+    /// The points with the render flags of their segments as `w`,
+    /// see [`ranim_core::core_item::vitem::VItem::points`].
     pub(crate) fn get_render_points(&self) -> Vec<Vec4> {
         self.vpoints
             .iter()
             .zip(self.vpoints.get_closepath_flags())
-            .map(|(p, f)| p.as_vec3().extend(f.into()))
+            .enumerate()
+            .map(|(i, (p, closed))| {
+                let mode = self.draw_modes.get(i / 2).copied().unwrap_or_default();
+                p.as_vec3().extend(mode.render_flags(closed))
+            })
             .collect()
     }
-    /// Put start and end on
+    /// Subdivide every subpath to have at least `n` segments, see [`VPointVec::with_min_curve_resolution`].
+    ///
+    /// The shape is kept, to make an arc viewed closely smoother, sample more segments from it
+    /// with [`geometry::EllipticArc::with_min_curve_resolution`] instead.
+    pub fn with_min_curve_resolution(mut self, n: usize) -> Self {
+        let vpoints = std::mem::replace(&mut self.vpoints, VPointVec(vec![]));
+        self.vpoints = vpoints.with_min_curve_resolution(n);
+        self.invalidate_aabb();
+        let len = self.vpoints.len().div_ceil(2);
+        self.stroke_rgbas.resize_preserving_order(len);
+        self.stroke_widths.resize_preserving_order(len);
+        self.fill_rgbas.resize_preserving_order(len);
+        self.draw_modes.resize_preserving_order(len);
+        self
+    }
+    /// The total arc length of the item, see [`VPointVec::arc_length`].
+    pub fn arc_length(&self) -> f64 {
+        self.vpoints.arc_length()
+    }
+    /// Sample `n` points uniformly spaced by arc length along the item,
+    /// see [`VPointVec::sample_points`].
+    ///
+    /// Useful for decorating a path, like placing dots or arrows at intervals.
+    pub fn sample_points(&self, n: usize) -> Vec<DVec3> {
+        self.vpoints.sample_points(n)
+    }
+    /// Split the item at `alpha` of its arc length into two items,
+    /// see [`VPointVec::segment_at_arc_length`].
+    ///
+    /// The first item ends and the second item starts at the split point, the attributes
+    /// there are interpolated. A split at an end of the item gives a single point on that side.
+    pub fn split_at(&self, alpha: f64) -> (Self, Self) {
+        let (seg, t) = self.vpoints.segment_at_arc_length(alpha);
+        // The anchor index of the split point, or `None` if it is within the segment
+        let anchor = if t <= 0.0 {
+            Some(seg)
+        } else if t >= 1.0 {
+            Some(seg + 1)
+        } else {
+            None
+        };
+
+        let (mut first, mut second) = match anchor {
+            Some(anchor) => (
+                self.vpoints[..=anchor * 2].to_vec(),
+                self.vpoints[anchor * 2..].to_vec(),
+            ),
+            None => {
+                let bezier = self.vpoints.get_seg(seg).unwrap();
+                let mut first = self.vpoints[..seg * 2].to_vec();
+                first.extend(trim_quad_bezier(bezier, 0.0, t));
+                let mut second = trim_quad_bezier(bezier, t, 1.0).to_vec();
+                second.extend_from_slice(&self.vpoints[seg * 2 + 3..]);
+                (first, second)
+            }
+        };
+        // A single point is kept as a degenerated segment
+        if first.len() == 1 {
+            first = vec![first[0]; 3];
+        }
+        if second.len() == 1 {
+            second = vec![second[0]; 3];
+        }
+
+        let (first_stroke_widths, second_stroke_widths) =
+            split_attrs(&self.stroke_widths, seg, t, anchor);
+        let (first_stroke_rgbas, second_stroke_rgbas) =
+            split_attrs(&self.stroke_rgbas, seg, t, anchor);
+        let (first_fill_rgbas, second_fill_rgbas) = split_attrs(&self.fill_rgbas, seg, t, anchor);
+        let (first_draw_modes, second_draw_modes) = split_attrs(&self.draw_modes, seg, t, anchor);
+        let mut first = Self {
+            vpoints: VPointVec(first),
+            stroke_widths: first_stroke_widths,
+            stroke_rgbas: first_stroke_rgbas,
+            fill_rgbas: first_fill_rgbas,
+            draw_modes: first_draw_modes,
+            normal: self.normal,
+            background_stroke: self.background_stroke,
+            fill_pattern: self.fill_pattern,
+            aabb_cache: AabbCache::default(),
+        };
+        let mut second = Self {
+            vpoints: VPointVec(second),
+            stroke_widths: second_stroke_widths,
+            stroke_rgbas: second_stroke_rgbas,
+            fill_rgbas: second_fill_rgbas,
+            draw_modes: second_draw_modes,
+            normal: self.normal,
+            background_stroke: self.background_stroke,
+            fill_pattern: self.fill_pattern,
+            aabb_cache: AabbCache::default(),
+        };
+        first.resize_attrs_preserving_order();
+        second.resize_attrs_preserving_order();
+
+        // A split at the end of a subpath leaves the jump to the next one at the start
+        while second.vpoints.len() > 3 && second.vpoints[0] == second.vpoints[1] {
+            second.vpoints.drain(..2);
+            second.stroke_widths.remove(0);
+            second.stroke_rgbas.remove(0);
+            second.fill_rgbas.remove(0);
+            second.draw_modes.remove(0);
+        }
+        (first, second)
+    }
+    /// Put the start and end points of the item on `start` and `end`.
+    ///
+    /// The whole path is scaled, rotated and shifted together, so its shape is kept while its
+    /// first and last points land on the targets. This works for any path, e.g. repositioning
+    /// a curved arrow or a multi-segment polyline by its endpoints:
+    ///
+    /// ```rust,ignore
+    /// let mut path = VItem::from_vpoints(points);
+    /// path.put_start_and_end_on(DVec3::NEG_X, DVec3::X);
+    /// ```
+    ///
+    /// Nothing happens if the item's start and end points are the same, e.g. a closed path.
     pub fn put_start_and_end_on(&mut self, start: DVec3, end: DVec3) -> &mut Self {
+        if let (Some(normal), Some(&cur_start), Some(&cur_end)) =
+            (self.normal, self.vpoints.first(), self.vpoints.last())
+        {
+            // The normal is rotated with a point off the path
+            let mut probe = VPointVec(vec![cur_start, cur_start + normal, cur_end]);
+            probe.put_start_and_end_on(start, end);
+            self.normal = Some((probe[1] - probe[0]).normalize_or(normal));
+        }
         self.vpoints.put_start_and_end_on(start, end);
+        self.invalidate_aabb();
+        self
+    }
+    /// Round the corners of the item with arc fillets of the given radius.
+    ///
+    /// Only the corners of closed subpaths made of straight segments are rounded,
+    /// other subpaths are kept as is. The radius is clamped at each corner so that
+    /// a fillet takes at most half of its adjacent edges.
+    pub fn round_corners(&mut self, radius: f64) -> &mut Self {
+        let mut builder = PathBuilder::new();
+        for subpath in self.vpoints.get_subpaths() {
+            let anchors = subpath.iter().step_by(2).cloned().collect::<Vec<_>>();
+            let is_closed = anchors.len() > 3
+                && anchors
+                    .first()
+                    .unwrap()
+                    .distance_squared(*anchors.last().unwrap())
+                    < f64::EPSILON;
+            let is_polygon = subpath.iter().tuple_windows().step_by(2).all(|(a, h, b)| {
+                (h - a).cross(b - a).length_squared() < f64::EPSILON * (b - a).length_squared()
+            });
+            if is_closed && is_polygon {
+                let mut corners = anchors;
+                corners.pop();
+                corners.dedup_by(|a, b| a.distance_squared(*b) < f64::EPSILON);
+                append_rounded_polygon(&mut builder, &corners, radius);
+            } else {
+                builder.move_to(subpath[0]);
+                for (h, p) in subpath.iter().skip(1).tuples() {
+                    builder.quad_to(*h, *p);
+                }
+            }
+        }
+
+        self.vpoints.0 = builder.vpoints().to_vec();
+        self.invalidate_aabb();
+        let len = self.vpoints.len().div_ceil(2);
+        self.stroke_rgbas.resize_preserving_order(len);
+        self.stroke_widths.resize_preserving_order(len);
+        self.fill_rgbas.resize_preserving_order(len);
+        self.draw_modes.resize_preserving_order(len);
+        self
+    }
+    /// Align the points of `self` and `other` in place, so that [`ranim_core::traits::Interpolatable::lerp`]
+    /// between them is well-defined for all `t`.
+    ///
+    /// This is the same preparation the morph animation does before playing,
+    /// it does nothing if they are already aligned.
+    pub fn align_points_to(&mut self, other: &mut Self) -> &mut Self {
+        if !self.is_aligned(other) {
+            self.align_with(other);
+        }
+        self
+    }
+    /// The [`Aabb`] of the item with its stroke included.
+    ///
+    /// [`Aabb::aabb`] only covers the points, while the stroke extends
+    /// up to the stroke width on both sides of the path.
+    pub fn stroke_aabb(&self) -> [DVec3; 2] {
+        let [min, max] = self.aabb();
+        let width = self
+            .stroke_widths
+            .iter()
+            .fold(0.0f32, |acc, w| acc.max(w.0)) as f64;
+        [min - DVec3::splat(width), max + DVec3::splat(width)]
+    }
+    /// Copy the style (fill colors, fill pattern, stroke colors, stroke widths and background stroke) of `other`,
+    /// without touching the points.
+    ///
+    /// If the point counts differ, the style arrays of `other` are resampled
+    /// to match the count of `self`.
+    pub fn match_style(&mut self, other: &Self) -> &mut Self {
+        let len = self.vpoints.len().div_ceil(2);
+        if !other.fill_rgbas.is_empty() {
+            self.fill_rgbas = other.fill_rgbas.clone();
+            self.fill_rgbas.resize_preserving_order(len);
+        }
+        if !other.stroke_rgbas.is_empty() {
+            self.stroke_rgbas = other.stroke_rgbas.clone();
+            self.stroke_rgbas.resize_preserving_order(len);
+        }
+        if !other.stroke_widths.is_empty() {
+            self.stroke_widths = other.stroke_widths.clone();
+            self.stroke_widths.resize_preserving_order(len);
+        }
+        self.background_stroke = other.background_stroke;
+        self.fill_pattern = other.fill_pattern;
+        self
+    }
+    /// Set a [`BackgroundStroke`] drawn behind the item, like manim's `set_background_stroke`.
+    ///
+    /// It is drawn with the same path, before the fill and the stroke of the item,
+    /// so a width larger than the stroke width makes a halo around the item.
+    pub fn set_background_stroke(&mut self, color: AlphaColor<Srgb>, width: f32) -> &mut Self {
+        self.background_stroke = Some(BackgroundStroke {
+            rgba: color.into(),
+            width: Width(width),
+        });
+        self
+    }
+    /// Remove the [`BackgroundStroke`]
+    pub fn clear_background_stroke(&mut self) -> &mut Self {
+        self.background_stroke = None;
+        self
+    }
+    /// Set the [`FillPattern`] of the fill.
+    ///
+    /// The pattern masks the fill colors, so the fill opacity and the even-odd rule still apply:
+    ///
+    /// ```rust,ignore
+    /// let mut square = VItem::from(Square::new(2.0));
+    /// square
+    ///     .set_fill_color(manim::BLUE_C)
+    ///     .set_fill_pattern(FillPattern::diagonal(0.2, 0.05));
+    /// ```
+    pub fn set_fill_pattern(&mut self, pattern: FillPattern) -> &mut Self {
+        self.fill_pattern = pattern;
+        self
+    }
+    /// The core item of the [`BackgroundStroke`], with no fill.
+    fn background_stroke_core_item(&self) -> Option<ranim_core::core_item::vitem::VItem> {
+        let background_stroke = self.background_stroke?;
+        let len = self.vpoints.len().div_ceil(2);
+        Some(ranim_core::core_item::vitem::VItem {
+            normal: self.normal.map(|n| n.as_vec3()),
+            // The halo goes around all the subpaths, whatever their draw modes are
+            points: self
+                .get_render_points()
+                .into_iter()
+                .map(|p| p.with_w(p.w % 2.0))
+                .collect(),
+            fill_rgbas: vec![Vec4::ZERO.into(); len],
+            stroke_rgbas: vec![background_stroke.rgba; len],
+            stroke_widths: vec![background_stroke.width; len],
+            fill_pattern: FillPattern::Solid,
+        })
+    }
+}
+
+/// Extract a group of [`VItem`]s with all the background strokes behind all the items,
+/// so that the halo of an item doesn't cover its neighbours (e.g. glyphs of a text).
+pub(crate) fn extract_vitems_with_background(vitems: &[VItem], buf: &mut Vec<CoreItem>) {
+    vitems
+        .iter()
+        .filter_map(|vitem| vitem.background_stroke_core_item())
+        .for_each(|background| background.extract_into(buf));
+    vitems.iter().for_each(|vitem| {
+        ranim_core::core_item::vitem::VItem::from(vitem.clone()).extract_into(buf)
+    });
+}
+
+impl BackgroundStroke {
+    fn with_transparent(mut self) -> Self {
+        self.rgba.set_opacity(0.0);
         self
     }
 }
 
+/// Append a closed polygon with rounded corners to the builder.
+fn append_rounded_polygon(builder: &mut PathBuilder, corners: &[DVec3], radius: f64) {
+    let n = corners.len();
+    // The start and end points of the fillet on each corner
+    let fillets = (0..n)
+        .map(|i| {
+            let (prev, p, next) = (corners[(i + n - 1) % n], corners[i], corners[(i + 1) % n]);
+            let (d1, d2) = ((prev - p).normalize(), (next - p).normalize());
+            let angle = d1.angle_between(d2);
+            if radius <= 0.0 || angle.is_nan() || angle > PI - 1e-6 {
+                return (p, p);
+            }
+            let max_t = prev.distance(p).min(next.distance(p)) / 2.0;
+            let t = (radius / (angle / 2.0).tan()).min(max_t);
+            (p + d1 * t, p + d2 * t)
+        })
+        .collect::<Vec<_>>();
+
+    builder.move_to(fillets[0].0);
+    for (i, &(start, end)) in fillets.iter().enumerate() {
+        let corner = corners[i];
+        if start.distance_squared(end) > f64::EPSILON {
+            append_fillet(builder, start, corner, end);
+        }
+        let next_start = fillets[(i + 1) % n].0;
+        if end.distance_squared(next_start) > f64::EPSILON {
+            builder.line_to(next_start);
+        }
+    }
+    builder.close_path();
+}
+
+/// Append a circular arc tangent to `corner - start` at `start` and `end - corner` at `end`.
+fn append_fillet(builder: &mut PathBuilder, start: DVec3, corner: DVec3, end: DVec3) {
+    let (d1, d2) = ((start - corner).normalize(), (end - corner).normalize());
+    let half_angle = d1.angle_between(d2) / 2.0;
+    let radius = start.distance(corner) * half_angle.tan();
+    let center = corner + (d1 + d2).normalize() * radius / half_angle.sin();
+
+    let (v_start, v_end) = (start - center, end - center);
+    let sweep = v_start.angle_between(v_end);
+    let axis = v_start.cross(v_end).normalize();
+    // Each quadratic bezier approximates at most a quarter of a right angle
+    let segs = (sweep / (PI / 8.0)).ceil().max(1.0) as usize;
+    let step = sweep / segs as f64;
+    for k in 0..segs {
+        let rotate = |angle: f64| DQuat::from_axis_angle(axis, angle) * v_start;
+        let handle = center + rotate(step * (k as f64 + 0.5)) / (step / 2.0).cos();
+        let p = if k + 1 == segs {
+            end
+        } else {
+            center + rotate(step * (k + 1) as f64)
+        };
+        builder.quad_to(handle, p);
+    }
+}
+
 impl From<VItem> for ranim_core::core_item::vitem::VItem {
     fn from(value: VItem) -> Self {
         Self {
@@ -203,6 +846,7 @@ impl From<VItem> for ranim_core::core_item::vitem::VItem {
             fill_rgbas: value.fill_rgbas.iter().cloned().collect(),
             stroke_rgbas: value.stroke_rgbas.iter().cloned().collect(),
             stroke_widths: value.stroke_widths.iter().cloned().collect(),
+            fill_pattern: value.fill_pattern,
         }
     }
 }
@@ -210,6 +854,10 @@ impl From<VItem> for ranim_core::core_item::vitem::VItem {
 impl Extract for VItem {
     type Target = CoreItem;
     fn extract_into(&self, buf: &mut Vec<Self::Target>) {
+        // Items extracted later are drawn on top, so the background stroke goes first
+        if let Some(background) = self.background_stroke_core_item() {
+            background.extract_into(buf);
+        }
         ranim_core::core_item::vitem::VItem::from(self.clone()).extract_into(buf);
     }
 }
@@ -221,16 +869,39 @@ impl Alignable for VItem {
             && self.stroke_widths.is_aligned(&other.stroke_widths)
             && self.stroke_rgbas.is_aligned(&other.stroke_rgbas)
             && self.fill_rgbas.is_aligned(&other.fill_rgbas)
+            && self.draw_modes.is_aligned(&other.draw_modes)
     }
     fn align_with(&mut self, other: &mut Self) {
         self.vpoints.align_with(&mut other.vpoints);
+        self.invalidate_aabb();
+        other.invalidate_aabb();
         let len = self.vpoints.len().div_ceil(2);
         self.stroke_rgbas.resize_preserving_order(len);
         other.stroke_rgbas.resize_preserving_order(len);
-        self.stroke_widths.resize_preserving_order(len);
-        other.stroke_widths.resize_preserving_order(len);
+        // The widths are resampled, so that they change smoothly along the new points
+        self.stroke_widths.resample(len);
+        other.stroke_widths.resample(len);
+        self.fill_rgbas.resize_preserving_order(len);
+        self.draw_modes.resize_preserving_order(len);
+        other.fill_rgbas.resize_preserving_order(len);
+        other.draw_modes.resize_preserving_order(len);
+    }
+}
+
+impl ArcLengthAlignable for VItem {
+    fn align_with_by_arc_length(&mut self, other: &mut Self) {
+        self.vpoints.align_with_by_arc_length(&mut other.vpoints);
+        self.invalidate_aabb();
+        other.invalidate_aabb();
+        let len = self.vpoints.len().div_ceil(2);
+        self.stroke_rgbas.resize_preserving_order(len);
+        other.stroke_rgbas.resize_preserving_order(len);
+        self.stroke_widths.resample(len);
+        other.stroke_widths.resample(len);
         self.fill_rgbas.resize_preserving_order(len);
+        self.draw_modes.resize_preserving_order(len);
         other.fill_rgbas.resize_preserving_order(len);
+        other.draw_modes.resize_preserving_order(len);
     }
 }
 
@@ -238,31 +909,132 @@ impl Opacity for VItem {
     fn set_opacity(&mut self, opacity: f32) -> &mut Self {
         self.stroke_rgbas.set_opacity(opacity);
         self.fill_rgbas.set_opacity(opacity);
+        if let Some(background_stroke) = &mut self.background_stroke {
+            background_stroke.rgba.set_opacity(opacity);
+        }
         self
     }
 }
 
 impl Partial for VItem {
+    /// The subpaths are drawn one after another, see [`PartialMode::Sequential`].
     fn get_partial(&self, range: std::ops::Range<f64>) -> Self {
+        let range = self.vpoints.skip_subpath_jumps(range.start)
+            ..self.vpoints.skip_subpath_jumps(range.end);
         let vpoints = self.vpoints.get_partial(range.clone());
         let stroke_rgbas = self.stroke_rgbas.get_partial(range.clone());
         let stroke_widths = self.stroke_widths.get_partial(range.clone());
         let fill_rgbas = self.fill_rgbas.get_partial(range.clone());
+        let draw_modes = self.draw_modes.get_partial(range.clone());
         Self {
             normal: self.normal,
             vpoints,
             stroke_widths,
             stroke_rgbas,
             fill_rgbas,
+            draw_modes,
+            background_stroke: self.background_stroke,
+            fill_pattern: self.fill_pattern,
+            aabb_cache: AabbCache::default(),
         }
     }
+    /// For a single closed subpath, a range crossing `1.0` wraps around its closure,
+    /// see [`VPointVec::get_partial_closed`]. The partial is then closed to be filled.
     fn get_partial_closed(&self, range: std::ops::Range<f64>) -> Self {
-        let mut partial = self.get_partial(range);
+        let is_closed = self.vpoints.get_subpath_closed_flags() == [true];
+        let mut partial = if is_closed {
+            Self {
+                normal: self.normal,
+                vpoints: self.vpoints.get_partial_closed(range.clone()),
+                stroke_widths: self.stroke_widths.get_partial_closed(range.clone()),
+                stroke_rgbas: self.stroke_rgbas.get_partial_closed(range.clone()),
+                fill_rgbas: self.fill_rgbas.get_partial_closed(range.clone()),
+                draw_modes: self.draw_modes.get_partial_closed(range),
+                background_stroke: self.background_stroke,
+                fill_pattern: self.fill_pattern,
+                aabb_cache: AabbCache::default(),
+            }
+        } else {
+            self.get_partial(range)
+        };
         partial.close();
         partial
     }
 }
 
+/// Split the pointwise attrs of [`VItem::split_at`], the split point is the anchor if any,
+/// or else at `t` in the segment `seg`.
+fn split_attrs<T: Interpolatable + Clone>(
+    attrs: &PointVec<T>,
+    seg: usize,
+    t: f64,
+    anchor: Option<usize>,
+) -> (PointVec<T>, PointVec<T>) {
+    match anchor {
+        Some(anchor) => (
+            attrs[..=anchor].to_vec().into(),
+            attrs[anchor..].to_vec().into(),
+        ),
+        None => {
+            let mid = attrs[seg].lerp(&attrs[seg + 1], t);
+            let mut first = attrs[..=seg].to_vec();
+            first.push(mid.clone());
+            let mut second = vec![mid];
+            second.extend_from_slice(&attrs[seg + 1..]);
+            (first.into(), second.into())
+        }
+    }
+}
+
+/// Whether the fill and the stroke of a subpath of a [`VItem`] are drawn,
+/// see [`VItem::set_subpath_draw_mode`].
+///
+/// Useful for icons where some paths are outlines and others are filled.
+/// A subpath that is not filled doesn't take part in the even-odd fill rule of the others.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SubpathDrawMode {
+    /// Both the fill and the stroke are drawn.
+    #[default]
+    Both,
+    /// Only the stroke is drawn.
+    StrokeOnly,
+    /// Only the fill is drawn.
+    FillOnly,
+}
+
+impl SubpathDrawMode {
+    /// The flags of a point for the renderer, see [`ranim_core::core_item::vitem::VItem::points`].
+    fn render_flags(self, closed: bool) -> f32 {
+        let filled = closed && self != Self::StrokeOnly;
+        let stroked = self != Self::FillOnly;
+        match (filled, stroked) {
+            (false, true) => 0.0,
+            (true, true) => 1.0,
+            (false, false) => 2.0,
+            (true, false) => 3.0,
+        }
+    }
+}
+
+impl Interpolatable for SubpathDrawMode {
+    fn lerp(&self, target: &Self, t: f64) -> Self {
+        // A draw mode can't be interpolated, so it switches at the half way
+        if t < 0.5 { *self } else { *target }
+    }
+}
+
+/// How the subpaths of a [`VItem`] are drawn partially, see [`VItem::become_partial`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PartialMode {
+    /// The subpaths are drawn one after another, like writing a word letter by letter.
+    ///
+    /// The jumps between the subpaths are skipped, see [`VPointVec::skip_subpath_jumps`].
+    #[default]
+    Sequential,
+    /// The subpaths are drawn at the same time, each of them partially in the same range.
+    Simultaneous,
+}
+
 impl Empty for VItem {
     fn empty() -> Self {
         Self {
@@ -271,6 +1043,10 @@ impl Empty for VItem {
             stroke_widths: vec![0.0.into(); 2].into(),
             stroke_rgbas: vec![Vec4::ZERO.into(); 2].into(),
             fill_rgbas: vec![Vec4::ZERO.into(); 2].into(),
+            draw_modes: vec![SubpathDrawMode::Both; 2].into(),
+            background_stroke: None,
+            fill_pattern: FillPattern::Solid,
+            aabb_cache: AabbCache::default(),
         }
     }
 }
@@ -322,3 +1098,710 @@ impl StrokeWidth for VItem {
         self
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vitem::geometry::{Circle, Square};
+    use ranim_core::traits::Interpolatable;
+
+    #[test]
+    fn test_move_anchor_to_anchor_of() {
+        use glam::dvec3;
+        use ranim_core::{
+            anchor::AabbPoint,
+            traits::{ShiftTransformExt, With},
+        };
+
+        let circle = Circle::new(1.0).with(|circle| {
+            circle.move_to(dvec3(1.0, 2.0, 0.0));
+        });
+        let mut square = Square::new(2.0);
+        square.move_anchor_to_anchor_of(AabbPoint(DVec3::NEG_X), &circle, AabbPoint(DVec3::X), 0.5);
+        let [min, max] = square.aabb();
+        assert!((min - dvec3(2.5, 1.0, 0.0)).length() < 1e-9);
+        assert!((max - dvec3(4.5, 3.0, 0.0)).length() < 1e-9);
+
+        // Works for groups, with the top edges aligned
+        let mut group = vec![
+            VItem::from(Square::new(1.0)),
+            VItem::from(Square::new(1.0)).with(|item| {
+                item.shift(DVec3::X * 2.0);
+            }),
+        ];
+        group.move_anchor_to_anchor_of(
+            AabbPoint(dvec3(-1.0, 1.0, 0.0)),
+            &circle,
+            AabbPoint(dvec3(1.0, 1.0, 0.0)),
+            0.0,
+        );
+        let [min, max] = group.aabb();
+        assert!((min - dvec3(2.0, 2.0, 0.0)).length() < 1e-9);
+        assert!((max - dvec3(5.0, 3.0, 0.0)).length() < 1e-9);
+    }
+
+    #[test]
+    fn test_align_points_to() {
+        let mut square = VItem::from(Square::new(2.0));
+        let mut circle = VItem::from(Circle::new(1.0));
+        assert!(!square.is_aligned(&circle));
+
+        square.align_points_to(&mut circle);
+        assert!(square.is_aligned(&circle));
+        let (aligned_square, aligned_circle) = (square.clone(), circle.clone());
+
+        // Aligning again changes nothing
+        square.align_points_to(&mut circle);
+        assert_eq!(square.vpoints, aligned_square.vpoints);
+        assert_eq!(circle.vpoints, aligned_circle.vpoints);
+
+        for t in [0.0, 0.3, 0.5, 1.0] {
+            let item = square.lerp(&circle, t);
+            assert_eq!(item.vpoints.len(), square.vpoints.len());
+            assert_eq!(item.stroke_widths.len(), square.stroke_widths.len());
+        }
+    }
+
+    #[test]
+    fn test_round_corners() {
+        use crate::vitem::geometry::{Polygon, Rectangle};
+        use ranim_core::glam::dvec3;
+
+        // Rounding a 4x2 rectangle with radius 1 gives a stadium with the same bbox
+        let mut stadium = VItem::from(Rectangle::new(4.0, 2.0));
+        let [min, max] = stadium.aabb();
+        stadium.round_corners(1.0);
+        let [r_min, r_max] = stadium.aabb();
+        assert!(min.distance(r_min) < 1e-6 && max.distance(r_max) < 1e-6);
+        let center = (min + max) / 2.0;
+        for p in stadium.vpoints.iter().step_by(2) {
+            let p = *p - center;
+            // Points on the caps are on the unit circles centered at (±1, 0)
+            if p.x.abs() > 1.0 + 1e-6 {
+                let cap_center = dvec3(p.x.signum(), 0.0, 0.0);
+                assert!((p.distance(cap_center) - 1.0).abs() < 1e-6);
+            }
+        }
+
+        // The corners of a diamond shrink inwards
+        let r = 0.5;
+        let diamond = Polygon::new(vec![
+            dvec3(1.0, 0.0, 0.0),
+            dvec3(0.0, 1.0, 0.0),
+            dvec3(-1.0, 0.0, 0.0),
+            dvec3(0.0, -1.0, 0.0),
+        ])
+        .round_corners(r);
+        let [min, max] = diamond.aabb();
+        // The arc apex is `r / sin(45°) - r` closer to the center than the corner
+        let expected = 1.0 - (r * 2f64.sqrt() - r);
+        assert!((max.x - expected).abs() < 1e-3 && (min.y + expected).abs() < 1e-3);
+
+        // Too large radius is clamped, a square becomes a circle
+        let circle = Polygon::new(vec![
+            dvec3(1.0, 1.0, 0.0),
+            dvec3(-1.0, 1.0, 0.0),
+            dvec3(-1.0, -1.0, 0.0),
+            dvec3(1.0, -1.0, 0.0),
+        ])
+        .round_corners(10.0);
+        for p in circle.vpoints.iter().step_by(2) {
+            assert!((p.length() - 1.0).abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn test_with_scale_stroke() {
+        use ranim_core::traits::{ScaleHint, ScaleTransformExt, ScaleTransformStrokeExt, With};
+
+        let item = VItem::from(Square::new(2.0)).with(|item| {
+            item.set_stroke_width(0.1);
+        });
+
+        let mut scaled = item.clone();
+        scaled
+            .with_scale_stroke(true)
+            .scale_to(ScaleHint::PorportionalX(4.0));
+        assert!((scaled.aabb_size().x - 4.0).abs() < 1e-6);
+        assert!((scaled.stroke_width() - 0.2).abs() < 1e-6);
+
+        let mut scaled = item.clone();
+        scaled
+            .with_scale_stroke(false)
+            .scale_to(ScaleHint::PorportionalX(4.0));
+        assert!((scaled.aabb_size().x - 4.0).abs() < 1e-6);
+        assert!((scaled.stroke_width() - 0.1).abs() < 1e-6);
+
+        // Consistent with the plain methods
+        let mut a = item.clone();
+        a.with_scale_stroke(true).scale(DVec3::splat(2.0));
+        let mut b = item.clone();
+        b.scale_with_stroke(DVec3::splat(2.0));
+        assert_eq!(a.stroke_widths, b.stroke_widths);
+        let mut a = item.clone();
+        a.with_scale_stroke(false).scale(DVec3::splat(2.0));
+        let mut b = item;
+        b.scale(DVec3::splat(2.0));
+        assert_eq!(a.stroke_widths, b.stroke_widths);
+    }
+
+    #[test]
+    fn test_partial_subpaths() {
+        use ranim_core::traits::{ShiftTransformExt, With};
+
+        let squares = (0..3)
+            .map(|i| {
+                VItem::from(Square::new(1.0)).with(|item| {
+                    item.move_to(DVec3::X * 2.0 * i as f64);
+                })
+            })
+            .collect::<Vec<_>>();
+        let mut item = squares[0].clone();
+        for square in &squares[1..] {
+            let end = *item.vpoints.last().unwrap();
+            item.extend_vpoints(&[end]);
+            item.extend_vpoints(&square.vpoints);
+        }
+        assert_eq!(item.vpoints.get_subpaths().len(), 3);
+        let half = |square: &VItem| square.vpoints.get_partial(0.0..0.5).to_vec();
+
+        // The subpaths are drawn one after another, without pausing at the jumps between them
+        let partial = item.get_partial(0.0..1.0 / 3.0);
+        assert_eq!(
+            partial.vpoints.get_subpaths(),
+            vec![squares[0].vpoints.to_vec()]
+        );
+        let partial = item.get_partial(0.0..0.5);
+        assert_eq!(
+            partial.vpoints.get_subpaths(),
+            vec![squares[0].vpoints.to_vec(), half(&squares[1])]
+        );
+        let partial = item.get_partial(0.0..1.0);
+        assert_eq!(partial.vpoints, item.vpoints);
+
+        // Or at the same time
+        let mut partial = VItem::empty();
+        partial.become_partial(&item, 0.0..0.5, PartialMode::Simultaneous);
+        assert_eq!(
+            partial.vpoints.get_subpaths(),
+            squares.iter().map(half).collect::<Vec<_>>()
+        );
+        assert_eq!(partial.fill_rgbas.len(), partial.vpoints.len().div_ceil(2));
+        partial.become_partial(&item, 0.0..1.0, PartialMode::Simultaneous);
+        assert_eq!(partial.vpoints, item.vpoints);
+    }
+
+    #[test]
+    fn test_partial_closed() {
+        let mut square = VItem::from(Square::new(2.0));
+        // Mark each anchor with its index, to check that the attrs wrap with the points
+        let anchor_cnt = square.vpoints.len().div_ceil(2);
+        square.stroke_widths = (0..anchor_cnt)
+            .map(|i| Width(i as f32))
+            .collect::<Vec<_>>()
+            .into();
+        assert_eq!(square.subpath_closed_flags(), [true]);
+
+        let full = square.get_partial_closed(0.0..1.0);
+        assert_eq!(full.vpoints, square.vpoints);
+        assert_eq!(full.stroke_widths, square.stroke_widths);
+
+        // Through the closure at the first anchor, without a jump or a gap
+        let partial = square.get_partial_closed(0.75..1.25);
+        let (tail, head) = (
+            square.vpoints.get_partial(0.75..1.0),
+            square.vpoints.get_partial(0.0..0.25),
+        );
+        assert_eq!(partial.vpoints[..tail.len()], tail[..]);
+        assert_eq!(
+            partial.vpoints[tail.len()..tail.len() + head.len() - 1],
+            head[1..]
+        );
+        assert_eq!(partial.vpoints.get_subpaths().len(), 1);
+        assert_eq!(partial.subpath_closed_flags(), [true]);
+        assert_eq!(
+            partial.stroke_widths.len(),
+            partial.vpoints.len().div_ceil(2)
+        );
+        let widths = [
+            &square.stroke_widths.get_partial(0.75..1.0)[..],
+            &square.stroke_widths.get_partial(0.0..0.25)[1..],
+        ]
+        .concat();
+        assert_eq!(partial.stroke_widths[..widths.len()], widths[..]);
+
+        let same = square.get_partial_closed(-0.25..0.25);
+        assert_eq!(same.vpoints, partial.vpoints);
+        assert_eq!(same.stroke_widths, partial.stroke_widths);
+
+        // The create anim still draws from the start
+        let mut half = square.get_partial(0.0..0.5);
+        half.close();
+        assert_eq!(square.get_partial_closed(0.0..0.5).vpoints, half.vpoints);
+    }
+
+    #[test]
+    fn test_scale_to_area() {
+        use crate::vitem::geometry::{Line, Rectangle};
+        use glam::dvec3;
+        use ranim_core::traits::{FilledArea, ScaleHint, ScaleTransformExt};
+
+        let mut rect = Rectangle::new(2.0, 1.0);
+        rect.scale_to(ScaleHint::Area(8.0));
+        assert!((rect.width() - 4.0).abs() < 1e-9 && (rect.height() - 2.0).abs() < 1e-9);
+
+        // Works with the other hints, the smaller ratio is used
+        let mut rect = Rectangle::new(2.0, 1.0);
+        rect.scale_to_min(&[ScaleHint::Area(8.0), ScaleHint::PorportionalX(3.0)]);
+        assert!((rect.width() - 3.0).abs() < 1e-9 && (rect.height() - 1.5).abs() < 1e-9);
+
+        // The filled area of a rectangle is the bounding box area
+        let mut vitem = VItem::from(Rectangle::new(2.0, 1.0));
+        assert!((vitem.filled_area() - 2.0).abs() < 1e-9);
+        vitem.scale_to(ScaleHint::filled_area(&vitem, 8.0));
+        assert!((vitem.aabb_size() - dvec3(4.0, 2.0, 0.0)).length() < 1e-9);
+
+        // But not for a circle, the curves are measured exactly
+        let mut circle = VItem::from(Circle::new(1.0));
+        let area = circle.filled_area();
+        assert!(area < 4.0 && (area - std::f64::consts::PI).abs() < 0.02);
+        circle.scale_to(ScaleHint::filled_area(&circle, 2.0 * area));
+        assert!((circle.filled_area() - 2.0 * area).abs() < 1e-9);
+
+        // A hole is subtracted, whatever its winding is
+        let mut ring = VItem::from(Square::new(2.0));
+        let hole = VItem::from(Square::new(1.0));
+        let end = *ring.vpoints.last().unwrap();
+        ring.vpoints.push(end);
+        ring.vpoints.extend(hole.vpoints.iter());
+        assert!((ring.filled_area() - 3.0).abs() < 1e-9);
+
+        // Zero area items are left unscaled
+        let mut line = VItem::from(Line::new(DVec3::ZERO, DVec3::X));
+        assert_eq!(line.filled_area(), 0.0);
+        let aabb = line.aabb();
+        line.scale_to(ScaleHint::Area(4.0));
+        assert_eq!(line.aabb(), aabb);
+        line.scale_to(ScaleHint::filled_area(&line, 4.0));
+        assert_eq!(line.aabb(), aabb);
+    }
+
+    #[test]
+    fn test_scale_to_stroke_aabb() {
+        use ranim_core::traits::{ScaleHint, ScaleTransformExt, ScaleTransformStrokeExt, With};
+
+        let circle = VItem::from(Circle::new(1.0)).with(|item| {
+            item.set_stroke_width(0.2);
+        });
+        let height = |[min, max]: [DVec3; 2]| max.y - min.y;
+        let stroke = |item: &VItem| height(item.stroke_aabb()) - height(item.aabb());
+        assert!((stroke(&circle) - 0.4).abs() < 1e-6);
+
+        // The hint is measured on the points, the stroke is kept as is
+        let scaled = circle.clone().with(|item| {
+            item.scale_to(ScaleHint::PorportionalY(4.0));
+        });
+        assert!((height(scaled.aabb()) - 4.0).abs() < 1e-6);
+        assert!((stroke(&scaled) - 0.4).abs() < 1e-6);
+
+        // The hint is measured on the points, the stroke is scaled along
+        let scaled = circle.clone().with(|item| {
+            item.scale_to_with_stroke(ScaleHint::PorportionalY(4.0));
+        });
+        assert!((height(scaled.aabb()) - 4.0).abs() < 1e-6);
+        assert!((stroke(&scaled) - 0.8).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_match_style() {
+        use ranim_core::traits::With;
+
+        let src = VItem::from(Circle::new(1.0)).with(|item| {
+            item.set_fill_color(css::RED)
+                .set_stroke_color(css::BLUE)
+                .set_stroke_width(0.3);
+        });
+        let mut dst = VItem::from(Square::new(2.0));
+        let vpoints = dst.vpoints.clone();
+        assert_ne!(src.vpoints.len(), dst.vpoints.len());
+
+        dst.match_style(&src);
+        assert_eq!(dst.vpoints, vpoints);
+        assert_eq!(dst.fill_color(), src.fill_color());
+        assert_eq!(dst.stroke_color(), src.stroke_color());
+        assert_eq!(dst.stroke_width(), src.stroke_width());
+        let len = dst.vpoints.len().div_ceil(2);
+        assert_eq!(dst.fill_rgbas.len(), len);
+        assert_eq!(dst.stroke_rgbas.len(), len);
+        assert_eq!(dst.stroke_widths.len(), len);
+    }
+
+    #[test]
+    fn test_set_opacities() {
+        use ranim_core::traits::{Color, With};
+
+        let alphas = |item: &VItem| {
+            (
+                item.fill_color().components[3],
+                item.stroke_color().components[3],
+            )
+        };
+        let mut item = VItem::from(Square::new(2.0)).with(|item| {
+            item.set_color(css::RED).set_opacities(0.5, 1.0);
+        });
+        assert_eq!(alphas(&item), (0.5, 1.0));
+        assert!(item.fill_rgbas.iter().all(|rgba| rgba.0.w == 0.5));
+        assert!(item.stroke_rgbas.iter().all(|rgba| rgba.0.w == 1.0));
+
+        item.set_opacities(1.0, 0.2);
+        assert_eq!(alphas(&item), (1.0, 0.2));
+
+        // Works for groups, and `set_opacity` still sets both
+        let mut group = vec![item.clone(), item];
+        group.set_opacities(0.3, 0.7);
+        assert!(group.iter().all(|item| alphas(item) == (0.3, 0.7)));
+        group.set_opacity(0.4);
+        assert!(group.iter().all(|item| alphas(item) == (0.4, 0.4)));
+    }
+
+    #[test]
+    fn test_background_stroke() {
+        use crate::vitem::{geometry::Rectangle, typst::TypstText};
+        use ranim_core::traits::With;
+
+        // Thin text over a colored rectangle
+        let rect = VItem::from(Rectangle::new(4.0, 2.0)).with(|item| {
+            item.set_fill_color(css::ORANGE);
+        });
+        let text = TypstText::new("ab").with(|text| {
+            text.set_stroke_width(0.01)
+                .set_background_stroke(css::BLACK, 0.1);
+        });
+        let glyphs = Vec::<VItem>::from(text.clone());
+
+        let mut buf = Vec::new();
+        rect.extract_into(&mut buf);
+        text.extract_into(&mut buf);
+        let core_vitems = buf
+            .into_iter()
+            .map(|item| match item {
+                CoreItem::VItem(vitem) => vitem,
+                _ => panic!("expected vitems"),
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(core_vitems.len(), 1 + glyphs.len() * 2);
+
+        // The rectangle, then all the halos, then all the glyphs on top of them
+        assert_eq!(core_vitems[0], rect.into());
+        let (halos, fronts) = core_vitems[1..].split_at(glyphs.len());
+        let black: Rgba = css::BLACK.into();
+        for ((halo, front), glyph) in halos.iter().zip(fronts).zip(&glyphs) {
+            assert_eq!(halo.points, front.points);
+            assert!(halo.fill_rgbas.iter().all(|rgba| rgba.w == 0.0));
+            assert!(halo.stroke_rgbas.iter().all(|rgba| *rgba == black));
+            assert!(halo.stroke_widths.iter().all(|w| w.0 == 0.1));
+            assert_eq!(*front, glyph.clone().into());
+        }
+
+        // Fading out the item fades its background stroke too
+        let mut glyph = glyphs[0].clone();
+        glyph.set_opacity(0.0);
+        assert_eq!(glyph.background_stroke.unwrap().rgba.w, 0.0);
+    }
+
+    #[test]
+    fn test_close_and_open_path() {
+        use crate::vitem::geometry::Arc;
+
+        let is_filled = |vitem: &VItem| vitem.get_render_points().iter().all(|p| p.w == 1.0);
+        let is_unfilled = |vitem: &VItem| vitem.get_render_points().iter().all(|p| p.w == 0.0);
+
+        // An open arc has no fill
+        let mut arc = VItem::from(Arc::new(std::f64::consts::PI, 1.0));
+        let len = arc.vpoints.len();
+        assert_eq!(arc.subpath_closed_flags(), vec![false]);
+        assert!(is_unfilled(&arc));
+
+        // A closed one does, with a line back to the start
+        arc.close_path();
+        assert_eq!(arc.subpath_closed_flags(), vec![true]);
+        assert!(is_filled(&arc));
+        assert_eq!(arc.vpoints.len(), len + 2);
+        assert_eq!(arc.fill_rgbas.len(), arc.vpoints.len().div_ceil(2));
+        // Closing again changes nothing
+        arc.close_path();
+        assert_eq!(arc.vpoints.len(), len + 2);
+
+        // Opening keeps the closing line as a subpath of its own
+        arc.open_path();
+        assert_eq!(arc.subpath_closed_flags(), vec![false, false]);
+        assert!(is_unfilled(&arc));
+        assert_eq!(arc.vpoints.len(), len + 4);
+        assert_eq!(arc.fill_rgbas.len(), arc.vpoints.len().div_ceil(2));
+        // And closing joins it back
+        arc.close_path();
+        assert_eq!(arc.subpath_closed_flags(), vec![true]);
+        assert_eq!(arc.vpoints.len(), len + 2);
+
+        // Each subpath is controlled on its own
+        let mut squares = VItem::from(Square::new(1.0));
+        squares.set_stroke_color(css::RED);
+        let red = squares.vpoints.clone();
+        // The end anchor repeated as a handle starts a new subpath
+        let end = *squares.vpoints.last().unwrap();
+        squares.extend_vpoints(&[end]);
+        let mut blue = VItem::from(Square::new(2.0));
+        blue.set_stroke_color(css::BLUE);
+        squares.extend_vpoints(&blue.vpoints);
+        let anchors = squares.subpath_anchor_ranges();
+        squares.stroke_rgbas[anchors[1].clone()].fill(blue.stroke_rgbas[0]);
+        let stroke_rgbas = squares.stroke_rgbas.clone();
+        assert_eq!(squares.subpath_closed_flags(), vec![true, true]);
+
+        squares.open_subpath(0);
+        assert_eq!(squares.subpath_closed_flags(), vec![false, false, true]);
+        // No point is dropped, the first square's last edge is split off
+        let subpaths = squares.vpoints.get_subpaths();
+        assert_eq!(subpaths[0], red[..red.len() - 2]);
+        assert_eq!(subpaths[1], red[red.len() - 3..]);
+        // The attrs of the second square are kept in step with its anchors
+        let anchors = squares.subpath_anchor_ranges();
+        assert_eq!(squares.stroke_rgbas.len(), anchors[2].end);
+        assert!(
+            squares.stroke_rgbas[anchors[2].clone()]
+                .iter()
+                .all(|rgba| *rgba == blue.stroke_rgbas[0])
+        );
+
+        squares.close_subpath(0);
+        assert_eq!(squares.subpath_closed_flags(), vec![true, true]);
+        assert_eq!(squares.stroke_rgbas, stroke_rgbas);
+
+        squares.open_subpath(1);
+        assert_eq!(squares.subpath_closed_flags(), vec![true, false, false]);
+        squares.close_subpath(1);
+        assert_eq!(squares.subpath_closed_flags(), vec![true, true]);
+        assert_eq!(squares.stroke_rgbas, stroke_rgbas);
+    }
+
+    #[test]
+    fn test_sample_points() {
+        let circle = VItem::from(Circle::new(2.0));
+        // The quad beziers slightly bulge out of the circle
+        assert!((circle.arc_length() - 4.0 * std::f64::consts::PI).abs() < 5e-2);
+        assert!(circle.sample_points(0).is_empty());
+
+        let points = circle.sample_points(13);
+        assert_eq!(points.len(), 13);
+        assert!(points[0].distance(points[12]) < 1e-9);
+        let distances = points
+            .windows(2)
+            .map(|w| w[0].distance(w[1]))
+            .collect::<Vec<_>>();
+        // The chord of 1/12 of the circle
+        let expected = 2.0 * 2.0 * (std::f64::consts::PI / 12.0).sin();
+        for d in distances {
+            assert!((d - expected).abs() < 1e-2, "{d} != {expected}");
+        }
+        points
+            .iter()
+            .for_each(|p| assert!((p.length() - 2.0).abs() < 1e-2));
+
+        // Two subpaths, the longer one gets more points and the jump is skipped
+        let item = VItem::from_vpoints(vec![
+            DVec3::ZERO,
+            DVec3::X * 0.5,
+            DVec3::X,
+            DVec3::X,
+            DVec3::Y,
+            DVec3::Y + DVec3::X,
+            DVec3::Y + DVec3::X * 2.0,
+        ]);
+        assert!((item.arc_length() - 3.0).abs() < 1e-9);
+        let points = item.sample_points(7);
+        assert_eq!(points[0], DVec3::ZERO);
+        assert!(points[2].distance(DVec3::X) < 1e-6);
+        assert!(points[3].distance(DVec3::Y + DVec3::X * 0.5) < 1e-6);
+        assert!(points[6].distance(DVec3::Y + DVec3::X * 2.0) < 1e-6);
+    }
+
+    #[test]
+    fn test_align_stroke_widths() {
+        let mut a = VItem::from_vpoints(vec![DVec3::ZERO, DVec3::X, DVec3::X * 2.0]);
+        a.stroke_widths = vec![Width(1.0), Width(3.0)].into();
+        let mut b = VItem::from_vpoints(
+            (0..7)
+                .map(|i| DVec3::new(i as f64, 1.0, 0.0))
+                .collect::<Vec<_>>(),
+        );
+        b.stroke_widths = vec![Width(2.0), Width(4.0), Width(2.0), Width(4.0)].into();
+
+        a.align_with(&mut b);
+        assert!(a.is_aligned(&b));
+        assert_eq!(a.stroke_widths.len(), a.vpoints.len().div_ceil(2));
+        // The widths are interpolated instead of repeated
+        let widths = a.stroke_widths.iter().map(|w| w.0).collect::<Vec<_>>();
+        assert_eq!(widths.first(), Some(&1.0));
+        assert_eq!(widths.last(), Some(&3.0));
+        assert!(widths.windows(2).all(|w| w[0] < w[1]), "{widths:?}");
+        assert_eq!(b.stroke_widths.len(), a.stroke_widths.len());
+        assert_eq!(b.stroke_widths.first(), Some(&Width(2.0)));
+        assert_eq!(b.stroke_widths.last(), Some(&Width(4.0)));
+
+        let mid = a.lerp(&b, 0.5);
+        assert_eq!(mid.stroke_widths.len(), mid.vpoints.len().div_ceil(2));
+        assert_eq!(mid.stroke_widths[0], Width(1.5));
+    }
+
+    #[test]
+    fn test_aabb_cache() {
+        use ranim_core::glam::dvec3;
+
+        let mut square = VItem::from(Square::new(2.0));
+        assert!(square.aabb_cache.0.get().is_none());
+        let aabb = square.aabb();
+        assert_eq!(aabb, [dvec3(-1.0, -1.0, 0.0), dvec3(1.0, 1.0, 0.0)]);
+
+        // The cached aabb is returned without recomputing it from the points
+        square.aabb_cache.0 = OnceLock::from([DVec3::ZERO; 2]);
+        assert_eq!(square.aabb(), [DVec3::ZERO; 2]);
+        square.invalidate_aabb();
+        assert_eq!(square.aabb(), aabb);
+
+        // Every mutation of the points invalidates it
+        square.shift(DVec3::X);
+        assert_eq!(square.aabb(), [dvec3(0.0, -1.0, 0.0), dvec3(2.0, 1.0, 0.0)]);
+        square.scale(DVec3::splat(2.0));
+        assert_eq!(square.aabb(), [dvec3(0.0, -2.0, 0.0), dvec3(4.0, 2.0, 0.0)]);
+        square.apply_points_func(|points| points.iter_mut().for_each(|p| p.y = 0.0));
+        assert_eq!(square.aabb(), [DVec3::ZERO, dvec3(4.0, 0.0, 0.0)]);
+        square.set_points(vec![DVec3::ZERO, DVec3::Y, DVec3::Y * 2.0]);
+        assert_eq!(square.aabb(), [DVec3::ZERO, DVec3::Y * 2.0]);
+        square.extend_vpoints(&[DVec3::X, DVec3::X * 4.0]);
+        assert_eq!(square.aabb(), [DVec3::ZERO, dvec3(4.0, 2.0, 0.0)]);
+
+        // The cache is ignored when comparing
+        let cached = VItem::from(Circle::new(1.0));
+        let uncached = cached.clone();
+        cached.aabb();
+        assert_eq!(cached, uncached);
+
+        let mut circle = cached;
+        let mut square = VItem::from(Square::new(4.0));
+        circle.align_with(&mut square);
+        assert_eq!(circle.aabb(), circle.vpoints.aabb());
+        assert_eq!(square.aabb(), square.vpoints.aabb());
+    }
+
+    #[test]
+    fn test_split_at() {
+        let concat = |first: &VItem, second: &VItem| {
+            let mut points = first.vpoints.0.clone();
+            points.extend_from_slice(&second.vpoints[1..]);
+            VItem::from_vpoints(points)
+        };
+        let check_attrs = |item: &VItem| {
+            let len = item.vpoints.len().div_ceil(2);
+            assert_eq!(item.stroke_widths.len(), len);
+            assert_eq!(item.stroke_rgbas.len(), len);
+            assert_eq!(item.fill_rgbas.len(), len);
+            assert_eq!(item.draw_modes.len(), len);
+        };
+
+        // On a vertex, the halves are exactly the original points
+        let square = VItem::from(Square::new(2.0));
+        let (first, second) = square.split_at(0.25);
+        check_attrs(&first);
+        check_attrs(&second);
+        assert_eq!(first.vpoints.last(), second.vpoints.first());
+        assert_eq!(concat(&first, &second).vpoints, square.vpoints);
+        assert!((first.arc_length() - 2.0).abs() < 1e-9);
+
+        // Within a segment, the geometry is the same
+        let circle = VItem::from(Circle::new(2.0));
+        for alpha in [0.1, 0.5, 0.73] {
+            let (first, second) = circle.split_at(alpha);
+            check_attrs(&first);
+            check_attrs(&second);
+            assert_eq!(first.vpoints.last(), second.vpoints.first());
+            // The arc length of a bezier is approximated, so it differs slightly after trimming
+            assert!((first.arc_length() - alpha * circle.arc_length()).abs() < 1e-3);
+            assert!((second.arc_length() - (1.0 - alpha) * circle.arc_length()).abs() < 1e-3);
+            concat(&first, &second)
+                .sample_points(17)
+                .iter()
+                .zip(circle.sample_points(17))
+                .for_each(|(a, b)| assert!(a.distance(b) < 1e-3));
+        }
+
+        // At the ends, the other half is the whole item
+        let (first, second) = square.split_at(0.0);
+        assert_eq!(first.vpoints.0, vec![square.vpoints[0]; 3]);
+        assert_eq!(second.vpoints, square.vpoints);
+        let (first, second) = square.split_at(1.0);
+        assert_eq!(first.vpoints, square.vpoints);
+        assert_eq!(second.vpoints.0, vec![*square.vpoints.last().unwrap(); 3]);
+
+        // At the end of a subpath, the jump is not kept in the second half
+        let item = VItem::from_vpoints(vec![
+            DVec3::ZERO,
+            DVec3::X * 0.5,
+            DVec3::X,
+            DVec3::X,
+            DVec3::Y,
+            DVec3::Y + DVec3::X,
+            DVec3::Y + DVec3::X * 2.0,
+        ]);
+        let (first, second) = item.split_at(1.0 / 3.0);
+        check_attrs(&second);
+        assert_eq!(first.vpoints.0, item.vpoints[..3]);
+        assert_eq!(second.vpoints.0, item.vpoints[4..]);
+    }
+
+    #[test]
+    fn test_put_start_and_end_on() {
+        use glam::dvec3;
+
+        // A zigzag polyline with a curved segment and two subpaths
+        let points = vec![
+            dvec3(0.0, 0.0, 0.0),
+            dvec3(0.5, 1.0, 0.0),
+            dvec3(1.0, 0.0, 0.0),
+            dvec3(1.5, -1.0, 0.0),
+            dvec3(2.0, 0.0, 0.0),
+            dvec3(2.0, 0.0, 0.0),
+            dvec3(2.0, 1.0, 0.0),
+            dvec3(3.0, 1.0, 0.0),
+            dvec3(4.0, 0.0, 0.0),
+        ];
+        let distances = |item: &VItem| {
+            item.vpoints
+                .iter()
+                .map(|p| p.distance(item.vpoints[0]))
+                .collect::<Vec<_>>()
+        };
+        let src = VItem::from_vpoints(points).with_normal(DVec3::Z);
+
+        let cases = [
+            // Scaled by 0.5 and rotated by a quarter turn
+            (dvec3(1.0, 1.0, 0.0), dvec3(1.0, 3.0, 0.0), DVec3::Z),
+            // Opposite direction
+            (dvec3(4.0, 0.0, 0.0), dvec3(0.0, 0.0, 0.0), DVec3::Z),
+            // Out of the xy plane
+            (dvec3(0.0, 0.0, 0.0), dvec3(0.0, 0.0, 8.0), DVec3::NEG_X),
+        ];
+        for (start, end, normal) in cases {
+            let mut item = src.clone();
+            item.put_start_and_end_on(start, end);
+            assert!(item.vpoints.first().unwrap().distance(start) < 1e-9);
+            assert!(item.vpoints.last().unwrap().distance(end) < 1e-9);
+            assert!(item.normal.unwrap().distance(normal) < 1e-9);
+            // The shape is kept
+            let k = start.distance(end) / 4.0;
+            distances(&item)
+                .iter()
+                .zip(distances(&src))
+                .for_each(|(a, b)| assert!((a - b * k).abs() < 1e-9));
+        }
+    }
+}
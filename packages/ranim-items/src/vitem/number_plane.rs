@@ -0,0 +1,352 @@
+use color::{AlphaColor, Srgb};
+use ranim_core::{
+    Extract,
+    color::{self, palettes::manim},
+    components::vpoint::VPointVec,
+    core_item::{CoreItem, vitem::DEFAULT_STROKE_WIDTH},
+    glam::DVec3,
+    traits::{
+        Aabb, AabbPoint, Discard, FillColor, Opacity, PointsFunc, RotateTransform, ScaleTransform,
+        ShiftTransform, ShiftTransformExt, StrokeColor, StrokeWidth,
+    },
+    utils::bezier::PathBuilder,
+};
+
+use crate::vitem::{VItem, text::TextItem};
+
+// MARK: ### NumberPlane ###
+/// A number plane, which is a coordinate grid used as the backdrop of math scenes.
+///
+/// The grid has three kinds of lines:
+/// - The axes through the origin, which are emphasized, see [`NumberPlane::with_axes`].
+/// - The major lines every `step`, see [`NumberPlane::with_step`].
+/// - The minor lines between two major lines, see [`NumberPlane::with_minor_lines`].
+///
+/// Each kind of lines is extracted as a single [`VItem`] with a subpath for every line,
+/// so a dense grid only costs three items to render:
+///
+/// ```rust,ignore
+/// let plane = NumberPlane::new([-8.0, 8.0], [-4.5, 4.5])
+///     .with_minor_lines(1)
+///     .with_labels(true);
+/// let dot = Circle::new(0.1).with(|c| c.move_to(plane.coords_to_point(2.0, 1.0)).discard());
+/// ```
+///
+/// By default, one unit of the coordinates is one unit in the world space, and the
+/// coordinates' origin is at the world's origin.
+#[derive(Clone, Debug)]
+pub struct NumberPlane {
+    x_range: [f64; 2],
+    y_range: [f64; 2],
+    step: [f64; 2],
+    minor_lines: usize,
+    axes: bool,
+    labels: bool,
+    /// The points of the coordinates `(0, 0)`, `(1, 0)` and `(0, 1)`
+    frame: [DVec3; 3],
+    axis_points: VPointVec,
+    major_points: VPointVec,
+    minor_points: VPointVec,
+    /// The labels and the points of the ticks they are attached to
+    label_items: Vec<(DVec3, TextItem)>,
+
+    /// Stroke rgba of the axes
+    pub axis_rgba: AlphaColor<Srgb>,
+    /// Stroke rgba of the major lines
+    pub major_rgba: AlphaColor<Srgb>,
+    /// Stroke rgba of the minor lines, its alpha is [`NumberPlane::MINOR_OPACITY`] times
+    /// the opacity of the plane
+    pub minor_rgba: AlphaColor<Srgb>,
+    /// Stroke width of the axes
+    pub axis_stroke_width: f32,
+    /// Stroke width of the major and minor lines
+    pub stroke_width: f32,
+}
+
+/// The kinds of the lines of a [`NumberPlane`]
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum LineKind {
+    Axis,
+    Major,
+    Minor,
+}
+
+impl NumberPlane {
+    /// The font size of the labels
+    pub const LABEL_SIZE: f64 = 0.3;
+    /// The distance between a label and its tick
+    pub const LABEL_BUFF: f64 = 0.1;
+    /// The opacity of the minor lines relative to the opacity of the plane
+    pub const MINOR_OPACITY: f32 = 0.4;
+
+    /// Constructor, the grid covers `x_range` and `y_range` in coordinates,
+    /// with a major line every `1.0`.
+    pub fn new(x_range: [f64; 2], y_range: [f64; 2]) -> Self {
+        let mut plane = Self {
+            x_range,
+            y_range,
+            step: [1.0, 1.0],
+            minor_lines: 0,
+            axes: true,
+            labels: false,
+            frame: [DVec3::ZERO, DVec3::X, DVec3::Y],
+            axis_points: VPointVec(Vec::new()),
+            major_points: VPointVec(Vec::new()),
+            minor_points: VPointVec(Vec::new()),
+            label_items: Vec::new(),
+            axis_rgba: AlphaColor::WHITE,
+            major_rgba: manim::BLUE_D,
+            minor_rgba: manim::BLUE_D.with_alpha(Self::MINOR_OPACITY),
+            axis_stroke_width: DEFAULT_STROKE_WIDTH,
+            stroke_width: DEFAULT_STROKE_WIDTH,
+        };
+        plane.build();
+        plane
+    }
+    /// Set the coordinate distance between two major lines, default is `[1.0, 1.0]`.
+    ///
+    /// # Panics
+    /// Panics if a step is not positive.
+    pub fn with_step(mut self, x_step: f64, y_step: f64) -> Self {
+        assert!(
+            x_step > 0.0 && y_step > 0.0,
+            "the steps of a number plane must be positive, got [{x_step}, {y_step}]"
+        );
+        self.step = [x_step, y_step];
+        self.build();
+        self
+    }
+    /// Set the count of minor lines between two major lines, default is `0`.
+    pub fn with_minor_lines(mut self, count: usize) -> Self {
+        self.minor_lines = count;
+        self.build();
+        self
+    }
+    /// Whether to emphasize the axes, default is `true`.
+    ///
+    /// If not, the axes are drawn as major lines.
+    pub fn with_axes(mut self, axes: bool) -> Self {
+        self.axes = axes;
+        self.build();
+        self
+    }
+    /// Whether to label the major lines with their coordinates along the axes, default is `false`.
+    ///
+    /// The labels stay upright and keep their size when the plane is transformed,
+    /// they only follow the ticks they are attached to.
+    pub fn with_labels(mut self, labels: bool) -> Self {
+        self.labels = labels;
+        self.build();
+        self
+    }
+    /// The point of the coordinates `(x, y)`
+    pub fn coords_to_point(&self, x: f64, y: f64) -> DVec3 {
+        let [o, px, py] = self.frame;
+        o + (px - o) * x + (py - o) * y
+    }
+    /// The point of the coordinates' origin
+    pub fn origin(&self) -> DVec3 {
+        self.frame[0]
+    }
+    /// The coordinates of the lines along an axis and their kinds
+    fn ticks(&self, [min, max]: [f64; 2], step: f64) -> Vec<(f64, LineKind)> {
+        let n = self.minor_lines as i64 + 1;
+        let minor_step = step / n as f64;
+        let (start, end) = (
+            (min / minor_step - 1e-9).ceil() as i64,
+            (max / minor_step + 1e-9).floor() as i64,
+        );
+        (start..=end)
+            .map(|i| {
+                let kind = match i {
+                    0 if self.axes => LineKind::Axis,
+                    i if i % n == 0 => LineKind::Major,
+                    _ => LineKind::Minor,
+                };
+                (i as f64 * minor_step, kind)
+            })
+            .collect()
+    }
+    /// Build the lines and the labels from the current frame
+    fn build(&mut self) {
+        let [x_range, y_range] = [self.x_range, self.y_range];
+        let mut builders = [PathBuilder::new(), PathBuilder::new(), PathBuilder::new()];
+        let mut label_items = Vec::new();
+        let vertical = self
+            .ticks(x_range, self.step[0])
+            .into_iter()
+            .map(|(x, kind)| {
+                let line = [
+                    self.coords_to_point(x, y_range[0]),
+                    self.coords_to_point(x, y_range[1]),
+                ];
+                (x, kind, line, self.coords_to_point(x, 0.0), DVec3::NEG_Y)
+            });
+        let horizontal = self
+            .ticks(y_range, self.step[1])
+            .into_iter()
+            .map(|(y, kind)| {
+                let line = [
+                    self.coords_to_point(x_range[0], y),
+                    self.coords_to_point(x_range[1], y),
+                ];
+                (y, kind, line, self.coords_to_point(0.0, y), DVec3::NEG_X)
+            });
+        for (value, kind, [start, end], tick, dir) in vertical.chain(horizontal) {
+            builders[kind as usize].move_to(start).line_to(end);
+            if self.labels && kind == LineKind::Major && value.abs() > 1e-9 {
+                // Get rid of the floating point noise like `0.30000000000000004`
+                let value = (value * 1e9).round() / 1e9;
+                let mut label = TextItem::new(value.to_string(), Self::LABEL_SIZE);
+                label.move_anchor_to(AabbPoint(-dir), tick + dir * Self::LABEL_BUFF);
+                label_items.push((tick, label));
+            }
+        }
+        let [axis, major, minor] = builders.map(|builder| VPointVec(builder.vpoints().to_vec()));
+        self.axis_points = axis;
+        self.major_points = major;
+        self.minor_points = minor;
+        self.label_items = label_items;
+    }
+    /// The lines of each kind as [`VItem`]s, skipping the empty ones
+    fn line_items(&self) -> impl Iterator<Item = VItem> + '_ {
+        let [o, px, py] = self.frame;
+        let normal = (px - o).cross(py - o).normalize_or(DVec3::Z);
+        [
+            (&self.minor_points, self.minor_rgba, self.stroke_width),
+            (&self.major_points, self.major_rgba, self.stroke_width),
+            (&self.axis_points, self.axis_rgba, self.axis_stroke_width),
+        ]
+        .into_iter()
+        .filter(|(points, _, _)| !points.is_empty())
+        .map(move |(points, rgba, width)| {
+            let mut item = VItem::from_vpoints(points.0.clone()).with_normal(normal);
+            item.set_stroke_color(rgba).set_stroke_width(width);
+            item
+        })
+    }
+}
+
+// MARK: Traits impl
+impl Aabb for NumberPlane {
+    fn aabb(&self) -> [DVec3; 2] {
+        [
+            self.coords_to_point(self.x_range[0], self.y_range[0]),
+            self.coords_to_point(self.x_range[1], self.y_range[0]),
+            self.coords_to_point(self.x_range[0], self.y_range[1]),
+            self.coords_to_point(self.x_range[1], self.y_range[1]),
+        ]
+        .aabb()
+    }
+}
+
+impl PointsFunc for NumberPlane {
+    fn apply_points_func(&mut self, f: impl for<'a> Fn(&'a mut [DVec3])) -> &mut Self {
+        f(&mut self.frame);
+        f(&mut self.axis_points);
+        f(&mut self.major_points);
+        f(&mut self.minor_points);
+        let mut ticks = self
+            .label_items
+            .iter()
+            .map(|(tick, _)| *tick)
+            .collect::<Vec<_>>();
+        f(&mut ticks);
+        for ((tick, label), new_tick) in self.label_items.iter_mut().zip(ticks) {
+            label.shift(new_tick - *tick);
+            *tick = new_tick;
+        }
+        self
+    }
+}
+
+impl ShiftTransform for NumberPlane {
+    fn shift(&mut self, shift: DVec3) -> &mut Self {
+        self.apply_points_func(|points| points.shift(shift).discard())
+    }
+}
+
+impl RotateTransform for NumberPlane {
+    fn rotate_on_axis(&mut self, axis: DVec3, angle: f64) -> &mut Self {
+        self.apply_points_func(|points| points.rotate_on_axis(axis, angle).discard())
+    }
+}
+
+impl ScaleTransform for NumberPlane {
+    fn scale(&mut self, scale: DVec3) -> &mut Self {
+        self.apply_points_func(|points| points.scale(scale).discard())
+    }
+}
+
+impl Opacity for NumberPlane {
+    fn set_opacity(&mut self, opacity: f32) -> &mut Self {
+        self.axis_rgba = self.axis_rgba.with_alpha(opacity);
+        self.major_rgba = self.major_rgba.with_alpha(opacity);
+        self.minor_rgba = self.minor_rgba.with_alpha(opacity * Self::MINOR_OPACITY);
+        self.label_items.iter_mut().for_each(|(_, label)| {
+            label.set_fill_opacity(opacity).set_stroke_opacity(opacity);
+        });
+        self
+    }
+}
+
+impl Extract for NumberPlane {
+    type Target = CoreItem;
+    fn extract_into(&self, buf: &mut Vec<Self::Target>) {
+        self.line_items().for_each(|item| item.extract_into(buf));
+        self.label_items
+            .iter()
+            .for_each(|(_, label)| label.extract_into(buf));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ranim_core::glam::dvec3;
+
+    fn assert_near(a: DVec3, b: DVec3) {
+        assert!((a - b).length() < 1e-9, "{a} != {b}");
+    }
+
+    #[test]
+    fn test_number_plane() {
+        let mut plane = NumberPlane::new([-4.0, 4.0], [-2.0, 2.0]).with_minor_lines(1);
+        let [min, max] = plane.aabb();
+        assert_near(plane.coords_to_point(0.0, 0.0), (min + max) / 2.0);
+        assert_near(plane.coords_to_point(1.5, -2.0), dvec3(1.5, -2.0, 0.0));
+
+        // 2 axes, 8 + 4 major lines and 8 + 4 minor lines, each line is a subpath of 3 points
+        assert_eq!(plane.axis_points.get_subpaths().len(), 2);
+        assert_eq!(plane.major_points.get_subpaths().len(), 12);
+        assert_eq!(plane.minor_points.get_subpaths().len(), 12);
+        assert_eq!(plane.extract().len(), 3);
+        let plane_without_axes = plane.clone().with_axes(false);
+        assert_eq!(plane_without_axes.major_points.get_subpaths().len(), 14);
+        assert_eq!(plane_without_axes.extract().len(), 2);
+
+        plane.scale(DVec3::splat(2.0)).shift(dvec3(1.0, 1.0, 0.0));
+        let [min, max] = plane.aabb();
+        assert_near(plane.origin(), (min + max) / 2.0);
+        assert_near(plane.coords_to_point(1.0, 1.0), dvec3(3.0, 3.0, 0.0));
+        assert_near(
+            plane.axis_points.get_subpaths()[0][0],
+            plane.coords_to_point(0.0, -2.0),
+        );
+
+        plane.set_opacity(0.5);
+        assert_eq!(plane.major_rgba.components[3], 0.5);
+        assert_eq!(
+            plane.minor_rgba.components[3],
+            0.5 * NumberPlane::MINOR_OPACITY
+        );
+        plane.set_opacity(1.0);
+        assert_eq!(plane.minor_rgba.components[3], NumberPlane::MINOR_OPACITY);
+    }
+
+    #[test]
+    #[should_panic(expected = "must be positive")]
+    fn test_number_plane_zero_step() {
+        let _ = NumberPlane::new([-4.0, 4.0], [-2.0, 2.0]).with_step(0.0, 1.0);
+    }
+}
@@ -1,44 +1,174 @@
+use std::{
+    num::NonZeroUsize,
+    sync::{Arc, Mutex, OnceLock},
+};
+
 use color::{AlphaColor, Srgb, palette::css, rgb8, rgba};
 use glam::DVec3;
 use glam::{DAffine2, dvec3};
+use lru::LruCache;
 use ranim_core::anchor::Aabb;
 use ranim_core::core_item::CoreItem;
-use ranim_core::traits::{PointsFunc, RotateTransform, ShiftTransformExt};
+use ranim_core::traits::{
+    PointsFunc, RotateTransform, ScaleTransform, ScaleTransformStrokeExt, ShiftTransform,
+    ShiftTransformExt,
+};
 use ranim_core::{Extract, components::width::Width, utils::bezier::PathBuilder};
 use ranim_core::{color, glam};
+use sha1::{Digest, Sha1};
 use tracing::warn;
 
+use ranim_core::prelude::Empty;
 use ranim_core::traits::{FillColor, Opacity, StrokeColor, StrokeWidth};
 
-use super::VItem;
+use super::{SubpathDrawMode, VItem, extract_vitems_with_background};
+
+// MARK: ### SvgScale ###
+/// How the coordinate system of a SVG maps to world units, see [`SvgItem::new_with_scale`].
+///
+/// The size of a SVG is its `width` and `height`, or the size of its `viewBox` if they are absent.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum SvgScale {
+    /// One SVG unit (px) is one world unit (default)
+    #[default]
+    Unit,
+    /// The given count of SVG units (px) is one world unit
+    UnitsPerWorldUnit(f64),
+    /// Scale so that the height of the SVG is the given world height
+    Height(f64),
+    /// Scale so that the width of the SVG is the given world width
+    Width(f64),
+}
+
+impl SvgScale {
+    /// The factor from SVG units to world units for a SVG of the given size
+    fn factor(&self, width: f64, height: f64) -> f64 {
+        match *self {
+            SvgScale::Unit => 1.0,
+            SvgScale::UnitsPerWorldUnit(units) => 1.0 / units,
+            SvgScale::Height(h) => h / height,
+            SvgScale::Width(w) => w / width,
+        }
+    }
+}
+
+// MARK: ### Cache ###
+/// The count of the SVGs whose items are cached by [`SvgItem::new_with_scale`],
+/// the least recently used ones are dropped first.
+pub const SVG_CACHE_CAPACITY: usize = 256;
+
+type SvgLruCache = LruCache<[u8; 20], Arc<Vec<VItem>>>;
+
+fn svg_lru() -> &'static Mutex<SvgLruCache> {
+    static LRU: OnceLock<Mutex<SvgLruCache>> = OnceLock::new();
+    LRU.get_or_init(|| {
+        Mutex::new(LruCache::new(
+            NonZeroUsize::new(SVG_CACHE_CAPACITY).unwrap(),
+        ))
+    })
+}
+
+fn svg_cache_key(svg: &str, scale: SvgScale) -> [u8; 20] {
+    let mut sha1 = Sha1::new();
+    sha1.update(svg.as_bytes());
+    sha1.update(format!("{scale:?}").as_bytes());
+    sha1.finalize().into()
+}
 
 // MARK: ### SvgItem ###
 /// An Svg Item
 ///
-/// Its inner is a `Vec<VItem>`
-#[derive(
-    Clone, ranim_macros::ShiftTransform, ranim_macros::RotateTransform, ranim_macros::ScaleTransform,
-)]
-pub struct SvgItem(Vec<VItem>);
+/// Its inner is a `Vec<VItem>`, which is shared by the items of the same SVG until one
+/// of them is modified, see [`SvgItem::new_with_scale`].
+#[derive(Clone)]
+pub struct SvgItem(Arc<Vec<VItem>>);
 
 impl From<SvgItem> for Vec<VItem> {
     fn from(value: SvgItem) -> Self {
-        value.0
+        Arc::unwrap_or_clone(value.0)
     }
 }
 
 impl SvgItem {
-    /// Creates a new SvgItem from a SVG string
+    /// Creates a new SvgItem from a SVG string, one SVG unit is one world unit.
+    ///
+    /// To make the preview app reload the scene on changes of a SVG file,
+    /// read it with [`ranim_core::RanimScene::read_asset`] instead of `include_str!`.
     pub fn new(svg: impl AsRef<str>) -> Self {
-        let mut vitem_group = Self(vitems_from_svg(svg.as_ref()));
+        Self::new_with_scale(svg, SvgScale::default())
+    }
+    /// Creates a new SvgItem from a SVG string, its units are mapped to world units by `scale`.
+    ///
+    /// SVGs have very different sizes, for example an icon with `viewBox="0 0 24 24"` and
+    /// a drawing of `1920x1080`, use [`SvgScale::Height`] to get items of the same height
+    /// from them. The stroke widths are scaled along.
+    ///
+    /// The items are cached by the content of the SVG and the scale, so inserting the same
+    /// SVG many times (grids, repeated logos) parses it only once, see [`SVG_CACHE_CAPACITY`].
+    /// The [`SvgItem`]s share the cached items, an item is copied only when it is modified
+    /// (copy-on-write), so transforming it doesn't affect the others.
+    pub fn new_with_scale(svg: impl AsRef<str>, scale: SvgScale) -> Self {
+        let svg = svg.as_ref();
+        let key = svg_cache_key(svg, scale);
+        let cached = svg_lru().lock().unwrap().get(&key).cloned();
+        // Parsed without holding the lock, so other threads are not blocked by it,
+        // and a SVG failing to parse doesn't poison the cache
+        Self(cached.unwrap_or_else(|| {
+            let items = Self::build_with_scale(svg, scale).0;
+            svg_lru().lock().unwrap().put(key, items.clone());
+            items
+        }))
+    }
+    /// The items to modify, copied from the shared ones if they are shared.
+    fn items_mut(&mut self) -> &mut Vec<VItem> {
+        Arc::make_mut(&mut self.0)
+    }
+    fn build_with_scale(svg: &str, scale: SvgScale) -> Self {
+        let tree = usvg::Tree::from_str(svg, &usvg::Options::default()).unwrap();
+        let size = tree.size();
+        let factor = scale.factor(size.width() as f64, size.height() as f64);
+        let mut vitem_group = Self(Arc::new(vitems_from_tree(&tree)));
         vitem_group
             .move_to(DVec3::ZERO)
             .rotate_on_x(std::f64::consts::PI);
+        if factor != 1.0 {
+            vitem_group.scale_with_stroke(DVec3::splat(factor));
+        }
         vitem_group
     }
+    /// Set a background stroke for all the paths, see [`VItem::set_background_stroke`].
+    ///
+    /// The background strokes of all the paths are drawn behind all the paths.
+    pub fn set_background_stroke(&mut self, color: AlphaColor<Srgb>, width: f32) -> &mut Self {
+        self.items_mut().iter_mut().for_each(|vitem| {
+            vitem.set_background_stroke(color, width);
+        });
+        self
+    }
 }
 
 // MARK: Trait impls
+impl ShiftTransform for SvgItem {
+    fn shift(&mut self, shift: DVec3) -> &mut Self {
+        self.items_mut().shift(shift);
+        self
+    }
+}
+
+impl RotateTransform for SvgItem {
+    fn rotate_on_axis(&mut self, axis: DVec3, angle: f64) -> &mut Self {
+        self.items_mut().rotate_on_axis(axis, angle);
+        self
+    }
+}
+
+impl ScaleTransform for SvgItem {
+    fn scale(&mut self, scale: DVec3) -> &mut Self {
+        self.items_mut().scale(scale);
+        self
+    }
+}
+
 impl Aabb for SvgItem {
     fn aabb(&self) -> [glam::DVec3; 2] {
         self.0.aabb()
@@ -50,11 +180,11 @@ impl FillColor for SvgItem {
         self.0[0].fill_color()
     }
     fn set_fill_color(&mut self, color: AlphaColor<Srgb>) -> &mut Self {
-        self.0.set_fill_color(color);
+        self.items_mut().set_fill_color(color);
         self
     }
     fn set_fill_opacity(&mut self, opacity: f32) -> &mut Self {
-        self.0.set_fill_opacity(opacity);
+        self.items_mut().set_fill_opacity(opacity);
         self
     }
 }
@@ -64,19 +194,19 @@ impl StrokeColor for SvgItem {
         self.0[0].fill_color()
     }
     fn set_stroke_color(&mut self, color: AlphaColor<Srgb>) -> &mut Self {
-        self.0.set_stroke_color(color);
+        self.items_mut().set_stroke_color(color);
         self
     }
     fn set_stroke_opacity(&mut self, opacity: f32) -> &mut Self {
-        self.0.set_stroke_opacity(opacity);
+        self.items_mut().set_stroke_opacity(opacity);
         self
     }
 }
 
 impl Opacity for SvgItem {
     fn set_opacity(&mut self, opacity: f32) -> &mut Self {
-        self.0.set_fill_opacity(opacity);
-        self.0.set_stroke_opacity(opacity);
+        self.items_mut().set_fill_opacity(opacity);
+        self.items_mut().set_stroke_opacity(opacity);
         self
     }
 }
@@ -86,13 +216,13 @@ impl StrokeWidth for SvgItem {
         self.0.stroke_width()
     }
     fn apply_stroke_func(&mut self, f: impl for<'a> Fn(&'a mut [Width])) -> &mut Self {
-        self.0.iter_mut().for_each(|vitem| {
+        self.items_mut().iter_mut().for_each(|vitem| {
             vitem.apply_stroke_func(&f);
         });
         self
     }
     fn set_stroke_width(&mut self, width: f32) -> &mut Self {
-        self.0.set_stroke_width(width);
+        self.items_mut().set_stroke_width(width);
         self
     }
 }
@@ -101,7 +231,7 @@ impl StrokeWidth for SvgItem {
 impl Extract for SvgItem {
     type Target = CoreItem;
     fn extract_into(&self, buf: &mut Vec<Self::Target>) {
-        self.0.extract_into(buf);
+        extract_vitems_with_background(&self.0, buf);
     }
 }
 
@@ -162,6 +292,39 @@ pub fn vitems_from_svg(svg: &str) -> Vec<VItem> {
 
 /// Construct a `Vec<VItem>` from `&usvg::Tree`
 pub fn vitems_from_tree(tree: &usvg::Tree) -> Vec<VItem> {
+    paths_from_tree(tree)
+        .into_iter()
+        .map(|(vitem, _)| vitem)
+        .collect()
+}
+
+/// Construct a single [`VItem`] from `&str` of a SVG, see [`vitem_from_tree`].
+pub fn vitem_from_svg(svg: &str) -> VItem {
+    let tree = usvg::Tree::from_str(svg, &usvg::Options::default()).unwrap();
+    vitem_from_tree(&tree)
+}
+
+/// Construct a single [`VItem`] from `&usvg::Tree`, with the paths as its subpaths.
+///
+/// The paths with `fill="none"` are [`SubpathDrawMode::StrokeOnly`] and the ones with
+/// `stroke="none"` are [`SubpathDrawMode::FillOnly`], so an icon mixing outlines and
+/// filled shapes is drawn right by one item.
+pub fn vitem_from_tree(tree: &usvg::Tree) -> VItem {
+    let mut paths = paths_from_tree(tree).into_iter().map(|(mut vitem, mode)| {
+        vitem.draw_modes.fill(mode);
+        vitem
+    });
+    let Some(mut merged) = paths.next() else {
+        return VItem::empty();
+    };
+    paths.for_each(|vitem| {
+        merged.append(&vitem);
+    });
+    merged
+}
+
+/// The [`VItem`] of each path with the [`SubpathDrawMode`] from its fill and stroke.
+fn paths_from_tree(tree: &usvg::Tree) -> Vec<(VItem, SubpathDrawMode)> {
     let mut vitems = vec![];
     for (path, transform) in walk_svg_group(tree.root()) {
         // println!("path: {:?}", path);
@@ -217,7 +380,12 @@ pub fn vitems_from_tree(tree: &usvg::Tree) -> Vec<VItem> {
             vitem.set_stroke_color(fill_color.with_alpha(0.0));
             vitem.set_stroke_width(0.0);
         }
-        vitems.push(vitem);
+        let draw_mode = match (path.fill(), path.stroke()) {
+            (None, Some(_)) => SubpathDrawMode::StrokeOnly,
+            (Some(_), None) => SubpathDrawMode::FillOnly,
+            _ => SubpathDrawMode::Both,
+        };
+        vitems.push((vitem, draw_mode));
     }
     vitems
 }
@@ -227,6 +395,7 @@ mod tests {
     use std::f64::consts::PI;
 
     use glam::dvec3;
+    use itertools::Itertools;
 
     use crate::vitem::{geometry::Arc, typst::typst_svg};
     use ranim_core::{
@@ -305,6 +474,113 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_svg_scale() {
+        let svg = |view_box: f64| {
+            format!(
+                r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 {view_box} {view_box}">
+                <rect x="0" y="0" width="{}" height="{}" fill="red"/></svg>"#,
+                view_box,
+                view_box / 2.0
+            )
+        };
+        let assert_size = |item: &SvgItem, expected: DVec3| {
+            assert!((item.aabb_size() - expected).length() < 1e-4);
+        };
+        assert_size(&SvgItem::new(svg(10.0)), dvec3(10.0, 5.0, 0.0));
+        for view_box in [10.0, 24.0, 1000.0] {
+            assert_size(
+                &SvgItem::new_with_scale(svg(view_box), SvgScale::Height(4.0)),
+                dvec3(4.0, 2.0, 0.0),
+            );
+            assert_size(
+                &SvgItem::new_with_scale(svg(view_box), SvgScale::Width(2.0)),
+                dvec3(2.0, 1.0, 0.0),
+            );
+            assert_size(
+                &SvgItem::new_with_scale(svg(view_box), SvgScale::UnitsPerWorldUnit(10.0)),
+                dvec3(view_box / 10.0, view_box / 20.0, 0.0),
+            );
+        }
+    }
+
+    #[test]
+    fn test_mixed_draw_modes() {
+        let svg = r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 24 24">
+            <rect x="2" y="2" width="20" height="20" fill="red" stroke="none"/>
+            <circle cx="12" cy="12" r="6" fill="none" stroke="blue" stroke-width="2"/>
+            <rect x="10" y="10" width="4" height="4" fill="green" stroke="black"/></svg>"#;
+        let modes = [
+            SubpathDrawMode::FillOnly,
+            SubpathDrawMode::StrokeOnly,
+            SubpathDrawMode::Both,
+        ];
+        // Separate items keep both, so that their strokes can still be set afterwards
+        let vitems = vitems_from_svg(svg);
+        assert!(vitems.iter().all(|vitem| {
+            vitem
+                .subpath_draw_modes()
+                .iter()
+                .all(|mode| *mode == SubpathDrawMode::Both)
+        }));
+
+        let mut vitem = vitem_from_svg(svg);
+        assert_eq!(vitem.subpath_draw_modes(), modes);
+        // The outline of the circle is not filled, so it doesn't cut a hole into the square,
+        // and the stroke of the square is not drawn
+        let flags = |vitem: &VItem| {
+            vitem
+                .get_render_points()
+                .iter()
+                .map(|p| p.w)
+                .dedup()
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(flags(&vitem), vec![3.0, 0.0, 1.0]);
+
+        vitem.set_subpath_draw_mode(1, SubpathDrawMode::Both);
+        assert_eq!(flags(&vitem), vec![3.0, 1.0]);
+        vitem.set_subpath_draw_mode(3, SubpathDrawMode::Both);
+        assert_eq!(vitem.subpath_draw_modes()[1], SubpathDrawMode::Both);
+    }
+
+    #[test]
+    fn test_svg_cache() {
+        let svg = r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 24 24">
+            <circle cx="12" cy="12" r="6" fill="red"/>
+            <rect x="2" y="2" width="8" height="8" fill="blue"/></svg>"#;
+        let items = (0..100)
+            .map(|_| SvgItem::new_with_scale(svg, SvgScale::Height(2.0)))
+            .collect::<Vec<_>>();
+        let cached = svg_lru()
+            .lock()
+            .unwrap()
+            .peek(&svg_cache_key(svg, SvgScale::Height(2.0)))
+            .cloned()
+            .unwrap();
+        assert_eq!(cached.len(), 2);
+        // The instances share the cached items
+        assert!(
+            items
+                .iter()
+                .all(|item| std::sync::Arc::ptr_eq(&item.0, &cached))
+        );
+
+        // Transforming an instance copies its items and doesn't affect the others
+        let mut item = SvgItem::new_with_scale(svg, SvgScale::Height(2.0));
+        ranim_core::traits::ShiftTransform::shift(&mut item, DVec3::X);
+        assert!(!std::sync::Arc::ptr_eq(&item.0, &cached));
+        assert_ne!(item.0[0].vpoints, cached[0].vpoints);
+        assert_eq!(items[0].0[0].vpoints, cached[0].vpoints);
+        let item = SvgItem::new_with_scale(svg, SvgScale::Height(2.0));
+        assert!(std::sync::Arc::ptr_eq(&item.0, &cached));
+
+        // A different scale is a different entry
+        let height = |item: &SvgItem| item.aabb()[1].y - item.aabb()[0].y;
+        let item = SvgItem::new_with_scale(svg, SvgScale::Height(4.0));
+        assert!((height(&item) - 2.0 * height(&items[0])).abs() < 1e-9);
+    }
+
     #[test]
     fn test_foo() {
         let svg = SvgItem::new(typst_svg("R")).with(|svg| {
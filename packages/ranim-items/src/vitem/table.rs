@@ -0,0 +1,370 @@
+use color::{AlphaColor, Srgb};
+use ranim_core::{
+    Extract, color,
+    core_item::{CoreItem, vitem::DEFAULT_STROKE_WIDTH},
+    glam::{DVec3, dvec2, dvec3},
+    traits::{Aabb, Discard, Opacity, ShiftTransform, StrokeColor},
+};
+
+use crate::vitem::geometry::{Line, Rectangle};
+
+// MARK: ### Table ###
+/// A table, which arranges the cell contents into rows and columns.
+///
+/// Each column is as wide as its widest content, and each row is as high as its highest
+/// content, plus the padding on both sides. The contents are centered in their cells.
+///
+/// The cell contents can be any item, like [`crate::vitem::text::TextItem`],
+/// [`crate::vitem::VItem`] or a group of them:
+///
+/// ```rust,ignore
+/// let mut table = Table::new([
+///     [TextItem::new("x", 0.5), TextItem::new("f(x)", 0.5)],
+///     [TextItem::new("1", 0.5), TextItem::new("2", 0.5)],
+/// ])
+/// .with_border(true);
+/// table.add_highlight(0, 1, manim::YELLOW_C.with_alpha(0.5));
+/// ```
+///
+/// The separating lines and the highlights are derived from the layout, so only the
+/// cells are stored as items.
+#[derive(Clone, Debug)]
+pub struct Table<T> {
+    /// `cells[r][c]` is the content of the cell at row `r` and column `c`
+    cells: Vec<Vec<T>>,
+    col_widths: Vec<f64>,
+    row_heights: Vec<f64>,
+    /// The top-left corner
+    corner: DVec3,
+    padding: f64,
+    lines: bool,
+    border: bool,
+    highlights: Vec<((usize, usize), AlphaColor<Srgb>)>,
+
+    /// Stroke rgba of the lines
+    pub stroke_rgba: AlphaColor<Srgb>,
+    /// Stroke width of the lines
+    pub stroke_width: f32,
+}
+
+impl<T: Aabb + ShiftTransform> Table<T> {
+    /// The default padding between the content and the cell's edges
+    pub const DEFAULT_PADDING: f64 = 0.2;
+
+    /// Constructor, the table is centered at the origin.
+    ///
+    /// Rows may have different counts of cells, the missing ones are left empty.
+    pub fn new(rows: impl IntoIterator<Item = impl IntoIterator<Item = T>>) -> Self {
+        let cells = rows
+            .into_iter()
+            .map(|row| row.into_iter().collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        let mut table = Self {
+            cells,
+            col_widths: Vec::new(),
+            row_heights: Vec::new(),
+            corner: DVec3::ZERO,
+            padding: Self::DEFAULT_PADDING,
+            lines: true,
+            border: false,
+            highlights: Vec::new(),
+            stroke_rgba: AlphaColor::WHITE,
+            stroke_width: DEFAULT_STROKE_WIDTH,
+        };
+        table.arrange();
+        table
+    }
+    /// Set the padding between the content and the cell's edges.
+    pub fn with_padding(mut self, padding: f64) -> Self {
+        self.padding = padding;
+        self.arrange();
+        self
+    }
+    /// Arrange the cells with the current padding, keeping the center of the table.
+    fn arrange(&mut self) {
+        let center = self.center();
+        let ncols = self.ncols();
+        self.col_widths = vec![0.0; ncols];
+        self.row_heights = vec![0.0; self.cells.len()];
+        for (r, row) in self.cells.iter().enumerate() {
+            for (c, cell) in row.iter().enumerate() {
+                let [min, max] = cell.aabb();
+                let size = max - min;
+                self.col_widths[c] = self.col_widths[c].max(size.x);
+                self.row_heights[r] = self.row_heights[r].max(size.y);
+            }
+        }
+        self.col_widths
+            .iter_mut()
+            .chain(self.row_heights.iter_mut())
+            .for_each(|size| *size += 2.0 * self.padding);
+
+        self.corner += center - self.center();
+        for r in 0..self.cells.len() {
+            for c in 0..self.cells[r].len() {
+                let target = self.cell_center(r, c);
+                let cell = &mut self.cells[r][c];
+                let [min, max] = cell.aabb();
+                cell.shift(target - (min + max) / 2.0);
+            }
+        }
+    }
+}
+
+impl<T> Table<T> {
+    /// Whether to draw the lines between the rows and columns, default is `true`.
+    pub fn with_lines(mut self, lines: bool) -> Self {
+        self.lines = lines;
+        self
+    }
+    /// Whether to draw the border around the table, default is `false`.
+    pub fn with_border(mut self, border: bool) -> Self {
+        self.border = border;
+        self
+    }
+    /// The count of rows
+    pub fn nrows(&self) -> usize {
+        self.cells.len()
+    }
+    /// The count of columns
+    pub fn ncols(&self) -> usize {
+        self.cells.iter().map(Vec::len).max().unwrap_or(0)
+    }
+    /// The widths of the columns, including the padding
+    pub fn col_widths(&self) -> &[f64] {
+        &self.col_widths
+    }
+    /// The heights of the rows, including the padding
+    pub fn row_heights(&self) -> &[f64] {
+        &self.row_heights
+    }
+    /// The width of the table
+    pub fn width(&self) -> f64 {
+        self.col_widths.iter().sum()
+    }
+    /// The height of the table
+    pub fn height(&self) -> f64 {
+        self.row_heights.iter().sum()
+    }
+    /// The center of the table
+    fn center(&self) -> DVec3 {
+        self.corner + dvec3(self.width(), -self.height(), 0.0) / 2.0
+    }
+    /// The content of the cell at row `r` and column `c`
+    pub fn get_cell(&self, r: usize, c: usize) -> Option<&T> {
+        self.cells.get(r).and_then(|row| row.get(c))
+    }
+    /// The mutable content of the cell at row `r` and column `c`
+    pub fn get_cell_mut(&mut self, r: usize, c: usize) -> Option<&mut T> {
+        self.cells.get_mut(r).and_then(|row| row.get_mut(c))
+    }
+    /// The contents of the cells in row `r`
+    pub fn get_row(&self, r: usize) -> Option<&[T]> {
+        self.cells.get(r).map(Vec::as_slice)
+    }
+    /// The contents of the cells in column `c`, skipping the rows without it
+    pub fn get_col(&self, c: usize) -> Vec<&T> {
+        self.cells.iter().filter_map(|row| row.get(c)).collect()
+    }
+    /// The center of the cell at row `r` and column `c`
+    ///
+    /// # Panics
+    /// Panics if the cell is out of range.
+    pub fn cell_center(&self, r: usize, c: usize) -> DVec3 {
+        let x = self.col_widths[..c].iter().sum::<f64>() + self.col_widths[c] / 2.0;
+        let y = self.row_heights[..r].iter().sum::<f64>() + self.row_heights[r] / 2.0;
+        self.corner + dvec3(x, -y, 0.0)
+    }
+    /// The rectangle of the cell at row `r` and column `c`
+    ///
+    /// # Panics
+    /// Panics if the cell is out of range.
+    pub fn cell_rect(&self, r: usize, c: usize) -> Rectangle {
+        let size = dvec2(self.col_widths[c], self.row_heights[r]);
+        let p0 = self.cell_center(r, c) - size.extend(0.0) / 2.0;
+        Rectangle::from_min_size(p0, size)
+    }
+    /// Highlight the cell at row `r` and column `c` with a filled rectangle behind it.
+    ///
+    /// # Panics
+    /// Panics if the cell is out of range.
+    pub fn add_highlight(&mut self, r: usize, c: usize, color: AlphaColor<Srgb>) -> &mut Self {
+        assert!(
+            r < self.nrows() && c < self.ncols(),
+            "cell ({r}, {c}) is out of range"
+        );
+        self.highlights.push(((r, c), color));
+        self
+    }
+    /// Remove all the highlights
+    pub fn clear_highlights(&mut self) -> &mut Self {
+        self.highlights.clear();
+        self
+    }
+    /// The highlight rectangles
+    fn highlight_rects(&self) -> impl Iterator<Item = Rectangle> + '_ {
+        self.highlights.iter().map(|&((r, c), color)| {
+            let mut rect = self.cell_rect(r, c);
+            rect.fill_rgba = color;
+            rect.stroke_rgba = AlphaColor::TRANSPARENT;
+            rect
+        })
+    }
+    /// The separating lines and the border
+    fn line_items(&self) -> Vec<Line> {
+        let (width, height) = (self.width(), self.height());
+        let mut xs = Vec::new();
+        let mut ys = Vec::new();
+        if self.lines {
+            xs.extend(self.col_widths.iter().scan(0.0, |x, w| {
+                *x += w;
+                Some(*x)
+            }));
+            xs.pop();
+            ys.extend(self.row_heights.iter().scan(0.0, |y, h| {
+                *y += h;
+                Some(*y)
+            }));
+            ys.pop();
+        }
+        if self.border {
+            xs.extend([0.0, width]);
+            ys.extend([0.0, height]);
+        }
+        let vertical = xs.into_iter().map(|x| {
+            Line::new(
+                self.corner + dvec3(x, 0.0, 0.0),
+                self.corner + dvec3(x, -height, 0.0),
+            )
+        });
+        let horizontal = ys.into_iter().map(|y| {
+            Line::new(
+                self.corner + dvec3(0.0, -y, 0.0),
+                self.corner + dvec3(width, -y, 0.0),
+            )
+        });
+        vertical
+            .chain(horizontal)
+            .map(|mut line| {
+                line.stroke_rgba = self.stroke_rgba;
+                line.stroke_width = self.stroke_width;
+                line
+            })
+            .collect()
+    }
+}
+
+// MARK: Traits impl
+impl<T> Aabb for Table<T> {
+    fn aabb(&self) -> [DVec3; 2] {
+        [
+            self.corner,
+            self.corner + dvec3(self.width(), -self.height(), 0.0),
+        ]
+        .aabb()
+    }
+}
+
+impl<T: ShiftTransform> ShiftTransform for Table<T> {
+    fn shift(&mut self, shift: DVec3) -> &mut Self {
+        self.corner += shift;
+        self.cells
+            .iter_mut()
+            .flatten()
+            .for_each(|cell| cell.shift(shift).discard());
+        self
+    }
+}
+
+impl<T: Opacity> Opacity for Table<T> {
+    fn set_opacity(&mut self, opacity: f32) -> &mut Self {
+        self.cells
+            .iter_mut()
+            .flatten()
+            .for_each(|cell| cell.set_opacity(opacity).discard());
+        self.stroke_rgba = self.stroke_rgba.with_alpha(opacity);
+        self.highlights
+            .iter_mut()
+            .for_each(|(_, color)| *color = color.with_alpha(opacity));
+        self
+    }
+}
+
+impl<T> StrokeColor for Table<T> {
+    fn stroke_color(&self) -> AlphaColor<Srgb> {
+        self.stroke_rgba
+    }
+    fn set_stroke_color(&mut self, color: AlphaColor<Srgb>) -> &mut Self {
+        self.stroke_rgba = color;
+        self
+    }
+    fn set_stroke_opacity(&mut self, opacity: f32) -> &mut Self {
+        self.stroke_rgba = self.stroke_rgba.with_alpha(opacity);
+        self
+    }
+}
+
+impl<T: Extract<Target = CoreItem>> Extract for Table<T> {
+    type Target = CoreItem;
+    fn extract_into(&self, buf: &mut Vec<Self::Target>) {
+        self.highlight_rects()
+            .for_each(|rect| rect.extract_into(buf));
+        self.cells
+            .iter()
+            .flatten()
+            .for_each(|cell| cell.extract_into(buf));
+        self.line_items()
+            .iter()
+            .for_each(|line| line.extract_into(buf));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vitem::geometry::Square;
+    use color::palette::css;
+
+    fn assert_near(a: DVec3, b: DVec3) {
+        assert!((a - b).length() < 1e-9, "{a} != {b}");
+    }
+
+    #[test]
+    fn test_table_layout() {
+        let mut table = Table::new([
+            [Square::new(1.0), Square::new(2.0)],
+            [Square::new(1.0), Square::new(1.0)],
+        ])
+        .with_padding(0.5);
+        assert_eq!((table.nrows(), table.ncols()), (2, 2));
+        assert_eq!(table.col_widths(), &[2.0, 3.0]);
+        assert_eq!(table.row_heights(), &[3.0, 2.0]);
+        assert_near(table.aabb()[0], dvec3(-2.5, -2.5, 0.0));
+        assert_near(table.aabb()[1], dvec3(2.5, 2.5, 0.0));
+
+        let expected = [
+            [dvec3(-1.5, 1.0, 0.0), dvec3(1.0, 1.0, 0.0)],
+            [dvec3(-1.5, -1.5, 0.0), dvec3(1.0, -1.5, 0.0)],
+        ];
+        for (r, row) in expected.iter().enumerate() {
+            for (c, center) in row.iter().enumerate() {
+                assert_near(table.cell_center(r, c), *center);
+                assert_near(table.get_cell(r, c).unwrap().center, *center);
+            }
+        }
+        assert_eq!(table.get_row(1).unwrap().len(), 2);
+        assert!(table.get_cell(2, 0).is_none());
+
+        table.shift(DVec3::X);
+        assert_near(table.get_cell(0, 1).unwrap().center, dvec3(2.0, 1.0, 0.0));
+        let [min, max] = table.cell_rect(0, 1).aabb();
+        assert_near(min, dvec3(0.5, -0.5, 0.0));
+        assert_near(max, dvec3(3.5, 2.5, 0.0));
+
+        // 4 cells and 2 inner lines, then a highlight and 4 border lines
+        assert_eq!(table.extract().len(), 6);
+        table.add_highlight(0, 1, css::YELLOW);
+        let table = table.with_border(true);
+        assert_eq!(table.extract().len(), 11);
+    }
+}
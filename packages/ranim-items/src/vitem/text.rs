@@ -7,7 +7,7 @@ use ranim_core::{
     Extract,
     color::{AlphaColor, Srgb},
     core_item::CoreItem,
-    glam::{DAffine3, DMat3, DVec3},
+    glam::{DAffine3, DMat3, DVec3, dvec2},
     traits::{
         Aabb, Discard, FillColor, Locate, PointsFunc, RotateTransform, ScaleTransform,
         ShiftTransform, StrokeColor, StrokeWidth, With,
@@ -72,7 +72,29 @@ impl Default for TextFont {
     }
 }
 
-/// Simple single-line text item
+/// Horizontal alignment of the lines in a [`TextItem`]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TextAlignment {
+    /// Align lines to the left edge
+    #[default]
+    Left,
+    /// Center lines horizontally
+    Center,
+    /// Align lines to the right edge
+    Right,
+}
+
+/// Simple text item
+///
+/// Lines are separated by `\n`, the first line's baseline starts at the origin,
+/// and the following lines go downwards along the basis.
+///
+/// Text is shaped by typst, which searches system fonts in addition to its embedded
+/// fonts (Libertinus Serif, New Computer Modern and DejaVu Sans Mono). The embedded
+/// fonts only cover Latin, Greek and Cyrillic, so rendering CJK or RTL scripts needs
+/// a system font covering them (e.g. Noto Sans CJK, Noto Sans Arabic). Typst falls
+/// back to such a font automatically, but listing it in [`TextFont`] makes the choice
+/// explicit. Use [`TextItem::with_lang`] to get language specific shaping and direction.
 #[derive(Clone, Debug)]
 pub struct TextItem {
     /// Origin
@@ -83,15 +105,23 @@ pub struct TextItem {
     text: String,
     /// Font info
     font: TextFont,
+    /// Line alignment
+    alignment: TextAlignment,
+    /// Distance between baselines of adjacent lines in em units
+    line_spacing: f64,
+    /// Language hint, an ISO 639 code optionally followed by a region, like `zh-CN`
+    lang: Option<String>,
+    /// OpenType script hint, like `hani`
+    script: Option<String>,
     /// Fill color
     fill_rgbas: AlphaColor<Srgb>,
     /// Stroke color
     stroke_rgbas: AlphaColor<Srgb>,
     /// Stroke width
     stroke_width: f32,
-    /// Cached items
-    items: RefCell<Option<Vec<VItem>>>,
-    /// cached text inline size
+    /// Cached items of each line
+    items: RefCell<Option<Vec<Vec<VItem>>>>,
+    /// cached inline size of the longest line
     inline_length_em: Cell<Option<f64>>,
 }
 
@@ -109,6 +139,10 @@ impl TextItem {
             basis: (DVec3::X * em_size, DVec3::Y * em_size),
             text: text.into(),
             font: TextFont::default(),
+            alignment: TextAlignment::default(),
+            line_spacing: 1.25,
+            lang: None,
+            script: None,
             fill_rgbas: AlphaColor::WHITE,
             stroke_rgbas: AlphaColor::WHITE,
             stroke_width: 0.0,
@@ -124,11 +158,55 @@ impl TextItem {
         self
     }
 
+    /// Set the alignment of lines
+    pub fn with_alignment(mut self, alignment: TextAlignment) -> Self {
+        self.alignment = alignment;
+        self.items.take();
+        self
+    }
+
+    /// Set the distance between baselines of adjacent lines in em units, default is `1.25`
+    pub fn with_line_spacing(mut self, line_spacing: f64) -> Self {
+        self.line_spacing = line_spacing;
+        self.items.take();
+        self
+    }
+
+    /// Set the language hint forwarded to typst
+    ///
+    /// It accepts an ISO 639 language code optionally followed by an ISO 3166 region,
+    /// like `"zh"`, `"zh-CN"` or `"ar"`. Typst uses it for language specific shaping,
+    /// and right-to-left languages will be laid out from right to left.
+    pub fn with_lang(mut self, lang: impl Into<String>) -> Self {
+        self.lang = Some(lang.into());
+        self.items.take();
+        self
+    }
+
+    /// Set the OpenType script hint forwarded to typst, like `"hani"` or `"arab"`
+    ///
+    /// Usually typst detects the script from the text, so this is rarely needed.
+    pub fn with_script(mut self, script: impl Into<String>) -> Self {
+        self.script = Some(script.into());
+        self.items.take();
+        self
+    }
+
     /// Get font
     pub fn font(&self) -> &TextFont {
         &self.font
     }
 
+    /// Get the alignment of lines
+    pub fn alignment(&self) -> TextAlignment {
+        self.alignment
+    }
+
+    /// Get the distance between baselines of adjacent lines in em units
+    pub fn line_spacing(&self) -> f64 {
+        self.line_spacing
+    }
+
     /// Get basis
     pub fn basis(&self) -> (DVec3, DVec3) {
         self.basis
@@ -139,21 +217,78 @@ impl TextItem {
         &self.text
     }
 
-    /// Get inline length in em units
+    /// Get inline length of the longest line in em units
     pub fn inline_length_em(&self) -> f64 {
-        let _ = self.items(); // ensure items are generated
+        let _ = self.lines_ref(); // ensure items are generated
         self.inline_length_em.get().unwrap()
     }
 
-    /// Returns the text outline box starting from baseline origin to the width of last character and em height.
+    /// Get the items of each non-empty line, useful for animating line by line
+    pub fn lines(&self) -> Vec<Vec<VItem>> {
+        self.lines_ref()
+            .iter()
+            .filter(|line| !line.is_empty())
+            .cloned()
+            .collect()
+    }
+
+    /// Returns the text outline box enclosing all lines.
+    ///
+    /// It spans from the last line's baseline to the em height of the first line, and has
+    /// the width of the longest line. It is extended to enclose all the glyphs, e.g. the
+    /// descenders below the last baseline.
     pub fn text_box(&self) -> Parallelogram {
         let (u, v) = self.basis;
-        Parallelogram::new(self.origin, (u * self.inline_length_em(), v))
+        let line_cnt = self.text.split('\n').count();
+        let last_baseline = -self.line_spacing * (line_cnt - 1) as f64;
+        let (mut min, mut max) = (
+            dvec2(0.0, last_baseline),
+            dvec2(self.inline_length_em(), 1.0),
+        );
+
+        // The em coordinates `(a, b)` of `p = origin + a * u + b * v`
+        let (uu, uv, vv) = (u.dot(u), u.dot(v), v.dot(v));
+        let det = uu * vv - uv * uv;
+        for p in self
+            .lines_ref()
+            .iter()
+            .flatten()
+            .flat_map(|item| item.vpoints.iter())
+        {
+            let d = *p - self.origin;
+            let (du, dv) = (d.dot(u), d.dot(v));
+            let em = dvec2((du * vv - dv * uv) / det, (dv * uu - du * uv) / det);
+            min = min.min(em);
+            max = max.max(em);
+        }
+        Parallelogram::new(
+            self.origin + u * min.x + v * min.y,
+            (u * (max.x - min.x), v * (max.y - min.y)),
+        )
     }
 
-    fn generate_items(&self) -> Vec<VItem> {
+    /// The language, region and script arguments of typst's `text` function
+    fn typst_hints(&self) -> String {
+        let mut hints = String::new();
+        if let Some(lang) = &self.lang {
+            let (lang, region) = match lang.split_once(['-', '_']) {
+                Some((lang, region)) => (lang, Some(region)),
+                None => (lang.as_str(), None),
+            };
+            hints.push_str(&format!("lang: {}, ", typst_str(&lang.to_lowercase())));
+            if let Some(region) = region {
+                hints.push_str(&format!("region: {}, ", typst_str(&region.to_uppercase())));
+            }
+        }
+        if let Some(script) = &self.script {
+            hints.push_str(&format!("script: {}, ", typst_str(&script.to_lowercase())));
+        }
+        hints
+    }
+
+    /// The typst source of a single line
+    fn typst_source(&self, text: &str) -> String {
         let font = &self.font;
-        let text = self.text.as_str();
 
         // font families
         let mut families = String::new();
@@ -194,10 +329,12 @@ impl TextItem {
             features
         };
 
-        let svg_src = typst_svg(
-            format!(
-                r#"#set text(
+        let hints = self.typst_hints();
+
+        format!(
+            r#"#set text(
     top-edge: 1em,
+    {hints}
     font: ({families}),
     weight: {weight},
     style: "{style}",
@@ -213,38 +350,79 @@ impl TextItem {
 
 {text}
 "#
-            )
-            .as_str(),
-        );
+        )
+    }
+
+    /// Generates the items of a single line in em units, with its baseline origin at zero.
+    ///
+    /// Returns the items and the inline length of the line.
+    fn generate_line_em(&self, text: &str) -> (Vec<VItem>, f64) {
+        let svg_src = typst_svg(self.typst_source(text).as_str());
 
         let mut items = Vec::<VItem>::from(SvgItem::new(svg_src));
         let baseline_em_box = items[0].aabb();
         let texts = items.split_off(1);
 
+        let [min, max] = baseline_em_box;
+        let h = max.y - min.y;
+        let texts = texts.with(|x| {
+            x.shift(-min)
+                .scale(DVec3::splat(1. / h)) // Make height = 1.0
+                .discard()
+        });
+        (texts, (max.x - min.x) / h)
+    }
+
+    fn generate_items(&self) -> Vec<Vec<VItem>> {
+        // Empty lines only take up space
+        let lines = self
+            .text
+            .split('\n')
+            .map(|line| {
+                if line.trim().is_empty() {
+                    (vec![], 0.0)
+                } else {
+                    self.generate_line_em(line)
+                }
+            })
+            .collect::<Vec<_>>();
+
         let &Self {
             basis: (u, v),
             origin,
+            alignment,
+            line_spacing,
             fill_rgbas,
             stroke_rgbas,
             stroke_width,
             ..
         } = self;
-        let [min, max] = baseline_em_box;
-        let h = max.y - min.y;
-        self.inline_length_em.set(Some((max.x - min.x) / h));
+        let max_len = lines.iter().map(|(_, len)| *len).fold(0.0, f64::max);
+        self.inline_length_em.set(Some(max_len));
         let mat = DAffine3::from_mat3_translation(DMat3::from_cols(u, v, DVec3::ZERO), origin);
-        texts.with(|x| {
-            x.shift(-min)
-                .scale(DVec3::splat(1. / h)) // Make height = 1.0
-                .apply_point_func(|p| *p = mat.transform_point3(*p))
-                .set_fill_color(fill_rgbas)
-                .set_stroke_color(stroke_rgbas)
-                .set_stroke_width(stroke_width)
-                .discard()
-        })
+        lines
+            .into_iter()
+            .enumerate()
+            .map(|(i, (line, len))| {
+                let dx = match alignment {
+                    TextAlignment::Left => 0.0,
+                    TextAlignment::Center => (max_len - len) / 2.0,
+                    TextAlignment::Right => max_len - len,
+                };
+                let dy = -line_spacing * i as f64;
+                line.with(|item| {
+                    item.shift(DVec3::new(dx, dy, 0.0))
+                        .apply_point_func(|p| *p = mat.transform_point3(*p))
+                        .set_fill_color(fill_rgbas)
+                        .set_stroke_color(stroke_rgbas)
+                        .set_stroke_width(stroke_width)
+                        .discard()
+                })
+            })
+            .collect()
     }
 
-    fn items(&self) -> Ref<'_, Vec<VItem>> {
+    fn lines_ref(&self) -> Ref<'_, Vec<Vec<VItem>>> {
         if self.items.borrow().is_none() {
             let items = self.generate_items();
             self.items.replace(Some(items));
@@ -252,9 +430,13 @@ impl TextItem {
         Ref::map(self.items.borrow(), |v| v.as_ref().unwrap())
     }
 
-    fn transform_items(&self, transformation: impl FnOnce(&mut Vec<VItem>)) {
-        if let Some(v) = self.items.borrow_mut().as_mut() {
-            transformation(v);
+    fn items(&self) -> Vec<VItem> {
+        self.lines_ref().iter().flatten().cloned().collect()
+    }
+
+    fn transform_items(&self, transformation: impl Fn(&mut Vec<VItem>)) {
+        if let Some(lines) = self.items.borrow_mut().as_mut() {
+            lines.iter_mut().for_each(&transformation);
         }
     }
 }
@@ -331,7 +513,7 @@ impl StrokeColor for TextItem {
 
 impl From<TextItem> for Vec<VItem> {
     fn from(item: TextItem) -> Self {
-        item.items().clone()
+        item.items()
     }
 }
 
@@ -339,10 +521,17 @@ impl Extract for TextItem {
     type Target = CoreItem;
 
     fn extract_into(&self, buf: &mut Vec<Self::Target>) {
-        self.items().extract_into(buf);
+        self.lines_ref()
+            .iter()
+            .for_each(|line| line.extract_into(buf));
     }
 }
 
+/// Quotes `s` as a typst string literal
+fn typst_str(s: &str) -> String {
+    format!("\"{}\"", s.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
 #[cfg(test)]
 mod tests {
     use assert_float_eq::assert_float_absolute_eq;
@@ -356,6 +545,99 @@ mod tests {
         assert_float_absolute_eq!(item.origin.distance(DVec3::ZERO), 0.0, 1e-10);
     }
 
+    #[test]
+    fn test_multi_line() {
+        let text = "Hello\n\njumpy, and more";
+        let item = TextItem::new(text, 0.5).with_alignment(TextAlignment::Center);
+        let lines = item.lines();
+        // The empty line produces no items
+        assert_eq!(lines.len(), 2);
+
+        // The text box encloses every line's glyphs, including the descenders
+        let [box_min, box_max] = item.text_box().aabb();
+        for line in &lines {
+            let [min, max] = line.aabb();
+            assert!(box_min.x <= min.x + 1e-6 && box_min.y <= min.y + 1e-6);
+            assert!(box_max.x >= max.x - 1e-6 && box_max.y >= max.y - 1e-6);
+        }
+        // The descenders go below the last baseline
+        assert!(box_min.y < item.origin.y - 0.5 * 1.25 * 2.0 - 1e-3);
+        // Three lines, the empty line still takes space
+        assert!(box_max.y - box_min.y >= 0.5 * (1.25 * 2.0 + 1.0) - 1e-6);
+
+        // Lines are centered
+        let center_x = |items: &Vec<VItem>| {
+            let [min, max] = items.aabb();
+            (min.x + max.x) / 2.0
+        };
+        assert_float_absolute_eq!(center_x(&lines[0]), center_x(&lines[1]), 0.05);
+    }
+
+    #[test]
+    #[ignore = "needs a CJK font installed on the system"]
+    fn test_mixed_latin_cjk() {
+        let text = "Hello 你好";
+        let item = TextItem::new(text, 0.5).with_lang("zh-CN");
+        let glyphs = text.chars().filter(|c| !c.is_whitespace());
+        assert_eq!(item.items().len(), glyphs.count());
+    }
+
+    #[test]
+    fn test_typst_hints() {
+        let item = TextItem::new("Hello", 0.5)
+            .with_lang("zh-cn")
+            .with_script("HANI");
+        assert_eq!(
+            item.typst_hints(),
+            r#"lang: "zh", region: "CN", script: "hani", "#
+        );
+
+        // Malformed hints must not break out of the typst string
+        let item = TextItem::new("Hello", 0.5)
+            .with_lang(r#"en", fill: red, x: ""#)
+            .with_script(r"latn\");
+        assert_eq!(
+            item.typst_hints(),
+            r#"lang: "en\", fill: red, x: \"", script: "latn\\", "#
+        );
+    }
+
+    #[test]
+    fn test_typst_source() {
+        let font = TextFont::new(["Noto Sans"]).with_features([("liga", 0)]);
+        let source = TextItem::new("Hello", 0.5)
+            .with_font(font)
+            .with_lang("en-us")
+            .typst_source("Hello");
+        let set_text = source.split_once(")\n#set page(").unwrap().0;
+        assert!(set_text.starts_with("#set text(\n    top-edge: 1em,\n"));
+        assert!(set_text.contains(r#"lang: "en", region: "US", "#));
+        assert!(set_text.contains(r#"font: ("Noto Sans", ),"#));
+        assert!(set_text.contains(r#"features: ("liga": 0, ),"#));
+        assert!(source.ends_with("\n\nHello\n"));
+
+        // Without hints, only the defaults are set
+        let source = TextItem::new("Hello", 0.5).typst_source("Hello");
+        assert!(!source.contains("lang:") && !source.contains("script:"));
+
+        // A right-to-left language is forwarded to typst, which lays out the line from
+        // right to left, and the text is kept in its logical order
+        let text = "مرحبا بالعالم";
+        let source = TextItem::new(text, 0.5)
+            .with_lang("ar-EG")
+            .with_script("Arab")
+            .typst_source(text);
+        assert!(source.contains(r#"lang: "ar", region: "EG", script: "arab", "#));
+        assert!(source.ends_with(&format!("\n\n{text}\n")));
+
+        // Malformed hints stay inside their strings
+        let source = TextItem::new("Hello", 0.5)
+            .with_lang(r#"ar", dir: ltr, x: ""#)
+            .typst_source("Hello");
+        assert!(source.contains(r#"lang: "ar\", dir: ltr, x: \"", "#));
+        assert!(!source.contains(r#"lang: "ar", dir: ltr"#));
+    }
+
     #[test]
     fn test_font() {
         let font = TextFont::new(["Arial", "Helvetica"])
@@ -2,6 +2,7 @@ use std::{
     collections::HashMap,
     io::Write,
     num::NonZeroUsize,
+    path::PathBuf,
     sync::{Arc, Mutex, OnceLock},
 };
 
@@ -12,7 +13,7 @@ use regex::bytes::Regex;
 use sha1::{Digest, Sha1};
 use typst::{
     Library, LibraryExt, World,
-    diag::{FileError, FileResult},
+    diag::{FileError, FileResult, PackageError},
     foundations::{Bytes, Datetime},
     layout::Abs,
     syntax::{FileId, Source},
@@ -21,7 +22,7 @@ use typst::{
 };
 use typst_kit::fonts::{FontSearcher, Fonts};
 
-use crate::vitem::{VItem, svg::SvgItem};
+use crate::vitem::{VItem, extract_vitems_with_background, svg::SvgItem};
 use ranim_core::Extract;
 use ranim_core::traits::Interpolatable;
 use ranim_core::{
@@ -52,22 +53,15 @@ impl TypstLruCache {
     //     let sha1 = sha1.finalize();
     //     self.inner.get::<[u8; 20]>(sha1.as_ref())
     // }
-    fn get_or_insert(&mut self, typst_str: &str) -> &String {
+    fn try_get_or_insert(&mut self, typst_str: &str) -> Result<&String, String> {
         let mut sha1 = Sha1::new();
         sha1.update(typst_str.as_bytes());
         let sha1 = sha1.finalize();
         self.inner
-            .get_or_insert_ref(AsRef::<[u8; 20]>::as_ref(&sha1), || {
+            .try_get_or_insert_ref(AsRef::<[u8; 20]>::as_ref(&sha1), || {
                 // let world = SingleFileTypstWorld::new(typst_str);
                 let world = typst_world().lock().unwrap();
-                let world = world.with_source_str(typst_str);
-                // world.set_source(typst_str);
-                let document = typst::compile(&world)
-                    .output
-                    .expect("failed to compile typst source");
-
-                let svg = typst_svg::svg_merged(&document, Abs::pt(2.0));
-                get_typst_element(&svg)
+                world.compile_svg(typst_str)
             })
     }
 }
@@ -81,7 +75,7 @@ fn typst_lru() -> &'static Arc<Mutex<TypstLruCache>> {
     })
 }
 
-fn fonts() -> &'static Fonts {
+pub(crate) fn fonts() -> &'static Fonts {
     static FONTS: OnceLock<Fonts> = OnceLock::new();
     FONTS.get_or_init(|| FontSearcher::new().include_system_fonts(true).search())
 }
@@ -92,8 +86,11 @@ fn typst_world() -> &'static Arc<Mutex<TypstWorld>> {
 }
 
 /// Compiles typst string to SVG string
+///
+/// # Panics
+/// Panics if the source fails to compile, see [`try_typst_svg`] for the fallible version.
 pub fn typst_svg(source: &str) -> String {
-    typst_lru().lock().unwrap().get_or_insert(source).clone()
+    try_typst_svg(source).unwrap_or_else(|err| panic!("failed to compile typst source: {err}"))
     // let world = SingleFileTypstWorld::new(source);
     // let document = typst::compile(&world)
     //     .output
@@ -103,6 +100,89 @@ pub fn typst_svg(source: &str) -> String {
     // get_typst_element(&svg)
 }
 
+/// Compiles typst string to SVG string, returns the compile errors if it fails to compile,
+/// e.g. an imported package can't be resolved.
+pub fn try_typst_svg(source: &str) -> Result<String, String> {
+    typst_lru()
+        .lock()
+        .unwrap()
+        .try_get_or_insert(source)
+        .cloned()
+}
+
+/// The config of the typst world used by [`typst_svg`] and [`TypstText`], see [`set_typst_config`].
+#[derive(Debug, Clone, Default)]
+pub struct TypstConfig {
+    /// Prepended to every source, e.g. `#set page(margin: 1em)` or an `#import` of a package.
+    ///
+    /// It should only contain rules and imports, a content will break [`TypstText`].
+    pub preamble: String,
+    /// Extra dirs to look for packages in, before the default ones.
+    ///
+    /// A package `@{namespace}/{name}:{version}` is resolved to `{dir}/{namespace}/{name}/{version}`.
+    /// The default dirs are the data dir (for `@local` packages) and the cache dir
+    /// (for the `@preview` packages downloaded by the typst CLI) of typst, i.e. `{dir}/typst/packages`.
+    /// Packages are never downloaded.
+    pub package_dirs: Vec<PathBuf>,
+}
+
+impl TypstConfig {
+    /// Set the [`TypstConfig::preamble`]
+    pub fn with_preamble(mut self, preamble: impl Into<String>) -> Self {
+        self.preamble = preamble.into();
+        self
+    }
+    /// Add a dir to [`TypstConfig::package_dirs`]
+    pub fn with_package_dir(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.package_dirs.push(dir.into());
+        self
+    }
+}
+
+/// Set the config of the typst world used by [`typst_svg`] and [`TypstText`].
+///
+/// This clears the cache of the compiled sources, so set it before constructing items:
+///
+/// ```rust,ignore
+/// set_typst_config(
+///     TypstConfig::default().with_preamble(r#"#import "@preview/physica:0.9.5": *"#),
+/// );
+/// let eq = TypstText::new("$dv(f, x)$");
+/// ```
+pub fn set_typst_config(config: TypstConfig) {
+    *typst_world().lock().unwrap() = TypstWorld::with_config(config);
+    typst_lru().lock().unwrap().inner.clear();
+}
+
+/// The default dirs to look for typst packages in, in the same order as the typst CLI.
+fn default_package_dirs() -> Vec<PathBuf> {
+    let env_dir = |key: &str| std::env::var_os(key).map(PathBuf::from);
+    let home = env_dir("HOME").or_else(|| env_dir("USERPROFILE"));
+    let (data_dir, cache_dir) = if cfg!(target_os = "windows") {
+        (env_dir("APPDATA"), env_dir("LOCALAPPDATA"))
+    } else if cfg!(target_os = "macos") {
+        let library = home.map(|home| home.join("Library"));
+        (
+            library.as_ref().map(|dir| dir.join("Application Support")),
+            library.map(|dir| dir.join("Caches")),
+        )
+    } else {
+        (
+            env_dir("XDG_DATA_HOME").or_else(|| home.as_ref().map(|h| h.join(".local/share"))),
+            env_dir("XDG_CACHE_HOME").or_else(|| home.as_ref().map(|h| h.join(".cache"))),
+        )
+    };
+    [
+        env_dir("TYPST_PACKAGE_PATH"),
+        data_dir.map(|dir| dir.join("typst/packages")),
+        env_dir("TYPST_PACKAGE_CACHE_PATH"),
+        cache_dir.map(|dir| dir.join("typst/packages")),
+    ]
+    .into_iter()
+    .flatten()
+    .collect()
+}
+
 struct FileEntry {
     bytes: Bytes,
     /// This field is filled on demand.
@@ -129,19 +209,46 @@ pub(crate) struct TypstWorld {
     library: LazyHash<Library>,
     book: LazyHash<FontBook>,
     files: Mutex<HashMap<FileId, FileEntry>>,
+    preamble: String,
+    package_dirs: Vec<PathBuf>,
 }
 
 impl TypstWorld {
     pub(crate) fn new() -> Self {
+        Self::with_config(TypstConfig::default())
+    }
+    pub(crate) fn with_config(config: TypstConfig) -> Self {
         let fonts = fonts();
+        let mut package_dirs = config.package_dirs;
+        package_dirs.extend(default_package_dirs());
         Self {
             library: LazyHash::new(Library::default()),
             book: LazyHash::new(fonts.book.clone()),
             files: Mutex::new(HashMap::new()),
+            preamble: config.preamble,
+            package_dirs,
         }
     }
     pub(crate) fn with_source_str(&self, source: &str) -> TypstWorldWithSource<'_> {
-        self.with_source(Source::detached(source))
+        if self.preamble.is_empty() {
+            self.with_source(Source::detached(source))
+        } else {
+            self.with_source(Source::detached(format!("{}\n{source}", self.preamble)))
+        }
+    }
+    /// Compile the source into the typst element of the svg, or the compile errors.
+    fn compile_svg(&self, source: &str) -> Result<String, String> {
+        let world = self.with_source_str(source);
+        let document = typst::compile(&world).output.map_err(|errors| {
+            errors
+                .iter()
+                .map(|error| error.message.to_string())
+                .collect::<Vec<_>>()
+                .join("; ")
+        })?;
+
+        let svg = typst_svg::svg_merged(&document, Abs::pt(2.0));
+        Ok(get_typst_element(&svg))
     }
     pub(crate) fn with_source(&self, source: Source) -> TypstWorldWithSource<'_> {
         TypstWorldWithSource {
@@ -152,30 +259,36 @@ impl TypstWorld {
     }
 
     // from https://github.com/mattfbacon/typst-bot
-    // TODO: package things
     // Weird pattern because mapping a MutexGuard is not stable yet.
     fn file<T>(&self, id: FileId, map: impl FnOnce(&mut FileEntry) -> T) -> FileResult<T> {
         let mut files = self.files.lock().unwrap();
         if let Some(entry) = files.get_mut(&id) {
             return Ok(map(entry));
         }
-        // `files` must stay locked here so we don't download the same package multiple times.
-        // TODO proper multithreading, maybe with typst-kit.
-
-        // 'x: {
-        // 	if let Some(package) = id.package() {
-        // 		let package_dir = self.ensure_package(package)?;
-        // 		let Some(path) = id.vpath().resolve(&package_dir) else {
-        // 			break 'x;
-        // 		};
-        // 		let contents = std::fs::read(&path).map_err(|error| FileError::from_io(error, &path))?;
-        // 		let entry = files.entry(id).or_insert(FileEntry {
-        // 			bytes: Bytes::new(contents),
-        // 			source: None,
-        // 		});
-        // 		return Ok(map(entry));
-        // 	}
-        // }
+
+        if let Some(package) = id.package() {
+            let package_dir = self
+                .package_dirs
+                .iter()
+                .map(|dir| {
+                    dir.join(package.namespace.as_str())
+                        .join(package.name.as_str())
+                        .join(package.version.to_string())
+                })
+                .find(|dir| dir.is_dir())
+                .ok_or_else(|| FileError::Package(PackageError::NotFound(package.clone())))?;
+            let path = id
+                .vpath()
+                .resolve(&package_dir)
+                .ok_or(FileError::AccessDenied)?;
+            let contents =
+                std::fs::read(&path).map_err(|error| FileError::from_io(error, &path))?;
+            let entry = files.entry(id).or_insert(FileEntry {
+                bytes: Bytes::new(contents),
+                source: None,
+            });
+            return Ok(map(entry));
+        }
 
         Err(FileError::NotFound(id.vpath().as_rootless_path().into()))
     }
@@ -255,6 +368,9 @@ impl TypstText {
     ///
     /// The typst string you provide should only produces text output,
     /// otherwise undefined behaviours may happens.
+    ///
+    /// To make the preview app reload the scene on changes of a typst file,
+    /// read it with [`ranim_core::RanimScene::read_asset`] instead of `include_str!`.
     pub fn new(typst_str: &str) -> Self {
         let svg = SvgItem::new(typst_svg(typst_str));
         let chars = typst_str
@@ -297,6 +413,20 @@ impl TypstText {
         assert_eq!(chars.len(), vitems.len());
         Self { chars, vitems }
     }
+
+    /// Set a background stroke for all the glyphs, see [`VItem::set_background_stroke`].
+    ///
+    /// The background strokes of all the glyphs are drawn behind all the glyphs.
+    pub fn set_background_stroke(
+        &mut self,
+        color: color::AlphaColor<color::Srgb>,
+        width: f32,
+    ) -> &mut Self {
+        self.vitems.iter_mut().for_each(|vitem| {
+            vitem.set_background_stroke(color, width);
+        });
+        self
+    }
 }
 
 impl Alignable for TypstText {
@@ -431,7 +561,7 @@ impl From<TypstText> for Vec<VItem> {
 impl Extract for TypstText {
     type Target = CoreItem;
     fn extract_into(&self, buf: &mut Vec<Self::Target>) {
-        self.vitems.extract_into(buf);
+        extract_vitems_with_background(&self.vitems, buf);
     }
 }
 
@@ -599,6 +729,35 @@ mod tests {
         // println!("{}", typst_svg!(source))
     }
 
+    #[test]
+    fn test_typst_package() {
+        let dir = std::env::temp_dir().join(format!("ranim-typst-packages-{}", std::process::id()));
+        let package_dir = dir.join("local/ranim-test/0.1.0");
+        std::fs::create_dir_all(&package_dir).unwrap();
+        std::fs::write(
+            package_dir.join("typst.toml"),
+            "[package]\nname = \"ranim-test\"\nversion = \"0.1.0\"\nentrypoint = \"lib.typ\"\n",
+        )
+        .unwrap();
+        std::fs::write(package_dir.join("lib.typ"), "#let twice(x) = [#x#x]").unwrap();
+
+        let world = TypstWorld::with_config(
+            TypstConfig::default()
+                .with_preamble(r#"#import "@local/ranim-test:0.1.0": twice"#)
+                .with_package_dir(&dir),
+        );
+        let svg = world.compile_svg("#twice[R]").unwrap();
+        assert_eq!(svg.matches("<use").count(), 2);
+
+        // A package that can't be resolved is an error instead of a panic
+        let err = world
+            .compile_svg(r#"#import "@local/not-exist:0.1.0": *"#)
+            .unwrap_err();
+        assert!(err.contains("package not found"), "{err}");
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
     ///
     /// ```
     /// <svg class="typst-doc" viewBox="0 0 11.483999999999998 11" width="11.483999999999998pt" height="11pt" xmlns="http://www.w3.org/2000/svg" xmlns:xlink="http://www.w3.org/1999/xlink" xmlns:h5="http://www.w3.org/1999/xhtml">
@@ -51,10 +51,13 @@ fn ranim_core_path() -> proc_macro2::TokenStream {
 /// 解析单个属性（#[scene(...)] /  / #[output(...)]）
 #[derive(Default)]
 struct SceneAttrs {
-    name: Option<String>,        // #[scene(name = "...")]
-    clear_color: Option<String>, // #[scene(clear_color = "#000000")]
-    wasm_demo_doc: bool,         // #[wasm_demo_doc]
-    outputs: Vec<OutputDef>,     // #[output(...)]
+    name: Option<String>,             // #[scene(name = "...")]
+    clear_color: Option<String>,      // #[scene(clear_color = "#000000")]
+    min_stroke_width_px: Option<f32>, // #[scene(min_stroke_width_px = 1.0)]
+    frame_width: Option<f32>,         // #[scene(frame_width = 14.0)]
+    wasm_demo_doc: bool,              // #[wasm_demo_doc]
+    preview: bool,                    // #[preview]
+    outputs: Vec<OutputDef>,          // #[output(...)]
 }
 
 /// 一个 #[output(...)] 里的字段
@@ -67,6 +70,9 @@ struct OutputDef {
     name: Option<String>,
     dir: String,
     format: Option<String>,
+    capture_metadata: bool,
+    background: Option<String>,
+    background_fit: Option<String>,
 }
 
 // MARK: scene
@@ -90,9 +96,19 @@ pub fn scene(args: TokenStream, input: TokenStream) -> TokenStream {
 
     // StaticSceneConfig
     let clear_color = attrs.clear_color.unwrap_or("#333333ff".to_string());
+    let min_stroke_width_px = attrs.min_stroke_width_px.unwrap_or(0.0);
+    let frame_width = match attrs.frame_width {
+        Some(w) => {
+            let w = w as f64;
+            quote! { Some(#w) }
+        }
+        None => quote! { None },
+    };
     let scene_config = quote! {
         #ranim::StaticSceneConfig {
             clear_color: #clear_color,
+            min_stroke_width_px: #min_stroke_width_px,
+            frame_width: #frame_width,
         }
     };
 
@@ -106,6 +122,9 @@ pub fn scene(args: TokenStream, input: TokenStream) -> TokenStream {
         name,
         dir,
         format,
+        capture_metadata,
+        background,
+        background_fit,
     } in attrs.outputs
     {
         let name_token = match name.as_deref() {
@@ -119,6 +138,16 @@ pub fn scene(args: TokenStream, input: TokenStream) -> TokenStream {
             Some("gif") => quote! { #ranim::OutputFormat::Gif },
             Some(other) => panic!("unknown output format: {other:?}"),
         };
+        let background_token = match background.as_deref() {
+            Some(b) if !b.is_empty() => quote! { Some(#b) },
+            _ => quote! { None },
+        };
+        let background_fit_token = match background_fit.as_deref() {
+            Some("cover") | None => quote! { #ranim::BackgroundFit::Cover },
+            Some("contain") => quote! { #ranim::BackgroundFit::Contain },
+            Some("stretch") => quote! { #ranim::BackgroundFit::Stretch },
+            Some(other) => panic!("unknown background fit: {other:?}"),
+        };
         outputs.push(quote! {
             #ranim::StaticOutput {
                 width: #width,
@@ -128,6 +157,9 @@ pub fn scene(args: TokenStream, input: TokenStream) -> TokenStream {
                 name: #name_token,
                 dir: #dir,
                 format: #format_token,
+                capture_metadata: #capture_metadata,
+                background: #background_token,
+                background_fit: #background_fit_token,
             }
         });
     }
@@ -153,6 +185,7 @@ pub fn scene(args: TokenStream, input: TokenStream) -> TokenStream {
     let static_scene_name = syn::Ident::new("__SCENE", fn_name.span());
 
     let output_cnt = outputs.len();
+    let preview = attrs.preview;
 
     let scene = quote! {
         #ranim::StaticScene {
@@ -160,6 +193,7 @@ pub fn scene(args: TokenStream, input: TokenStream) -> TokenStream {
             constructor: super::#fn_name,
             config: #scene_config,
             outputs: &#static_output_name,
+            preview: #preview,
         }
     };
 
@@ -204,10 +238,13 @@ pub fn output(_: TokenStream, _: TokenStream) -> TokenStream {
     TokenStream::new()
 }
 
-// #[proc_macro_attribute]
-// pub fn preview(_: TokenStream, _: TokenStream) -> TokenStream {
-//     TokenStream::new()
-// }
+/// Mark the scene to preview when `ranim preview` is run without a scene name.
+///
+/// Without any marked scene, the first scene is previewed.
+#[proc_macro_attribute]
+pub fn preview(_: TokenStream, _: TokenStream) -> TokenStream {
+    TokenStream::new()
+}
 
 #[proc_macro_attribute]
 pub fn wasm_demo_doc(_attr: TokenStream, _: TokenStream) -> TokenStream {
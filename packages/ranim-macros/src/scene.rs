@@ -1,4 +1,4 @@
-use crate::utils::{expr_to_bool, expr_to_u32};
+use crate::utils::{expr_to_bool, expr_to_f32, expr_to_u32};
 use crate::{OutputDef, SceneAttrs};
 
 use syn::{Expr, ExprLit, Lit, Meta, MetaList, MetaNameValue, token::Comma};
@@ -30,6 +30,10 @@ pub fn parse_scene_attrs(
                 }) = nv.value
             {
                 res.clear_color = Some(s.value());
+            } else if nv.path.is_ident("min_stroke_width_px") {
+                res.min_stroke_width_px = Some(expr_to_f32(&nv.value)?);
+            } else if nv.path.is_ident("frame_width") {
+                res.frame_width = Some(expr_to_f32(&nv.value)?);
             }
         }
     }
@@ -39,6 +43,10 @@ pub fn parse_scene_attrs(
             res.wasm_demo_doc = true;
             continue;
         }
+        if attr.path().is_ident("preview") {
+            res.preview = true;
+            continue;
+        }
 
         if let Meta::List(list) = &attr.meta
             && list.path.is_ident("output")
@@ -62,17 +70,22 @@ pub fn parse_output_list(list: &MetaList) -> syn::Result<OutputDef> {
         name: None,
         dir: "./output".into(),
         format: None,
+        capture_metadata: false,
+        background: None,
+        background_fit: None,
     };
 
     let parser = Punctuated::<MetaNameValue, Comma>::parse_terminated;
     let kvs = parser.parse2(list.tokens.clone())?;
 
+    let mut aspect = None;
     for nv in kvs {
         match nv.path.get_ident().map(|i| i.to_string()).as_deref() {
             Some("width") => def.width = expr_to_u32(&nv.value)?,
             Some("height") => def.height = expr_to_u32(&nv.value)?,
             Some("fps") => def.fps = expr_to_u32(&nv.value)?,
             Some("save_frames") => def.save_frames = expr_to_bool(&nv.value)?,
+            Some("capture_metadata") => def.capture_metadata = expr_to_bool(&nv.value)?,
             Some("name") => {
                 if let Expr::Lit(ExprLit {
                     lit: Lit::Str(s), ..
@@ -89,6 +102,22 @@ pub fn parse_output_list(list: &MetaList) -> syn::Result<OutputDef> {
                     def.dir = s.value();
                 }
             }
+            Some("background") => {
+                if let Expr::Lit(ExprLit {
+                    lit: Lit::Str(s), ..
+                }) = nv.value
+                {
+                    def.background = Some(s.value());
+                }
+            }
+            Some("background_fit") => {
+                if let Expr::Lit(ExprLit {
+                    lit: Lit::Str(s), ..
+                }) = nv.value
+                {
+                    def.background_fit = Some(s.value());
+                }
+            }
             Some("format") => {
                 if let Expr::Lit(ExprLit {
                     lit: Lit::Str(s), ..
@@ -97,8 +126,34 @@ pub fn parse_output_list(list: &MetaList) -> syn::Result<OutputDef> {
                     def.format = Some(s.value());
                 }
             }
+            Some("aspect") => {
+                if let Expr::Lit(ExprLit {
+                    lit: Lit::Str(s), ..
+                }) = &nv.value
+                {
+                    aspect = Some(parse_aspect(&s.value()).ok_or_else(|| {
+                        syn::Error::new_spanned(&nv.value, "expected aspect like \"9:16\"")
+                    })?);
+                }
+            }
             _ => {}
         }
     }
+    // Keep the shorter side, same as `Output::with_aspect`
+    if let Some((w, h)) = aspect {
+        let short = def.width.min(def.height);
+        (def.width, def.height) = if w >= h {
+            (short * w / h, short)
+        } else {
+            (short, short * h / w)
+        };
+    }
     Ok(def)
 }
+
+/// Parse an aspect ratio like `"9:16"`
+fn parse_aspect(s: &str) -> Option<(u32, u32)> {
+    let (w, h) = s.split_once(':')?;
+    let (w, h) = (w.trim().parse().ok()?, h.trim().parse().ok()?);
+    (w > 0 && h > 0).then_some((w, h))
+}
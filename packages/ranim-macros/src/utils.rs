@@ -8,6 +8,20 @@ pub fn expr_to_u32(expr: &syn::Expr) -> syn::Result<u32> {
     }
 }
 
+pub fn expr_to_f32(expr: &syn::Expr) -> syn::Result<f32> {
+    match expr {
+        syn::Expr::Lit(syn::ExprLit {
+            lit: syn::Lit::Float(f),
+            ..
+        }) => f.base10_parse(),
+        syn::Expr::Lit(syn::ExprLit {
+            lit: syn::Lit::Int(i),
+            ..
+        }) => i.base10_parse(),
+        _ => Err(syn::Error::new_spanned(expr, "expected number literal")),
+    }
+}
+
 pub fn expr_to_bool(expr: &syn::Expr) -> syn::Result<bool> {
     match expr {
         syn::Expr::Lit(syn::ExprLit {
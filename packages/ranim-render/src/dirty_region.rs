@@ -0,0 +1,545 @@
+//! Partial redraw support.
+//!
+//! When only a few items change between two frames, only the pixels they covered
+//! in the previous frame and the pixels they cover now need to be redrawn. The
+//! [`DirtyRegionTracker`] remembers the content hash and the screen rect of every
+//! item of the last rendered frame, and computes the [`DirtyRegion`] of the next one.
+use std::{
+    collections::HashMap,
+    hash::{DefaultHasher, Hash, Hasher},
+    sync::atomic::{AtomicU64, Ordering},
+};
+
+use glam::{Vec2, Vec3};
+use ranim_core::{
+    core_item::{camera_frame::ViewportRect, mesh_item::MeshItem, vitem::VItem},
+    store::CoreItemStore,
+};
+
+use crate::primitives::viewport::ViewportUniform;
+
+/// The region is redrawn in full once the dirty rect covers more than this ratio of the screen.
+pub const FULL_REDRAW_AREA_RATIO: f32 = 0.5;
+
+/// Extra pixels around each item's rect to cover anti-aliasing.
+const AA_MARGIN_PX: f32 = 2.0;
+
+/// A rect in pixels, with the origin at the top-left.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PixelRect {
+    pub x: u32,
+    pub y: u32,
+    pub width: u32,
+    pub height: u32,
+}
+
+impl PixelRect {
+    /// The rect covering the whole target.
+    pub fn full(width: u32, height: u32) -> Self {
+        Self {
+            x: 0,
+            y: 0,
+            width,
+            height,
+        }
+    }
+
+    /// The area in pixels.
+    pub fn area(&self) -> u64 {
+        self.width as u64 * self.height as u64
+    }
+
+    /// The smallest rect covering both `self` and `other`.
+    pub fn union(&self, other: &Self) -> Self {
+        let (x, y) = (self.x.min(other.x), self.y.min(other.y));
+        let max_x = (self.x + self.width).max(other.x + other.width);
+        let max_y = (self.y + self.height).max(other.y + other.height);
+        Self {
+            x,
+            y,
+            width: max_x - x,
+            height: max_y - y,
+        }
+    }
+
+    /// The overlapping rect of `self` and `other`, `None` if they don't overlap.
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        let (x, y) = (self.x.max(other.x), self.y.max(other.y));
+        let max_x = (self.x + self.width).min(other.x + other.width);
+        let max_y = (self.y + self.height).min(other.y + other.height);
+        (x < max_x && y < max_y).then(|| Self {
+            x,
+            y,
+            width: max_x - x,
+            height: max_y - y,
+        })
+    }
+
+    /// The rect of a [`ViewportRect`] in a target of `width` by `height` pixels,
+    /// rounded to whole pixels and clamped to the target.
+    ///
+    /// Returns `None` if the rect doesn't cover any pixel.
+    pub fn from_viewport_rect(rect: &ViewportRect, width: u32, height: u32) -> Option<Self> {
+        let size = Vec2::new(width as f32, height as f32);
+        let min = Vec2::new(rect.x as f32, rect.y as f32) * size;
+        let max = min + Vec2::new(rect.width as f32, rect.height as f32) * size;
+        Self::from_corners(min.round(), max.round(), width, height)
+    }
+
+    /// Build the rect from min and max corners in pixels, clamped to the target.
+    ///
+    /// Returns `None` if the rect is entirely outside of the target.
+    fn from_corners(min: Vec2, max: Vec2, width: u32, height: u32) -> Option<Self> {
+        let min = min.floor().max(Vec2::ZERO);
+        let max = max.ceil().min(Vec2::new(width as f32, height as f32));
+        if min.x >= max.x || min.y >= max.y {
+            return None;
+        }
+        Some(Self {
+            x: min.x as u32,
+            y: min.y as u32,
+            width: (max.x - min.x) as u32,
+            height: (max.y - min.y) as u32,
+        })
+    }
+}
+
+/// The region of the target that needs to be redrawn.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DirtyRegion {
+    /// Nothing changed, the previous frame can be kept as is.
+    Clean,
+    /// Only the pixels inside the rect changed.
+    Partial(PixelRect),
+    /// The whole target needs to be redrawn.
+    Full,
+}
+
+/// The footprint of an item in a rendered frame.
+#[derive(Debug, Clone, Copy)]
+struct ItemFootprint {
+    hash: u64,
+    /// `None` if the item is off-screen.
+    rect: Option<PixelRect>,
+}
+
+/// Everything other than the items that affects the whole frame.
+#[derive(Debug, Clone, PartialEq)]
+struct FrameState {
+    viewport: ViewportUniform,
+    clear_color: [f64; 4],
+    min_stroke_width: f32,
+    size: (u32, u32),
+}
+
+static NEXT_FRAME_TOKEN: AtomicU64 = AtomicU64::new(0);
+
+/// Tracks the items of the last rendered frame to compute the [`DirtyRegion`] of the next one.
+#[derive(Default)]
+pub struct DirtyRegionTracker {
+    /// A globally unique token of the last recorded frame.
+    frame_token: Option<u64>,
+    frame_state: Option<FrameState>,
+    items: HashMap<(u8, (usize, usize)), ItemFootprint>,
+}
+
+impl DirtyRegionTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A globally unique token of the last recorded frame, `None` if there is none.
+    ///
+    /// It is stored in the [`crate::resource::RenderTextures`] the frame is rendered to,
+    /// so that a partial redraw is only done over the frame it is computed against.
+    pub fn frame_token(&self) -> Option<u64> {
+        self.frame_token
+    }
+
+    /// Forget the last frame, the next [`Self::update`] will return [`DirtyRegion::Full`].
+    pub fn reset(&mut self) {
+        self.frame_token = None;
+        self.frame_state = None;
+        self.items.clear();
+    }
+
+    /// Record the given frame and return its [`DirtyRegion`] against the last recorded one.
+    pub fn update(
+        &mut self,
+        store: &CoreItemStore,
+        viewport: &ViewportUniform,
+        clear_color: wgpu::Color,
+        min_stroke_width: f32,
+        (width, height): (u32, u32),
+    ) -> DirtyRegion {
+        let frame_state = FrameState {
+            viewport: *viewport,
+            clear_color: [clear_color.r, clear_color.g, clear_color.b, clear_color.a],
+            min_stroke_width,
+            size: (width, height),
+        };
+
+        let vitems = store.vitem_ids.iter().zip(&store.vitems).enumerate();
+        let vitems = vitems.map(|(idx, (id, vitem))| {
+            let footprint = ItemFootprint {
+                hash: vitem_hash(idx, vitem),
+                rect: vitem_rect(vitem, viewport, min_stroke_width, (width, height)),
+            };
+            ((0, *id), footprint)
+        });
+        let mesh_items = store
+            .mesh_item_ids
+            .iter()
+            .zip(&store.mesh_items)
+            .enumerate();
+        let mesh_items = mesh_items.map(|(idx, (id, mesh_item))| {
+            let footprint = ItemFootprint {
+                hash: mesh_item_hash(idx, mesh_item),
+                rect: mesh_item_rect(mesh_item, viewport, (width, height)),
+            };
+            ((1, *id), footprint)
+        });
+        let items = vitems.chain(mesh_items).collect::<HashMap<_, _>>();
+
+        let full_redraw = self.frame_state.as_ref() != Some(&frame_state);
+        let prev_items = std::mem::replace(&mut self.items, items);
+        self.frame_state = Some(frame_state);
+        self.frame_token = Some(NEXT_FRAME_TOKEN.fetch_add(1, Ordering::Relaxed));
+        if full_redraw {
+            return DirtyRegion::Full;
+        }
+
+        let mut dirty: Option<PixelRect> = None;
+        let mut mark = |rect: Option<PixelRect>| {
+            if let Some(rect) = rect {
+                dirty = Some(dirty.map_or(rect, |dirty| dirty.union(&rect)));
+            }
+        };
+        for (key, footprint) in &self.items {
+            match prev_items.get(key) {
+                Some(prev) if prev.hash == footprint.hash => {}
+                Some(prev) => {
+                    mark(prev.rect);
+                    mark(footprint.rect);
+                }
+                None => mark(footprint.rect),
+            }
+        }
+        for (key, prev) in &prev_items {
+            if !self.items.contains_key(key) {
+                mark(prev.rect);
+            }
+        }
+
+        match dirty {
+            None => DirtyRegion::Clean,
+            Some(rect)
+                if rect.area() as f32
+                    > PixelRect::full(width, height).area() as f32 * FULL_REDRAW_AREA_RATIO =>
+            {
+                DirtyRegion::Full
+            }
+            Some(rect) => DirtyRegion::Partial(rect),
+        }
+    }
+}
+
+// The index is hashed too, since the draw order affects the result.
+fn vitem_hash(idx: usize, vitem: &VItem) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    idx.hash(&mut hasher);
+    vitem
+        .normal
+        .map(|n| n.to_array())
+        .map(|n| n.map(f32::to_bits))
+        .hash(&mut hasher);
+    hasher.write(bytemuck::cast_slice(&vitem.points));
+    hasher.write(bytemuck::cast_slice(&vitem.fill_rgbas));
+    hasher.write(bytemuck::cast_slice(&vitem.stroke_rgbas));
+    hasher.write(bytemuck::cast_slice(&vitem.stroke_widths));
+    hasher.write(bytemuck::bytes_of(&vitem.fill_pattern.to_vec4()));
+    hasher.finish()
+}
+
+fn mesh_item_hash(idx: usize, mesh_item: &MeshItem) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    idx.hash(&mut hasher);
+    hasher.write(bytemuck::cast_slice(&mesh_item.points));
+    hasher.write(bytemuck::cast_slice(&mesh_item.triangle_indices));
+    hasher.write(bytemuck::bytes_of(&mesh_item.transform));
+    hasher.write(bytemuck::cast_slice(&mesh_item.vertex_colors));
+    hasher.write(bytemuck::cast_slice(&mesh_item.vertex_normals));
+    hasher.finish()
+}
+
+/// The screen rect of the world space box, `None` if it is off-screen.
+///
+/// If any corner is behind the camera, the whole target is returned.
+fn projected_rect(
+    min: Vec3,
+    max: Vec3,
+    viewport: &ViewportUniform,
+    (width, height): (u32, u32),
+) -> Option<PixelRect> {
+    let mut pixel_min = Vec2::splat(f32::INFINITY);
+    let mut pixel_max = Vec2::splat(f32::NEG_INFINITY);
+    for i in 0..8 {
+        let corner = Vec3::new(
+            if i & 1 == 0 { min.x } else { max.x },
+            if i & 2 == 0 { min.y } else { max.y },
+            if i & 4 == 0 { min.z } else { max.z },
+        );
+        let Some(pixel) = viewport.world_to_pixel(corner, width, height) else {
+            return Some(PixelRect::full(width, height));
+        };
+        pixel_min = pixel_min.min(pixel);
+        pixel_max = pixel_max.max(pixel);
+    }
+    PixelRect::from_corners(
+        pixel_min - AA_MARGIN_PX,
+        pixel_max + AA_MARGIN_PX,
+        width,
+        height,
+    )
+}
+
+fn vitem_rect(
+    vitem: &VItem,
+    viewport: &ViewportUniform,
+    min_stroke_width: f32,
+    size: (u32, u32),
+) -> Option<PixelRect> {
+    let (min, max) = vitem.points.iter().fold(
+        (Vec3::splat(f32::INFINITY), Vec3::splat(f32::NEG_INFINITY)),
+        |(min, max), p| (min.min(p.truncate()), max.max(p.truncate())),
+    );
+    if !min.is_finite() || !max.is_finite() {
+        return None;
+    }
+    // A stroke covers `width` on each side of the path
+    let stroke_width = vitem
+        .stroke_widths
+        .iter()
+        .map(|w| w.0)
+        .fold(min_stroke_width, f32::max);
+    projected_rect(min - stroke_width, max + stroke_width, viewport, size)
+}
+
+fn mesh_item_rect(
+    mesh_item: &MeshItem,
+    viewport: &ViewportUniform,
+    size: (u32, u32),
+) -> Option<PixelRect> {
+    let (min, max) = mesh_item.points.iter().fold(
+        (Vec3::splat(f32::INFINITY), Vec3::splat(f32::NEG_INFINITY)),
+        |(min, max), p| {
+            let p = mesh_item.transform.transform_point3(*p);
+            (min.min(p), max.max(p))
+        },
+    );
+    if !min.is_finite() || !max.is_finite() {
+        return None;
+    }
+    projected_rect(min, max, viewport, size)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use glam::Vec4;
+    use ranim_core::{
+        components::{rgba::Rgba, width::Width},
+        core_item::{CoreItem, camera_frame::CameraFrame, vitem::FillPattern},
+    };
+
+    fn dot(center: Vec3) -> VItem {
+        let half_size = 0.3;
+        let corners = [
+            center + Vec3::new(-half_size, -half_size, 0.0),
+            center + Vec3::new(half_size, -half_size, 0.0),
+            center + Vec3::new(half_size, half_size, 0.0),
+            center + Vec3::new(-half_size, half_size, 0.0),
+        ];
+        let mut points = vec![corners[0]];
+        for i in 0..4 {
+            let (a, b) = (corners[i], corners[(i + 1) % 4]);
+            points.push((a + b) / 2.0);
+            points.push(b);
+        }
+        VItem {
+            normal: Some(Vec3::Z),
+            points: points.into_iter().map(|p| p.extend(1.0)).collect(),
+            fill_rgbas: vec![Rgba(Vec4::ONE); 5],
+            stroke_rgbas: vec![Rgba(Vec4::new(1.0, 0.0, 0.0, 1.0)); 5],
+            stroke_widths: vec![Width(0.02); 5],
+            fill_pattern: FillPattern::Solid,
+        }
+    }
+
+    fn update(
+        tracker: &mut DirtyRegionTracker,
+        store: &mut CoreItemStore,
+        camera_frame: &CameraFrame,
+        items: &[VItem],
+    ) -> DirtyRegion {
+        let size = (1920, 1080);
+        store.update(
+            std::iter::once(((0, 0), CoreItem::CameraFrame(camera_frame.clone()))).chain(
+                items
+                    .iter()
+                    .enumerate()
+                    .map(|(i, x)| ((i + 1, 0), CoreItem::VItem(x.clone()))),
+            ),
+        );
+        let viewport = ViewportUniform::from_camera_frame(camera_frame, size.0, size.1);
+        tracker.update(store, &viewport, wgpu::Color::BLACK, 0.0, size)
+    }
+
+    #[test]
+    fn test_pixel_rect() {
+        let a = PixelRect::full(100, 50);
+        let b = PixelRect {
+            x: 80,
+            y: 40,
+            width: 40,
+            height: 40,
+        };
+        assert_eq!(
+            a.intersection(&b),
+            Some(PixelRect {
+                x: 80,
+                y: 40,
+                width: 20,
+                height: 10
+            })
+        );
+        let c = PixelRect { x: 100, ..b };
+        assert_eq!(a.intersection(&c), None);
+
+        let right = PixelRect::from_viewport_rect(&ViewportRect::right_half(), 193, 108);
+        assert_eq!(
+            right,
+            Some(PixelRect {
+                x: 97,
+                y: 0,
+                width: 96,
+                height: 108
+            })
+        );
+        let outside = ViewportRect::new(1.0, 0.0, 0.5, 1.0);
+        assert_eq!(PixelRect::from_viewport_rect(&outside, 192, 108), None);
+    }
+
+    #[test]
+    fn test_dirty_region() {
+        let mut camera_frame = CameraFrame::default();
+        let mut store = CoreItemStore::new();
+        let mut tracker = DirtyRegionTracker::new();
+
+        let a = dot(Vec3::new(-4.0, 0.0, 0.0));
+        let b = dot(Vec3::new(4.0, 0.0, 0.0));
+        let items = [a.clone(), b.clone()];
+        let region = update(&mut tracker, &mut store, &camera_frame, &items);
+        assert_eq!(region, DirtyRegion::Full);
+        let region = update(&mut tracker, &mut store, &camera_frame, &items);
+        assert_eq!(region, DirtyRegion::Clean);
+
+        // Moving `b` dirties its old and new rects, but not `a`'s
+        let items = [a.clone(), dot(Vec3::new(4.0, 1.0, 0.0))];
+        let region = update(&mut tracker, &mut store, &camera_frame, &items);
+        let DirtyRegion::Partial(rect) = region else {
+            panic!("expected a partial redraw, got {region:?}");
+        };
+        // 135 pixels per unit
+        assert!(rect.x > 1920 / 2 && rect.width < 100, "{rect:?}");
+        assert!(rect.height > 135 && rect.height < 250, "{rect:?}");
+
+        // Swapping the draw order dirties both of them
+        let items = [b.clone(), a.clone()];
+        let region = update(&mut tracker, &mut store, &camera_frame, &items);
+        let DirtyRegion::Partial(rect) = region else {
+            panic!("expected a partial redraw, got {region:?}");
+        };
+        assert!(
+            rect.x < 1920 / 2 && rect.x + rect.width > 1920 / 2,
+            "{rect:?}"
+        );
+
+        // Moving the camera redraws everything
+        camera_frame.pos.x += 1.0;
+        let region = update(&mut tracker, &mut store, &camera_frame, &items);
+        assert_eq!(region, DirtyRegion::Full);
+
+        tracker.reset();
+        let region = update(&mut tracker, &mut store, &camera_frame, &items);
+        assert_eq!(region, DirtyRegion::Full);
+    }
+
+    #[test]
+    fn test_partial_redraw_matches_full_redraw() {
+        use crate::{Renderer, resource::RenderPool, utils::WgpuContext};
+        use pollster::block_on;
+
+        let ctx = block_on(WgpuContext::new());
+        let (width, height) = (192u32, 108u32);
+        let mut pool = RenderPool::new();
+        let camera_frame = CameraFrame::default();
+        let mut render = |renderer: &mut Renderer,
+                          render_textures: &mut crate::resource::RenderTextures,
+                          items: &[VItem]| {
+            let mut store = CoreItemStore::new();
+            store.update(
+                std::iter::once(((0, 0), CoreItem::CameraFrame(camera_frame.clone()))).chain(
+                    items
+                        .iter()
+                        .enumerate()
+                        .map(|(i, x)| ((i + 1, 0), CoreItem::VItem(x.clone()))),
+                ),
+            );
+            let clear_color = wgpu::Color {
+                r: 0.1,
+                g: 0.2,
+                b: 0.3,
+                a: 1.0,
+            };
+            renderer.render_store_with_pool(&ctx, render_textures, clear_color, &store, &mut pool);
+            pool.clean();
+            ctx.device
+                .poll(wgpu::PollType::wait_indefinitely())
+                .unwrap();
+            let buffer = render_textures.get_rendered_texture_img_buffer(&ctx);
+            image::RgbaImage::from_raw(width, height, buffer.as_raw().to_vec()).unwrap()
+        };
+
+        let a = dot(Vec3::new(-4.0, 0.0, 0.0));
+        let b = dot(Vec3::new(4.0, 0.0, 0.0));
+        let moved_b = dot(Vec3::new(4.0, 1.0, 0.0));
+
+        let mut renderer = Renderer::new(&ctx, width, height, 8);
+        renderer.set_partial_redraw(true);
+        let mut render_textures = renderer.new_render_textures(&ctx);
+        render(&mut renderer, &mut render_textures, &[a.clone(), b]);
+        assert_eq!(renderer.last_dirty_region(), DirtyRegion::Full);
+        let partial = render(
+            &mut renderer,
+            &mut render_textures,
+            &[a.clone(), moved_b.clone()],
+        );
+        assert!(matches!(
+            renderer.last_dirty_region(),
+            DirtyRegion::Partial(_)
+        ));
+
+        let mut renderer = Renderer::new(&ctx, width, height, 8);
+        let mut render_textures = renderer.new_render_textures(&ctx);
+        let full = render(&mut renderer, &mut render_textures, &[a, moved_b]);
+
+        for (x, y, p) in partial.enumerate_pixels() {
+            let q = full.get_pixel(x, y);
+            assert!(
+                p.0.iter().zip(q.0).all(|(a, b)| a.abs_diff(b) <= 1),
+                "({x}, {y}): {p:?} != {q:?}"
+            );
+        }
+    }
+}
@@ -1,4 +1,7 @@
-use crate::{RenderContext, graph::GlobalRenderNodeTrait, resource::RenderTextures};
+use crate::{
+    RenderContext, graph::GlobalRenderNodeTrait, pipelines::ClearRectPipeline,
+    resource::RenderTextures,
+};
 
 pub struct ClearNode;
 
@@ -20,6 +23,14 @@ impl GlobalRenderNodeTrait for ClearNode {
             ..
         } = render_ctx.render_textures;
 
+        // A partial redraw keeps the previous frame and only clears the scissor rect
+        let (color_load, depth_load) = match render_ctx.scissor {
+            Some(_) => (wgpu::LoadOp::Load, wgpu::LoadOp::Load),
+            None => (
+                wgpu::LoadOp::Clear(render_ctx.clear_color),
+                wgpu::LoadOp::Clear(1.0),
+            ),
+        };
         let pass_desc = wgpu::RenderPassDescriptor {
             label: Some("Clear Pass"),
             color_attachments: &[Some(wgpu::RenderPassColorAttachment {
@@ -27,14 +38,14 @@ impl GlobalRenderNodeTrait for ClearNode {
                 view: render_view,
                 resolve_target: None,
                 ops: wgpu::Operations {
-                    load: wgpu::LoadOp::Clear(render_ctx.clear_color),
+                    load: color_load,
                     store: wgpu::StoreOp::Store,
                 },
             })],
             depth_stencil_attachment: Some(wgpu::RenderPassDepthStencilAttachment {
                 view: depth_stencil_view,
                 depth_ops: Some(wgpu::Operations {
-                    load: wgpu::LoadOp::Clear(1.0),
+                    load: depth_load,
                     store: wgpu::StoreOp::Store,
                 }),
                 stencil_ops: None,
@@ -43,6 +54,16 @@ impl GlobalRenderNodeTrait for ClearNode {
             occlusion_query_set: None,
             multiview_mask: None,
         };
-        encoder.begin_render_pass(&pass_desc);
+        let mut rpass = encoder.begin_render_pass(&pass_desc);
+        if render_ctx.scissor.is_some() {
+            render_ctx.apply_scissor(&mut rpass);
+            rpass.set_pipeline(
+                &render_ctx
+                    .pipelines
+                    .get_or_init::<ClearRectPipeline>(render_ctx.wgpu_ctx),
+            );
+            rpass.set_blend_constant(render_ctx.clear_color);
+            rpass.draw(0..3, 0..1);
+        }
     }
 }
@@ -40,6 +40,7 @@ impl GlobalRenderNodeTrait for OITResolveNode {
             let mut rpass = encoder.scoped_render_pass("OIT Resolve Pass", rpass_desc);
             #[cfg(not(feature = "profiling"))]
             let mut rpass = encoder.begin_render_pass(&rpass_desc);
+            ctx.apply_scissor(&mut rpass);
 
             rpass.set_pipeline(
                 &ctx.pipelines
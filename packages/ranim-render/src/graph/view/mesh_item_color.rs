@@ -57,6 +57,7 @@ impl ViewRenderNodeTrait for MergedMeshItemColorNode {
         let mut rpass = encoder.scoped_render_pass("Merged MeshItem Color Render Pass", rpass_desc);
         #[cfg(not(feature = "profiling"))]
         let mut rpass = encoder.begin_render_pass(&rpass_desc);
+        ctx.apply_scissor(&mut rpass);
         rpass.set_pipeline(
             &ctx.pipelines
                 .get_or_init::<MeshItemColorPipeline>(ctx.wgpu_ctx),
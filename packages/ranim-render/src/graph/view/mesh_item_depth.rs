@@ -47,6 +47,7 @@ impl ViewRenderNodeTrait for MergedMeshItemDepthNode {
         let mut rpass = encoder.scoped_render_pass("Merged MeshItem Depth Render Pass", rpass_desc);
         #[cfg(not(feature = "profiling"))]
         let mut rpass = encoder.begin_render_pass(&rpass_desc);
+        ctx.apply_scissor(&mut rpass);
         rpass.set_pipeline(
             &ctx.pipelines
                 .get_or_init::<MeshItemDepthPipeline>(ctx.wgpu_ctx),
@@ -57,6 +57,7 @@ impl ViewRenderNodeTrait for MergedVItemColorNode {
         let mut rpass = encoder.scoped_render_pass("Merged VItem Color Render Pass", rpass_desc);
         #[cfg(not(feature = "profiling"))]
         let mut rpass = encoder.begin_render_pass(&rpass_desc);
+        ctx.apply_scissor(&mut rpass);
         rpass.set_pipeline(
             &ctx.pipelines
                 .get_or_init::<VItemColorPipeline>(ctx.wgpu_ctx),
@@ -52,6 +52,7 @@ impl ViewRenderNodeTrait for MergedVItemDepthNode {
                 encoder.scoped_render_pass("Merged VItem Depth Render Pass", rpass_desc);
             #[cfg(not(feature = "profiling"))]
             let mut rpass = encoder.begin_render_pass(&rpass_desc);
+            ctx.apply_scissor(&mut rpass);
             rpass.set_pipeline(
                 &ctx.pipelines
                     .get_or_init::<VItemDepthPipeline>(ctx.wgpu_ctx),
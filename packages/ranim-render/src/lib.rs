@@ -6,6 +6,8 @@
     html_logo_url = "https://raw.githubusercontent.com/AzurIce/ranim/refs/heads/main/assets/ranim.svg",
     html_favicon_url = "https://raw.githubusercontent.com/AzurIce/ranim/refs/heads/main/assets/ranim.svg"
 )]
+/// Partial redraw
+pub mod dirty_region;
 /// Render Graph
 pub mod graph;
 /// The pipelines
@@ -19,6 +21,7 @@ pub mod utils;
 use glam::{UVec3, uvec3};
 
 use crate::{
+    dirty_region::{DirtyRegion, DirtyRegionTracker, PixelRect},
     graph::{AnyGlobalRenderNodeTrait, GlobalRenderGraph, RenderPackets},
     primitives::{mesh_items::MeshItemsBuffer, viewport::ViewportUniform, vitems::VItemsBuffer},
     resource::{PipelinesPool, RenderPool, RenderTextures},
@@ -95,6 +98,48 @@ pub struct RenderContext<'a> {
     pub merged_buffer: Option<&'a VItemsBuffer>,
     /// Present when using the merged mesh rendering path.
     pub merged_mesh_buffer: Option<&'a MeshItemsBuffer>,
+    /// The dirty rect of a partial redraw, `None` means the whole target is redrawn.
+    pub scissor: Option<PixelRect>,
+    /// The rect of the view being rendered, `None` means the whole target,
+    /// see [`ranim_core::prelude::CameraFrame::viewport`].
+    pub view_rect: Option<PixelRect>,
+}
+
+impl RenderContext<'_> {
+    /// Restrict the render pass to [`Self::view_rect`] and [`Self::scissor`], if any.
+    ///
+    /// The view rect is also set as the viewport of the pass, so the clip space maps to it.
+    pub fn apply_scissor(&self, rpass: &mut wgpu::RenderPass<'_>) {
+        if let Some(view) = self.view_rect
+            && view.area() > 0
+        {
+            rpass.set_viewport(
+                view.x as f32,
+                view.y as f32,
+                view.width as f32,
+                view.height as f32,
+                0.0,
+                1.0,
+            );
+        }
+        let rect = match (self.scissor, self.view_rect) {
+            (Some(scissor), Some(view)) => Some(scissor.intersection(&view).unwrap_or(PixelRect {
+                width: 0,
+                height: 0,
+                ..view
+            })),
+            (scissor, view) => scissor.or(view),
+        };
+        if let Some(PixelRect {
+            x,
+            y,
+            width,
+            height,
+        }) = rect
+        {
+            rpass.set_scissor_rect(x, y, width, height);
+        }
+    }
 }
 
 // MARK: Renderer
@@ -110,6 +155,16 @@ pub struct Renderer {
     merged_buffer: Option<VItemsBuffer>,
     /// Present when using the merged mesh rendering path (lazily initialized on first use).
     merged_mesh_buffer: Option<MeshItemsBuffer>,
+    /// The minimum stroke width in pixels, `0.0` means no clamping.
+    min_stroke_width_px: f32,
+    /// The frame width overriding the camera's frame height, `None` means no overriding.
+    frame_width: Option<f64>,
+    /// Whether the VItem fill edges use analytic coverage.
+    analytic_aa: bool,
+    /// Present when partial redraw is enabled.
+    dirty_tracker: Option<DirtyRegionTracker>,
+    /// The [`DirtyRegion`] of the last rendered frame.
+    last_dirty_region: DirtyRegion,
 
     #[cfg(feature = "profiling")]
     pub(crate) profiler: wgpu_profiler::GpuProfiler,
@@ -128,6 +183,55 @@ impl Renderer {
         self.width as f32 / self.height as f32
     }
 
+    /// Set the minimum on-screen stroke width in pixels.
+    ///
+    /// Strokes thinner than this (e.g. of items scaled very small) will be
+    /// rendered with this width instead, while zero width strokes stay hidden.
+    /// `0.0` disables the clamping.
+    pub fn set_min_stroke_width_px(&mut self, px: f32) {
+        self.min_stroke_width_px = px.max(0.0);
+    }
+
+    /// Set the frame width in world units.
+    ///
+    /// When set, the camera's [`ranim_core::prelude::CameraFrame::frame_height`] is derived from it
+    /// and the output's aspect ratio, so the horizontal extent stays the same
+    /// for any aspect ratio. `None` uses the camera's frame height as is.
+    pub fn set_frame_width(&mut self, frame_width: Option<f64>) {
+        self.frame_width = frame_width;
+    }
+
+    /// Enable or disable the analytic anti-aliasing of VItem fills, enabled by default.
+    ///
+    /// When enabled, the coverage of a pixel on the fill edge is computed from the distance
+    /// to the edge and the pixel's footprint on the item's plane, so the edges are smooth
+    /// at any zoom without MSAA. When disabled, a fixed antialias radius in world units is used.
+    pub fn set_analytic_aa(&mut self, enabled: bool) {
+        self.analytic_aa = enabled;
+    }
+
+    /// Enable or disable partial redraw.
+    ///
+    /// When enabled, the items' content hashes and screen rects are tracked across
+    /// frames, and only the region covering the changed items is redrawn over the
+    /// previous frame kept in the [`RenderTextures`]. The whole frame is still redrawn
+    /// when the camera changes, when the textures didn't hold the previous frame, or
+    /// when the dirty region covers more than [`dirty_region::FULL_REDRAW_AREA_RATIO`]
+    /// of the screen.
+    ///
+    /// It pays off when the same [`RenderTextures`] are rendered into frame after frame and
+    /// most frames only change a small part of the screen, like in the preview app. It is
+    /// disabled by default, since rendering into several textures in turn, like the video
+    /// output does to overlap the readbacks, always falls back to the full redraw.
+    pub fn set_partial_redraw(&mut self, enabled: bool) {
+        self.dirty_tracker = enabled.then(DirtyRegionTracker::new);
+    }
+
+    /// The [`DirtyRegion`] of the last rendered frame.
+    pub fn last_dirty_region(&self) -> DirtyRegion {
+        self.last_dirty_region
+    }
+
     fn build_render_graph() -> GlobalRenderGraph {
         use graph::*;
         let mut render_graph = GlobalRenderGraph::new();
@@ -185,6 +289,11 @@ impl Renderer {
             render_graph,
             merged_buffer: None,
             merged_mesh_buffer: None,
+            min_stroke_width_px: 0.0,
+            frame_width: None,
+            analytic_aa: true,
+            dirty_tracker: None,
+            last_dirty_region: DirtyRegion::Full,
             #[cfg(feature = "profiling")]
             profiler,
         }
@@ -194,7 +303,46 @@ impl Renderer {
         RenderTextures::new(ctx, self.width, self.height)
     }
 
+    /// The views to render: the [`ViewportUniform`] of each camera and its rect in the target.
+    ///
+    /// The first camera is the main one, the others are only rendered if they have a
+    /// viewport, see [`ranim_core::prelude::CameraFrame::viewport`]. Without any camera, the
+    /// default one is used, see [`CoreItemStore::camera_frames_or_default`].
+    fn views(&self, store: &CoreItemStore) -> Vec<(ViewportUniform, Option<PixelRect>)> {
+        store
+            .camera_frames_or_default()
+            .iter()
+            .enumerate()
+            .filter(|(idx, camera_frame)| *idx == 0 || camera_frame.viewport.is_some())
+            .filter_map(|(idx, camera_frame)| {
+                let view_rect = match &camera_frame.viewport {
+                    Some(rect) => {
+                        match PixelRect::from_viewport_rect(rect, self.width, self.height) {
+                            Some(rect) => Some(rect),
+                            // The main view still clears the target
+                            None if idx == 0 => Some(PixelRect::full(0, 0)),
+                            None => return None,
+                        }
+                    }
+                    None => None,
+                };
+                let (width, height) = view_rect
+                    .map(|rect| (rect.width.max(1), rect.height.max(1)))
+                    .unwrap_or((self.width, self.height));
+                let mut camera_frame = camera_frame.clone();
+                if let Some(frame_width) = self.frame_width {
+                    camera_frame.set_frame_width(frame_width, width as f64 / height as f64);
+                }
+                let viewport = ViewportUniform::from_camera_frame(&camera_frame, width, height);
+                Some((viewport, view_rect))
+            })
+            .collect()
+    }
+
     /// Render a frame. Pushes viewport + VItem packets via pool, then execs the render graph.
+    ///
+    /// With more than one view (see [`ranim_core::prelude::CameraFrame::viewport`]), the render
+    /// graph is executed once for each of them in order, restricted to its rect.
     pub fn render_store_with_pool(
         &mut self,
         ctx: &WgpuContext,
@@ -204,15 +352,65 @@ impl Renderer {
         pool: &mut RenderPool,
     ) {
         // Viewport — always needed
-        let camera_frame = &store.camera_frames[0];
-        let viewport = ViewportUniform::from_camera_frame(camera_frame, self.width, self.height);
-        self.packets.push(pool.alloc_packet(ctx, &viewport));
+        let views = self.views(store);
+        let mut camera_frame = store.camera_frames_or_default()[0].clone();
+        if let Some(frame_width) = self.frame_width {
+            camera_frame.set_frame_width(frame_width, self.width as f64 / self.height as f64);
+        }
+        let viewport = ViewportUniform::from_camera_frame(&camera_frame, self.width, self.height);
+        // A stroke covers `width` on each side of the path
+        let height = views
+            .first()
+            .and_then(|(_, rect)| rect.map(|rect| rect.height))
+            .unwrap_or(self.height);
+        let min_stroke_width =
+            (camera_frame.world_per_pixel(height) as f32) * self.min_stroke_width_px / 2.0;
+
+        let dirty_region = match self.dirty_tracker.as_mut() {
+            Some(tracker) => {
+                // The textures have to hold the previous frame to be drawn over
+                let holds_last_frame = render_textures.last_frame_token() == tracker.frame_token();
+                let dirty_region = tracker.update(
+                    store,
+                    &viewport,
+                    clear_color,
+                    min_stroke_width,
+                    (self.width, self.height),
+                );
+                // The tracker only follows the main camera
+                if holds_last_frame && views.len() == 1 && views[0].1.is_none() {
+                    dirty_region
+                } else {
+                    DirtyRegion::Full
+                }
+            }
+            None => DirtyRegion::Full,
+        };
+        self.last_dirty_region = dirty_region;
+        let frame_token = self
+            .dirty_tracker
+            .as_ref()
+            .and_then(DirtyRegionTracker::frame_token);
+        let scissor = match dirty_region {
+            DirtyRegion::Clean => {
+                render_textures.set_last_frame_token(frame_token);
+                return;
+            }
+            DirtyRegion::Partial(rect) => Some(rect),
+            DirtyRegion::Full => None,
+        };
+
+        // All the packets are allocated before encoding, so that none of them is reused
+        let view_packets = views
+            .iter()
+            .map(|(viewport, view_rect)| (pool.alloc_packet(ctx, viewport), *view_rect))
+            .collect::<Vec<_>>();
 
         // Merged buffer (merged nodes read this; old nodes ignore it)
         let merged = self
             .merged_buffer
             .get_or_insert_with(|| VItemsBuffer::new(ctx));
-        merged.update(ctx, &store.vitems);
+        merged.update(ctx, &store.vitems, min_stroke_width, self.analytic_aa);
 
         // Merged mesh buffer
         let merged_mesh = self
@@ -233,25 +431,44 @@ impl Renderer {
                 #[cfg(feature = "profiling")]
                 let mut scope = self.profiler.scope("render", &mut encoder);
 
-                let render_ctx = RenderContext {
-                    pipelines: &self.pipelines,
-                    render_textures,
-                    render_packets: &self.packets,
-                    render_pool: pool,
-                    wgpu_ctx: ctx,
-                    resolution_info: &self.resolution_info,
-                    clear_color,
-                    merged_buffer: self.merged_buffer.as_ref(),
-                    merged_mesh_buffer: self.merged_mesh_buffer.as_ref(),
-                };
-
-                self.render_graph.exec(
-                    #[cfg(not(feature = "profiling"))]
-                    &mut encoder,
-                    #[cfg(feature = "profiling")]
-                    &mut scope,
-                    render_ctx,
-                );
+                for (idx, (packet, view_rect)) in view_packets.into_iter().enumerate() {
+                    self.packets.push(packet);
+                    // The views after the first one are drawn over it, with their rects cleared
+                    let scissor = match (idx, view_rect) {
+                        (0, _) | (_, None) => scissor,
+                        (_, Some(view_rect)) => Some(
+                            scissor
+                                .map_or(Some(view_rect), |scissor| scissor.intersection(&view_rect))
+                                .unwrap_or(PixelRect {
+                                    width: 0,
+                                    height: 0,
+                                    ..view_rect
+                                }),
+                        ),
+                    };
+                    let render_ctx = RenderContext {
+                        pipelines: &self.pipelines,
+                        render_textures,
+                        render_packets: &self.packets,
+                        render_pool: pool,
+                        wgpu_ctx: ctx,
+                        resolution_info: &self.resolution_info,
+                        clear_color,
+                        merged_buffer: self.merged_buffer.as_ref(),
+                        merged_mesh_buffer: self.merged_mesh_buffer.as_ref(),
+                        scissor,
+                        view_rect,
+                    };
+
+                    self.render_graph.exec(
+                        #[cfg(not(feature = "profiling"))]
+                        &mut encoder,
+                        #[cfg(feature = "profiling")]
+                        &mut scope,
+                        render_ctx,
+                    );
+                    self.packets.clear();
+                }
             }
 
             #[cfg(not(feature = "profiling"))]
@@ -282,9 +499,8 @@ impl Renderer {
             }
 
             render_textures.mark_dirty();
+            render_textures.set_last_frame_token(frame_token);
         }
-
-        self.packets.clear();
     }
 }
 
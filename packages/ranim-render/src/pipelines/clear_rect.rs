@@ -0,0 +1,85 @@
+use std::ops::Deref;
+
+use crate::{
+    WgpuContext,
+    resource::{GpuResource, OUTPUT_TEXTURE_FORMAT},
+};
+
+/// Resets the color to the blend constant and the depth to `1.0`.
+///
+/// Unlike [`wgpu::LoadOp::Clear`] this respects the scissor rect, which is
+/// used to clear the dirty region of a partial redraw.
+pub struct ClearRectPipeline {
+    pipeline: wgpu::RenderPipeline,
+}
+
+impl Deref for ClearRectPipeline {
+    type Target = wgpu::RenderPipeline;
+    fn deref(&self) -> &Self::Target {
+        &self.pipeline
+    }
+}
+
+impl GpuResource for ClearRectPipeline {
+    fn new(wgpu_ctx: &WgpuContext) -> Self {
+        let WgpuContext { device, .. } = wgpu_ctx;
+
+        let module = &device.create_shader_module(wgpu::include_wgsl!("./shaders/clear_rect.wgsl"));
+
+        let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("Clear Rect Pipeline Layout"),
+            bind_group_layouts: &[],
+            immediate_size: 0,
+        });
+
+        // color = constant * 1 + dst * 0
+        let replace_with_constant = wgpu::BlendComponent {
+            src_factor: wgpu::BlendFactor::Constant,
+            dst_factor: wgpu::BlendFactor::Zero,
+            operation: wgpu::BlendOperation::Add,
+        };
+        let pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            label: Some("Clear Rect Pipeline"),
+            layout: Some(&pipeline_layout),
+            vertex: wgpu::VertexState {
+                module,
+                entry_point: Some("vs_main"),
+                buffers: &[],
+                compilation_options: wgpu::PipelineCompilationOptions::default(),
+            },
+            fragment: Some(wgpu::FragmentState {
+                module,
+                entry_point: Some("fs_main"),
+                compilation_options: wgpu::PipelineCompilationOptions::default(),
+                targets: &[Some(wgpu::ColorTargetState {
+                    format: OUTPUT_TEXTURE_FORMAT,
+                    blend: Some(wgpu::BlendState {
+                        color: replace_with_constant,
+                        alpha: replace_with_constant,
+                    }),
+                    write_mask: wgpu::ColorWrites::ALL,
+                })],
+            }),
+            primitive: wgpu::PrimitiveState {
+                topology: wgpu::PrimitiveTopology::TriangleList,
+                ..Default::default()
+            },
+            depth_stencil: Some(wgpu::DepthStencilState {
+                format: wgpu::TextureFormat::Depth32Float,
+                depth_write_enabled: Some(true),
+                depth_compare: Some(wgpu::CompareFunction::Always),
+                stencil: wgpu::StencilState::default(),
+                bias: wgpu::DepthBiasState::default(),
+            }),
+            multisample: wgpu::MultisampleState {
+                count: 1,
+                mask: !0,
+                alpha_to_coverage_enabled: false,
+            },
+            multiview_mask: None,
+            cache: None,
+        });
+
+        Self { pipeline }
+    }
+}
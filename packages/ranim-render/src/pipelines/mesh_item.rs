@@ -21,7 +21,7 @@ impl GpuResource for MeshItemColorPipeline {
     fn new(ctx: &WgpuContext) -> Self {
         let module = &ctx
             .device
-            .create_shader_module(wgpu::include_wgsl!("./shaders/mesh_item.wgsl"));
+            .create_shader_module(include_oit_wgsl!("./shaders/mesh_item.wgsl"));
         let layout = ctx
             .device
             .create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
@@ -93,7 +93,7 @@ impl GpuResource for MeshItemDepthPipeline {
     fn new(ctx: &WgpuContext) -> Self {
         let module = &ctx
             .device
-            .create_shader_module(wgpu::include_wgsl!("./shaders/mesh_item.wgsl"));
+            .create_shader_module(include_oit_wgsl!("./shaders/mesh_item.wgsl"));
         let layout = ctx
             .device
             .create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
@@ -1,9 +1,27 @@
 //! The pipelines of ranim
+
+/// Like [`wgpu::include_wgsl`], but with `shaders/oit_color.wgsl` prepended,
+/// which has the color encoding shared by the shaders writing and resolving the OIT layers.
+macro_rules! include_oit_wgsl {
+    ($path:literal) => {
+        wgpu::ShaderModuleDescriptor {
+            label: Some($path),
+            source: wgpu::ShaderSource::Wgsl(std::borrow::Cow::Borrowed(concat!(
+                include_str!("./shaders/oit_color.wgsl"),
+                "\n",
+                include_str!($path)
+            ))),
+        }
+    };
+}
+
+pub mod clear_rect;
 pub mod debug;
 pub mod mesh_item;
 pub mod oit_resolve;
 pub mod vitem;
 
+pub use clear_rect::ClearRectPipeline;
 pub use mesh_item::{MeshItemColorPipeline, MeshItemDepthPipeline};
 pub use oit_resolve::OITResolvePipeline;
 pub use vitem::{VItemColorPipeline, VItemComputePipeline, VItemDepthPipeline};
@@ -39,8 +39,7 @@ impl GpuResource for OITResolvePipeline {
     fn new(wgpu_ctx: &WgpuContext) -> Self {
         let WgpuContext { device, .. } = wgpu_ctx;
 
-        let module =
-            &device.create_shader_module(wgpu::include_wgsl!("./shaders/oit_resolve.wgsl"));
+        let module = &device.create_shader_module(include_oit_wgsl!("./shaders/oit_resolve.wgsl"));
 
         let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
             label: Some("OIT Resolve Pipeline Layout"),
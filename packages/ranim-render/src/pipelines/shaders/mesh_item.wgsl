@@ -7,9 +7,17 @@ struct CameraUniforms {
     proj_mat: mat4x4<f32>,
     view_mat: mat4x4<f32>,
     half_frame_size: vec2<f32>,
+    clip_min: vec4<f32>,
+    clip_max: vec4<f32>,
 }
 @group(1) @binding(0) var<uniform> cam_uniforms: CameraUniforms;
 
+// Whether the world position is out of the camera's clip region
+fn is_clipped(world_pos: vec3<f32>) -> bool {
+    return cam_uniforms.clip_min.w > 0.5
+        && (any(world_pos < cam_uniforms.clip_min.xyz) || any(world_pos > cam_uniforms.clip_max.xyz));
+}
+
 @group(2) @binding(0) var<storage> transforms: array<mat4x4<f32>>;
 
 struct VertexOutput {
@@ -20,11 +28,6 @@ struct VertexOutput {
     @location(3) world_normal: vec3<f32>,
 }
 
-fn pack_color(color: vec4<f32>) -> u32 {
-    let c = vec4<u32>(color * 255.0);
-    return (c.r) | (c.g << 8u) | (c.b << 16u) | (c.a << 24u);
-}
-
 struct FragmentOutput {
     @location(0) color: vec4<f32>,
 }
@@ -54,6 +57,9 @@ fn fs_color(
     @location(3) world_normal: vec3<f32>,
 ) -> @location(0) vec4<f32> {
     let color = compute_lighting(world_pos, world_normal, vertex_color);
+    if (is_clipped(world_pos)) {
+        discard;
+    }
 
     // Opaque: output directly
     if (color.a >= 0.99) {
@@ -83,6 +89,9 @@ fn fs_depth(
     @location(2) vertex_color: vec4<f32>,
     @location(3) world_normal: vec3<f32>,
 ) -> @builtin(frag_depth) f32 {
+    if (is_clipped(world_pos)) {
+        discard;
+    }
     let color = vertex_color;
 
     // Only write depth for opaque objects
@@ -0,0 +1,30 @@
+// The color encoding of the OIT layers, prepended to the shaders writing and resolving them.
+//
+// The OIT layers only have 8 bits per channel, so the colors are stored
+// sRGB encoded (like the output texture) to avoid banding in dark colors.
+
+fn linear_to_srgb(c: vec3<f32>) -> vec3<f32> {
+    let lo = c * 12.92;
+    let hi = 1.055 * pow(c, vec3(1.0 / 2.4)) - 0.055;
+    return select(hi, lo, c <= vec3(0.0031308));
+}
+
+fn srgb_to_linear(c: vec3<f32>) -> vec3<f32> {
+    let lo = c / 12.92;
+    let hi = pow((c + 0.055) / 1.055, vec3(2.4));
+    return select(hi, lo, c <= vec3(0.04045));
+}
+
+fn pack_color(color: vec4<f32>) -> u32 {
+    let clamped = clamp(color, vec4(0.0), vec4(1.0));
+    let c = vec4<u32>(round(vec4(linear_to_srgb(clamped.rgb), clamped.a) * 255.0));
+    return (c.r) | (c.g << 8u) | (c.b << 16u) | (c.a << 24u);
+}
+
+fn unpack_color(packed: u32) -> vec4<f32> {
+    let r = f32(packed & 0xFFu) / 255.0;
+    let g = f32((packed >> 8u) & 0xFFu) / 255.0;
+    let b = f32((packed >> 16u) & 0xFFu) / 255.0;
+    let a = f32((packed >> 24u) & 0xFFu) / 255.0;
+    return vec4<f32>(srgb_to_linear(vec3(r, g, b)), a);
+}
@@ -31,14 +31,6 @@ struct Node {
     depth: f32,
 }
 
-fn unpack_color(packed: u32) -> vec4<f32> {
-    let r = f32(packed & 0xFFu) / 255.0;
-    let g = f32((packed >> 8u) & 0xFFu) / 255.0;
-    let b = f32((packed >> 16u) & 0xFFu) / 255.0;
-    let a = f32((packed >> 24u) & 0xFFu) / 255.0;
-    return vec4<f32>(r, g, b, a);
-}
-
 // Simple blend function (standard alpha blending: src OVER dst)
 fn blend(src: vec4<f32>, dst: vec4<f32>) -> vec4<f32> {
     // result.a = src.a + dst.a * (1.0 - src.a);
@@ -9,9 +9,17 @@ struct CameraUniforms {
     proj_mat: mat4x4<f32>,
     view_mat: mat4x4<f32>,
     half_frame_size: vec2<f32>,
+    clip_min: vec4<f32>,
+    clip_max: vec4<f32>,
 }
 @group(1) @binding(0) var<uniform> cam_uniforms: CameraUniforms;
 
+// Whether the world position is out of the camera's clip region
+fn is_clipped(world_pos: vec3<f32>) -> bool {
+    return cam_uniforms.clip_min.w > 0.5
+        && (any(world_pos < cam_uniforms.clip_min.xyz) || any(world_pos > cam_uniforms.clip_max.xyz));
+}
+
 // === Merged VItem data (group 2) ===
 
 struct ItemInfo {
@@ -24,6 +32,8 @@ struct ItemInfo {
 struct PlaneData {
     normal: vec4<f32>,
     origin: vec4<f32>,
+    // (kind, spacing, width or radius, angle), kind: 0 solid, 1 hatch, 2 cross hatch, 3 dots
+    fill_pattern: vec4<f32>,
 }
 
 @group(2) @binding(0) var<storage> item_infos: array<ItemInfo>;
@@ -41,6 +51,7 @@ struct VertexOutput {
     @builtin(position) frag_pos: vec4<f32>,
     @location(0) pos: vec2<f32>,
     @location(1) @interpolate(flat) instance_id: u32,
+    @location(2) world_pos: vec3<f32>,
 }
 
 // === Helper: access item's point/attr data ===
@@ -49,8 +60,18 @@ fn item_point(info: ItemInfo, local_idx: u32) -> vec2<f32> {
     return points[info.point_offset + local_idx].xy;
 }
 
+// The flags of a segment: bit 0 is set if it is filled (in a closed subpath),
+// bit 1 is set if its stroke is not drawn
+fn item_flags(info: ItemInfo, local_idx: u32) -> u32 {
+    return u32(points[info.point_offset + local_idx].z);
+}
+
 fn item_is_closed(info: ItemInfo, local_idx: u32) -> bool {
-    return bool(points[info.point_offset + local_idx].z);
+    return (item_flags(info, local_idx) & 1u) != 0u;
+}
+
+fn item_is_stroked(info: ItemInfo, local_idx: u32) -> bool {
+    return (item_flags(info, local_idx) & 2u) == 0u;
 }
 
 fn item_fill_rgba(info: ItemInfo, anchor_idx: u32) -> vec4<f32> {
@@ -67,11 +88,6 @@ fn item_stroke_width(info: ItemInfo, anchor_idx: u32) -> f32 {
 
 // === SDF math (same as original) ===
 
-fn pack_color(color: vec4<f32>) -> u32 {
-    let c = vec4<u32>(color * 255.0);
-    return (c.r) | (c.g << 8u) | (c.b << 16u) | (c.a << 24u);
-}
-
 fn cross_2d(a: vec2<f32>, b: vec2<f32>) -> f32 {
     return a.x * b.y - a.y * b.x;
 }
@@ -156,18 +172,24 @@ fn sign_line(p: vec2<f32>, A: vec2<f32>, B: vec2<f32>) -> f32 {
 
 // === SDF rendering (adapted for merged buffers) ===
 
+// The nearest segments are tracked separately for the fill and the stroke,
+// so that a segment that is only stroked doesn't cut into the fill around it, and vice versa
 struct SubpathAttr {
     end_idx: u32,
-    nearest_idx: u32,
-    d: f32,
+    fill_idx: u32,
+    fill_d: f32,
+    stroke_idx: u32,
+    stroke_d: f32,
     sgn: f32,
 }
 
 fn get_subpath_attr(pos: vec2<f32>, info: ItemInfo, start_local_idx: u32) -> SubpathAttr {
     var attr: SubpathAttr;
     attr.end_idx = info.point_count;
-    attr.nearest_idx = 0u;
-    attr.d = 3.40282346638528859812e38;
+    attr.fill_idx = 0u;
+    attr.fill_d = 3.40282346638528859812e38;
+    attr.stroke_idx = 0u;
+    attr.stroke_d = 3.40282346638528859812e38;
     attr.sgn = 1.0;
 
     let n = (info.point_count - 1u) / 2u * 2u;
@@ -184,11 +206,15 @@ fn get_subpath_attr(pos: vec2<f32>, info: ItemInfo, start_local_idx: u32) -> Sub
         let v2 = normalize(c - b);
         let is_line = abs(cross_2d(v1, v2)) < 0.0001 && dot(v1, v2) > 0.0;
         let dist = select(distance_bezier(pos, a, b, c), distance_line(pos, a, c), is_line);
-        if dist < attr.d {
-            attr.d = dist;
-            attr.nearest_idx = i;
+        if item_is_stroked(info, i) && dist < attr.stroke_d {
+            attr.stroke_d = dist;
+            attr.stroke_idx = i;
         }
         if item_is_closed(info, i) {
+            if dist < attr.fill_d {
+                attr.fill_d = dist;
+                attr.fill_idx = i;
+            }
             attr.sgn *= select(sign_bezier(pos, a, b, c), sign_line(pos, a, c), is_line);
         }
     }
@@ -196,49 +222,125 @@ fn get_subpath_attr(pos: vec2<f32>, info: ItemInfo, start_local_idx: u32) -> Sub
     return attr;
 }
 
-fn render(pos: vec2<f32>, info: ItemInfo) -> vec4<f32> {
-    var idx = 0u;
-    var d = 3.40282346638528859812e38;
+// === Fill pattern ===
+
+// The position on the item's plane used to evaluate the fill pattern, anchored at the
+// world origin so that the patterns of neighbouring items line up.
+// For the xy plane, it is the world (x, y).
+fn pattern_pos(world_pos: vec3<f32>, instance_id: u32) -> vec2<f32> {
+    let basis = basis_from_normal(planes[instance_id].normal.xyz);
+    return vec2(-dot(world_pos, basis.v), dot(world_pos, basis.u));
+}
+
+// The coverage of the lines perpendicular to the axis where `t` is measured
+fn hatch_coverage(t: f32, spacing: f32, width: f32, aa: f32) -> f32 {
+    let cell = t / spacing;
+    let d = abs(cell - round(cell)) * spacing;
+    return smoothstep(aa, -aa, d - width / 2.0);
+}
+
+fn pattern_coverage(q: vec2<f32>, pattern: vec4<f32>, aa: f32) -> f32 {
+    let kind = u32(pattern.x + 0.5);
+    if kind == 0u {
+        return 1.0;
+    }
+    let spacing = max(pattern.y, 1e-4);
+    let size = pattern.z;
+    if kind == 3u {
+        let cell = q / spacing;
+        let offset = (cell - round(cell)) * spacing;
+        return smoothstep(aa, -aa, length(offset) - size);
+    }
+    let dir = vec2(cos(pattern.w), sin(pattern.w));
+    let normal = vec2(-dir.y, dir.x);
+    var coverage = hatch_coverage(dot(q, normal), spacing, size, aa);
+    if kind == 2u {
+        coverage = max(coverage, hatch_coverage(dot(q, dir), spacing, size, aa));
+    }
+    return coverage;
+}
+
+// The size of a pixel on the item's plane, used for the analytic coverage of the fill edge.
+// It is `0.0` if the analytic anti-aliasing is disabled for the item.
+// This uses derivatives, so it has to be called in uniform control flow.
+fn plane_pixel_size(pos: vec2<f32>, instance_id: u32) -> f32 {
+    // fwidth is |dx| + |dy|, which is sqrt(2) times the pixel size for an axis aligned plane
+    let size = length(fwidth(pos)) * 0.70710678;
+    return select(0.0, size, planes[instance_id].origin.w > 0.5);
+}
+
+// The coverage of the fill, `sgn_d` is the signed distance to the edge (negative inside)
+fn fill_coverage(sgn_d: f32, pixel_size: f32, antialias_radius: f32) -> f32 {
+    if pixel_size > 0.0 {
+        // The area of the pixel inside the edge, approximating the edge as a straight line
+        return clamp(0.5 - sgn_d / pixel_size, 0.0, 1.0);
+    }
+    return smoothstep(1.0, -1.0, sgn_d / antialias_radius);
+}
+
+// Where `pos` projects on the segment starting at `idx`, used to mix the attributes of its anchors
+fn segment_ratio(pos: vec2<f32>, info: ItemInfo, idx: u32) -> f32 {
+    let e = item_point(info, idx + 1u) - item_point(info, idx);
+    let w = pos - item_point(info, idx);
+    return clamp(dot(w, e) / dot(e, e), 0.0, 1.0);
+}
+
+fn render(
+    pos: vec2<f32>,
+    info: ItemInfo,
+    pattern_q: vec2<f32>,
+    pattern: vec4<f32>,
+    pixel_size: f32,
+) -> vec4<f32> {
+    var fill_idx = 0u;
+    var fill_d = 3.40282346638528859812e38;
+    var stroke_idx = 0u;
+    var stroke_d = 3.40282346638528859812e38;
     var sgn = 1.0;
 
     var start_idx = 0u;
     while start_idx < info.point_count {
         let attr = get_subpath_attr(pos, info, start_idx);
-        if attr.d < d {
-            idx = attr.nearest_idx;
-            d = attr.d;
+        if attr.fill_d < fill_d {
+            fill_idx = attr.fill_idx;
+            fill_d = attr.fill_d;
+        }
+        if attr.stroke_d < stroke_d {
+            stroke_idx = attr.stroke_idx;
+            stroke_d = attr.stroke_d;
         }
         sgn *= attr.sgn;
         start_idx = attr.end_idx + 2u;
     }
 
-    let sgn_d = sgn * d;
-
-    let e = item_point(info, idx + 1u) - item_point(info, idx);
-    let w = pos - item_point(info, idx);
-    let ratio = clamp(dot(w, e) / dot(e, e), 0.0, 1.0);
-    let anchor_index = idx / 2u;
+    let sgn_d = sgn * fill_d;
 
     let antialias_radius = 0.015 / 4.0;
 
+    let fill_ratio = segment_ratio(pos, info, fill_idx);
+    let fill_anchor = fill_idx / 2u;
     var fill_rgba: vec4<f32> = select(
         vec4(0.0),
-        mix(item_fill_rgba(info, anchor_index), item_fill_rgba(info, anchor_index + 1u), ratio),
-        item_is_closed(info, idx)
+        mix(item_fill_rgba(info, fill_anchor), item_fill_rgba(info, fill_anchor + 1u), fill_ratio),
+        item_is_closed(info, fill_idx)
     );
-    fill_rgba.a *= smoothstep(1.0, -1.0, (sgn_d) / antialias_radius);
+    fill_rgba.a *= fill_coverage(sgn_d, pixel_size, antialias_radius);
+    // The pattern only masks the fill, so the fill opacity and the even-odd rule still apply
+    fill_rgba.a *= pattern_coverage(pattern_q, pattern, antialias_radius);
 
+    let stroke_ratio = segment_ratio(pos, info, stroke_idx);
+    let stroke_anchor = stroke_idx / 2u;
     var stroke_width = mix(
-        item_stroke_width(info, anchor_index),
-        item_stroke_width(info, anchor_index + 1u),
-        ratio
+        item_stroke_width(info, stroke_anchor),
+        item_stroke_width(info, stroke_anchor + 1u),
+        stroke_ratio
     );
     var stroke_rgba: vec4<f32> = mix(
-        item_stroke_rgba(info, anchor_index),
-        item_stroke_rgba(info, anchor_index + 1u),
-        ratio
+        item_stroke_rgba(info, stroke_anchor),
+        item_stroke_rgba(info, stroke_anchor + 1u),
+        stroke_ratio
     );
-    stroke_rgba.a *= smoothstep(1.0, -1.0, (d - stroke_width) / antialias_radius);
+    stroke_rgba.a *= smoothstep(1.0, -1.0, (stroke_d - stroke_width) / antialias_radius);
 
     var f_color = blend_color(stroke_rgba, fill_rgba);
 
@@ -261,10 +363,21 @@ fn fs_main(
     @builtin(position) frag_pos: vec4<f32>,
     @location(0) pos: vec2<f32>,
     @location(1) @interpolate(flat) instance_id: u32,
+    @location(2) world_pos: vec3<f32>,
 ) -> FragmentOutput {
     var out: FragmentOutput;
+    let pixel_size = plane_pixel_size(pos, instance_id);
+    if (is_clipped(world_pos)) {
+        discard;
+    }
     let info = item_infos[instance_id];
-    let color = render(pos, info);
+    let color = render(
+        pos,
+        info,
+        pattern_pos(world_pos, instance_id),
+        planes[instance_id].fill_pattern,
+        pixel_size
+    );
 
     if (color.a >= 0.99) {
         out.color = color;
@@ -294,9 +407,20 @@ fn fs_depth_only(
     @builtin(position) frag_pos: vec4<f32>,
     @location(0) pos: vec2<f32>,
     @location(1) @interpolate(flat) instance_id: u32,
+    @location(2) world_pos: vec3<f32>,
 ) -> @builtin(frag_depth) f32 {
+    let pixel_size = plane_pixel_size(pos, instance_id);
+    if (is_clipped(world_pos)) {
+        discard;
+    }
     let info = item_infos[instance_id];
-    let color = render(pos, info);
+    let color = render(
+        pos,
+        info,
+        pattern_pos(world_pos, instance_id),
+        planes[instance_id].fill_pattern,
+        pixel_size
+    );
 
     if (color.a < 0.99) {
         discard;
@@ -360,5 +484,6 @@ fn vs_main(
     out.frag_pos = cam_uniforms.proj_mat * cam_uniforms.view_mat * vec4(pos3d, 1.0);
     out.pos = clip_point;
     out.instance_id = instance_index;
+    out.world_pos = pos3d;
     return out;
 }
@@ -22,6 +22,7 @@ struct Basis {
 struct PlaneData {
     normal: vec4<f32>,
     origin: vec4<f32>,
+    fill_pattern: vec4<f32>,
 }
 
 fn basis_from_normal(n: vec3<f32>) -> Basis {
@@ -74,7 +75,8 @@ fn cs_main(
 
     let p_vec = points3d[index];
     let p = p_vec.xyz;
-    let is_closed = p_vec.w;
+    // The render flags of the segment starting at the point, see `item_is_closed` in vitem.wgsl
+    let flags = p_vec.w;
     let diff = p - plane_origin;
 
     let x = dot(diff, basis.u);
@@ -84,7 +86,7 @@ fn cs_main(
     let local_idx = index - info.point_offset;
     let w = stroke_widths[info.attr_offset + local_idx / 2u];
 
-    points2d[index] = vec4(x, y, is_closed, 0.0);
+    points2d[index] = vec4(x, y, flags, 0.0);
 
     let scale = 1000.0;
     let clip_base = item_idx * 5u;
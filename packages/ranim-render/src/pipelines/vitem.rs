@@ -62,7 +62,7 @@ impl GpuResource for VItemColorPipeline {
     fn new(ctx: &WgpuContext) -> Self {
         let module = &ctx
             .device
-            .create_shader_module(wgpu::include_wgsl!("./shaders/vitem.wgsl"));
+            .create_shader_module(include_oit_wgsl!("./shaders/vitem.wgsl"));
         let layout = ctx
             .device
             .create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
@@ -135,7 +135,7 @@ impl GpuResource for VItemDepthPipeline {
     fn new(ctx: &WgpuContext) -> Self {
         let module = &ctx
             .device
-            .create_shader_module(wgpu::include_wgsl!("./shaders/vitem.wgsl"));
+            .create_shader_module(include_oit_wgsl!("./shaders/vitem.wgsl"));
         let layout = ctx
             .device
             .create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
@@ -1,4 +1,4 @@
-use glam::{Mat4, Vec2};
+use glam::{Mat4, Vec2, Vec3, Vec4};
 use ranim_core::prelude::CameraFrame;
 
 use crate::{
@@ -8,12 +8,16 @@ use crate::{
 
 /// Uniforms for the camera
 #[repr(C, align(16))]
-#[derive(Debug, Clone, Copy, bytemuck::Pod, bytemuck::Zeroable)]
+#[derive(Debug, Clone, Copy, PartialEq, bytemuck::Pod, bytemuck::Zeroable)]
 pub struct ViewportUniform {
     proj_mat: Mat4,
     view_mat: Mat4,
     half_frame_size: Vec2,
     _padding: [u32; 2],
+    /// The min corner of [`CameraFrame::clip_region`], `w` is `1.0` if it is enabled
+    clip_min: Vec4,
+    /// The max corner of [`CameraFrame::clip_region`]
+    clip_max: Vec4,
 }
 impl Primitive for ViewportUniform {
     type RenderPacket = ViewportGpuPacket;
@@ -22,6 +26,10 @@ impl Primitive for ViewportUniform {
 impl ViewportUniform {
     pub fn from_camera_frame(camera_frame: &CameraFrame, width: u32, height: u32) -> Self {
         let ratio = width as f64 / height as f64;
+        let (clip_min, clip_max) = match camera_frame.clip_region {
+            Some([min, max]) => (min.as_vec3().extend(1.0), max.as_vec3().extend(1.0)),
+            None => (Vec4::ZERO, Vec4::ZERO),
+        };
         Self {
             proj_mat: camera_frame.projection_matrix(ratio).as_mat4(),
             view_mat: camera_frame.view_matrix().as_mat4(),
@@ -30,8 +38,24 @@ impl ViewportUniform {
                 camera_frame.frame_height as f32 / 2.0,
             ),
             _padding: [0; 2],
+            clip_min,
+            clip_max,
         }
     }
+    /// Project a world space point to pixel coordinates (origin at the top-left).
+    ///
+    /// Returns `None` if the point is behind the camera.
+    pub(crate) fn world_to_pixel(&self, point: Vec3, width: u32, height: u32) -> Option<Vec2> {
+        let clip = self.proj_mat * self.view_mat * point.extend(1.0);
+        if clip.w <= f32::EPSILON {
+            return None;
+        }
+        let ndc = clip.truncate().truncate() / clip.w;
+        Some(Vec2::new(
+            (ndc.x + 1.0) / 2.0 * width as f32,
+            (1.0 - ndc.y) / 2.0 * height as f32,
+        ))
+    }
     pub(crate) fn as_bind_group_layout_entry(binding: u32) -> wgpu::BindGroupLayoutEntry {
         wgpu::BindGroupLayoutEntry {
             binding,
@@ -107,3 +131,39 @@ impl RenderResource for ViewportGpuPacket {
         self.uniforms_buffer.set(ctx, *data);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_portrait_frame_size() {
+        let camera_frame = CameraFrame::default();
+        let uniform = ViewportUniform::from_camera_frame(&camera_frame, 1080, 1920);
+        assert_eq!(
+            uniform.half_frame_size,
+            Vec2::new(camera_frame.frame_height as f32 * 9.0 / 16.0 / 2.0, 4.0)
+        );
+
+        // With a fixed frame width, the frame height follows the aspect ratio
+        let mut camera_frame = CameraFrame::default();
+        camera_frame.set_frame_width(4.5, 1080.0 / 1920.0);
+        let uniform = ViewportUniform::from_camera_frame(&camera_frame, 1080, 1920);
+        assert!((uniform.half_frame_size - Vec2::new(2.25, 4.0)).length() < 1e-5);
+    }
+
+    #[test]
+    fn test_clip_region_uniform() {
+        let mut camera_frame = CameraFrame::default();
+        let uniform = ViewportUniform::from_camera_frame(&camera_frame, 1920, 1080);
+        assert_eq!(uniform.clip_min.w, 0.0);
+
+        camera_frame.set_clip_region(Some([
+            glam::DVec3::new(-1.0, -2.0, -3.0),
+            glam::DVec3::new(1.0, 2.0, 3.0),
+        ]));
+        let uniform = ViewportUniform::from_camera_frame(&camera_frame, 1920, 1080);
+        assert_eq!(uniform.clip_min, Vec4::new(-1.0, -2.0, -3.0, 1.0));
+        assert_eq!(uniform.clip_max, Vec4::new(1.0, 2.0, 3.0, 1.0));
+    }
+}
@@ -21,14 +21,17 @@ pub struct ItemInfo {
     pub attr_count: u32,
 }
 
-/// Per-item plane data (normal + origin), stored as array of structs.
+/// Per-item plane data (normal + origin + fill pattern), stored as array of structs.
 /// The origin is the first point of the item (used by vertex shader).
+/// The fill pattern is packed by [`FillPattern::to_vec4`](ranim_core::core_item::vitem::FillPattern::to_vec4).
+/// The `w` of the origin is `1.0` if the fill edge uses analytic coverage, see [`crate::Renderer::set_analytic_aa`].
 /// basis_u/basis_v are generated deterministically from the normal in the shader.
 #[repr(C)]
 #[derive(Debug, Default, Clone, Copy, Pod, Zeroable)]
 pub struct PlaneData {
-    pub normal: Vec4, // xyz = normal, w = pad
-    pub origin: Vec4, // xyz = first point, w = pad
+    pub normal: Vec4,       // xyz = normal, w = pad
+    pub origin: Vec4,       // xyz = first point, w = analytic aa
+    pub fill_pattern: Vec4, // kind, spacing, width or radius, angle
 }
 
 /// Merged GPU buffers for all VItems in a frame.
@@ -96,7 +99,13 @@ impl VItemsBuffer {
     }
 
     /// Pack all VItems into the merged buffers. Called once per frame.
-    pub fn update(&mut self, ctx: &WgpuContext, vitems: &[VItem]) {
+    pub fn update(
+        &mut self,
+        ctx: &WgpuContext,
+        vitems: &[VItem],
+        min_stroke_width: f32,
+        analytic_aa: bool,
+    ) {
         if vitems.is_empty() {
             self.item_count = 0;
             self.total_points = 0;
@@ -137,13 +146,18 @@ impl VItemsBuffer {
             let origin = Vec3::new(vitem.points[0].x, vitem.points[0].y, vitem.points[0].z);
             planes.push(PlaneData {
                 normal: Vec4::from((normal, 0.0)),
-                origin: Vec4::from((origin, 0.0)),
+                origin: Vec4::from((origin, if analytic_aa { 1.0 } else { 0.0 })),
+                fill_pattern: vitem.fill_pattern.to_vec4(),
             });
 
             all_points3d.extend_from_slice(&vitem.points);
             all_fill_rgbas.extend_from_slice(&vitem.fill_rgbas);
             all_stroke_rgbas.extend_from_slice(&vitem.stroke_rgbas);
             all_stroke_widths.extend_from_slice(&vitem.stroke_widths);
+            if min_stroke_width > 0.0 {
+                let start = all_stroke_widths.len() - vitem.stroke_widths.len();
+                Width::clamp_min(&mut all_stroke_widths[start..], min_stroke_width);
+            }
 
             point_offset += pc;
             attr_offset += ac;
@@ -292,3 +306,338 @@ fn bg_entry(binding: u32, buffer: &wgpu::Buffer) -> wgpu::BindGroupEntry<'_> {
         resource: wgpu::BindingResource::Buffer(buffer.as_entire_buffer_binding()),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Renderer, resource::RenderPool};
+    use pollster::block_on;
+    use ranim_core::{
+        color::AlphaColor, core_item::CoreItem, core_item::camera_frame::CameraFrame,
+        core_item::vitem::FillPattern, store::CoreItemStore,
+    };
+
+    fn square_vitem(center: Vec3, half_size: f32, fill: Rgba) -> VItem {
+        let corners = [
+            center + Vec3::new(-half_size, -half_size, 0.0),
+            center + Vec3::new(half_size, -half_size, 0.0),
+            center + Vec3::new(half_size, half_size, 0.0),
+            center + Vec3::new(-half_size, half_size, 0.0),
+        ];
+        let mut points = vec![corners[0]];
+        for i in 0..4 {
+            let (a, b) = (corners[i], corners[(i + 1) % 4]);
+            points.push((a + b) / 2.0);
+            points.push(b);
+        }
+        VItem {
+            normal: Some(Vec3::Z),
+            points: points.into_iter().map(|p| p.extend(1.0)).collect(),
+            fill_rgbas: vec![fill; 5],
+            stroke_rgbas: vec![Rgba(Vec4::ZERO); 5],
+            stroke_widths: vec![Width(0.0); 5],
+            fill_pattern: FillPattern::Solid,
+        }
+    }
+
+    fn circle_vitem(radius: f32, segments: usize, fill: Rgba) -> VItem {
+        let half_angle = std::f32::consts::PI / segments as f32;
+        let at = |angle: f32, r: f32| Vec3::new(angle.cos(), angle.sin(), 0.0) * r;
+        let mut points = vec![at(0.0, radius)];
+        for i in 0..segments {
+            let angle = 2.0 * half_angle * i as f32;
+            // The intersection of the tangents at the two anchors
+            points.push(at(angle + half_angle, radius / half_angle.cos()));
+            points.push(at(angle + 2.0 * half_angle, radius));
+        }
+        VItem {
+            normal: Some(Vec3::Z),
+            points: points.into_iter().map(|p| p.extend(1.0)).collect(),
+            fill_rgbas: vec![fill; segments + 1],
+            stroke_rgbas: vec![Rgba(Vec4::ZERO); segments + 1],
+            stroke_widths: vec![Width(0.0); segments + 1],
+            fill_pattern: FillPattern::Solid,
+        }
+    }
+
+    #[test]
+    fn test_analytic_aa_circle_edge() {
+        let ctx = block_on(WgpuContext::new());
+        let (width, height) = (192u32, 108u32);
+        let radius = 2.0;
+        // 13.5 pixels per unit
+        let radius_px = radius * height as f32 / CameraFrame::default().frame_height as f32;
+
+        let render = |analytic_aa: bool| {
+            let mut renderer = Renderer::new(&ctx, width, height, 8);
+            renderer.set_analytic_aa(analytic_aa);
+            let mut render_textures = renderer.new_render_textures(&ctx);
+            let mut pool = RenderPool::new();
+            let mut store = CoreItemStore::new();
+            store.update(
+                [
+                    ((0, 0), CoreItem::CameraFrame(CameraFrame::default())),
+                    (
+                        (1, 0),
+                        CoreItem::VItem(circle_vitem(radius, 32, Rgba(Vec4::ONE))),
+                    ),
+                ]
+                .into_iter(),
+            );
+            renderer.render_store_with_pool(
+                &ctx,
+                &mut render_textures,
+                wgpu::Color::BLACK,
+                &store,
+                &mut pool,
+            );
+            pool.clean();
+            ctx.device
+                .poll(wgpu::PollType::wait_indefinitely())
+                .unwrap();
+            let buffer = render_textures.get_rendered_texture_img_buffer(&ctx);
+            // The linear coverages of the pixels
+            buffer
+                .pixels()
+                .map(|p| {
+                    let c = p.0[0] as f32 / 255.0;
+                    if c <= 0.04045 {
+                        c / 12.92
+                    } else {
+                        ((c + 0.055) / 1.055).powf(2.4)
+                    }
+                })
+                .collect::<Vec<_>>()
+        };
+        let partial_cnt = |coverages: &[f32]| {
+            coverages
+                .iter()
+                .filter(|&&c| (0.05..0.95).contains(&c))
+                .count()
+        };
+
+        let analytic = render(true);
+        let fixed = render(false);
+
+        // Almost every pixel on the edge is partially covered
+        let perimeter_px = 2.0 * std::f32::consts::PI * radius_px;
+        assert!(
+            partial_cnt(&analytic) as f32 > perimeter_px * 0.6,
+            "{} partially covered pixels",
+            partial_cnt(&analytic)
+        );
+        assert!(partial_cnt(&analytic) > partial_cnt(&fixed) * 2);
+        // And the coverages sum up to the area of the circle
+        let area_px = std::f32::consts::PI * radius_px * radius_px;
+        let covered: f32 = analytic.iter().sum();
+        assert!(
+            (covered - area_px).abs() / area_px < 0.02,
+            "covered {covered}, area {area_px}"
+        );
+    }
+
+    #[test]
+    fn test_mid_gray_srgb_output() {
+        let ctx = block_on(WgpuContext::new());
+        let (width, height) = (192u32, 108u32);
+
+        let mut renderer = Renderer::new(&ctx, width, height, 8);
+        let mut render_textures = renderer.new_render_textures(&ctx);
+        let mut pool = RenderPool::new();
+        let mut store = CoreItemStore::new();
+
+        let camera_frame = CameraFrame::default();
+        let frame_width = camera_frame.frame_height as f32 * width as f32 / height as f32;
+        // An opaque sRGB mid-gray, which is drawn directly
+        let gray = Rgba::from(AlphaColor::from_rgb8(128, 128, 128));
+        // A half transparent white, which goes through the OIT layers
+        let translucent_white = Rgba(Vec4::new(1.0, 1.0, 1.0, 0.5));
+        let right_x = frame_width / 4.0;
+
+        store.update(
+            [
+                ((0, 0), CoreItem::CameraFrame(camera_frame)),
+                ((1, 0), CoreItem::VItem(square_vitem(Vec3::ZERO, 1.0, gray))),
+                (
+                    (2, 0),
+                    CoreItem::VItem(square_vitem(
+                        Vec3::new(right_x, 0.0, 0.0),
+                        1.0,
+                        translucent_white,
+                    )),
+                ),
+            ]
+            .into_iter(),
+        );
+
+        let clear_color = wgpu::Color::BLACK;
+        renderer.render_store_with_pool(&ctx, &mut render_textures, clear_color, &store, &mut pool);
+        pool.clean();
+        ctx.device
+            .poll(wgpu::PollType::wait_indefinitely())
+            .unwrap();
+
+        let buffer = render_textures.get_rendered_texture_img_buffer(&ctx);
+        let center = buffer.get_pixel(width / 2, height / 2);
+        let right = buffer.get_pixel(width * 3 / 4, height / 2);
+
+        // The linear value is encoded back to the same sRGB byte
+        for c in &center.0[..3] {
+            assert!(c.abs_diff(128) <= 1, "mid-gray: {:?}", center);
+        }
+        // Linear 0.5 white over black is encoded to ~188 in sRGB
+        for c in &right.0[..3] {
+            assert!(c.abs_diff(188) <= 2, "half transparent white: {:?}", right);
+        }
+    }
+
+    #[test]
+    fn test_fill_pattern() {
+        let ctx = block_on(WgpuContext::new());
+        let (width, height) = (192u32, 108u32);
+
+        let mut renderer = Renderer::new(&ctx, width, height, 8);
+        let mut render_textures = renderer.new_render_textures(&ctx);
+        let mut pool = RenderPool::new();
+        let mut store = CoreItemStore::new();
+
+        // Horizontal lines at y = 0, ±0.5, ... which are 0.2 wide
+        let mut vitem = square_vitem(Vec3::ZERO, 1.0, Rgba(Vec4::ONE));
+        vitem.fill_pattern = FillPattern::horizontal(0.5, 0.2);
+        store.update(
+            [
+                ((0, 0), CoreItem::CameraFrame(CameraFrame::default())),
+                ((1, 0), CoreItem::VItem(vitem)),
+            ]
+            .into_iter(),
+        );
+
+        renderer.render_store_with_pool(
+            &ctx,
+            &mut render_textures,
+            wgpu::Color::BLACK,
+            &store,
+            &mut pool,
+        );
+        pool.clean();
+        ctx.device
+            .poll(wgpu::PollType::wait_indefinitely())
+            .unwrap();
+
+        let buffer = render_textures.get_rendered_texture_img_buffer(&ctx);
+        // 13.5 pixels per unit, the row is at y ≈ -0.04, on the line
+        let on_line = buffer.get_pixel(width / 2, height / 2);
+        // The row is at y ≈ 0.26, between two lines
+        let between = buffer.get_pixel(width / 2, height / 2 - 4);
+        // Outside of the square
+        let outside = buffer.get_pixel(width / 2 + 20, height / 2);
+
+        assert!(on_line.0[..3].iter().all(|&c| c >= 250), "{on_line:?}");
+        assert!(between.0[..3].iter().all(|&c| c <= 5), "{between:?}");
+        assert!(outside.0[..3].iter().all(|&c| c <= 5), "{outside:?}");
+    }
+
+    #[test]
+    fn test_hidden_camera_fallback() {
+        use ranim_core::RanimScene;
+
+        let ctx = block_on(WgpuContext::new());
+        let (width, height) = (192u32, 108u32);
+
+        let mut renderer = Renderer::new(&ctx, width, height, 8);
+        let mut render_textures = renderer.new_render_textures(&ctx);
+        let mut pool = RenderPool::new();
+        let mut store = CoreItemStore::new();
+
+        // The camera is only shown from 1.0 sec, so there is no camera at 0.0 sec
+        let mut r = RanimScene::new();
+        r.insert_at(CameraFrame::default(), 1.0);
+        r.insert(square_vitem(Vec3::ZERO, 1.0, Rgba(Vec4::ONE)));
+        let scene = r.seal();
+        store.update(scene.eval_at_sec(0.0));
+        assert!(store.camera_frames.is_empty());
+
+        renderer.render_store_with_pool(
+            &ctx,
+            &mut render_textures,
+            wgpu::Color::BLACK,
+            &store,
+            &mut pool,
+        );
+        pool.clean();
+        ctx.device
+            .poll(wgpu::PollType::wait_indefinitely())
+            .unwrap();
+
+        // Rendered with the default camera
+        let buffer = render_textures.get_rendered_texture_img_buffer(&ctx);
+        let center = buffer.get_pixel(width / 2, height / 2);
+        let outside = buffer.get_pixel(width / 2 + 20, height / 2);
+        assert!(center.0[..3].iter().all(|&c| c >= 250), "{center:?}");
+        assert!(outside.0[..3].iter().all(|&c| c <= 5), "{outside:?}");
+    }
+
+    #[test]
+    fn test_split_screen_viewports() {
+        use ranim_core::core_item::camera_frame::ViewportRect;
+
+        let ctx = block_on(WgpuContext::new());
+        let (width, height) = (192u32, 108u32);
+
+        let mut renderer = Renderer::new(&ctx, width, height, 8);
+        let mut render_textures = renderer.new_render_textures(&ctx);
+        let mut pool = RenderPool::new();
+        let mut store = CoreItemStore::new();
+
+        // The left camera looks at a white square, the right one at a gray square far away
+        let left = CameraFrame::default().with_viewport(ViewportRect::left_half());
+        let mut right = CameraFrame::default().with_viewport(ViewportRect::right_half());
+        right.pos.x = 20.0;
+        let gray = Rgba::from(AlphaColor::from_rgb8(128, 128, 128));
+        store.update(
+            [
+                ((0, 0), CoreItem::CameraFrame(left)),
+                ((1, 0), CoreItem::CameraFrame(right)),
+                (
+                    (2, 0),
+                    CoreItem::VItem(square_vitem(Vec3::ZERO, 1.0, Rgba(Vec4::ONE))),
+                ),
+                (
+                    (3, 0),
+                    CoreItem::VItem(square_vitem(Vec3::new(20.0, 0.0, 0.0), 1.0, gray)),
+                ),
+            ]
+            .into_iter(),
+        );
+
+        renderer.render_store_with_pool(
+            &ctx,
+            &mut render_textures,
+            wgpu::Color::BLACK,
+            &store,
+            &mut pool,
+        );
+        pool.clean();
+        ctx.device
+            .poll(wgpu::PollType::wait_indefinitely())
+            .unwrap();
+
+        let buffer = render_textures.get_rendered_texture_img_buffer(&ctx);
+        // Each square is at the center of its half
+        let left_center = buffer.get_pixel(width / 4, height / 2);
+        let right_center = buffer.get_pixel(width * 3 / 4, height / 2);
+        // Between the squares, i.e. the edges of the halves
+        let left_edge = buffer.get_pixel(width / 2 - 2, height / 2);
+        let right_edge = buffer.get_pixel(width / 2 + 2, height / 2);
+
+        assert!(
+            left_center.0[..3].iter().all(|&c| c >= 250),
+            "{left_center:?}"
+        );
+        for c in &right_center.0[..3] {
+            assert!(c.abs_diff(128) <= 1, "{right_center:?}");
+        }
+        assert!(left_edge.0[..3].iter().all(|&c| c <= 5), "{left_edge:?}");
+        assert!(right_edge.0[..3].iter().all(|&c| c <= 5), "{right_edge:?}");
+    }
+}
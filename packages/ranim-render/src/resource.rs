@@ -1,6 +1,7 @@
 use std::{
     any::{Any, TypeId},
     collections::HashMap,
+    path::Path,
     sync::{Arc, RwLock},
 };
 
@@ -68,6 +69,8 @@ pub struct RenderTextures {
 
     output_dirty: bool,
     depth_dirty: bool,
+    /// See [`crate::dirty_region::DirtyRegionTracker::frame_token`].
+    last_frame_token: Option<u64>,
 }
 
 pub(crate) const OUTPUT_TEXTURE_FORMAT: wgpu::TextureFormat = wgpu::TextureFormat::Rgba8UnormSrgb;
@@ -191,9 +194,19 @@ impl RenderTextures {
             depth_stencil_view,
             output_dirty: true,
             depth_dirty: true,
+            last_frame_token: None,
         }
     }
 
+    /// The token of the frame the textures hold, see [`crate::dirty_region::DirtyRegionTracker::frame_token`].
+    pub(crate) fn last_frame_token(&self) -> Option<u64> {
+        self.last_frame_token
+    }
+
+    pub(crate) fn set_last_frame_token(&mut self, token: Option<u64>) {
+        self.last_frame_token = token;
+    }
+
     /// Mark textures as dirty after rendering.
     pub fn mark_dirty(&mut self) {
         self.output_dirty = true;
@@ -233,6 +246,22 @@ impl RenderTextures {
         ImageBuffer::from_raw(self.width, self.height, self.get_rendered_texture_data(ctx)).unwrap()
     }
 
+    /// Read back the rendered output and save it as an image at full render resolution,
+    /// the format is deduced from the extension of `path`.
+    ///
+    /// The parent directories of `path` are created if they don't exist.
+    pub fn save_rendered_image(
+        &mut self,
+        ctx: &WgpuContext,
+        path: impl AsRef<Path>,
+    ) -> image::ImageResult<()> {
+        let path = path.as_ref();
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        self.get_rendered_texture_img_buffer(ctx).save(path)
+    }
+
     pub fn get_depth_texture_data(&mut self, ctx: &WgpuContext) -> &[f32] {
         if !self.depth_dirty {
             return bytemuck::cast_slice(self.depth_stencil_texture.texture_data());
@@ -1,12 +1,16 @@
 mod depth_visual;
 mod timeline;
 
-use std::sync::Arc;
+use std::{
+    path::{Path, PathBuf},
+    sync::Arc,
+    time::SystemTime,
+};
 
 use crate::{
     Output, Scene, SceneConfig, SceneConstructor,
     core::{
-        SealedRanimScene,
+        SealedRanimScene, TimelineInfo,
         color::{self, LinearSrgb},
         store::CoreItemStore,
     },
@@ -51,6 +55,30 @@ impl TimelineInfoState {
     }
 }
 
+/// The path of a screenshot at `sec`: `<dir>/screenshots/<name>_<sec>s.png`,
+/// with a `_<n>` suffix if it already exists.
+#[cfg(all(not(target_family = "wasm"), feature = "render"))]
+fn screenshot_path(dir: &Path, name: &str, sec: f64) -> PathBuf {
+    let dir = dir.join("screenshots");
+    let stem = format!("{name}_{sec:.3}s");
+    std::iter::once(dir.join(format!("{stem}.png")))
+        .chain((1..).map(|n| dir.join(format!("{stem}_{n}.png"))))
+        .find(|path| !path.exists())
+        .unwrap()
+}
+
+/// Get the last modified time of the assets.
+fn asset_mtimes(paths: &[impl AsRef<Path>]) -> Vec<(PathBuf, Option<SystemTime>)> {
+    paths
+        .iter()
+        .map(|path| {
+            let path = path.as_ref();
+            let mtime = std::fs::metadata(path).and_then(|m| m.modified()).ok();
+            (path.to_path_buf(), mtime)
+        })
+        .collect()
+}
+
 pub enum RanimPreviewAppCmd {
     ReloadScene(Scene, Sender<()>),
 }
@@ -84,6 +112,18 @@ impl Resolution {
         self.width as f32 / self.height as f32
     }
 
+    /// The largest size with this resolution's aspect ratio that fits in `available`
+    pub fn fit_size(&self, available: egui::Vec2) -> egui::Vec2 {
+        let aspect_ratio = self.ratio();
+        let mut size = available;
+        if size.x / size.y > aspect_ratio {
+            size.x = size.y * aspect_ratio;
+        } else {
+            size.y = size.x / aspect_ratio;
+        }
+        size
+    }
+
     /// Calculate and return the simplified aspect ratio (e.g., (16, 9) for 1920x1080)
     pub fn aspect_ratio(&self) -> (u32, u32) {
         fn gcd(a: u32, b: u32) -> u32 {
@@ -154,6 +194,10 @@ pub struct RanimPreviewApp {
 
     // Resolution changed flag
     resolution_dirty: bool,
+    // Whether to draw the frame bounds and safe areas over the preview
+    show_frame_bounds: bool,
+    // Whether to show the ghosts of the anims
+    show_ghosts: bool,
 
     // Export
     #[cfg(all(not(target_family = "wasm"), feature = "render"))]
@@ -169,6 +213,10 @@ pub struct RanimPreviewApp {
     // Playback
     playback_speed: f64,
     looping: bool,
+
+    // Assets read by the scene, with their last modified time
+    asset_mtimes: Vec<(PathBuf, Option<SystemTime>)>,
+    last_asset_check: Instant,
 }
 
 impl RanimPreviewApp {
@@ -181,12 +229,14 @@ impl RanimPreviewApp {
         let scene_constructor = Arc::new(scene_constructor);
 
         info!("building scene...");
-        let timeline = scene_constructor.build_scene();
+        let mut timeline = scene_constructor.build_scene();
+        timeline.set_ghosts(true);
         info!("Scene built, cost: {:?}", t.elapsed());
 
         info!("Getting timelines info...");
         let timeline_infos = timeline.get_timeline_infos();
         info!("Total {} timelines", timeline_infos.len());
+        let asset_mtimes = asset_mtimes(timeline.asset_paths());
 
         let (cmd_tx, cmd_rx) = unbounded();
 
@@ -217,6 +267,8 @@ impl RanimPreviewApp {
             depth_visual_texture: None,
             depth_visual_view: None,
             resolution_dirty: false,
+            show_frame_bounds: false,
+            show_ghosts: true,
             #[cfg(all(not(target_family = "wasm"), feature = "render"))]
             export_dialog_open: false,
             export_config: Output::default(),
@@ -228,6 +280,8 @@ impl RanimPreviewApp {
             export_total_frames: 0,
             playback_speed: 1.0,
             looping: false,
+            asset_mtimes,
+            last_asset_check: Instant::now(),
         }
     }
 
@@ -244,6 +298,7 @@ impl RanimPreviewApp {
     /// Set clear color
     pub fn set_clear_color(&mut self, color: wgpu::Color) {
         self.clear_color = color;
+        self.need_eval = true;
     }
 
     /// Set preview resolution
@@ -254,6 +309,25 @@ impl RanimPreviewApp {
         }
     }
 
+    /// Set the output config used by the export dialog
+    pub fn set_export_config(&mut self, output: Output) {
+        self.export_config = output;
+    }
+
+    /// Use the aspect ratio of the scene's first output
+    ///
+    /// The current resolution is kept if it already has the same aspect ratio,
+    /// otherwise the output's resolution is used, so the preview is never distorted.
+    pub fn set_resolution_from_outputs(&mut self, outputs: &[Output]) {
+        let Some(output) = outputs.first() else {
+            return;
+        };
+        let resolution = Resolution::new(output.width, output.height);
+        if resolution.aspect_ratio() != self.resolution.aspect_ratio() {
+            self.set_resolution(resolution);
+        }
+    }
+
     /// Calculate OIT layers based on resolution to stay within GPU buffer limits
     fn calculate_oit_layers(&self, ctx: &WgpuContext, width: u32, height: u32) -> usize {
         const BYTES_PER_PIXEL_PER_LAYER: usize = 8; // 4 bytes color + 4 bytes depth
@@ -283,16 +357,10 @@ impl RanimPreviewApp {
         if let Ok(cmd) = self.cmd_rx.try_recv() {
             match cmd {
                 RanimPreviewAppCmd::ReloadScene(scene, tx) => {
-                    let timeline = scene.constructor.build_scene();
-                    let timeline_infos = timeline.get_timeline_infos();
-                    let old_cur_second = self.timeline_state.current_sec;
-                    self.timeline_state = TimelineState::new(timeline.total_secs(), timeline_infos);
-                    self.timeline_state.current_sec =
-                        old_cur_second.clamp(0.0, self.timeline_state.total_sec);
-                    self.timeline = timeline;
-                    self.store.update(std::iter::empty());
-                    self.pool.clean();
-                    self.need_eval = true;
+                    self.scene_constructor = Arc::new(scene.constructor);
+                    self.scene_config = scene.config.clone();
+                    self.set_resolution_from_outputs(&scene.outputs);
+                    self.reload_scene();
 
                     self.set_clear_color_str(&scene.config.clear_color);
 
@@ -304,6 +372,56 @@ impl RanimPreviewApp {
         }
     }
 
+    /// Rebuild the scene with the current scene constructor.
+    fn reload_scene(&mut self) {
+        let mut timeline = self.scene_constructor.build_scene();
+        timeline.set_ghosts(self.show_ghosts);
+        let timeline_infos = timeline.get_timeline_infos();
+        let old_cur_second = self.timeline_state.current_sec;
+        // Keep the playhead on the "same" animation if possible
+        let cur_second = TimelineInfo::remap_sec(
+            &self.timeline_state.timeline_infos,
+            &timeline_infos,
+            old_cur_second,
+        )
+        .unwrap_or(old_cur_second);
+        self.timeline_state = TimelineState::new(timeline.total_secs(), timeline_infos);
+        self.timeline_state.current_sec = cur_second.clamp(0.0, self.timeline_state.total_sec);
+        self.asset_mtimes = asset_mtimes(timeline.asset_paths());
+        self.timeline = timeline;
+        self.store.update(std::iter::empty());
+        self.pool.clean();
+        self.need_eval = true;
+    }
+
+    /// Rebuild the scene if any of the assets read by the scene is modified.
+    ///
+    /// The assets are polled, so a repaint is requested to check them again even if the
+    /// preview is paused or idle.
+    fn check_assets(&mut self, ctx: &egui::Context) {
+        const CHECK_INTERVAL: std::time::Duration = std::time::Duration::from_millis(500);
+        if self.asset_mtimes.is_empty() {
+            return;
+        }
+        ctx.request_repaint_after(CHECK_INTERVAL);
+        if self.last_asset_check.elapsed() < CHECK_INTERVAL {
+            return;
+        }
+        self.last_asset_check = Instant::now();
+
+        let paths = self
+            .asset_mtimes
+            .iter()
+            .map(|(path, _)| path.clone())
+            .collect::<Vec<_>>();
+        let mtimes = asset_mtimes(&paths);
+        // Files may be missing for a moment while being saved
+        if mtimes.iter().all(|(_, mtime)| mtime.is_some()) && mtimes != self.asset_mtimes {
+            info!("assets modified, rebuilding scene...");
+            self.reload_scene();
+        }
+    }
+
     fn prepare_renderer(&mut self, frame: &eframe::Frame) {
         // Check if we need to recreate renderer
         let needs_init = self.renderer.is_none();
@@ -336,7 +454,9 @@ impl RanimPreviewApp {
 
         let (width, height) = (self.resolution.width, self.resolution.height);
         let oit_layers = self.calculate_oit_layers(&ctx, width, height);
-        let renderer = Renderer::new(&ctx, width, height, oit_layers);
+        let mut renderer = Renderer::new(&ctx, width, height, oit_layers);
+        // The same textures are rendered into every frame, so only the changes are redrawn
+        renderer.set_partial_redraw(true);
         let render_textures = renderer.new_render_textures(&ctx);
 
         // Init Depth Visual Pipeline
@@ -395,15 +515,32 @@ impl RanimPreviewApp {
             if self.last_sec == self.timeline_state.current_sec && !self.need_eval {
                 return;
             }
-            self.need_eval = false;
+            let need_eval = std::mem::take(&mut self.need_eval);
+            // Only fire the callbacks when the playhead moves forward
+            if self.timeline_state.current_sec > self.last_sec {
+                let from = if self.last_sec < 0.0 {
+                    f64::NEG_INFINITY
+                } else {
+                    self.last_sec
+                };
+                self.timeline
+                    .fire_callbacks(from, self.timeline_state.current_sec);
+            }
             self.last_sec = self.timeline_state.current_sec;
 
             let start_eval = Instant::now();
-            self.store
+            let changed = self
+                .store
                 .update(self.timeline.eval_at_sec(self.timeline_state.current_sec));
             self.last_eval_time = Some(start_eval.elapsed());
+            // Nothing changed, the rendered texture is still up to date
+            if !changed && !need_eval {
+                return;
+            }
 
             let start = Instant::now();
+            renderer.set_min_stroke_width_px(self.scene_config.min_stroke_width_px);
+            renderer.set_frame_width(self.scene_config.frame_width);
             renderer.render_store_with_pool(
                 ctx,
                 render_textures,
@@ -462,6 +599,27 @@ impl RanimPreviewApp {
         }
     }
 
+    /// Save the current rendered frame as a PNG at full render resolution,
+    /// see [`screenshot_path`] for where it goes.
+    #[cfg(all(not(target_family = "wasm"), feature = "render"))]
+    fn take_screenshot(&mut self) {
+        let (Some(ctx), Some(render_textures)) =
+            (self.wgpu_ctx.as_ref(), self.render_textures.as_mut())
+        else {
+            return;
+        };
+        let mut dir = PathBuf::from(&self.export_config.dir);
+        if !dir.is_absolute() {
+            dir = std::env::current_dir().unwrap_or_default().join(dir);
+        }
+        let name = self.export_config.name.as_deref().unwrap_or(&self.title);
+        let path = screenshot_path(&dir, name, self.timeline_state.current_sec);
+        match render_textures.save_rendered_image(ctx, &path) {
+            Ok(()) => info!("Screenshot saved to {}", path.display()),
+            Err(err) => error!("Failed to save screenshot to {}: {err}", path.display()),
+        }
+    }
+
     #[cfg(all(not(target_family = "wasm"), feature = "render"))]
     fn start_export(&mut self, ctx: egui::Context) {
         let (progress_tx, progress_rx) = unbounded();
@@ -513,6 +671,7 @@ impl eframe::App for RanimPreviewApp {
         let ctx = ui.ctx().clone();
         self.prepare_renderer(frame);
         self.handle_events();
+        self.check_assets(&ctx);
 
         // Space bar toggles play/pause
         if ctx.input(|i| i.key_pressed(egui::Key::Space)) {
@@ -656,6 +815,17 @@ impl eframe::App for RanimPreviewApp {
                     }
                 }
 
+                ui.checkbox(&mut self.show_frame_bounds, "Frame bounds")
+                    .on_hover_text("Show the frame bounds and the action/title safe areas");
+                if ui
+                    .checkbox(&mut self.show_ghosts, "Ghosts")
+                    .on_hover_text("Show the ghosts of the anims played with `with_ghosts`")
+                    .changed()
+                {
+                    self.timeline.set_ghosts(self.show_ghosts);
+                    self.need_eval = true;
+                }
+
                 ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
                     let dark_mode = ui.visuals().dark_mode;
                     let button_text = if dark_mode {
@@ -681,6 +851,13 @@ impl eframe::App for RanimPreviewApp {
                         {
                             self.export_dialog_open = true;
                         }
+                        if ui
+                            .button(format!("{} Screenshot", egui_phosphor::regular::CAMERA))
+                            .on_hover_text("Save the current frame as a PNG in the output dir")
+                            .clicked()
+                        {
+                            self.take_screenshot();
+                        }
                         ui.separator();
                     }
                     ui.selectable_value(&mut self.view_mode, ViewMode::Output, "Output");
@@ -824,24 +1001,37 @@ impl eframe::App for RanimPreviewApp {
             };
 
             if let Some(tid) = texture_id {
-                // Maintain aspect ratio
+                // Maintain aspect ratio, letterbox/pillarbox in the available space
                 // TODO: We could update renderer size here if we want dynamic resolution
-                let available_size = ui.available_size();
-                let aspect_ratio = self
+                let resolution = self
                     .render_textures
                     .as_ref()
-                    .map(|rt| rt.ratio())
-                    .unwrap_or(1280.0 / 7.0);
-                let mut size = available_size;
-
-                if size.x / size.y > aspect_ratio {
-                    size.x = size.y * aspect_ratio;
-                } else {
-                    size.y = size.x / aspect_ratio;
-                }
+                    .map(|rt| Resolution::new(rt.width(), rt.height()))
+                    .unwrap_or(self.resolution);
+                let size = resolution.fit_size(ui.available_size());
 
                 ui.centered_and_justified(|ui| {
-                    ui.image(egui::load::SizedTexture::new(tid, size));
+                    let response = ui.image(egui::load::SizedTexture::new(tid, size));
+                    if self.show_frame_bounds {
+                        let frame = egui::Rect::from_center_size(response.rect.center(), size);
+                        let painter = ui.painter();
+                        let color = ui.visuals().warn_fg_color;
+                        painter.rect_stroke(
+                            frame,
+                            0.0,
+                            egui::Stroke::new(1.5, color),
+                            egui::StrokeKind::Inside,
+                        );
+                        // Action safe (90%) and title safe (80%) areas
+                        for scale in [0.9, 0.8] {
+                            painter.rect_stroke(
+                                frame.scale_from_center(scale),
+                                0.0,
+                                egui::Stroke::new(1.0, color.gamma_multiply(0.5)),
+                                egui::StrokeKind::Inside,
+                            );
+                        }
+                    }
                 });
             } else {
                 ui.centered_and_justified(|ui| {
@@ -1101,6 +1291,7 @@ pub fn preview_scene(scene: &Scene) {
 pub fn preview_scene_with_name(scene: &Scene, name: &str) {
     let mut app = RanimPreviewApp::new(scene.constructor, name.to_string(), scene.config.clone());
     app.set_clear_color_str(&scene.config.clear_color);
+    app.set_resolution_from_outputs(&scene.outputs);
     run_app(
         app,
         #[cfg(target_arch = "wasm32")]
@@ -1,11 +1,11 @@
 use std::{
     io::Write,
-    path::PathBuf,
+    path::{Path, PathBuf},
     process::{Child, ChildStdin, Command, Stdio},
 };
 
-use crate::OutputFormat;
-use tracing::info;
+use crate::{BackgroundFit, OutputFormat, VideoCodec};
+use tracing::{error, info};
 
 /// Extension trait providing ffmpeg encoding parameters for [`OutputFormat`].
 pub(crate) trait OutputFormatExt {
@@ -52,10 +52,14 @@ pub struct FileWriterBuilder {
     pub height: u32,
     pub fps: u32,
     pub vf_args: Vec<String>,
+    /// Image or video to composite the frames over
+    pub background: Option<(PathBuf, BackgroundFit)>,
 
     pub video_codec: String,
     pub pixel_format: String,
     pub extra_codec_args: Vec<String>,
+    /// The constant rate factor, added to the codec args in [`Self::build`]
+    pub crf: Option<u32>,
 }
 
 impl Default for FileWriterBuilder {
@@ -67,9 +71,11 @@ impl Default for FileWriterBuilder {
             fps: 60,
 
             vf_args: vec!["eq=saturation=1.0:gamma=1.0".to_string()],
+            background: None,
             video_codec: "libx264".to_string(),
             pixel_format: "yuv420p".to_string(),
             extra_codec_args: Vec::new(),
+            crf: None,
         }
     }
 }
@@ -111,6 +117,46 @@ impl FileWriterBuilder {
         self
     }
 
+    /// Use `codec` instead of the default codec of the format,
+    /// call this after [`Self::with_output_format`].
+    pub fn with_codec(mut self, codec: VideoCodec) -> Self {
+        self.video_codec = codec.encoder().to_string();
+        if codec == VideoCodec::H265 {
+            // Tag it as `hvc1`, so that it can be played by QuickTime
+            self.extra_codec_args
+                .extend(["-tag:v".to_string(), "hvc1".to_string()]);
+        }
+        self
+    }
+
+    /// Encode with the constant rate factor `crf`, lower is better quality and larger files.
+    ///
+    /// It is resolved against the final codec in [`Self::build`], so the order with
+    /// [`Self::with_codec`] doesn't matter.
+    pub fn with_crf(mut self, crf: u32) -> Self {
+        self.crf = Some(crf);
+        self
+    }
+
+    /// Encode with the target bitrate of `kbps` kbps.
+    pub fn with_bitrate(mut self, kbps: u32) -> Self {
+        self.extra_codec_args
+            .extend(["-b:v".to_string(), format!("{kbps}k")]);
+        self
+    }
+
+    /// Use `pixel_format` instead of the default pixel format of the format,
+    /// call this after [`Self::with_output_format`].
+    pub fn with_pixel_format(mut self, pixel_format: impl Into<String>) -> Self {
+        self.pixel_format = pixel_format.into();
+        self
+    }
+
+    pub fn with_background(mut self, path: PathBuf, fit: BackgroundFit) -> Self {
+        self.background = Some((path, fit));
+        self
+    }
+
     pub fn enable_fast_encoding(mut self) -> Self {
         self.video_codec = "libx264rgb".to_string();
         self.pixel_format = "rgb32".to_string();
@@ -129,6 +175,62 @@ impl FileWriterBuilder {
         self
     }
 
+    /// The ffmpeg args of the codec, the [`Self::extra_codec_args`] and the [`Self::crf`].
+    fn codec_args(&self) -> Vec<String> {
+        let mut args = self.extra_codec_args.clone();
+        if let Some(crf) = self.crf {
+            args.extend(["-crf".to_string(), crf.to_string()]);
+            // VP9 is only in the constant quality mode with a zero bitrate
+            if self.video_codec == VideoCodec::Vp9.encoder() {
+                args.extend(["-b:v".to_string(), "0".to_string()]);
+            }
+        }
+        args
+    }
+
+    /// The ffmpeg args to composite the frames over the background at `path`.
+    ///
+    /// They are the background input and a `-filter_complex` that fits it to the frames,
+    /// which also applies the [`Self::vf_args`] instead of `-vf`.
+    fn background_args(&self, path: &Path, fit: BackgroundFit) -> Vec<String> {
+        // Images are repeated as a still frame, videos are looped
+        let is_image = path
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .is_some_and(|ext| {
+                ["png", "jpg", "jpeg", "bmp", "webp", "tiff"]
+                    .contains(&ext.to_ascii_lowercase().as_str())
+            });
+        let mut args = if is_image {
+            vec!["-loop".to_string(), "1".to_string()]
+        } else {
+            vec!["-stream_loop".to_string(), "-1".to_string()]
+        };
+        args.extend(["-i".to_string(), path.to_string_lossy().to_string()]);
+
+        let (w, h) = (self.width, self.height);
+        let fit = match fit {
+            BackgroundFit::Cover => {
+                format!("scale={w}:{h}:force_original_aspect_ratio=increase,crop={w}:{h}")
+            }
+            BackgroundFit::Contain => format!(
+                "scale={w}:{h}:force_original_aspect_ratio=decrease,format=rgba,pad={w}:{h}:(ow-iw)/2:(oh-ih)/2:color=black@0"
+            ),
+            BackgroundFit::Stretch => format!("scale={w}:{h}"),
+        };
+        // The frames are the overlay, the output ends with them
+        let mut filter = format!(
+            "[1:v]{fit},setsar=1,fps={},format=rgba[bg];[bg][0:v]overlay=shortest=1:format=auto",
+            self.fps
+        );
+        if !self.vf_args.is_empty() {
+            filter.push(',');
+            filter.push_str(&self.vf_args.join(","));
+        }
+        args.extend(["-filter_complex".to_string(), filter]);
+        args
+    }
+
     pub fn build(self) -> FileWriter {
         let parent = self.file_path.parent().unwrap();
         if !parent.exists() {
@@ -151,11 +253,14 @@ impl FileWriterBuilder {
         command.args([
             "-y", "-f", "rawvideo", "-s", &size, "-pix_fmt", "rgba", "-r", &fps, "-i", "-",
         ]);
+        if let Some((path, fit)) = &self.background {
+            command.args(self.background_args(path, *fit));
+        }
         // Output options (before output file)
         command.args(["-an", "-loglevel", "error", "-vcodec", &self.video_codec]);
-        command.args(&self.extra_codec_args);
+        command.args(self.codec_args());
         command.args(["-pix_fmt", &self.pixel_format]);
-        if !self.vf_args.is_empty() {
+        if self.background.is_none() && !self.vf_args.is_empty() {
             let vf = self.vf_args.join(",");
             command.args(["-vf", &vf]);
         }
@@ -163,10 +268,12 @@ impl FileWriterBuilder {
         command.arg(&file_path);
         command.stdin(Stdio::piped());
 
+        let command_line = format!("{command:?}");
         let mut child = command.spawn().expect("Failed to spawn ffmpeg");
         FileWriter {
             child_in: child.stdin.take(),
             child,
+            command_line,
         }
     }
 }
@@ -174,17 +281,24 @@ impl FileWriterBuilder {
 pub struct FileWriter {
     child: Child,
     child_in: Option<ChildStdin>,
+    /// The ffmpeg command, for reporting errors
+    command_line: String,
 }
 
 impl Drop for FileWriter {
     fn drop(&mut self) {
-        self.child_in
-            .as_mut()
-            .unwrap()
-            .flush()
-            .expect("Failed to flush ffmpeg");
-        drop(self.child_in.take());
-        self.child.wait().expect("Failed to wait ffmpeg");
+        // A failed flush means that ffmpeg has exited, which is reported below
+        if let Some(mut child_in) = self.child_in.take() {
+            let _ = child_in.flush();
+        }
+        match self.child.wait() {
+            Ok(status) if !status.success() => error!(
+                "ffmpeg exited with {status}, see its errors above, the command was: {}",
+                self.command_line
+            ),
+            Ok(_) => {}
+            Err(err) => error!("Failed to wait ffmpeg: {err}"),
+        }
     }
 }
 
@@ -194,10 +308,110 @@ impl FileWriter {
     // }
 
     pub fn write_frame(&mut self, frame: &[u8]) {
-        self.child_in
-            .as_mut()
-            .unwrap()
-            .write_all(frame)
-            .expect("Failed to write frame");
+        if let Err(err) = self.child_in.as_mut().unwrap().write_all(frame) {
+            // ffmpeg exits early on errors like unsupported encoding options
+            let status = self.child.try_wait().ok().flatten();
+            match status {
+                Some(status) => panic!(
+                    "Failed to write frame, ffmpeg exited with {status}, see its errors above, the command was: {}",
+                    self.command_line
+                ),
+                None => panic!("Failed to write frame: {err}"),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn background_args(path: &str, fit: BackgroundFit) -> Vec<String> {
+        let builder = FileWriterBuilder::default()
+            .with_size(1280, 720)
+            .with_fps(30);
+        builder.background_args(Path::new(path), fit)
+    }
+
+    fn filter_of(args: &[String]) -> &str {
+        let idx = args
+            .iter()
+            .position(|arg| arg == "-filter_complex")
+            .unwrap();
+        &args[idx + 1]
+    }
+
+    #[test]
+    fn test_codec_args() {
+        let builder = FileWriterBuilder::default().with_output_format(OutputFormat::Webm);
+        assert!(builder.codec_args().is_empty());
+        // The crf is resolved against the final codec, before or after `with_codec`
+        let args = builder
+            .clone()
+            .with_crf(30)
+            .with_codec(VideoCodec::Vp9)
+            .codec_args();
+        assert_eq!(args, ["-crf", "30", "-b:v", "0"]);
+        let args = builder
+            .with_codec(VideoCodec::Vp9)
+            .with_crf(30)
+            .codec_args();
+        assert_eq!(args, ["-crf", "30", "-b:v", "0"]);
+
+        let args = FileWriterBuilder::default()
+            .with_crf(20)
+            .with_codec(VideoCodec::H265)
+            .codec_args();
+        assert_eq!(args, ["-tag:v", "hvc1", "-crf", "20"]);
+    }
+
+    #[test]
+    fn test_background_inputs() {
+        let args = background_args("bg.PNG", BackgroundFit::Cover);
+        assert_eq!(args[..4], ["-loop", "1", "-i", "bg.PNG"]);
+        let args = background_args("bg.mp4", BackgroundFit::Cover);
+        assert_eq!(args[..4], ["-stream_loop", "-1", "-i", "bg.mp4"]);
+        assert_eq!(args.len(), 6);
+    }
+
+    #[test]
+    fn test_background_cover() {
+        let args = background_args("bg.png", BackgroundFit::Cover);
+        assert_eq!(
+            filter_of(&args),
+            "[1:v]scale=1280:720:force_original_aspect_ratio=increase,crop=1280:720,\
+             setsar=1,fps=30,format=rgba[bg];[bg][0:v]overlay=shortest=1:format=auto,\
+             eq=saturation=1.0:gamma=1.0"
+        );
+    }
+
+    #[test]
+    fn test_background_contain() {
+        let args = background_args("bg.png", BackgroundFit::Contain);
+        assert_eq!(
+            filter_of(&args),
+            "[1:v]scale=1280:720:force_original_aspect_ratio=decrease,format=rgba,\
+             pad=1280:720:(ow-iw)/2:(oh-ih)/2:color=black@0,\
+             setsar=1,fps=30,format=rgba[bg];[bg][0:v]overlay=shortest=1:format=auto,\
+             eq=saturation=1.0:gamma=1.0"
+        );
+    }
+
+    #[test]
+    fn test_background_stretch() {
+        let args = background_args("bg.png", BackgroundFit::Stretch);
+        assert_eq!(
+            filter_of(&args),
+            "[1:v]scale=1280:720,\
+             setsar=1,fps=30,format=rgba[bg];[bg][0:v]overlay=shortest=1:format=auto,\
+             eq=saturation=1.0:gamma=1.0"
+        );
+
+        // Without the vf args, the filter ends with the overlay
+        let builder = FileWriterBuilder::default()
+            .with_size(1280, 720)
+            .with_output_format(OutputFormat::Webm);
+        let args = builder.background_args(Path::new("bg.png"), BackgroundFit::Stretch);
+        assert!(filter_of(&args).ends_with("overlay=shortest=1:format=auto"));
     }
 }
@@ -1,11 +1,12 @@
 // MARK: Render api
-use std::collections::VecDeque;
+use std::collections::{HashSet, VecDeque};
+use std::ops::Range;
 
-use crate::cmd::render::file_writer::OutputFormatExt;
 use crate::{Output, Scene, SceneConfig, SceneConstructor};
 use file_writer::{FileWriter, FileWriterBuilder};
 use indicatif::{ProgressState, ProgressStyle};
 use ranim_core::color::{self, LinearSrgb};
+use ranim_core::prelude::CameraFrame;
 use ranim_core::store::CoreItemStore;
 use ranim_core::{SealedRanimScene, TimeMark};
 use ranim_render::resource::{RenderPool, RenderTextures};
@@ -13,7 +14,7 @@ use ranim_render::{Renderer, utils::WgpuContext};
 use std::path::{Path, PathBuf};
 use std::time::Duration;
 use std::time::Instant;
-use tracing::{Span, info, instrument, trace};
+use tracing::{Span, info, instrument, trace, warn};
 use tracing_indicatif::span_ext::IndicatifSpanExt;
 
 pub(crate) mod file_writer;
@@ -65,12 +66,19 @@ pub fn render_scene_output_with_progress(
     use std::time::Instant;
 
     info!(
-        "Output: {}x{} {}fps {} dir={:?} save_frames={}",
-        output.width, output.height, output.fps, output.format, output.dir, output.save_frames
+        "Output: {}x{} {}fps {} dir={:?} save_frames={} time_range={:?}",
+        output.width,
+        output.height,
+        output.fps,
+        output.format,
+        output.dir,
+        output.save_frames,
+        output.time_range
     );
 
     let t = Instant::now();
-    let scene = constructor.build_scene();
+    let mut scene = constructor.build_scene();
+    scene.set_ghosts(output.ghosts);
     trace!("Build timeline cost: {:?}", t.elapsed());
 
     let mut app = RanimRenderApp::new(name, scene_config, output, buffer_count);
@@ -117,6 +125,10 @@ struct RenderWorker {
     width: u32,
     height: u32,
     fps: u32,
+    // captures
+    capture_metadata: bool,
+    captured_paths: HashSet<PathBuf>,
+    frame_width: Option<f64>,
 }
 
 impl RenderWorker {
@@ -127,6 +139,9 @@ impl RenderWorker {
         buffer_count: usize,
     ) -> Self {
         assert!(buffer_count >= 1, "buffer_count must be at least 1");
+        if let Err(err) = output.validate_encoding() {
+            panic!("Invalid encoding options of the output: {err}");
+        }
         info!("Checking ffmpeg...");
         let t = Instant::now();
         if let Ok(ffmpeg_path) = which::which("ffmpeg") {
@@ -152,11 +167,10 @@ impl RenderWorker {
         let ctx = pollster::block_on(WgpuContext::new());
         trace!("Create wgpu context cost: {:?}", t.elapsed());
 
-        let mut output_dir = PathBuf::from(&output.dir);
-        if !output_dir.is_absolute() {
-            output_dir = std::env::current_dir().unwrap().join(output_dir);
-        }
-        let renderer = Renderer::new(&ctx, output.width, output.height, 8);
+        let output_dir = absolute_path(&output.dir);
+        let mut renderer = Renderer::new(&ctx, output.width, output.height, 8);
+        renderer.set_min_stroke_width_px(scene_config.min_stroke_width_px);
+        renderer.set_frame_width(scene_config.frame_width);
         let render_textures: Vec<RenderTextures> = (0..buffer_count)
             .map(|_| renderer.new_render_textures(&ctx))
             .collect();
@@ -165,7 +179,27 @@ impl RenderWorker {
             .convert::<LinearSrgb>();
         let [r, g, b, a] = clear_color.components.map(|x| x as f64);
         let clear_color = wgpu::Color { r, g, b, a };
-        let (_, _, ext) = output.format.encoding_params();
+        let mut video_writer_builder = FileWriterBuilder::default()
+            .with_fps(output.fps)
+            .with_size(output.width, output.height)
+            .with_file_path(output_dir.join(output.file_name(&scene_name)))
+            .with_output_format(output.format);
+        if let Some(codec) = output.codec {
+            video_writer_builder = video_writer_builder.with_codec(codec);
+        }
+        if let Some(crf) = output.crf {
+            video_writer_builder = video_writer_builder.with_crf(crf);
+        }
+        if let Some(kbps) = output.bitrate {
+            video_writer_builder = video_writer_builder.with_bitrate(kbps);
+        }
+        if let Some(pixel_format) = &output.pixel_format {
+            video_writer_builder = video_writer_builder.with_pixel_format(pixel_format);
+        }
+        if let Some(background) = &output.background {
+            let path = absolute_path(&background.path);
+            video_writer_builder = video_writer_builder.with_background(path, background.fit);
+        }
         Self {
             ctx,
             renderer,
@@ -173,25 +207,16 @@ impl RenderWorker {
             pool: RenderPool::new(),
             clear_color,
             video_writer: None,
-            video_writer_builder: Some(
-                FileWriterBuilder::default()
-                    .with_fps(output.fps)
-                    .with_size(output.width, output.height)
-                    .with_file_path(output_dir.join(format!(
-                        "{}_{}x{}_{}.{ext}",
-                        output.name.clone().unwrap_or(scene_name.clone()),
-                        output.width,
-                        output.height,
-                        output.fps
-                    )))
-                    .with_output_format(output.format),
-            ),
+            video_writer_builder: Some(video_writer_builder),
             save_frames: output.save_frames,
             output_dir,
             scene_name,
             width: output.width,
             height: output.height,
             fps: output.fps,
+            capture_metadata: output.capture_metadata,
+            captured_paths: HashSet::new(),
+            frame_width: scene_config.frame_width,
         }
     }
 
@@ -326,24 +351,45 @@ impl RenderWorker {
     }
 
     /// Capture frame to image file (sync path, uses target 0).
-    pub fn capture_frame(&mut self, path: impl AsRef<Path>) {
-        let path = path.as_ref();
-        let path = if !path.is_absolute() {
-            self.output_dir
-                .join(format!(
-                    "{}_{}x{}_{}",
-                    self.scene_name, self.width, self.height, self.fps
-                ))
-                .join(path)
-        } else {
-            path.to_path_buf()
-        };
+    ///
+    /// See [`capture_path`] for where it is saved. If the path was already captured in this
+    /// render, a `_<n>` suffix is added to the file stem.
+    /// Writes a `<path>.json` metadata file next to it if [`Output::capture_metadata`] is set.
+    pub fn capture_frame(&mut self, path: impl AsRef<Path>, sec: f64, camera_frame: &CameraFrame) {
+        let path = capture_path(
+            path.as_ref(),
+            &self.output_dir,
+            &self.scene_name,
+            self.width,
+            self.height,
+            self.fps,
+        );
+        let path = unique_path(path, &self.captured_paths);
+        self.captured_paths.insert(path.clone());
+
         let dir = path.parent().unwrap();
         if !dir.exists() || !dir.is_dir() {
             std::fs::create_dir_all(dir).unwrap();
         }
         let buffer = self.render_textures[0].get_rendered_texture_img_buffer(&self.ctx);
-        buffer.save(path).unwrap();
+        buffer.save(&path).unwrap();
+
+        if self.capture_metadata {
+            let mut camera_frame = camera_frame.clone();
+            if let Some(frame_width) = self.frame_width {
+                camera_frame.set_frame_width(frame_width, self.width as f64 / self.height as f64);
+            }
+            let mut json_path = path.clone().into_os_string();
+            json_path.push(".json");
+            let json = capture_metadata_json(
+                &self.scene_name,
+                sec,
+                self.width,
+                self.height,
+                &camera_frame,
+            );
+            std::fs::write(json_path, json).unwrap();
+        }
     }
 }
 
@@ -351,6 +397,7 @@ impl RenderWorker {
 struct RanimRenderApp {
     render_worker: Option<RenderWorker>,
     fps: u32,
+    time_range: Option<Range<f64>>,
     store: CoreItemStore,
 }
 
@@ -365,6 +412,7 @@ impl RanimRenderApp {
         Self {
             render_worker: Some(render_worker),
             fps: output.fps,
+            time_range: output.time_range.clone(),
             store: CoreItemStore::default(),
         }
     }
@@ -396,16 +444,9 @@ impl RanimRenderApp {
         let worker_thread = self.render_worker.take().unwrap().yeet();
 
         let total_secs = timeline.total_secs();
-        let fps = self.fps as f64;
-        let raw_frames = total_secs * fps;
-        // Add an extra frame to sample the final state exactly,
-        // unless total_secs * fps is already an integer (last frame lands on total_secs).
-        let n = raw_frames.ceil() as u64;
-        let num_frames = if (raw_frames - raw_frames.round()).abs() < 1e-9 {
-            n
-        } else {
-            n + 1
-        };
+        let range = clip_time_range(self.time_range.clone(), total_secs);
+        let secs = frame_secs(range.clone(), self.fps as f64);
+        let num_frames = secs.len() as u64;
         let style =             ProgressStyle::with_template(
                 "[{elapsed_precise}] [{wide_bar:.cyan/blue}] frame {human_pos}/{human_len} (eta {eta}) {msg}",
             )
@@ -419,33 +460,35 @@ impl RanimRenderApp {
         span.pb_set_style(&style);
         span.pb_set_length(num_frames);
 
-        (0..num_frames)
-            .map(|f| (f as f64 / fps).min(total_secs))
-            .enumerate()
-            .for_each(|(i, sec)| {
-                worker_thread.sync_and_submit(|store| {
-                    store.update(timeline.eval_at_sec(sec));
-                });
-
-                span.pb_inc(1);
-                if let Some(cb) = &on_progress {
-                    cb(i as u64 + 1, num_frames);
-                }
-                span.pb_set_message(
-                    format!(
-                        "rendering {:.1?}/{:.1?}",
-                        Duration::from_secs_f64(sec),
-                        Duration::from_secs_f64(total_secs)
-                    )
-                    .as_str(),
-                );
+        // Callbacks before the range are fired with the first frame,
+        // as if the scene was played from the beginning.
+        let mut prev_sec = f64::NEG_INFINITY;
+        secs.into_iter().enumerate().for_each(|(i, sec)| {
+            timeline.fire_callbacks(prev_sec, sec);
+            prev_sec = sec;
+            worker_thread.sync_and_submit(|store| {
+                store.update(timeline.eval_at_sec(sec));
             });
+
+            span.pb_inc(1);
+            if let Some(cb) = &on_progress {
+                cb(i as u64 + 1, num_frames);
+            }
+            span.pb_set_message(
+                format!(
+                    "rendering {:.1?}/{:.1?}",
+                    Duration::from_secs_f64(sec),
+                    Duration::from_secs_f64(total_secs)
+                )
+                .as_str(),
+            );
+        });
         self.render_worker.replace(worker_thread.retrive());
 
         info!(
             "rendered {} frames({:?}) in {:?}",
             num_frames,
-            Duration::from_secs_f64(timeline.total_secs()),
+            Duration::from_secs_f64(range.end - range.start),
             start.elapsed(),
         );
         trace!("render timeline cost: {:?}", start.elapsed());
@@ -480,7 +523,7 @@ impl RanimRenderApp {
             self.store.update(timeline.eval_at_alpha(alpha));
             let worker = self.render_worker.as_mut().unwrap();
             worker.render_store(&self.store);
-            worker.capture_frame(filename);
+            worker.capture_frame(filename, *sec, &self.store.camera_frames_or_default()[0]);
             span.pb_inc(1);
         }
         info!("saved {} capture frames from time marks", timemarks.len());
@@ -488,6 +531,134 @@ impl RanimRenderApp {
     }
 }
 
+/// The range of secs to render for [`Output::time_range`], the whole scene if it is `None`.
+///
+/// The range is clamped to `0.0..=total_secs`, an empty or out of bounds range
+/// falls back to the whole scene with a warning.
+fn clip_time_range(range: Option<Range<f64>>, total_secs: f64) -> Range<f64> {
+    let Some(range) = range else {
+        return 0.0..total_secs;
+    };
+    let clipped = range.start.max(0.0)..range.end.min(total_secs);
+    if clipped.is_empty() {
+        warn!(
+            "time range {range:?} is empty or out of the scene's duration {total_secs}s, rendering the whole scene"
+        );
+        return 0.0..total_secs;
+    }
+    if clipped != range {
+        warn!(
+            "time range {range:?} is clamped to {clipped:?} by the scene's duration {total_secs}s"
+        );
+    }
+    clipped
+}
+
+/// The secs of the frames to render in `range`, one every `1 / fps` from its start.
+///
+/// An extra frame is added to sample the end exactly,
+/// unless the length of the range times `fps` is already an integer (last frame lands on the end).
+fn frame_secs(range: Range<f64>, fps: f64) -> Vec<f64> {
+    let raw_frames = (range.end - range.start) * fps;
+    let n = raw_frames.ceil() as u64;
+    let num_frames = if (raw_frames - raw_frames.round()).abs() < 1e-9 {
+        n
+    } else {
+        n + 1
+    };
+    (0..num_frames)
+        .map(|f| (range.start + f as f64 / fps).min(range.end))
+        .collect()
+}
+
+/// The path to save a capture at.
+///
+/// A relative `path` is placed in the `<scene>_<width>x<height>_<fps>` dir of the output,
+/// and the `_<width>x<height>_<fps>` suffix is added to the file stem of an absolute `path`,
+/// so the captures of different outputs of a scene never overwrite each other.
+fn capture_path(
+    path: &Path,
+    output_dir: &Path,
+    scene_name: &str,
+    width: u32,
+    height: u32,
+    fps: u32,
+) -> PathBuf {
+    let suffix = format!("{width}x{height}_{fps}");
+    if !path.is_absolute() {
+        return output_dir.join(format!("{scene_name}_{suffix}")).join(path);
+    }
+    let stem = path
+        .file_stem()
+        .map(|s| s.to_string_lossy().to_string())
+        .unwrap_or_default();
+    let file_name = match path.extension() {
+        Some(ext) => format!("{stem}_{suffix}.{}", ext.to_string_lossy()),
+        None => format!("{stem}_{suffix}"),
+    };
+    path.with_file_name(file_name)
+}
+
+/// Add a `_<n>` suffix to the file stem of `path` until it is not in `used`
+fn unique_path(path: PathBuf, used: &HashSet<PathBuf>) -> PathBuf {
+    if !used.contains(&path) {
+        return path;
+    }
+    let stem = path
+        .file_stem()
+        .map(|s| s.to_string_lossy().to_string())
+        .unwrap_or_default();
+    let ext = path.extension().map(|s| s.to_string_lossy().to_string());
+    (1..)
+        .map(|n| {
+            let file_name = match &ext {
+                Some(ext) => format!("{stem}_{n}.{ext}"),
+                None => format!("{stem}_{n}"),
+            };
+            path.with_file_name(file_name)
+        })
+        .find(|p| !used.contains(p))
+        .unwrap()
+}
+
+/// Makes `path` absolute against the current directory.
+///
+/// If the current directory can't be read, it logs the error and keeps `path` as is.
+fn absolute_path(path: impl AsRef<Path>) -> PathBuf {
+    let path = path.as_ref();
+    std::path::absolute(path).unwrap_or_else(|err| {
+        warn!("Failed to make {path:?} absolute, using it as is: {err}");
+        path.to_path_buf()
+    })
+}
+
+/// The metadata of a capture as JSON
+fn capture_metadata_json(
+    scene_name: &str,
+    sec: f64,
+    width: u32,
+    height: u32,
+    camera_frame: &CameraFrame,
+) -> String {
+    let vec3 = |v: ranim_core::glam::DVec3| [v.x, v.y, v.z];
+    let json = serde_json::json!({
+        "scene": scene_name,
+        "sec": sec,
+        "width": width,
+        "height": height,
+        "camera": {
+            "pos": vec3(camera_frame.pos),
+            "up": vec3(camera_frame.up),
+            "facing": vec3(camera_frame.facing),
+            "frame_height": camera_frame.frame_height,
+            "scale": camera_frame.scale,
+            "perspective_blend": camera_frame.perspective_blend,
+            "fovy": camera_frame.fovy,
+        }
+    });
+    format!("{json:#}\n")
+}
+
 // MARK: Download ffmpeg
 const FFMPEG_RELEASE_URL: &str = "https://github.com/eugeneware/ffmpeg-static/releases/latest";
 
@@ -548,3 +719,83 @@ pub fn download_ffmpeg(target_dir: impl AsRef<Path>) -> Result<PathBuf, anyhow::
     info!("ffmpeg downloaded to {target_dir:?}");
     Ok(ffmpeg_path)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_clip_time_range() {
+        assert_eq!(clip_time_range(None, 5.0), 0.0..5.0);
+        assert_eq!(clip_time_range(Some(1.0..2.0), 5.0), 1.0..2.0);
+        assert_eq!(clip_time_range(Some(-1.0..8.0), 5.0), 0.0..5.0);
+        assert_eq!(clip_time_range(Some(6.0..8.0), 5.0), 0.0..5.0);
+        assert_eq!(clip_time_range(Some(2.0..2.0), 5.0), 0.0..5.0);
+    }
+
+    #[test]
+    fn test_frame_secs_clip() {
+        // a 1 second clip of a 5 seconds scene
+        let secs = frame_secs(clip_time_range(Some(2.0..3.0), 5.0), 30.0);
+        assert_eq!(secs.len(), 30);
+        assert_eq!(secs[0], 2.0);
+        assert!((secs[29] - (2.0 + 29.0 / 30.0)).abs() < 1e-9);
+
+        // the end is sampled exactly if it doesn't land on a frame
+        let secs = frame_secs(2.0..3.01, 30.0);
+        assert_eq!(secs.len(), 32);
+        assert_eq!(*secs.last().unwrap(), 3.01);
+
+        // the whole scene renders the same frames as before
+        let secs = frame_secs(clip_time_range(None, 5.0), 60.0);
+        assert_eq!(secs.len(), 300);
+        assert_eq!(secs[0], 0.0);
+    }
+
+    #[test]
+    fn test_absolute_path() {
+        let output_dir = std::env::current_dir().unwrap().join("output");
+        assert_eq!(absolute_path("output"), output_dir);
+        assert_eq!(absolute_path(&output_dir), output_dir);
+    }
+
+    #[test]
+    fn test_capture_path() {
+        let output_dir = absolute_path("./output");
+        let path = |path: &str, width, height, fps| {
+            capture_path(Path::new(path), &output_dir, "scene", width, height, fps)
+        };
+        assert_eq!(
+            path("capture.png", 1920, 1080, 60),
+            output_dir.join("scene_1920x1080_60/capture.png")
+        );
+        let dir = absolute_path("captures");
+        let capture = dir.join("capture.png");
+        let capture = capture.to_str().unwrap();
+        assert_eq!(
+            path(capture, 1920, 1080, 60),
+            dir.join("capture_1920x1080_60.png")
+        );
+        // Different outputs sharing the dir don't collide
+        assert_ne!(path(capture, 1920, 1080, 60), path(capture, 1280, 720, 60));
+        assert_ne!(path(capture, 1920, 1080, 60), path(capture, 1920, 1080, 30));
+
+        let used = HashSet::from([dir.join("capture_1920x1080_60.png")]);
+        assert_eq!(
+            unique_path(path(capture, 1920, 1080, 60), &used),
+            dir.join("capture_1920x1080_60_1.png")
+        );
+    }
+
+    #[test]
+    fn test_capture_metadata_json() {
+        let json =
+            capture_metadata_json("a \"quoted\"\nscene", 1.5, 1920, 1080, &CameraFrame::new());
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(value["scene"], "a \"quoted\"\nscene");
+        assert_eq!(value["sec"], 1.5);
+        assert_eq!(value["width"], 1920);
+        assert_eq!(value["camera"]["pos"], serde_json::json!([0.0, 0.0, 0.0]));
+        assert_eq!(value["camera"]["frame_height"], 8.0);
+    }
+}
@@ -47,11 +47,15 @@ pub use link_magic::*;
 mod scene;
 pub use scene::*;
 
+/// Animations across item types
+#[cfg(all(feature = "anims", feature = "items"))]
+pub mod transform;
+
 pub use core::glam;
 pub use ranim_core::RanimScene;
 
 /// The preludes
 pub mod prelude {
     pub use ranim_core::prelude::*;
-    pub use ranim_macros::{output, scene, wasm_demo_doc};
+    pub use ranim_macros::{output, preview, scene, wasm_demo_doc};
 }
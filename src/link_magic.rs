@@ -1,5 +1,5 @@
 //! Scene types for dylib / inventory registration and runtime use.
-use crate::{Output, OutputFormat, Scene, SceneConfig};
+use crate::{BackgroundFit, Output, OutputBackground, OutputFormat, Scene, SceneConfig};
 use ranim_core::RanimScene;
 
 #[cfg(target_arch = "wasm32")]
@@ -16,6 +16,8 @@ pub struct StaticScene {
     pub config: StaticSceneConfig,
     /// Scene outputs
     pub outputs: &'static [StaticOutput],
+    /// Whether the scene is marked with `#[preview]`
+    pub preview: bool,
 }
 
 /// Static scene config for inventory registration
@@ -23,6 +25,10 @@ pub struct StaticScene {
 pub struct StaticSceneConfig {
     /// The clear color
     pub clear_color: &'static str,
+    /// The minimum on-screen stroke width in pixels
+    pub min_stroke_width_px: f32,
+    /// The frame width in world units
+    pub frame_width: Option<f64>,
 }
 
 /// Static output for inventory registration
@@ -42,6 +48,12 @@ pub struct StaticOutput {
     pub dir: &'static str,
     /// The output format
     pub format: OutputFormat,
+    /// Whether to write metadata files for captures
+    pub capture_metadata: bool,
+    /// The path of the background image or video
+    pub background: Option<&'static str>,
+    /// How to fit the background
+    pub background_fit: BackgroundFit,
 }
 
 impl StaticOutput {
@@ -54,6 +66,9 @@ impl StaticOutput {
         name: None,
         dir: "./output",
         format: OutputFormat::Mp4,
+        capture_metadata: false,
+        background: None,
+        background_fit: BackgroundFit::Cover,
     };
 }
 
@@ -96,6 +111,7 @@ impl From<&StaticScene> for Scene {
             constructor: s.constructor,
             config: SceneConfig::from(&s.config),
             outputs: s.outputs.iter().map(Output::from).collect(),
+            preview: s.preview,
         }
     }
 }
@@ -104,6 +120,8 @@ impl From<&StaticSceneConfig> for SceneConfig {
     fn from(c: &StaticSceneConfig) -> Self {
         Self {
             clear_color: c.clear_color.to_string(),
+            min_stroke_width_px: c.min_stroke_width_px,
+            frame_width: c.frame_width,
         }
     }
 }
@@ -118,6 +136,17 @@ impl From<&StaticOutput> for Output {
             name: o.name.map(|n| n.to_string()),
             dir: o.dir.to_string(),
             format: o.format,
+            capture_metadata: o.capture_metadata,
+            background: o.background.map(|path| OutputBackground {
+                path: path.to_string(),
+                fit: o.background_fit,
+            }),
+            time_range: None,
+            codec: None,
+            crf: None,
+            bitrate: None,
+            pixel_format: None,
+            ghosts: false,
         }
     }
 }
@@ -3,7 +3,10 @@
 //! These types describe *what* to render (scene metadata, output settings)
 //! rather than *how* to animate (which lives in `ranim-core`).
 
-use std::sync::Arc;
+use std::{ops::Range, sync::Arc};
+
+use anyhow::bail;
+use itertools::Itertools;
 
 use ranim_core::{RanimScene, SealedRanimScene};
 
@@ -27,6 +30,9 @@ pub struct Scene {
     /// Scene outputs
     #[cfg_attr(target_arch = "wasm32", wasm_bindgen(skip))]
     pub outputs: Vec<Output>,
+    /// Whether the scene is marked with `#[preview]`, see [`crate::prelude::preview`]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(skip))]
+    pub preview: bool,
 }
 
 /// Scene config
@@ -34,12 +40,20 @@ pub struct Scene {
 pub struct SceneConfig {
     /// The clear color
     pub clear_color: String,
+    /// The minimum on-screen stroke width in pixels, `0.0` means no clamping
+    pub min_stroke_width_px: f32,
+    /// The frame width in world units, `None` means using the camera's frame height.
+    ///
+    /// When set, the frame height is derived from it and the output's aspect ratio.
+    pub frame_width: Option<f64>,
 }
 
 impl Default for SceneConfig {
     fn default() -> Self {
         Self {
             clear_color: "#333333ff".to_string(),
+            min_stroke_width_px: 0.0,
+            frame_width: None,
         }
     }
 }
@@ -69,6 +83,96 @@ impl std::fmt::Display for OutputFormat {
     }
 }
 
+/// The video codec of an [`Output`], see [`Output::codec`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VideoCodec {
+    /// H.264, for [`OutputFormat::Mp4`]
+    H264,
+    /// H.265, for [`OutputFormat::Mp4`], smaller files at the same quality but slower to encode
+    H265,
+    /// VP9, for [`OutputFormat::Webm`]
+    Vp9,
+}
+
+impl VideoCodec {
+    /// The ffmpeg encoder of the codec
+    pub fn encoder(&self) -> &'static str {
+        match self {
+            Self::H264 => "libx264",
+            Self::H265 => "libx265",
+            Self::Vp9 => "libvpx-vp9",
+        }
+    }
+    /// The maximum crf of the codec, `0` is the best quality
+    pub fn max_crf(&self) -> u32 {
+        match self {
+            Self::H264 | Self::H265 => 51,
+            Self::Vp9 => 63,
+        }
+    }
+    /// The codecs that can be used with the format
+    pub fn supported_by(format: OutputFormat) -> &'static [Self] {
+        match format {
+            OutputFormat::Mp4 => &[Self::H264, Self::H265],
+            OutputFormat::Webm => &[Self::Vp9],
+            OutputFormat::Mov | OutputFormat::Gif => &[],
+        }
+    }
+}
+
+impl std::fmt::Display for VideoCodec {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::H264 => write!(f, "h264"),
+            Self::H265 => write!(f, "h265"),
+            Self::Vp9 => write!(f, "vp9"),
+        }
+    }
+}
+
+impl std::str::FromStr for VideoCodec {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "h264" | "avc" => Ok(Self::H264),
+            "h265" | "hevc" => Ok(Self::H265),
+            "vp9" => Ok(Self::Vp9),
+            _ => bail!("unsupported video codec `{s}`, expected one of `h264`, `h265` and `vp9`"),
+        }
+    }
+}
+
+/// How an [`OutputBackground`] is fitted into the output resolution
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BackgroundFit {
+    /// Scale to cover the whole frame keeping the aspect ratio, the overflow is cropped (default)
+    #[default]
+    Cover,
+    /// Scale to fit in the frame keeping the aspect ratio, the rest is transparent
+    Contain,
+    /// Stretch to the frame size, ignoring the aspect ratio
+    Stretch,
+}
+
+/// A static image or a video composited under the rendered frames at encode time.
+///
+/// The rendered frames (including the scene's `clear_color`) are drawn over it,
+/// so it only shows through the transparent areas: use a transparent clear color
+/// like `#00000000` to show it everywhere nothing is drawn.
+///
+/// A video background is looped if it is shorter than the scene.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OutputBackground {
+    /// The path of the image or video, can be relative (resolved from cwd) or absolute.
+    ///
+    /// Files with an image extension (`png`, `jpg`, `jpeg`, `bmp`, `webp`, `tiff`) are
+    /// treated as images, others as videos.
+    pub path: String,
+    /// How to fit it into the output resolution
+    pub fit: BackgroundFit,
+}
+
 /// The output of a scene
 #[derive(Debug, Clone)]
 pub struct Output {
@@ -90,6 +194,168 @@ pub struct Output {
     pub dir: String,
     /// The output video format.
     pub format: OutputFormat,
+    /// Whether to write a `<capture>.json` metadata file next to each captured image
+    /// of [`ranim_core::TimeMark::Capture`], with the scene name, second, resolution and camera.
+    pub capture_metadata: bool,
+    /// The background to composite the frames over, see [`OutputBackground`].
+    pub background: Option<OutputBackground>,
+    /// Only render the frames in this range of secs instead of the whole scene.
+    ///
+    /// The range is clamped to the scene's duration, and the frames of the video
+    /// (and the saved frames) are numbered from its start.
+    pub time_range: Option<Range<f64>>,
+    /// The video codec, `None` uses the default codec of the [`OutputFormat`].
+    ///
+    /// See [`VideoCodec::supported_by`] for the codecs of each format.
+    pub codec: Option<VideoCodec>,
+    /// The constant rate factor of the codec, lower is better quality and larger files.
+    ///
+    /// `None` uses the default of ffmpeg, conflicts with [`Output::bitrate`].
+    pub crf: Option<u32>,
+    /// The target bitrate of the video in kbps, conflicts with [`Output::crf`].
+    pub bitrate: Option<u32>,
+    /// The ffmpeg pixel format like `yuv444p`, `None` uses the default of the [`OutputFormat`].
+    pub pixel_format: Option<String>,
+    /// Whether to render the ghosts of the anims, see
+    /// [`ranim_core::animation::AnimationCell::with_ghosts`].
+    ///
+    /// They are an authoring aid, so they are only shown in the preview app by default.
+    pub ghosts: bool,
+}
+
+impl Output {
+    /// 16:9 landscape, 1920x1080
+    pub fn landscape() -> Self {
+        Self::default().with_aspect(16, 9)
+    }
+    /// 9:16 portrait for shorts/reels, 1080x1920
+    pub fn portrait() -> Self {
+        Self::default().with_aspect(9, 16)
+    }
+    /// 1:1 square, 1080x1080
+    pub fn square() -> Self {
+        Self::default().with_aspect(1, 1)
+    }
+    /// 4:3 standard, 1440x1080
+    pub fn standard() -> Self {
+        Self::default().with_aspect(4, 3)
+    }
+    /// Change the width and height to the aspect ratio `w:h`, keeping the shorter side.
+    ///
+    /// # Panics
+    /// Panics if `w` or `h` is zero.
+    pub fn with_aspect(mut self, w: u32, h: u32) -> Self {
+        assert!(
+            w > 0 && h > 0,
+            "invalid aspect ratio {w}:{h}, both sides must be non-zero"
+        );
+        let short = self.width.min(self.height);
+        (self.width, self.height) = if w >= h {
+            (short * w / h, short)
+        } else {
+            (short, short * h / w)
+        };
+        self
+    }
+    /// Composite the frames over the image or video at `path`, see [`OutputBackground`].
+    pub fn with_background(mut self, path: impl Into<String>, fit: BackgroundFit) -> Self {
+        self.background = Some(OutputBackground {
+            path: path.into(),
+            fit,
+        });
+        self
+    }
+    /// Only render the frames in `range` of secs, see [`Output::time_range`].
+    pub fn with_time_range(mut self, range: Range<f64>) -> Self {
+        self.time_range = Some(range);
+        self
+    }
+    /// Encode with the given codec, see [`Output::codec`].
+    pub fn with_codec(mut self, codec: VideoCodec) -> Self {
+        self.codec = Some(codec);
+        self
+    }
+    /// Encode with the given constant rate factor, see [`Output::crf`].
+    pub fn with_crf(mut self, crf: u32) -> Self {
+        self.crf = Some(crf);
+        self
+    }
+    /// Encode with the given bitrate in kbps, see [`Output::bitrate`].
+    pub fn with_bitrate(mut self, kbps: u32) -> Self {
+        self.bitrate = Some(kbps);
+        self
+    }
+    /// Encode with the given ffmpeg pixel format, see [`Output::pixel_format`].
+    pub fn with_pixel_format(mut self, pixel_format: impl Into<String>) -> Self {
+        self.pixel_format = Some(pixel_format.into());
+        self
+    }
+    /// Render the ghosts of the anims, see [`Output::ghosts`].
+    pub fn with_ghosts(mut self, enabled: bool) -> Self {
+        self.ghosts = enabled;
+        self
+    }
+    /// Check that the encoding options ([`Output::codec`], [`Output::crf`] and [`Output::bitrate`])
+    /// are supported by the [`OutputFormat`].
+    pub fn validate_encoding(&self) -> anyhow::Result<()> {
+        let format = self.format;
+        if let Some(codec) = self.codec {
+            let supported = VideoCodec::supported_by(format);
+            if !supported.contains(&codec) {
+                match supported {
+                    [] => bail!(
+                        "the {format} format doesn't support choosing the codec, got `{codec}`"
+                    ),
+                    _ => bail!(
+                        "the {format} format doesn't support the `{codec}` codec, expected one of {}",
+                        supported.iter().map(|c| format!("`{c}`")).join(", ")
+                    ),
+                }
+            }
+        }
+        if matches!(format, OutputFormat::Mov | OutputFormat::Gif)
+            && (self.crf.is_some() || self.bitrate.is_some())
+        {
+            bail!("the {format} format doesn't support setting the crf or the bitrate");
+        }
+        if self.crf.is_some() && self.bitrate.is_some() {
+            bail!("the crf and the bitrate can't be set at the same time");
+        }
+        if let Some(crf) = self.crf {
+            let codec = self.codec.unwrap_or(VideoCodec::supported_by(format)[0]);
+            if crf > codec.max_crf() {
+                bail!(
+                    "the crf of the `{codec}` codec should be in 0..={}, got {crf}",
+                    codec.max_crf()
+                );
+            }
+        }
+        if self.bitrate == Some(0) {
+            bail!("the bitrate should be positive");
+        }
+        Ok(())
+    }
+    /// The file name of the video rendered for the scene of the given name,
+    /// `<name>_<width>x<height>_<fps>.<ext>`
+    ///
+    /// With a [`Output::time_range`], the range is appended to the name as
+    /// `<name>_<width>x<height>_<fps>_<start>-<end>s.<ext>`, so the outputs of
+    /// different ranges don't overwrite each other.
+    pub fn file_name(&self, scene_name: &str) -> String {
+        let range = self
+            .time_range
+            .as_ref()
+            .map(|range| format!("_{}-{}s", range.start, range.end))
+            .unwrap_or_default();
+        format!(
+            "{}_{}x{}_{}{range}.{}",
+            self.name.as_deref().unwrap_or(scene_name),
+            self.width,
+            self.height,
+            self.fps,
+            self.format
+        )
+    }
 }
 
 impl Default for Output {
@@ -102,6 +368,14 @@ impl Default for Output {
             name: None,
             dir: "./output".to_string(),
             format: OutputFormat::default(),
+            capture_metadata: false,
+            background: None,
+            time_range: None,
+            codec: None,
+            crf: None,
+            bitrate: None,
+            pixel_format: None,
+            ghosts: false,
         }
     }
 }
@@ -136,3 +410,79 @@ impl SceneConstructor for Arc<dyn SceneConstructor> {
         self.as_ref().construct(r)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_with_aspect() {
+        let output = Output::default().with_aspect(16, 9);
+        assert_eq!((output.width, output.height), (1920, 1080));
+        let output = Output::default().with_aspect(9, 16);
+        assert_eq!((output.width, output.height), (1080, 1920));
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid aspect ratio 0:9")]
+    fn test_with_aspect_zero() {
+        let _ = Output::default().with_aspect(0, 9);
+    }
+
+    #[test]
+    fn test_file_name() {
+        let output = Output::default();
+        assert_eq!(output.file_name("foo"), "foo_1920x1080_60.mp4");
+        let output = output.with_time_range(1.5..3.0);
+        assert_eq!(output.file_name("foo"), "foo_1920x1080_60_1.5-3s.mp4");
+    }
+
+    #[test]
+    fn test_validate_encoding() {
+        assert!(Output::default().validate_encoding().is_ok());
+        let output = Output::default().with_codec(VideoCodec::H265).with_crf(28);
+        assert!(output.validate_encoding().is_ok());
+
+        let err = "h266".parse::<VideoCodec>().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "unsupported video codec `h266`, expected one of `h264`, `h265` and `vp9`"
+        );
+        assert_eq!("HEVC".parse::<VideoCodec>().unwrap(), VideoCodec::H265);
+
+        let err = Output::default()
+            .with_codec(VideoCodec::Vp9)
+            .validate_encoding()
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "the mp4 format doesn't support the `vp9` codec, expected one of `h264`, `h265`"
+        );
+
+        let mov = Output {
+            format: OutputFormat::Mov,
+            ..Default::default()
+        };
+        assert!(
+            mov.clone()
+                .with_codec(VideoCodec::H264)
+                .validate_encoding()
+                .is_err()
+        );
+        assert!(mov.with_crf(20).validate_encoding().is_err());
+        assert!(
+            Output::default()
+                .with_crf(20)
+                .with_bitrate(8000)
+                .validate_encoding()
+                .is_err()
+        );
+        // The default codec of mp4 is h264, whose crf is at most 51
+        assert!(Output::default().with_crf(52).validate_encoding().is_err());
+        let webm = Output {
+            format: OutputFormat::Webm,
+            ..Default::default()
+        };
+        assert!(webm.with_crf(60).validate_encoding().is_ok());
+    }
+}
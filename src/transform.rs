@@ -0,0 +1,65 @@
+use ranim_anims::morph::MorphAnim;
+use ranim_core::{ItemId, RanimScene, core_item::AnyExtractCoreItem};
+use ranim_items::vitem::VItem;
+
+/// Morphing an item into an item of another type.
+///
+/// [`MorphAnim::morph_to`] needs both ends to be of the same type, so to morph a `Square`
+/// into a `Circle` both of them have to be converted into [`VItem`] first, and the id of the
+/// timeline has to be mapped with [`ItemId::map`]. This trait does it under the hood:
+///
+/// ```
+/// use ranim::{
+///     anims::{creation::WritingAnim, fading::FadingAnim},
+///     color::palettes::manim,
+///     items::vitem::{
+///         VItem,
+///         geometry::{Circle, Square},
+///     },
+///     prelude::*,
+///     transform::MorphToAnim,
+/// };
+///
+/// let mut r = RanimScene::new();
+/// let _r_cam = r.insert(CameraFrame::default());
+///
+/// let mut square = Square::new(2.0);
+/// square.set_color(manim::BLUE_C);
+/// let r_square = r.insert(square);
+/// r.timeline_mut(r_square).forward(1.0);
+///
+/// let mut circle = Circle::new(2.0);
+/// circle.set_color(manim::RED_C);
+/// // The timeline continues as a `VItem`
+/// let r_vitem: ItemId<VItem> = r.morph_to(r_square, circle);
+/// r.timeline_mut(r_vitem)
+///     .forward(1.0)
+///     .play_with(|mut vitem: VItem| vitem.unwrite())
+///     .play_with(|mut vitem: VItem| vitem.write())
+///     .play_with(|mut vitem: VItem| vitem.fade_out());
+/// assert_eq!(r.timeline(r_vitem).cur_sec(), 6.0);
+/// ```
+pub trait MorphToAnim {
+    /// Play a [`MorphAnim::morph_to`] anim from the current state of the item of `id` to
+    /// `target` as [`VItem`]s, and return the id of the timeline as an [`ItemId<VItem>`].
+    ///
+    /// # Panics
+    /// Panics if nothing is played on the timeline yet.
+    fn morph_to<T, U>(&mut self, id: ItemId<T>, target: U) -> ItemId<VItem>
+    where
+        T: AnyExtractCoreItem + Into<VItem>,
+        U: Into<VItem>;
+}
+
+impl MorphToAnim for RanimScene {
+    fn morph_to<T, U>(&mut self, id: ItemId<T>, target: U) -> ItemId<VItem>
+    where
+        T: AnyExtractCoreItem + Into<VItem>,
+        U: Into<VItem>,
+    {
+        let timeline = self.timeline_mut(id);
+        let mut vitem: VItem = timeline.cur_state::<T>().into();
+        timeline.play(vitem.morph_to(target.into()));
+        id.map()
+    }
+}